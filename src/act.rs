@@ -4,12 +4,19 @@ use crate::{
     game::{
         QUESTION_MONSTER_BASE_WEIGHT, QUESTION_SHOP_BASE_WEIGHT, QUESTION_TREASURE_BASE_WEIGHT,
         encounter::Encounter,
+        event::{
+            EventName, ONE_TIME_SHRINES, act_1_events, act_1_shrines, act_2_events, act_2_shrines,
+            act_3_events, act_3_shrines,
+        },
     },
-    map::RoomType,
+    map::{NUM_FLOORS, RoomType},
+    rng::Rng,
 };
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Act {
+    //Acts are numbered from 1.
+    pub number: i32,
     pub question_monster_weight: i32,
     pub question_shop_weight: i32,
     pub question_treasure_weight: i32,
@@ -19,15 +26,71 @@ pub struct Act {
     pub room_history: Vec<RoomType>,
     pub prior_fights: [Option<Encounter>; 2],
     pub position: Option<MapPosition>,
+    //The percent chance that non-rare card rewards come upgraded.
+    pub card_upgrade_chance: i32,
+    //Events and shrines which haven't been seen yet.
+    pub events: Vec<EventName>,
+    pub shrines: Vec<EventName>,
+    //The boss is chosen at the start of the act and is visible on the map.
+    pub boss: Encounter,
+    //Ascension 20 adds a second boss to Act 3, fought after the first one's rewards.
+    pub second_boss: Option<Encounter>,
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct MapPosition {
     pub x: i32,
     pub y: i32,
 }
+fn act_shrines(number: i32) -> Vec<EventName> {
+    match number {
+        1 => act_1_shrines(),
+        2 => act_2_shrines(),
+        3 => act_3_shrines(),
+        //Act 4 has no question mark rooms.
+        4 => vec![],
+        _ => panic!("Act {} isn't implemented", number),
+    }
+}
+
 impl Act {
-    pub fn new() -> Act {
+    pub fn new(rng: &mut Rng, number: i32, ascension: i32) -> Act {
+        let (bosses, events): (&[Encounter], _) = match number {
+            1 => (
+                &[
+                    Encounter::TheGuardian,
+                    Encounter::Hexaghost,
+                    Encounter::SlimeBoss,
+                ],
+                act_1_events(),
+            ),
+            2 => (
+                &[
+                    Encounter::Champ,
+                    Encounter::TheCollector,
+                    Encounter::BronzeAutomaton,
+                ],
+                act_2_events(),
+            ),
+            3 => (
+                &[
+                    Encounter::AwakenedOne,
+                    Encounter::TimeEater,
+                    Encounter::DonuAndDeca,
+                ],
+                act_3_events(),
+            ),
+            4 => (&[Encounter::CorruptHeart], vec![]),
+            _ => panic!("Act {} isn't implemented", number),
+        };
+        let boss = rng.sample(bosses.len());
+        let second_boss = if ascension >= 20 && number == 3 {
+            let second = (boss + 1 + rng.sample(bosses.len() - 1)) % bosses.len();
+            Some(bosses[second])
+        } else {
+            None
+        };
         Self {
+            number,
             question_monster_weight: QUESTION_MONSTER_BASE_WEIGHT,
             question_shop_weight: QUESTION_SHOP_BASE_WEIGHT,
             question_treasure_weight: QUESTION_TREASURE_BASE_WEIGHT,
@@ -37,6 +100,36 @@ impl Act {
             room_history: Vec::new(),
             prior_fights: [None, None],
             position: None,
+            //Ascension 12 halves the chance.
+            card_upgrade_chance: match (number, ascension >= 12) {
+                (1, _) => 0,
+                (2, false) => 25,
+                (2, true) => 12,
+                (_, false) => 50,
+                (_, true) => 25,
+            },
+            events,
+            shrines: act_shrines(number),
+            boss: bosses[boss],
+            second_boss,
         }
     }
+
+    //Shrines shared between acts can only be seen once per run. A shrine missing
+    //from this act's pool was either seen or already filtered out by an earlier act.
+    pub fn next(&self, rng: &mut Rng, ascension: i32) -> Act {
+        let mut act = Act::new(rng, self.number + 1, ascension);
+        let previous = act_shrines(self.number);
+        act.shrines.retain(|shrine| {
+            !ONE_TIME_SHRINES.contains(shrine)
+                || !previous.contains(shrine)
+                || self.shrines.contains(shrine)
+        });
+        act
+    }
+
+    //Floors are counted across the run. Each earlier act had its map floors, the boss and the boss chest.
+    pub fn floor_offset(&self) -> i32 {
+        (self.number - 1) * (NUM_FLOORS as i32 + 2)
+    }
 }
@@ -1,7 +1,7 @@
 use std::{
     collections::HashMap,
     fmt::Write,
-    hash::{DefaultHasher, Hash, Hasher},
+    hash::{DefaultHasher, Hasher},
 };
 
 use crate::{
@@ -99,9 +99,11 @@ impl MctsEntry {
     }
 }
 
+//Only what the player can observe is hashed so that determinized states with
+//different hidden intents share their statistics.
 fn hash_choice_state(state: &ChoiceState) -> u64 {
     let mut s = DefaultHasher::new();
-    state.hash(&mut s);
+    state.hash_observation(&mut s);
     s.finish()
 }
 
@@ -110,7 +112,7 @@ fn mcts(state: &ChoiceState, rng: &mut Rng) -> usize {
     //This should be changed to an identity hasher.
     let mut value_map: HashMap<u64, MctsEntry> = HashMap::new();
     //This will be overwritten.
-    let mut temp_game = Game::new(crate::game::Character::IRONCLAD).start();
+    let mut temp_game = Game::new(crate::game::Character::IRONCLAD, 0).start();
     let state_hash = hash_choice_state(&state);
     for i in 0..MCTS_ITERATIONS {
         state.clone_to_reseeded(&mut temp_game);
@@ -149,10 +151,13 @@ fn mcts_rollout(
         //Check if the game is over before computing any hashes
         let num_actions = match &state.choice() {
             Choice::Win => {
-                break state.game().act().position.map(|p| p.y).unwrap_or_default();
+                //Beating the boss is worth one more than reaching its floor.
+                let act = state.game().act();
+                break act.floor_offset() + act.position.map(|p| p.y + 1).unwrap_or_default();
             }
             Choice::Loss => {
-                break state.game().act().position.map(|p| p.y).unwrap_or_default();
+                let act = state.game().act();
+                break act.floor_offset() + act.position.map(|p| p.y).unwrap_or_default();
             }
             _ => state.num_actions(),
         };
@@ -3,7 +3,7 @@ use std::cmp::max;
 use serde::{Deserialize, Serialize};
 use strum::VariantArray;
 
-use crate::rng::Rng;
+use crate::{orb::Orb, rng::Rng, stance::Stance};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Card {
@@ -12,6 +12,8 @@ pub struct Card {
     pub assoc_data: CardAssoc,
     pub temp_cost: Option<i32>,
     upgraded: bool,
+    //Bottled cards start every fight in hand.
+    pub bottled: bool,
 }
 
 //In order to have the CardBody enum be trivially constructable the
@@ -22,7 +24,9 @@ pub struct Card {
 pub enum CardAssoc {
     None,
     UnlimitedUpgrade(i32), //Used for Searing Blow
-    BonusDamage(i32),      //Used for Rampage
+    BonusDamage(i32),      //Used for Rampage and Ritual Dagger
+    BonusBlock(i32),       //Used for Steam Barrier and Genetic Algorithm
+    Hits(i32),             //Used for Expunger
 }
 
 impl CardAssoc {
@@ -38,13 +42,36 @@ impl CardAssoc {
         };
         *amount
     }
+    pub fn get_bonus_block(&self) -> i32 {
+        let Self::BonusBlock(amount) = self else {
+            panic!("Expected bonus block data");
+        };
+        *amount
+    }
+    pub fn get_hits(&self) -> i32 {
+        let Self::Hits(amount) = self else {
+            panic!("Expected hits data");
+        };
+        *amount
+    }
+    //Used for cards like Ritual Dagger and Steam Barrier which change each time they're played.
+    pub fn grow(&self, amount: i32) -> CardAssoc {
+        match self {
+            Self::BonusDamage(bonus) => Self::BonusDamage(bonus + amount),
+            Self::BonusBlock(bonus) => Self::BonusBlock(bonus + amount),
+            _ => panic!("Expected bonus damage or block data"),
+        }
+    }
 }
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Cost {
     Unplayable,
     Fixed(i32),
     X,
-    NumMinusHpLoss(i32), //This is for Blood for Blood
+    NumMinusHpLoss(i32),   //This is for Blood for Blood
+    NumPlusHpLoss(i32),    //This is for Masterful Stab
+    NumMinusDiscards(i32), //This is for Eviscerate
+    NumMinusPowers(i32),   //This is for Force Field
 }
 #[derive(
     Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, VariantArray, Serialize, Deserialize,
@@ -133,6 +160,279 @@ pub enum CardBody {
     Injury,
     Parasite,
     Doubt,
+    Decay,
+    Pain,
+    Shame,
+    Writhe,
+    AscendersBane,
+    Apparition,
+    Bite,
+    JAX,
+    RitualDagger,
+    Void,
+    Neutralize,
+    Survivor,
+    Acrobatics,
+    Backflip,
+    Bane,
+    BladeDance,
+    CloakAndDagger,
+    DaggerSpray,
+    DaggerThrow,
+    DeadlyPoison,
+    Deflect,
+    DodgeAndRoll,
+    FlyingKnee,
+    Outmaneuver,
+    PiercingWail,
+    PoisonedStab,
+    Prepared,
+    QuickSlash,
+    Slice,
+    SneakyStrike,
+    SuckerPunch,
+    Accuracy,
+    AllOutAttack,
+    Backstab,
+    Blur,
+    BouncingFlask,
+    CalculatedGamble,
+    Caltrops,
+    Catalyst,
+    Choke,
+    Concentrate,
+    CripplingCloud,
+    Dash,
+    Distraction,
+    EndlessAgony,
+    EscapePlan,
+    Eviscerate,
+    Expertise,
+    Finisher,
+    Flechettes,
+    Footwork,
+    HeelHook,
+    InfiniteBlades,
+    LegSweep,
+    MasterfulStab,
+    NoxiousFumes,
+    Predator,
+    Reflex,
+    RiddleWithHoles,
+    Setup,
+    Skewer,
+    Tactician,
+    Terror,
+    WellLaidPlans,
+    AThousandCuts,
+    Adrenaline,
+    AfterImage,
+    Alchemize,
+    BulletTime,
+    Burst,
+    CorpseExplosion,
+    DieDieDie,
+    Doppelganger,
+    Envenom,
+    GlassKnife,
+    GrandFinale,
+    Malaise,
+    Nightmare,
+    PhantasmalKiller,
+    StormOfSteel,
+    ToolsOfTheTrade,
+    Unload,
+    WraithForm,
+    Shiv,
+    Zap,
+    Dualcast,
+    BallLightning,
+    Barrage,
+    BeamCell,
+    ChargeBattery,
+    Claw,
+    ColdSnap,
+    CompileDriver,
+    Coolheaded,
+    GoForTheEyes,
+    Hologram,
+    Leap,
+    Rebound,
+    Recursion,
+    Stack,
+    SteamBarrier,
+    Streamline,
+    SweepingBeam,
+    Turbo,
+    Aggregate,
+    AutoShields,
+    Blizzard,
+    BootSequence,
+    Bullseye,
+    Capacitor,
+    Chaos,
+    Chill,
+    Consume,
+    Darkness,
+    Defragment,
+    DoomAndGloom,
+    DoubleEnergy,
+    Equilibrium,
+    FTL,
+    ForceField,
+    Fusion,
+    GeneticAlgorithm,
+    Glacier,
+    Heatsinks,
+    HelloWorld,
+    Loop,
+    Melter,
+    Overclock,
+    Recycle,
+    ReinforcedBody,
+    Reprogram,
+    RipAndTear,
+    Scrape,
+    SelfRepair,
+    Skim,
+    StaticDischarge,
+    Storm,
+    Sunder,
+    Tempest,
+    WhiteNoise,
+    AllForOne,
+    Amplify,
+    BiasedCognition,
+    Buffer,
+    CoreSurge,
+    CreativeAI,
+    EchoForm,
+    Electrodynamics,
+    Fission,
+    Hyperbeam,
+    MachineLearning,
+    MeteorStrike,
+    MultiCast,
+    Rainbow,
+    Reboot,
+    Seek,
+    ThunderStrike,
+    Eruption,
+    Vigilance,
+    BowlingBash,
+    Consecrate,
+    Crescendo,
+    CrushJoints,
+    CutThroughFate,
+    EmptyBody,
+    EmptyFist,
+    Evaluate,
+    FlurryOfBlows,
+    FlyingSleeves,
+    FollowUp,
+    Halt,
+    JustLucky,
+    PressurePoints,
+    Prostrate,
+    Protect,
+    SashWhip,
+    ThirdEye,
+    Tranquility,
+    BattleHymn,
+    CarveReality,
+    Collect,
+    Conclude,
+    DeceiveReality,
+    EmptyMind,
+    Fasting,
+    FearNoEvil,
+    ForeignInfluence,
+    Foresight,
+    Indignation,
+    InnerPeace,
+    LikeWater,
+    Meditate,
+    MentalFortress,
+    Nirvana,
+    Perseverance,
+    Pray,
+    ReachHeaven,
+    Rushdown,
+    Sanctity,
+    SandsOfTime,
+    SignatureMove,
+    SimmeringFury,
+    Study,
+    Swivel,
+    TalkToTheHand,
+    Tantrum,
+    Wallop,
+    WaveOfTheHand,
+    Weave,
+    WheelKick,
+    WindmillStrike,
+    Worship,
+    WreathOfFlame,
+    Alpha,
+    Blasphemy,
+    Brilliance,
+    ConjureBlade,
+    DeusExMachina,
+    DevaForm,
+    Devotion,
+    Establishment,
+    Judgment,
+    LessonLearned,
+    MasterReality,
+    Omniscience,
+    Ragnarok,
+    Scrawl,
+    SpiritShield,
+    Vault,
+    Wish,
+    Miracle,
+    Insight,
+    Smite,
+    Safety,
+    ThroughViolence,
+    Expunger,
+    Beta,
+    Omega,
+    BecomeAlmighty,
+    FameAndFortune,
+    LiveForever,
+    Apotheosis,
+    BandageUp,
+    Blind,
+    Chrysalis,
+    DarkShackles,
+    DeepBreath,
+    Discovery,
+    DramaticEntrance,
+    Enlightenment,
+    Finesse,
+    FlashOfSteel,
+    GoodInstincts,
+    HandOfGreed,
+    Impatience,
+    JackOfAllTrades,
+    Madness,
+    Magnetism,
+    MasterOfStrategy,
+    Mayhem,
+    Metamorphosis,
+    MindBlast,
+    Panacea,
+    Panache,
+    PanicButton,
+    SecretTechnique,
+    SecretWeapon,
+    SwiftStrike,
+    TheBomb,
+    ThinkingAhead,
+    Transmutation,
+    Trip,
+    Violence,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -170,6 +470,104 @@ pub enum PlayEffect {
     AddCardToDiscard(CardBody),
     DoubleStrength,
     AttackAllForHP(i32),
+    Heal(i32),
+    AttackIfPoisoned(i32), //Used for Bane.
+    AttackX(i32),
+    AttackPerAttackPlayed(i32), //Used for Finisher.
+    AttackPerSkillInHand(i32),  //Used for Flechettes.
+    DebuffRandomEnemy(Debuff),
+    MultiplyPoison(i32),
+    DiscardRandomInHand,
+    DiscardHandAndDraw, //Used for Calculated Gamble.
+    DiscardNonAttacks,
+    //Storm of Steel, the Shivs are upgraded if the bool is set.
+    DiscardHandForShivs(bool),
+    GenerateSkillDistraction,
+    DrawBlockIfSkill(i32), //Used for Escape Plan.
+    DrawToHandSize(i32),
+    HeelHookDraw,
+    BlockNextTurn(i32),
+    GainEnergyIfDiscarded(i32), //Used for Sneaky Strike.
+    HandCostsZero,
+    Doppelganger(i32),
+    GainRandomPotion,
+    MalaiseDebuff(i32),
+    Channel(Orb),
+    ChannelRandomOrb,
+    ChannelFrostPerEnemy,   //Used for Chill.
+    ChannelLightningX(i32), //Used for Tempest.
+    Evoke(i32),             //Evokes the next orb this many times.
+    EvokeX(i32),            //Used for Multi-Cast.
+    EvokeAndRechannel,      //Used for Recursion.
+    TriggerDarkPassives,    //Used for Darkness.
+    Fission(bool),          //The orbs are evoked if the bool is set.
+    GainOrbSlots(i32),
+    AttackPerOrb(i32),             //Used for Barrage.
+    DrawPerUniqueOrb,              //Used for Compile Driver.
+    AttackAllPerFrost(i32),        //Used for Blizzard.
+    AttackRandomPerLightning(i32), //Used for Thunder Strike.
+    IncreaseClawDamage(i32),
+    WeakIfAttacking(i32), //Used for Go for the Eyes.
+    BlockPerDiscard(i32), //Used for Stack.
+    BlockPlusBonus(i32),
+    IncreaseBlock(i32),
+    //Genetic Algorithm's block increase carries over to the deck.
+    IncreaseBlockPermanently(i32),
+    ReduceOwnCost, //Used for Streamline.
+    Rebound,
+    EnergyPerDrawPile(i32), //Used for Aggregate.
+    BlockIfNoBlock(i32),    //Used for Auto-Shields.
+    DoubleEnergy,
+    DrawIfFewCardsPlayed(i32), //Used for FTL.
+    RemoveEnemyBlock,
+    //Scrape discards the cards it draws which don't cost 0.
+    ScrapeDraw(i32),
+    BlockX(i32),
+    GenerateRandomPower,   //Used for White Noise.
+    ZeroCostDiscardToHand, //Used for All for One.
+    Reboot(i32),
+    EnterStance(Stance),
+    Scry(i32),
+    GainMantra(i32),
+    AttackPerEnemy(i32), //Used for Bowling Bash.
+    //These check the type of the card played before this one.
+    VulnerableIfLastSkill(i32), //Used for Crush Joints.
+    EnergyIfLastAttack(i32),    //Used for Follow-Up.
+    WeakIfLastAttack(i32),      //Used for Sash Whip.
+    DrawIfLastSkill(i32),       //Used for Sanctity.
+    BlockIfWrath(i32),          //Used for Halt.
+    TriggerMarks,               //Used for Pressure Points.
+    CalmIfAttacking,            //Used for Fear No Evil.
+    Indignation(i32),
+    InnerPeace(i32),
+    //Ends the turn once the card finishes resolving.
+    EndTurn,
+    ExtraTurn,               //Used for Vault.
+    AttackGainBlock(i32),    //Used for Wallop.
+    ShuffleBack,             //Used for Tantrum.
+    BlockPerCardInHand(i32), //Used for Spirit Shield.
+    Judgment(i32),
+    ConjureBlade(i32),
+    AttackPerHit(i32), //Used for Expunger, the number of hits is stored on the card.
+    CollectX(i32),
+    GainGold(i32),
+    //Foreign Influence, the chosen card costs 0 this turn if the bool is set.
+    ChooseClassAttack(bool),
+    Wish,
+    UpgradeAllCards, //Used for Apotheosis.
+    //Chrysalis and Metamorphosis, the cards cost 0 for the rest of the fight.
+    ShuffleInFreeClassCards(CardType, i32),
+    ShuffleDiscardIntoDeck, //Used for Deep Breath.
+    Discovery,
+    //Enlightenment, the cost reduction lasts the whole fight if the bool is set.
+    Enlightenment(bool),
+    DrawIfNoAttacks(i32),    //Used for Impatience.
+    AddRandomColorless(i32), //Used for Jack of All Trades.
+    Madness,
+    AttackPerCardInDeck,    //Used for Mind Blast.
+    DeckAttacksToHand(i32), //Used for Violence.
+    //Transmutation, the cards are upgraded if the bool is set.
+    TransmutationX(bool),
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -178,6 +576,16 @@ pub enum LethalEffect {
     //An additional i32 would raise the overall size of the containing enum.
     Gain3MaxHP,
     Gain4MaxHP,
+    //Ritual Dagger permanently gains damage.
+    DaggerDamage3,
+    DaggerDamage5,
+    //Sunder refunds its cost.
+    Gain3Energy,
+    //Lesson Learned upgrades a random card in the deck.
+    UpgradeRandomCard,
+    //Hand of Greed gains gold.
+    Gain20Gold,
+    Gain25Gold,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -188,6 +596,26 @@ pub enum SelectCardEffect {
     HandToTop,
     DuplicatePowerOrAttack(i32),
     ExhaustToHand,
+    DiscardChosen,
+    //Setup puts the card on top of the draw pile and makes it free until played.
+    HandToTopFree,
+    Nightmare,
+    //Well-Laid Plans keeps the chosen card in hand at the end of the turn.
+    Retain,
+    DiscardToHand,
+    //Recycle exhausts the chosen card and gains energy equal to its cost.
+    ExhaustForEnergy,
+    //Seek puts the chosen card from the draw pile into the hand.
+    DeckToHand,
+    //Meditate keeps the chosen card from the discard pile in hand at the end of the turn.
+    DiscardToRetained,
+    //Omniscience plays the chosen card from the draw pile twice and exhausts it.
+    PlayTwiceFromDeck,
+    //These choose from the cards offered by Foreign Influence and Wish.
+    TakeOffered(bool),
+    PlayOffered,
+    //Secret Technique and Secret Weapon take a card of the type from the draw pile.
+    DeckTypeToHand(CardType),
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -201,6 +629,25 @@ pub enum Debuff {
     MinusDexterity(i32),
     Entangled,
     NoDraw,
+    Hex(i32),
+    Confused,
+    //Constricted deals damage at the end of each of the player's turns.
+    Constricted(i32),
+    //Draw less cards next turn.
+    DrawReduction(i32),
+    Poison(i32),
+    //Choked enemies lose HP whenever the player plays a card this turn.
+    Choked(i32),
+    CorpseExplosion,
+    LockOn(i32),
+    MinusFocus(i32),
+    //Pressure Points makes enemies lose HP equal to their Mark.
+    Mark(i32),
+    //Talk to the Hand gives the player block whenever the enemy is attacked.
+    BlockReturn(i32),
+    EnergyDown(i32),
+    //Panic Button stops block from cards.
+    NoBlock(i32),
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -227,9 +674,69 @@ pub enum Buff {
     Enrage(i32),
     Vigor(i32),
     Thorns(i32),
+    SharpHide(i32),
+    Flying(i32),
+    Intangible(i32),
+    BeatOfDeath(i32),
+    PainfulStabs,
+    Dexterity(i32),
+    EnergyNextTurn(i32),
+    DrawNextTurn(i32),
+    Blur(i32),
+    Accuracy(i32),
+    InfiniteBlades(i32),
+    NoxiousFumes(i32),
+    Envenom(i32),
+    AfterImage(i32),
+    ThousandCuts(i32),
+    Burst(i32),
+    Phantasmal(i32),
+    ToolsOfTheTrade(i32),
+    WraithForm(i32),
+    WellLaidPlans(i32),
+    Focus(i32),
+    Artifact(i32),
+    Buffer(i32),
+    Amplify(i32),
+    BiasedCognition(i32),
+    CreativeAI(i32),
+    EchoForm(i32),
+    Electrodynamics,
+    Heatsinks(i32),
+    HelloWorld(i32),
+    Loop(i32),
+    MachineLearning(i32),
+    SelfRepair(i32),
+    StaticDischarge(i32),
+    Storm(i32),
+    //Equilibrium retains the whole hand at the end of the turn.
+    Equilibrium(i32),
+    MentalFortress(i32),
+    Rushdown(i32),
+    Nirvana(i32),
+    Establishment(i32),
+    MasterReality,
+    LikeWater(i32),
+    WaveOfTheHand(i32),
+    BattleHymn(i32),
+    Foresight(i32),
+    Study(i32),
+    Devotion(i32),
+    DevaForm(i32),
+    Collect(i32),
+    //Blasphemy kills the player at the start of their next turn.
+    Blasphemy,
+    SimmeringFury(i32),
+    //Swivel makes the next attack free.
+    FreeAttack,
+    PlatedArmor(i32),
+    Magnetism(i32),
+    Mayhem(i32),
+    Panache(i32),
+    TheBomb(i32),
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CardType {
     Attack,
     Skill,
@@ -247,6 +754,17 @@ pub enum CardCharachter {
     COLORLESS,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CardRarity {
+    Basic,
+    Common,
+    Uncommon,
+    Rare,
+    Curse,
+    //Cards which only come from events.
+    Special,
+}
+
 struct CardProps {
     actions: &'static [PlayEffect],
     cost: Cost,
@@ -260,6 +778,8 @@ struct CardProps {
     removable: bool,
     starter: bool,
     innate: Innate,
+    retain: Retain,
+    rarity: CardRarity,
 }
 
 enum Ethereal {
@@ -273,6 +793,13 @@ enum Innate {
     Yes,
     Upgraded,
 }
+
+//Retained cards stay in hand at the end of the turn.
+enum Retain {
+    No,
+    Yes,
+    Upgraded,
+}
 impl CardProps {
     const fn new(
         actions: &'static [PlayEffect],
@@ -292,17 +819,28 @@ impl CardProps {
             upgraded_requires_target: requires_target,
             ethereal: Ethereal::No,
             innate: Innate::No,
+            retain: Retain::No,
             removable: true,
             charachter,
             starter: false,
+            //Statuses are common in STS, curses have their own rarity.
+            rarity: if matches!(card_type, CardType::Curse) {
+                CardRarity::Curse
+            } else {
+                CardRarity::Common
+            },
         }
     }
     const fn with_starter(self) -> Self {
         Self {
             starter: true,
+            rarity: CardRarity::Basic,
             ..self
         }
     }
+    const fn with_rarity(self, rarity: CardRarity) -> Self {
+        Self { rarity, ..self }
+    }
     const fn with_upgraded_cost(self, cost: Cost) -> Self {
         Self {
             upgraded_cost: cost,
@@ -321,6 +859,12 @@ impl CardProps {
     const fn with_innate(self, innate: Innate) -> Self {
         Self { innate, ..self }
     }
+    const fn with_retain(self, retain: Retain) -> Self {
+        Self { retain, ..self }
+    }
+    const fn with_removable(self, removable: bool) -> Self {
+        Self { removable, ..self }
+    }
 }
 
 macro_rules! const_card {
@@ -496,14 +1040,17 @@ impl CardBody {
                 CardType::Attack,
                 CardCharachter::IRONCLAD
             )),
-            CardBody::SearingBlow => const_card!(&CardProps::new(
-                &[PlayEffect::Attack(12)],
-                &[PlayEffect::Attack(12)],
-                Cost::Fixed(2),
-                true,
-                CardType::Attack,
-                CardCharachter::IRONCLAD
-            )),
+            CardBody::SearingBlow => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(12)],
+                    &[PlayEffect::Attack(12)],
+                    Cost::Fixed(2),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
             CardBody::PerfectedStrike => const_card!(&CardProps::new(
                 &[PlayEffect::Attack(6)],
                 &[PlayEffect::Attack(6)],
@@ -616,14 +1163,17 @@ impl CardBody {
                 CardType::Status,
                 CardCharachter::COLORLESS
             )),
-            CardBody::BattleTrance => const_card!(&CardProps::new(
-                &[PlayEffect::Draw(3), PlayEffect::DebuffSelf(Debuff::NoDraw)],
-                &[PlayEffect::Draw(4), PlayEffect::DebuffSelf(Debuff::NoDraw)],
-                Cost::Fixed(0),
-                false,
-                CardType::Skill,
-                CardCharachter::IRONCLAD
-            )),
+            CardBody::BattleTrance => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Draw(3), PlayEffect::DebuffSelf(Debuff::NoDraw)],
+                    &[PlayEffect::Draw(4), PlayEffect::DebuffSelf(Debuff::NoDraw)],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
             CardBody::BloodForBlood => const_card!(
                 &CardProps::new(
                     &[PlayEffect::Attack(18)],
@@ -634,29 +1184,36 @@ impl CardBody {
                     CardCharachter::IRONCLAD
                 )
                 .with_upgraded_cost(Cost::NumMinusHpLoss(3))
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Bloodletting => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::LoseHP(3), PlayEffect::GainEnergy(2)],
+                    &[PlayEffect::LoseHP(3), PlayEffect::GainEnergy(3)],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::BurningPact => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::SelectCardEffect(SelectCardEffect::ExhaustChosen),
+                        PlayEffect::Draw(2),
+                    ],
+                    &[
+                        PlayEffect::SelectCardEffect(SelectCardEffect::ExhaustChosen),
+                        PlayEffect::Draw(3),
+                    ],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
             ),
-            CardBody::Bloodletting => const_card!(&CardProps::new(
-                &[PlayEffect::LoseHP(3), PlayEffect::GainEnergy(2)],
-                &[PlayEffect::LoseHP(3), PlayEffect::GainEnergy(3)],
-                Cost::Fixed(0),
-                false,
-                CardType::Skill,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::BurningPact => const_card!(&CardProps::new(
-                &[
-                    PlayEffect::SelectCardEffect(SelectCardEffect::ExhaustChosen),
-                    PlayEffect::Draw(2),
-                ],
-                &[
-                    PlayEffect::SelectCardEffect(SelectCardEffect::ExhaustChosen),
-                    PlayEffect::Draw(3),
-                ],
-                Cost::Fixed(1),
-                false,
-                CardType::Skill,
-                CardCharachter::IRONCLAD
-            )),
             CardBody::Carnage => const_card!(
                 &CardProps::new(
                     &[PlayEffect::Attack(20)],
@@ -667,21 +1224,25 @@ impl CardBody {
                     CardCharachter::IRONCLAD
                 )
                 .with_ethereal(Ethereal::Yes)
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Combust => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Buff(Buff::EndTurnLoseHP(1)),
+                        PlayEffect::Buff(Buff::EndTurnDamageAllEnemies(5)),
+                    ],
+                    &[
+                        PlayEffect::Buff(Buff::EndTurnLoseHP(1)),
+                        PlayEffect::Buff(Buff::EndTurnDamageAllEnemies(7)),
+                    ],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
             ),
-            CardBody::Combust => const_card!(&CardProps::new(
-                &[
-                    PlayEffect::Buff(Buff::EndTurnLoseHP(1)),
-                    PlayEffect::Buff(Buff::EndTurnDamageAllEnemies(5)),
-                ],
-                &[
-                    PlayEffect::Buff(Buff::EndTurnLoseHP(1)),
-                    PlayEffect::Buff(Buff::EndTurnDamageAllEnemies(7)),
-                ],
-                Cost::Fixed(1),
-                false,
-                CardType::Power,
-                CardCharachter::IRONCLAD
-            )),
             CardBody::DarkEmbrace => const_card!(
                 &CardProps::new(
                     &[PlayEffect::Buff(Buff::DarkEmbraceBuff)],
@@ -692,41 +1253,51 @@ impl CardBody {
                     CardCharachter::IRONCLAD
                 )
                 .with_upgraded_cost(Cost::Fixed(1))
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Disarm => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::DebuffEnemy(Debuff::StrengthDown(2)),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    &[
+                        PlayEffect::DebuffEnemy(Debuff::StrengthDown(3)),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Skill,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Dropkick => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(5), PlayEffect::DropkickDraw],
+                    &[PlayEffect::Attack(8), PlayEffect::DropkickDraw],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::DualWield => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::SelectCardEffect(
+                        SelectCardEffect::DuplicatePowerOrAttack(1),
+                    )],
+                    &[PlayEffect::SelectCardEffect(
+                        SelectCardEffect::DuplicatePowerOrAttack(2),
+                    )],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
             ),
-            CardBody::Disarm => const_card!(&CardProps::new(
-                &[
-                    PlayEffect::DebuffEnemy(Debuff::StrengthDown(2)),
-                    PlayEffect::MarkExhaust,
-                ],
-                &[
-                    PlayEffect::DebuffEnemy(Debuff::StrengthDown(3)),
-                    PlayEffect::MarkExhaust,
-                ],
-                Cost::Fixed(1),
-                true,
-                CardType::Skill,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::Dropkick => const_card!(&CardProps::new(
-                &[PlayEffect::Attack(5), PlayEffect::DropkickDraw],
-                &[PlayEffect::Attack(8), PlayEffect::DropkickDraw],
-                Cost::Fixed(1),
-                true,
-                CardType::Attack,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::DualWield => const_card!(&CardProps::new(
-                &[PlayEffect::SelectCardEffect(
-                    SelectCardEffect::DuplicatePowerOrAttack(1),
-                )],
-                &[PlayEffect::SelectCardEffect(
-                    SelectCardEffect::DuplicatePowerOrAttack(2),
-                )],
-                Cost::Fixed(1),
-                false,
-                CardType::Skill,
-                CardCharachter::IRONCLAD
-            )),
             CardBody::Entrench => const_card!(
                 &CardProps::new(
                     &[PlayEffect::DoubleBlock],
@@ -737,39 +1308,52 @@ impl CardBody {
                     CardCharachter::IRONCLAD
                 )
                 .with_upgraded_cost(Cost::Fixed(1))
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Evolve => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::EvolveBuff(1))],
+                    &[PlayEffect::Buff(Buff::EvolveBuff(2))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::FeelNoPain => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::FNPBuff(3))],
+                    &[PlayEffect::Buff(Buff::FNPBuff(4))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::FireBreathing => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::FireBreathingBuff(6))],
+                    &[PlayEffect::Buff(Buff::FireBreathingBuff(10))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::FlameBarrier => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Block(12), PlayEffect::Buff(Buff::TempSpikes(4))],
+                    &[PlayEffect::Block(16), PlayEffect::Buff(Buff::TempSpikes(6))],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
             ),
-            CardBody::Evolve => const_card!(&CardProps::new(
-                &[PlayEffect::Buff(Buff::EvolveBuff(1))],
-                &[PlayEffect::Buff(Buff::EvolveBuff(2))],
-                Cost::Fixed(1),
-                false,
-                CardType::Power,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::FeelNoPain => const_card!(&CardProps::new(
-                &[PlayEffect::Buff(Buff::FNPBuff(3))],
-                &[PlayEffect::Buff(Buff::FNPBuff(4))],
-                Cost::Fixed(1),
-                false,
-                CardType::Power,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::FireBreathing => const_card!(&CardProps::new(
-                &[PlayEffect::Buff(Buff::FireBreathingBuff(6))],
-                &[PlayEffect::Buff(Buff::FireBreathingBuff(10))],
-                Cost::Fixed(1),
-                false,
-                CardType::Power,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::FlameBarrier => const_card!(&CardProps::new(
-                &[PlayEffect::Block(12), PlayEffect::Buff(Buff::TempSpikes(4))],
-                &[PlayEffect::Block(16), PlayEffect::Buff(Buff::TempSpikes(6))],
-                Cost::Fixed(2),
-                false,
-                CardType::Skill,
-                CardCharachter::IRONCLAD
-            )),
             CardBody::GhostlyArmor => const_card!(
                 &CardProps::new(
                     &[PlayEffect::Block(10)],
@@ -780,15 +1364,19 @@ impl CardBody {
                     CardCharachter::IRONCLAD
                 )
                 .with_ethereal(Ethereal::Yes)
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Hemokinesis => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::LoseHP(2), PlayEffect::Attack(15)],
+                    &[PlayEffect::LoseHP(2), PlayEffect::Attack(20)],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
             ),
-            CardBody::Hemokinesis => const_card!(&CardProps::new(
-                &[PlayEffect::LoseHP(2), PlayEffect::Attack(15)],
-                &[PlayEffect::LoseHP(2), PlayEffect::Attack(20)],
-                Cost::Fixed(1),
-                true,
-                CardType::Attack,
-                CardCharachter::IRONCLAD
-            )),
             CardBody::InfernalBlade => const_card!(
                 &CardProps::new(
                     &[PlayEffect::GenerateAttackInfernal],
@@ -799,102 +1387,127 @@ impl CardBody {
                     CardCharachter::IRONCLAD
                 )
                 .with_upgraded_cost(Cost::Fixed(0))
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Inflame => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Strength(2))],
+                    &[PlayEffect::Buff(Buff::Strength(3))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Intimidate => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::DebuffAll(Debuff::Weak(1)),
+                        PlayEffect::MarkExhaust
+                    ],
+                    &[
+                        PlayEffect::DebuffAll(Debuff::Weak(2)),
+                        PlayEffect::MarkExhaust
+                    ],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Metallicize => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Metallicize(3))],
+                    &[PlayEffect::Buff(Buff::Metallicize(4))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::PowerThrough => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Block(15),
+                        PlayEffect::AddCardToHand(CardBody::Wound),
+                        PlayEffect::AddCardToHand(CardBody::Wound)
+                    ],
+                    &[
+                        PlayEffect::Block(20),
+                        PlayEffect::AddCardToHand(CardBody::Wound),
+                        PlayEffect::AddCardToHand(CardBody::Wound)
+                    ],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Pummel => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Attack(2),
+                        PlayEffect::Attack(2),
+                        PlayEffect::Attack(2),
+                        PlayEffect::Attack(2),
+                    ],
+                    &[
+                        PlayEffect::Attack(2),
+                        PlayEffect::Attack(2),
+                        PlayEffect::Attack(2),
+                        PlayEffect::Attack(2),
+                        PlayEffect::Attack(2),
+                    ],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Rage => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::RageBuff(3))],
+                    &[PlayEffect::Buff(Buff::RageBuff(5))],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Rampage => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(8), PlayEffect::IncreaseDamage(5)],
+                    &[PlayEffect::Attack(8), PlayEffect::IncreaseDamage(5)],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::RecklessCharge => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Attack(7),
+                        PlayEffect::ShuffleInCard(CardBody::Dazed)
+                    ],
+                    &[
+                        PlayEffect::Attack(10),
+                        PlayEffect::ShuffleInCard(CardBody::Dazed)
+                    ],
+                    Cost::Fixed(0),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
             ),
-            CardBody::Inflame => const_card!(&CardProps::new(
-                &[PlayEffect::Buff(Buff::Strength(2))],
-                &[PlayEffect::Buff(Buff::Strength(3))],
-                Cost::Fixed(1),
-                false,
-                CardType::Power,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::Intimidate => const_card!(&CardProps::new(
-                &[
-                    PlayEffect::DebuffAll(Debuff::Weak(1)),
-                    PlayEffect::MarkExhaust
-                ],
-                &[
-                    PlayEffect::DebuffAll(Debuff::Weak(2)),
-                    PlayEffect::MarkExhaust
-                ],
-                Cost::Fixed(0),
-                false,
-                CardType::Skill,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::Metallicize => const_card!(&CardProps::new(
-                &[PlayEffect::Buff(Buff::Metallicize(3))],
-                &[PlayEffect::Buff(Buff::Metallicize(4))],
-                Cost::Fixed(1),
-                false,
-                CardType::Power,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::PowerThrough => const_card!(&CardProps::new(
-                &[
-                    PlayEffect::Block(15),
-                    PlayEffect::AddCardToHand(CardBody::Wound),
-                    PlayEffect::AddCardToHand(CardBody::Wound)
-                ],
-                &[
-                    PlayEffect::Block(20),
-                    PlayEffect::AddCardToHand(CardBody::Wound),
-                    PlayEffect::AddCardToHand(CardBody::Wound)
-                ],
-                Cost::Fixed(1),
-                false,
-                CardType::Skill,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::Pummel => const_card!(&CardProps::new(
-                &[
-                    PlayEffect::Attack(2),
-                    PlayEffect::Attack(2),
-                    PlayEffect::Attack(2),
-                    PlayEffect::Attack(2),
-                ],
-                &[
-                    PlayEffect::Attack(2),
-                    PlayEffect::Attack(2),
-                    PlayEffect::Attack(2),
-                    PlayEffect::Attack(2),
-                    PlayEffect::Attack(2),
-                ],
-                Cost::Fixed(1),
-                true,
-                CardType::Attack,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::Rage => const_card!(&CardProps::new(
-                &[PlayEffect::Buff(Buff::RageBuff(3))],
-                &[PlayEffect::Buff(Buff::RageBuff(5))],
-                Cost::Fixed(0),
-                false,
-                CardType::Skill,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::Rampage => const_card!(&CardProps::new(
-                &[PlayEffect::Attack(8), PlayEffect::IncreaseDamage(5)],
-                &[PlayEffect::Attack(8), PlayEffect::IncreaseDamage(5)],
-                Cost::Fixed(1),
-                true,
-                CardType::Attack,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::RecklessCharge => const_card!(&CardProps::new(
-                &[
-                    PlayEffect::Attack(7),
-                    PlayEffect::ShuffleInCard(CardBody::Dazed)
-                ],
-                &[
-                    PlayEffect::Attack(10),
-                    PlayEffect::ShuffleInCard(CardBody::Dazed)
-                ],
-                Cost::Fixed(0),
-                true,
-                CardType::Attack,
-                CardCharachter::IRONCLAD
-            )),
             CardBody::Dazed => const_card!(
                 &CardProps::new(
                     &[PlayEffect::MarkExhaust],
@@ -906,22 +1519,28 @@ impl CardBody {
                 )
                 .with_ethereal(Ethereal::Yes)
             ),
-            CardBody::Rupture => const_card!(&CardProps::new(
-                &[PlayEffect::Buff(Buff::RuptureBuff(1)),],
-                &[PlayEffect::Buff(Buff::RuptureBuff(2)),],
-                Cost::Fixed(1),
-                false,
-                CardType::Power,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::SecondWind => const_card!(&CardProps::new(
-                &[PlayEffect::ExhaustNonAttackForBlock(5)],
-                &[PlayEffect::ExhaustNonAttackForBlock(7)],
-                Cost::Fixed(1),
-                false,
-                CardType::Skill,
-                CardCharachter::IRONCLAD
-            )),
+            CardBody::Rupture => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::RuptureBuff(1)),],
+                    &[PlayEffect::Buff(Buff::RuptureBuff(2)),],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::SecondWind => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::ExhaustNonAttackForBlock(5)],
+                    &[PlayEffect::ExhaustNonAttackForBlock(7)],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
             CardBody::SeeingRed => const_card!(
                 &CardProps::new(
                     &[PlayEffect::GainEnergy(2), PlayEffect::MarkExhaust],
@@ -932,284 +1551,3846 @@ impl CardBody {
                     CardCharachter::IRONCLAD
                 )
                 .with_upgraded_cost(Cost::Fixed(0))
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Sentinel => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Block(5)],
+                    &[PlayEffect::Block(8)],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::SeverSoul => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(16), PlayEffect::ExhaustNonAttack],
+                    &[PlayEffect::Attack(22), PlayEffect::ExhaustNonAttack],
+                    Cost::Fixed(2),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Shockwave => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::DebuffAll(Debuff::Weak(3)),
+                        PlayEffect::DebuffAll(Debuff::Vulnerable(3)),
+                        PlayEffect::MarkExhaust
+                    ],
+                    &[
+                        PlayEffect::DebuffAll(Debuff::Weak(5)),
+                        PlayEffect::DebuffAll(Debuff::Vulnerable(5)),
+                        PlayEffect::MarkExhaust
+                    ],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::SpotWeakness => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::SpotWeakness(3),],
+                    &[PlayEffect::SpotWeakness(3),],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Skill,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Uppercut => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Attack(13),
+                        PlayEffect::DebuffEnemy(Debuff::Weak(1)),
+                        PlayEffect::DebuffEnemy(Debuff::Vulnerable(1))
+                    ],
+                    &[
+                        PlayEffect::Attack(13),
+                        PlayEffect::DebuffEnemy(Debuff::Weak(2)),
+                        PlayEffect::DebuffEnemy(Debuff::Vulnerable(2))
+                    ],
+                    Cost::Fixed(2),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Whirlwind => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::AttackAllX(5)],
+                    &[PlayEffect::AttackAllX(8)],
+                    Cost::X,
+                    false,
+                    CardType::Attack,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Uncommon)
             ),
-            CardBody::Sentinel => const_card!(&CardProps::new(
-                &[PlayEffect::Block(5)],
-                &[PlayEffect::Block(8)],
-                Cost::Fixed(1),
-                false,
-                CardType::Skill,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::SeverSoul => const_card!(&CardProps::new(
-                &[PlayEffect::Attack(16), PlayEffect::ExhaustNonAttack],
-                &[PlayEffect::Attack(22), PlayEffect::ExhaustNonAttack],
-                Cost::Fixed(2),
-                true,
-                CardType::Attack,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::Shockwave => const_card!(&CardProps::new(
-                &[
-                    PlayEffect::DebuffAll(Debuff::Weak(3)),
-                    PlayEffect::DebuffAll(Debuff::Vulnerable(3)),
-                    PlayEffect::MarkExhaust
-                ],
-                &[
-                    PlayEffect::DebuffAll(Debuff::Weak(5)),
-                    PlayEffect::DebuffAll(Debuff::Vulnerable(5)),
-                    PlayEffect::MarkExhaust
-                ],
-                Cost::Fixed(2),
-                false,
-                CardType::Skill,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::SpotWeakness => const_card!(&CardProps::new(
-                &[PlayEffect::SpotWeakness(3),],
-                &[PlayEffect::SpotWeakness(3),],
-                Cost::Fixed(1),
-                true,
-                CardType::Skill,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::Uppercut => const_card!(&CardProps::new(
-                &[
-                    PlayEffect::Attack(13),
-                    PlayEffect::DebuffEnemy(Debuff::Weak(1)),
-                    PlayEffect::DebuffEnemy(Debuff::Vulnerable(1))
-                ],
-                &[
-                    PlayEffect::Attack(13),
-                    PlayEffect::DebuffEnemy(Debuff::Weak(2)),
-                    PlayEffect::DebuffEnemy(Debuff::Vulnerable(2))
-                ],
-                Cost::Fixed(2),
-                true,
-                CardType::Attack,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::Whirlwind => const_card!(&CardProps::new(
-                &[PlayEffect::AttackAllX(5)],
-                &[PlayEffect::AttackAllX(8)],
-                Cost::X,
-                false,
-                CardType::Attack,
-                CardCharachter::IRONCLAD
-            )),
             CardBody::Barricade => const_card!(
                 &CardProps::new(
-                    &[PlayEffect::Buff(Buff::BarricadeBuff)],
-                    &[PlayEffect::Buff(Buff::BarricadeBuff)],
-                    Cost::Fixed(3),
+                    &[PlayEffect::Buff(Buff::BarricadeBuff)],
+                    &[PlayEffect::Buff(Buff::BarricadeBuff)],
+                    Cost::Fixed(3),
+                    false,
+                    CardType::Power,
+                    CardCharachter::IRONCLAD
+                )
+                .with_upgraded_cost(Cost::Fixed(2))
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Berserk => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Buff(Buff::EnergyEveryTurn),
+                        PlayEffect::DebuffSelf(Debuff::Vulnerable(2))
+                    ],
+                    &[
+                        PlayEffect::Buff(Buff::EnergyEveryTurn),
+                        PlayEffect::DebuffSelf(Debuff::Vulnerable(2))
+                    ],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Power,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Bludgeon => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(32)],
+                    &[PlayEffect::Attack(42)],
+                    Cost::Fixed(3),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Brutality => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::BrutalityBuff),],
+                    &[PlayEffect::Buff(Buff::BrutalityBuff),],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Power,
+                    CardCharachter::IRONCLAD
+                )
+                .with_innate(Innate::Upgraded)
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Corruption => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::CorruptionBuff)],
+                    &[PlayEffect::Buff(Buff::CorruptionBuff)],
+                    Cost::Fixed(3),
+                    false,
+                    CardType::Power,
+                    CardCharachter::IRONCLAD
+                )
+                .with_upgraded_cost(Cost::Fixed(2))
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::DemonForm => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Ritual(2))],
+                    &[PlayEffect::Buff(Buff::Ritual(3))],
+                    Cost::Fixed(3),
+                    false,
+                    CardType::Power,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::DoubleTap => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::DoubleTap(1))],
+                    &[PlayEffect::Buff(Buff::DoubleTap(2))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Exhume => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::SelectCardEffect(SelectCardEffect::ExhaustToHand),
+                        PlayEffect::MarkExhaust
+                    ],
+                    &[
+                        PlayEffect::SelectCardEffect(SelectCardEffect::ExhaustToHand),
+                        PlayEffect::MarkExhaust
+                    ],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::IRONCLAD
+                )
+                .with_upgraded_cost(Cost::Fixed(0))
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Feed => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::AttackLethalEffect(10, LethalEffect::Gain3MaxHP)],
+                    &[PlayEffect::AttackLethalEffect(12, LethalEffect::Gain4MaxHP)],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::FiendFire => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::AttackFiendFire(7), PlayEffect::MarkExhaust],
+                    &[PlayEffect::AttackFiendFire(10), PlayEffect::MarkExhaust],
+                    Cost::Fixed(2),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Immolate => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::AttackAll(21),
+                        PlayEffect::AddCardToDiscard(CardBody::Burn)
+                    ],
+                    &[
+                        PlayEffect::AttackAll(28),
+                        PlayEffect::AddCardToDiscard(CardBody::Burn)
+                    ],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Attack,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Burn => const_card!(&CardProps::new(
+                &[PlayEffect::MarkExhaust],
+                &[PlayEffect::MarkExhaust],
+                Cost::Unplayable,
+                false,
+                CardType::Status,
+                CardCharachter::COLORLESS
+            )),
+            CardBody::Impervious => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Block(30), PlayEffect::MarkExhaust],
+                    &[PlayEffect::Block(40), PlayEffect::MarkExhaust],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Juggernaut => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Juggernaut(5))],
+                    &[PlayEffect::Buff(Buff::Juggernaut(7))],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Power,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::LimitBreak => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::DoubleStrength, PlayEffect::MarkExhaust],
+                    &[PlayEffect::DoubleStrength],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Offering => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::LoseHP(6),
+                        PlayEffect::GainEnergy(2),
+                        PlayEffect::Draw(3),
+                        PlayEffect::MarkExhaust
+                    ],
+                    &[
+                        PlayEffect::LoseHP(6),
+                        PlayEffect::GainEnergy(2),
+                        PlayEffect::Draw(5),
+                        PlayEffect::MarkExhaust
+                    ],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Reaper => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::AttackAllForHP(4), PlayEffect::MarkExhaust],
+                    &[PlayEffect::AttackAllForHP(5), PlayEffect::MarkExhaust],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Attack,
+                    CardCharachter::IRONCLAD
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Regret => const_card!(&CardProps::new(
+                &[PlayEffect::MarkExhaust],
+                &[PlayEffect::MarkExhaust],
+                Cost::Unplayable,
+                false,
+                CardType::Curse,
+                CardCharachter::COLORLESS
+            )),
+            CardBody::Injury => const_card!(&CardProps::new(
+                &[PlayEffect::MarkExhaust],
+                &[PlayEffect::MarkExhaust],
+                Cost::Unplayable,
+                false,
+                CardType::Curse,
+                CardCharachter::COLORLESS
+            )),
+            CardBody::Parasite => const_card!(&CardProps::new(
+                &[PlayEffect::MarkExhaust],
+                &[PlayEffect::MarkExhaust],
+                Cost::Unplayable,
+                false,
+                CardType::Curse,
+                CardCharachter::COLORLESS
+            )),
+            CardBody::Doubt => const_card!(&CardProps::new(
+                &[PlayEffect::MarkExhaust],
+                &[PlayEffect::MarkExhaust],
+                Cost::Unplayable,
+                false,
+                CardType::Curse,
+                CardCharachter::COLORLESS
+            )),
+            CardBody::Decay => const_card!(&CardProps::new(
+                &[PlayEffect::MarkExhaust],
+                &[PlayEffect::MarkExhaust],
+                Cost::Unplayable,
+                false,
+                CardType::Curse,
+                CardCharachter::COLORLESS
+            )),
+            CardBody::Pain => const_card!(&CardProps::new(
+                &[PlayEffect::MarkExhaust],
+                &[PlayEffect::MarkExhaust],
+                Cost::Unplayable,
+                false,
+                CardType::Curse,
+                CardCharachter::COLORLESS
+            )),
+            CardBody::Shame => const_card!(&CardProps::new(
+                &[PlayEffect::MarkExhaust],
+                &[PlayEffect::MarkExhaust],
+                Cost::Unplayable,
+                false,
+                CardType::Curse,
+                CardCharachter::COLORLESS
+            )),
+            CardBody::Writhe => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::MarkExhaust],
+                    &[PlayEffect::MarkExhaust],
+                    Cost::Unplayable,
+                    false,
+                    CardType::Curse,
+                    CardCharachter::COLORLESS
+                )
+                .with_innate(Innate::Yes)
+            ),
+            //Ascender's Bane is added at Ascension 10 and can't be removed.
+            CardBody::AscendersBane => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::MarkExhaust],
+                    &[PlayEffect::MarkExhaust],
+                    Cost::Unplayable,
+                    false,
+                    CardType::Curse,
+                    CardCharachter::COLORLESS
+                )
+                .with_ethereal(Ethereal::Yes)
+                .with_removable(false)
+            ),
+            CardBody::Apparition => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Buff(Buff::Intangible(1)),
+                        PlayEffect::MarkExhaust
+                    ],
+                    &[
+                        PlayEffect::Buff(Buff::Intangible(1)),
+                        PlayEffect::MarkExhaust
+                    ],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_ethereal(Ethereal::NotUpgraded)
+                .with_rarity(CardRarity::Special)
+            ),
+            CardBody::Bite => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(7), PlayEffect::Heal(2)],
+                    &[PlayEffect::Attack(8), PlayEffect::Heal(3)],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Special)
+            ),
+            CardBody::JAX => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::LoseHP(3), PlayEffect::Buff(Buff::Strength(2))],
+                    &[PlayEffect::LoseHP(3), PlayEffect::Buff(Buff::Strength(3))],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Special)
+            ),
+            CardBody::RitualDagger => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::AttackLethalEffect(15, LethalEffect::DaggerDamage3),
+                        PlayEffect::MarkExhaust
+                    ],
+                    &[
+                        PlayEffect::AttackLethalEffect(15, LethalEffect::DaggerDamage5),
+                        PlayEffect::MarkExhaust
+                    ],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Special)
+            ),
+            //Void's energy loss is handled when it is drawn.
+            CardBody::Void => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::MarkExhaust],
+                    &[PlayEffect::MarkExhaust],
+                    Cost::Unplayable,
+                    false,
+                    CardType::Status,
+                    CardCharachter::COLORLESS
+                )
+                .with_ethereal(Ethereal::Yes)
+            ),
+            CardBody::Neutralize => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Attack(3),
+                        PlayEffect::DebuffEnemy(Debuff::Weak(1)),
+                    ],
+                    &[
+                        PlayEffect::Attack(4),
+                        PlayEffect::DebuffEnemy(Debuff::Weak(2)),
+                    ],
+                    Cost::Fixed(0),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::SILENT
+                )
+                .with_starter()
+            ),
+            CardBody::Survivor => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Block(8),
+                        PlayEffect::SelectCardEffect(SelectCardEffect::DiscardChosen),
+                    ],
+                    &[
+                        PlayEffect::Block(11),
+                        PlayEffect::SelectCardEffect(SelectCardEffect::DiscardChosen),
+                    ],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_starter()
+            ),
+            CardBody::Acrobatics => const_card!(&CardProps::new(
+                &[
+                    PlayEffect::Draw(3),
+                    PlayEffect::SelectCardEffect(SelectCardEffect::DiscardChosen),
+                ],
+                &[
+                    PlayEffect::Draw(4),
+                    PlayEffect::SelectCardEffect(SelectCardEffect::DiscardChosen),
+                ],
+                Cost::Fixed(1),
+                false,
+                CardType::Skill,
+                CardCharachter::SILENT
+            )),
+            CardBody::Backflip => const_card!(&CardProps::new(
+                &[PlayEffect::Block(5), PlayEffect::Draw(2)],
+                &[PlayEffect::Block(8), PlayEffect::Draw(2)],
+                Cost::Fixed(1),
+                false,
+                CardType::Skill,
+                CardCharachter::SILENT
+            )),
+            CardBody::Bane => const_card!(&CardProps::new(
+                &[PlayEffect::Attack(7), PlayEffect::AttackIfPoisoned(7)],
+                &[PlayEffect::Attack(10), PlayEffect::AttackIfPoisoned(10)],
+                Cost::Fixed(1),
+                true,
+                CardType::Attack,
+                CardCharachter::SILENT
+            )),
+            CardBody::BladeDance => const_card!(&CardProps::new(
+                &[
+                    PlayEffect::AddCardToHand(CardBody::Shiv),
+                    PlayEffect::AddCardToHand(CardBody::Shiv),
+                    PlayEffect::AddCardToHand(CardBody::Shiv),
+                ],
+                &[
+                    PlayEffect::AddCardToHand(CardBody::Shiv),
+                    PlayEffect::AddCardToHand(CardBody::Shiv),
+                    PlayEffect::AddCardToHand(CardBody::Shiv),
+                    PlayEffect::AddCardToHand(CardBody::Shiv),
+                ],
+                Cost::Fixed(1),
+                false,
+                CardType::Skill,
+                CardCharachter::SILENT
+            )),
+            CardBody::CloakAndDagger => const_card!(&CardProps::new(
+                &[
+                    PlayEffect::Block(6),
+                    PlayEffect::AddCardToHand(CardBody::Shiv),
+                ],
+                &[
+                    PlayEffect::Block(6),
+                    PlayEffect::AddCardToHand(CardBody::Shiv),
+                    PlayEffect::AddCardToHand(CardBody::Shiv),
+                ],
+                Cost::Fixed(1),
+                false,
+                CardType::Skill,
+                CardCharachter::SILENT
+            )),
+            CardBody::DaggerSpray => const_card!(&CardProps::new(
+                &[PlayEffect::AttackAll(4), PlayEffect::AttackAll(4)],
+                &[PlayEffect::AttackAll(6), PlayEffect::AttackAll(6)],
+                Cost::Fixed(1),
+                false,
+                CardType::Attack,
+                CardCharachter::SILENT
+            )),
+            CardBody::DaggerThrow => const_card!(&CardProps::new(
+                &[
+                    PlayEffect::Attack(9),
+                    PlayEffect::Draw(1),
+                    PlayEffect::SelectCardEffect(SelectCardEffect::DiscardChosen),
+                ],
+                &[
+                    PlayEffect::Attack(12),
+                    PlayEffect::Draw(1),
+                    PlayEffect::SelectCardEffect(SelectCardEffect::DiscardChosen),
+                ],
+                Cost::Fixed(1),
+                true,
+                CardType::Attack,
+                CardCharachter::SILENT
+            )),
+            CardBody::DeadlyPoison => const_card!(&CardProps::new(
+                &[PlayEffect::DebuffEnemy(Debuff::Poison(5))],
+                &[PlayEffect::DebuffEnemy(Debuff::Poison(7))],
+                Cost::Fixed(1),
+                true,
+                CardType::Skill,
+                CardCharachter::SILENT
+            )),
+            CardBody::Deflect => const_card!(&CardProps::new(
+                &[PlayEffect::Block(4)],
+                &[PlayEffect::Block(7)],
+                Cost::Fixed(0),
+                false,
+                CardType::Skill,
+                CardCharachter::SILENT
+            )),
+            CardBody::DodgeAndRoll => const_card!(&CardProps::new(
+                &[PlayEffect::Block(4), PlayEffect::BlockNextTurn(4)],
+                &[PlayEffect::Block(6), PlayEffect::BlockNextTurn(6)],
+                Cost::Fixed(1),
+                false,
+                CardType::Skill,
+                CardCharachter::SILENT
+            )),
+            CardBody::FlyingKnee => const_card!(&CardProps::new(
+                &[
+                    PlayEffect::Attack(8),
+                    PlayEffect::Buff(Buff::EnergyNextTurn(1)),
+                ],
+                &[
+                    PlayEffect::Attack(11),
+                    PlayEffect::Buff(Buff::EnergyNextTurn(1)),
+                ],
+                Cost::Fixed(1),
+                true,
+                CardType::Attack,
+                CardCharachter::SILENT
+            )),
+            CardBody::Outmaneuver => const_card!(&CardProps::new(
+                &[PlayEffect::Buff(Buff::EnergyNextTurn(2))],
+                &[PlayEffect::Buff(Buff::EnergyNextTurn(3))],
+                Cost::Fixed(1),
+                false,
+                CardType::Skill,
+                CardCharachter::SILENT
+            )),
+            CardBody::PiercingWail => const_card!(&CardProps::new(
+                &[
+                    PlayEffect::DebuffAll(Debuff::StrengthDown(6)),
+                    PlayEffect::MarkExhaust,
+                ],
+                &[
+                    PlayEffect::DebuffAll(Debuff::StrengthDown(8)),
+                    PlayEffect::MarkExhaust,
+                ],
+                Cost::Fixed(1),
+                false,
+                CardType::Skill,
+                CardCharachter::SILENT
+            )),
+            CardBody::PoisonedStab => const_card!(&CardProps::new(
+                &[
+                    PlayEffect::Attack(6),
+                    PlayEffect::DebuffEnemy(Debuff::Poison(3)),
+                ],
+                &[
+                    PlayEffect::Attack(8),
+                    PlayEffect::DebuffEnemy(Debuff::Poison(4)),
+                ],
+                Cost::Fixed(1),
+                true,
+                CardType::Attack,
+                CardCharachter::SILENT
+            )),
+            CardBody::Prepared => const_card!(&CardProps::new(
+                &[
+                    PlayEffect::Draw(1),
+                    PlayEffect::SelectCardEffect(SelectCardEffect::DiscardChosen),
+                ],
+                &[
+                    PlayEffect::Draw(2),
+                    PlayEffect::SelectCardEffect(SelectCardEffect::DiscardChosen),
+                    PlayEffect::SelectCardEffect(SelectCardEffect::DiscardChosen),
+                ],
+                Cost::Fixed(0),
+                false,
+                CardType::Skill,
+                CardCharachter::SILENT
+            )),
+            CardBody::QuickSlash => const_card!(&CardProps::new(
+                &[PlayEffect::Attack(8), PlayEffect::Draw(1)],
+                &[PlayEffect::Attack(12), PlayEffect::Draw(1)],
+                Cost::Fixed(1),
+                true,
+                CardType::Attack,
+                CardCharachter::SILENT
+            )),
+            CardBody::Slice => const_card!(&CardProps::new(
+                &[PlayEffect::Attack(6)],
+                &[PlayEffect::Attack(9)],
+                Cost::Fixed(0),
+                true,
+                CardType::Attack,
+                CardCharachter::SILENT
+            )),
+            CardBody::SneakyStrike => const_card!(&CardProps::new(
+                &[PlayEffect::Attack(12), PlayEffect::GainEnergyIfDiscarded(2)],
+                &[PlayEffect::Attack(16), PlayEffect::GainEnergyIfDiscarded(2)],
+                Cost::Fixed(2),
+                true,
+                CardType::Attack,
+                CardCharachter::SILENT
+            )),
+            CardBody::SuckerPunch => const_card!(&CardProps::new(
+                &[
+                    PlayEffect::Attack(7),
+                    PlayEffect::DebuffEnemy(Debuff::Weak(1)),
+                ],
+                &[
+                    PlayEffect::Attack(9),
+                    PlayEffect::DebuffEnemy(Debuff::Weak(2)),
+                ],
+                Cost::Fixed(1),
+                true,
+                CardType::Attack,
+                CardCharachter::SILENT
+            )),
+            CardBody::Accuracy => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Accuracy(4))],
+                    &[PlayEffect::Buff(Buff::Accuracy(6))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::AllOutAttack => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::AttackAll(10), PlayEffect::DiscardRandomInHand],
+                    &[PlayEffect::AttackAll(14), PlayEffect::DiscardRandomInHand],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Attack,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Backstab => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(11), PlayEffect::MarkExhaust],
+                    &[PlayEffect::Attack(15), PlayEffect::MarkExhaust],
+                    Cost::Fixed(0),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::SILENT
+                )
+                .with_innate(Innate::Yes)
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Blur => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Block(5), PlayEffect::Buff(Buff::Blur(1))],
+                    &[PlayEffect::Block(8), PlayEffect::Buff(Buff::Blur(1))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::BouncingFlask => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::DebuffRandomEnemy(Debuff::Poison(3)),
+                        PlayEffect::DebuffRandomEnemy(Debuff::Poison(3)),
+                        PlayEffect::DebuffRandomEnemy(Debuff::Poison(3)),
+                    ],
+                    &[
+                        PlayEffect::DebuffRandomEnemy(Debuff::Poison(3)),
+                        PlayEffect::DebuffRandomEnemy(Debuff::Poison(3)),
+                        PlayEffect::DebuffRandomEnemy(Debuff::Poison(3)),
+                        PlayEffect::DebuffRandomEnemy(Debuff::Poison(3)),
+                    ],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::CalculatedGamble => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::DiscardHandAndDraw, PlayEffect::MarkExhaust],
+                    &[PlayEffect::DiscardHandAndDraw],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Caltrops => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Thorns(3))],
+                    &[PlayEffect::Buff(Buff::Thorns(5))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Catalyst => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::MultiplyPoison(2), PlayEffect::MarkExhaust],
+                    &[PlayEffect::MultiplyPoison(3), PlayEffect::MarkExhaust],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Choke => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Attack(12),
+                        PlayEffect::DebuffEnemy(Debuff::Choked(3)),
+                    ],
+                    &[
+                        PlayEffect::Attack(12),
+                        PlayEffect::DebuffEnemy(Debuff::Choked(5)),
+                    ],
+                    Cost::Fixed(2),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Concentrate => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::SelectCardEffect(SelectCardEffect::DiscardChosen),
+                        PlayEffect::SelectCardEffect(SelectCardEffect::DiscardChosen),
+                        PlayEffect::SelectCardEffect(SelectCardEffect::DiscardChosen),
+                        PlayEffect::GainEnergy(2),
+                    ],
+                    &[
+                        PlayEffect::SelectCardEffect(SelectCardEffect::DiscardChosen),
+                        PlayEffect::SelectCardEffect(SelectCardEffect::DiscardChosen),
+                        PlayEffect::GainEnergy(2),
+                    ],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::CripplingCloud => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::DebuffAll(Debuff::Poison(4)),
+                        PlayEffect::DebuffAll(Debuff::Weak(2)),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    &[
+                        PlayEffect::DebuffAll(Debuff::Poison(7)),
+                        PlayEffect::DebuffAll(Debuff::Weak(2)),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Dash => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Block(10), PlayEffect::Attack(10)],
+                    &[PlayEffect::Block(13), PlayEffect::Attack(13)],
+                    Cost::Fixed(2),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Distraction => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::GenerateSkillDistraction,
+                        PlayEffect::MarkExhaust
+                    ],
+                    &[
+                        PlayEffect::GenerateSkillDistraction,
+                        PlayEffect::MarkExhaust
+                    ],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_upgraded_cost(Cost::Fixed(0))
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            //Endless Agony copies itself when drawn, which is handled in draw.
+            CardBody::EndlessAgony => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(4), PlayEffect::MarkExhaust],
+                    &[PlayEffect::Attack(6), PlayEffect::MarkExhaust],
+                    Cost::Fixed(0),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::EscapePlan => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::DrawBlockIfSkill(3)],
+                    &[PlayEffect::DrawBlockIfSkill(5)],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Eviscerate => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Attack(7),
+                        PlayEffect::Attack(7),
+                        PlayEffect::Attack(7),
+                    ],
+                    &[
+                        PlayEffect::Attack(9),
+                        PlayEffect::Attack(9),
+                        PlayEffect::Attack(9),
+                    ],
+                    Cost::NumMinusDiscards(3),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Expertise => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::DrawToHandSize(6)],
+                    &[PlayEffect::DrawToHandSize(7)],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Finisher => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::AttackPerAttackPlayed(6)],
+                    &[PlayEffect::AttackPerAttackPlayed(8)],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Flechettes => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::AttackPerSkillInHand(4)],
+                    &[PlayEffect::AttackPerSkillInHand(6)],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Footwork => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Dexterity(2))],
+                    &[PlayEffect::Buff(Buff::Dexterity(3))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::HeelHook => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(5), PlayEffect::HeelHookDraw],
+                    &[PlayEffect::Attack(8), PlayEffect::HeelHookDraw],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::InfiniteBlades => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::InfiniteBlades(1))],
+                    &[PlayEffect::Buff(Buff::InfiniteBlades(1))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::SILENT
+                )
+                .with_innate(Innate::Upgraded)
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::LegSweep => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::DebuffEnemy(Debuff::Weak(2)),
+                        PlayEffect::Block(11),
+                    ],
+                    &[
+                        PlayEffect::DebuffEnemy(Debuff::Weak(3)),
+                        PlayEffect::Block(14),
+                    ],
+                    Cost::Fixed(2),
+                    true,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::MasterfulStab => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(12)],
+                    &[PlayEffect::Attack(16)],
+                    Cost::NumPlusHpLoss(0),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::NoxiousFumes => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::NoxiousFumes(2))],
+                    &[PlayEffect::Buff(Buff::NoxiousFumes(3))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Predator => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Attack(15),
+                        PlayEffect::Buff(Buff::DrawNextTurn(2)),
+                    ],
+                    &[
+                        PlayEffect::Attack(20),
+                        PlayEffect::Buff(Buff::DrawNextTurn(2)),
+                    ],
+                    Cost::Fixed(2),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            //Reflex and Tactician only do something when discarded.
+            CardBody::Reflex => const_card!(
+                &CardProps::new(
+                    &[],
+                    &[],
+                    Cost::Unplayable,
+                    false,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::RiddleWithHoles => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Attack(3),
+                        PlayEffect::Attack(3),
+                        PlayEffect::Attack(3),
+                        PlayEffect::Attack(3),
+                        PlayEffect::Attack(3),
+                    ],
+                    &[
+                        PlayEffect::Attack(4),
+                        PlayEffect::Attack(4),
+                        PlayEffect::Attack(4),
+                        PlayEffect::Attack(4),
+                        PlayEffect::Attack(4),
+                    ],
+                    Cost::Fixed(2),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Setup => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::SelectCardEffect(
+                        SelectCardEffect::HandToTopFree
+                    )],
+                    &[PlayEffect::SelectCardEffect(
+                        SelectCardEffect::HandToTopFree
+                    )],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_upgraded_cost(Cost::Fixed(0))
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Skewer => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::AttackX(7)],
+                    &[PlayEffect::AttackX(10)],
+                    Cost::X,
+                    true,
+                    CardType::Attack,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Tactician => const_card!(
+                &CardProps::new(
+                    &[],
+                    &[],
+                    Cost::Unplayable,
+                    false,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Terror => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::DebuffEnemy(Debuff::Vulnerable(99)),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    &[
+                        PlayEffect::DebuffEnemy(Debuff::Vulnerable(99)),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_upgraded_cost(Cost::Fixed(0))
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::WellLaidPlans => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::WellLaidPlans(1))],
+                    &[PlayEffect::Buff(Buff::WellLaidPlans(2))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::AThousandCuts => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::ThousandCuts(1))],
+                    &[PlayEffect::Buff(Buff::ThousandCuts(2))],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Power,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Adrenaline => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::GainEnergy(1),
+                        PlayEffect::Draw(2),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    &[
+                        PlayEffect::GainEnergy(2),
+                        PlayEffect::Draw(2),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::AfterImage => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::AfterImage(1))],
+                    &[PlayEffect::Buff(Buff::AfterImage(1))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::SILENT
+                )
+                .with_innate(Innate::Upgraded)
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Alchemize => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::GainRandomPotion, PlayEffect::MarkExhaust],
+                    &[PlayEffect::GainRandomPotion, PlayEffect::MarkExhaust],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_upgraded_cost(Cost::Fixed(0))
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::BulletTime => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::HandCostsZero,
+                        PlayEffect::DebuffSelf(Debuff::NoDraw),
+                    ],
+                    &[
+                        PlayEffect::HandCostsZero,
+                        PlayEffect::DebuffSelf(Debuff::NoDraw),
+                    ],
+                    Cost::Fixed(3),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_upgraded_cost(Cost::Fixed(2))
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Burst => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Burst(1))],
+                    &[PlayEffect::Buff(Buff::Burst(2))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::CorpseExplosion => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::DebuffEnemy(Debuff::Poison(6)),
+                        PlayEffect::DebuffEnemy(Debuff::CorpseExplosion),
+                    ],
+                    &[
+                        PlayEffect::DebuffEnemy(Debuff::Poison(9)),
+                        PlayEffect::DebuffEnemy(Debuff::CorpseExplosion),
+                    ],
+                    Cost::Fixed(2),
+                    true,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::DieDieDie => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::AttackAll(13), PlayEffect::MarkExhaust],
+                    &[PlayEffect::AttackAll(17), PlayEffect::MarkExhaust],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Attack,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Doppelganger => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Doppelganger(0), PlayEffect::MarkExhaust],
+                    &[PlayEffect::Doppelganger(1), PlayEffect::MarkExhaust],
+                    Cost::X,
+                    false,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Envenom => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Envenom(1))],
+                    &[PlayEffect::Buff(Buff::Envenom(1))],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Power,
+                    CardCharachter::SILENT
+                )
+                .with_upgraded_cost(Cost::Fixed(1))
+                .with_rarity(CardRarity::Rare)
+            ),
+            //Glass Knife loses damage for the rest of the fight each time it is played.
+            CardBody::GlassKnife => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Attack(8),
+                        PlayEffect::Attack(8),
+                        PlayEffect::IncreaseDamage(-2),
+                    ],
+                    &[
+                        PlayEffect::Attack(12),
+                        PlayEffect::Attack(12),
+                        PlayEffect::IncreaseDamage(-2),
+                    ],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::GrandFinale => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::AttackAll(50)],
+                    &[PlayEffect::AttackAll(60)],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Attack,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Malaise => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::MalaiseDebuff(0), PlayEffect::MarkExhaust],
+                    &[PlayEffect::MalaiseDebuff(1), PlayEffect::MarkExhaust],
+                    Cost::X,
+                    true,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Nightmare => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::SelectCardEffect(SelectCardEffect::Nightmare),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    &[
+                        PlayEffect::SelectCardEffect(SelectCardEffect::Nightmare),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    Cost::Fixed(3),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_upgraded_cost(Cost::Fixed(2))
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::PhantasmalKiller => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Phantasmal(1))],
+                    &[PlayEffect::Buff(Buff::Phantasmal(1))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_upgraded_cost(Cost::Fixed(0))
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::StormOfSteel => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::DiscardHandForShivs(false)],
+                    &[PlayEffect::DiscardHandForShivs(true)],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::ToolsOfTheTrade => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::ToolsOfTheTrade(1))],
+                    &[PlayEffect::Buff(Buff::ToolsOfTheTrade(1))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::SILENT
+                )
+                .with_upgraded_cost(Cost::Fixed(0))
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Unload => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(14), PlayEffect::DiscardNonAttacks],
+                    &[PlayEffect::Attack(18), PlayEffect::DiscardNonAttacks],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::WraithForm => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Buff(Buff::Intangible(2)),
+                        PlayEffect::Buff(Buff::WraithForm(1)),
+                    ],
+                    &[
+                        PlayEffect::Buff(Buff::Intangible(3)),
+                        PlayEffect::Buff(Buff::WraithForm(1)),
+                    ],
+                    Cost::Fixed(3),
+                    false,
+                    CardType::Power,
+                    CardCharachter::SILENT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Shiv => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(4), PlayEffect::MarkExhaust],
+                    &[PlayEffect::Attack(6), PlayEffect::MarkExhaust],
+                    Cost::Fixed(0),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Special)
+            ),
+            CardBody::Zap => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Channel(Orb::Lightning)],
+                    &[PlayEffect::Channel(Orb::Lightning)],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_upgraded_cost(Cost::Fixed(0))
+                .with_starter()
+            ),
+            CardBody::Dualcast => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Evoke(2)],
+                    &[PlayEffect::Evoke(2)],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_upgraded_cost(Cost::Fixed(0))
+                .with_starter()
+            ),
+            CardBody::BallLightning => const_card!(&CardProps::new(
+                &[PlayEffect::Attack(7), PlayEffect::Channel(Orb::Lightning)],
+                &[PlayEffect::Attack(10), PlayEffect::Channel(Orb::Lightning)],
+                Cost::Fixed(1),
+                true,
+                CardType::Attack,
+                CardCharachter::DEFECT
+            )),
+            CardBody::Barrage => const_card!(&CardProps::new(
+                &[PlayEffect::AttackPerOrb(4)],
+                &[PlayEffect::AttackPerOrb(6)],
+                Cost::Fixed(1),
+                true,
+                CardType::Attack,
+                CardCharachter::DEFECT
+            )),
+            CardBody::BeamCell => const_card!(&CardProps::new(
+                &[
+                    PlayEffect::Attack(3),
+                    PlayEffect::DebuffEnemy(Debuff::Vulnerable(1))
+                ],
+                &[
+                    PlayEffect::Attack(4),
+                    PlayEffect::DebuffEnemy(Debuff::Vulnerable(2))
+                ],
+                Cost::Fixed(0),
+                true,
+                CardType::Attack,
+                CardCharachter::DEFECT
+            )),
+            CardBody::ChargeBattery => const_card!(&CardProps::new(
+                &[
+                    PlayEffect::Block(7),
+                    PlayEffect::Buff(Buff::EnergyNextTurn(1))
+                ],
+                &[
+                    PlayEffect::Block(10),
+                    PlayEffect::Buff(Buff::EnergyNextTurn(1))
+                ],
+                Cost::Fixed(1),
+                false,
+                CardType::Skill,
+                CardCharachter::DEFECT
+            )),
+            CardBody::Claw => const_card!(&CardProps::new(
+                &[PlayEffect::Attack(3), PlayEffect::IncreaseClawDamage(2)],
+                &[PlayEffect::Attack(5), PlayEffect::IncreaseClawDamage(2)],
+                Cost::Fixed(0),
+                true,
+                CardType::Attack,
+                CardCharachter::DEFECT
+            )),
+            CardBody::ColdSnap => const_card!(&CardProps::new(
+                &[PlayEffect::Attack(6), PlayEffect::Channel(Orb::Frost)],
+                &[PlayEffect::Attack(9), PlayEffect::Channel(Orb::Frost)],
+                Cost::Fixed(1),
+                true,
+                CardType::Attack,
+                CardCharachter::DEFECT
+            )),
+            CardBody::CompileDriver => const_card!(&CardProps::new(
+                &[PlayEffect::Attack(7), PlayEffect::DrawPerUniqueOrb],
+                &[PlayEffect::Attack(10), PlayEffect::DrawPerUniqueOrb],
+                Cost::Fixed(1),
+                true,
+                CardType::Attack,
+                CardCharachter::DEFECT
+            )),
+            CardBody::Coolheaded => const_card!(&CardProps::new(
+                &[PlayEffect::Channel(Orb::Frost), PlayEffect::Draw(1)],
+                &[PlayEffect::Channel(Orb::Frost), PlayEffect::Draw(2)],
+                Cost::Fixed(1),
+                false,
+                CardType::Skill,
+                CardCharachter::DEFECT
+            )),
+            CardBody::GoForTheEyes => const_card!(&CardProps::new(
+                &[PlayEffect::Attack(3), PlayEffect::WeakIfAttacking(1)],
+                &[PlayEffect::Attack(4), PlayEffect::WeakIfAttacking(2)],
+                Cost::Fixed(0),
+                true,
+                CardType::Attack,
+                CardCharachter::DEFECT
+            )),
+            CardBody::Hologram => const_card!(&CardProps::new(
+                &[
+                    PlayEffect::Block(3),
+                    PlayEffect::SelectCardEffect(SelectCardEffect::DiscardToHand),
+                    PlayEffect::MarkExhaust,
+                ],
+                &[
+                    PlayEffect::Block(5),
+                    PlayEffect::SelectCardEffect(SelectCardEffect::DiscardToHand),
+                ],
+                Cost::Fixed(1),
+                false,
+                CardType::Skill,
+                CardCharachter::DEFECT
+            )),
+            CardBody::Leap => const_card!(&CardProps::new(
+                &[PlayEffect::Block(9)],
+                &[PlayEffect::Block(12)],
+                Cost::Fixed(1),
+                false,
+                CardType::Skill,
+                CardCharachter::DEFECT
+            )),
+            CardBody::Rebound => const_card!(&CardProps::new(
+                &[PlayEffect::Attack(9), PlayEffect::Rebound],
+                &[PlayEffect::Attack(12), PlayEffect::Rebound],
+                Cost::Fixed(1),
+                true,
+                CardType::Attack,
+                CardCharachter::DEFECT
+            )),
+            CardBody::Recursion => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::EvokeAndRechannel],
+                    &[PlayEffect::EvokeAndRechannel],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_upgraded_cost(Cost::Fixed(0))
+            ),
+            CardBody::Stack => const_card!(&CardProps::new(
+                &[PlayEffect::BlockPerDiscard(0)],
+                &[PlayEffect::BlockPerDiscard(3)],
+                Cost::Fixed(1),
+                false,
+                CardType::Skill,
+                CardCharachter::DEFECT
+            )),
+            //Steam Barrier gives less block each time it is played.
+            CardBody::SteamBarrier => const_card!(&CardProps::new(
+                &[PlayEffect::BlockPlusBonus(6), PlayEffect::IncreaseBlock(-1)],
+                &[PlayEffect::BlockPlusBonus(8), PlayEffect::IncreaseBlock(-1)],
+                Cost::Fixed(0),
+                false,
+                CardType::Skill,
+                CardCharachter::DEFECT
+            )),
+            CardBody::Streamline => const_card!(&CardProps::new(
+                &[PlayEffect::Attack(15), PlayEffect::ReduceOwnCost],
+                &[PlayEffect::Attack(20), PlayEffect::ReduceOwnCost],
+                Cost::Fixed(2),
+                true,
+                CardType::Attack,
+                CardCharachter::DEFECT
+            )),
+            CardBody::SweepingBeam => const_card!(&CardProps::new(
+                &[PlayEffect::AttackAll(6), PlayEffect::Draw(1)],
+                &[PlayEffect::AttackAll(9), PlayEffect::Draw(1)],
+                Cost::Fixed(1),
+                false,
+                CardType::Attack,
+                CardCharachter::DEFECT
+            )),
+            CardBody::Turbo => const_card!(&CardProps::new(
+                &[
+                    PlayEffect::GainEnergy(2),
+                    PlayEffect::AddCardToDiscard(CardBody::Void)
+                ],
+                &[
+                    PlayEffect::GainEnergy(3),
+                    PlayEffect::AddCardToDiscard(CardBody::Void)
+                ],
+                Cost::Fixed(0),
+                false,
+                CardType::Skill,
+                CardCharachter::DEFECT
+            )),
+            CardBody::Aggregate => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::EnergyPerDrawPile(4)],
+                    &[PlayEffect::EnergyPerDrawPile(3)],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::AutoShields => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::BlockIfNoBlock(11)],
+                    &[PlayEffect::BlockIfNoBlock(15)],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Blizzard => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::AttackAllPerFrost(2)],
+                    &[PlayEffect::AttackAllPerFrost(3)],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Attack,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::BootSequence => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Block(10), PlayEffect::MarkExhaust],
+                    &[PlayEffect::Block(13), PlayEffect::MarkExhaust],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_innate(Innate::Yes)
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Bullseye => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Attack(8),
+                        PlayEffect::DebuffEnemy(Debuff::LockOn(2))
+                    ],
+                    &[
+                        PlayEffect::Attack(11),
+                        PlayEffect::DebuffEnemy(Debuff::LockOn(3))
+                    ],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Capacitor => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::GainOrbSlots(2)],
+                    &[PlayEffect::GainOrbSlots(3)],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Chaos => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::ChannelRandomOrb],
+                    &[PlayEffect::ChannelRandomOrb, PlayEffect::ChannelRandomOrb],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Chill => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::ChannelFrostPerEnemy, PlayEffect::MarkExhaust],
+                    &[PlayEffect::ChannelFrostPerEnemy, PlayEffect::MarkExhaust],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_innate(Innate::Upgraded)
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Consume => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Buff(Buff::Focus(2)),
+                        PlayEffect::GainOrbSlots(-1)
+                    ],
+                    &[
+                        PlayEffect::Buff(Buff::Focus(3)),
+                        PlayEffect::GainOrbSlots(-1)
+                    ],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Darkness => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Channel(Orb::DARK)],
+                    &[
+                        PlayEffect::Channel(Orb::DARK),
+                        PlayEffect::TriggerDarkPassives
+                    ],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Defragment => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Focus(1))],
+                    &[PlayEffect::Buff(Buff::Focus(2))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::DoomAndGloom => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::AttackAll(10), PlayEffect::Channel(Orb::DARK)],
+                    &[PlayEffect::AttackAll(14), PlayEffect::Channel(Orb::DARK)],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Attack,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::DoubleEnergy => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::DoubleEnergy, PlayEffect::MarkExhaust],
+                    &[PlayEffect::DoubleEnergy, PlayEffect::MarkExhaust],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_upgraded_cost(Cost::Fixed(0))
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Equilibrium => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Block(13),
+                        PlayEffect::Buff(Buff::Equilibrium(1))
+                    ],
+                    &[
+                        PlayEffect::Block(16),
+                        PlayEffect::Buff(Buff::Equilibrium(1))
+                    ],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::FTL => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(5), PlayEffect::DrawIfFewCardsPlayed(3)],
+                    &[PlayEffect::Attack(6), PlayEffect::DrawIfFewCardsPlayed(4)],
+                    Cost::Fixed(0),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::ForceField => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Block(12)],
+                    &[PlayEffect::Block(16)],
+                    Cost::NumMinusPowers(4),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Fusion => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Channel(Orb::Plasma)],
+                    &[PlayEffect::Channel(Orb::Plasma)],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_upgraded_cost(Cost::Fixed(1))
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::GeneticAlgorithm => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::BlockPlusBonus(1),
+                        PlayEffect::IncreaseBlockPermanently(2),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    &[
+                        PlayEffect::BlockPlusBonus(1),
+                        PlayEffect::IncreaseBlockPermanently(3),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Glacier => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Block(7),
+                        PlayEffect::Channel(Orb::Frost),
+                        PlayEffect::Channel(Orb::Frost),
+                    ],
+                    &[
+                        PlayEffect::Block(10),
+                        PlayEffect::Channel(Orb::Frost),
+                        PlayEffect::Channel(Orb::Frost),
+                    ],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Heatsinks => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Heatsinks(1))],
+                    &[PlayEffect::Buff(Buff::Heatsinks(2))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::HelloWorld => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::HelloWorld(1))],
+                    &[PlayEffect::Buff(Buff::HelloWorld(1))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::DEFECT
+                )
+                .with_innate(Innate::Upgraded)
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Loop => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Loop(1))],
+                    &[PlayEffect::Buff(Buff::Loop(2))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Melter => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::RemoveEnemyBlock, PlayEffect::Attack(10)],
+                    &[PlayEffect::RemoveEnemyBlock, PlayEffect::Attack(14)],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Overclock => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Draw(2),
+                        PlayEffect::AddCardToDiscard(CardBody::Burn)
+                    ],
+                    &[
+                        PlayEffect::Draw(3),
+                        PlayEffect::AddCardToDiscard(CardBody::Burn)
+                    ],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Recycle => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::SelectCardEffect(
+                        SelectCardEffect::ExhaustForEnergy
+                    )],
+                    &[PlayEffect::SelectCardEffect(
+                        SelectCardEffect::ExhaustForEnergy
+                    )],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_upgraded_cost(Cost::Fixed(0))
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::ReinforcedBody => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::BlockX(7)],
+                    &[PlayEffect::BlockX(9)],
+                    Cost::X,
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Reprogram => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::DebuffSelf(Debuff::MinusFocus(1)),
+                        PlayEffect::Buff(Buff::Strength(1)),
+                        PlayEffect::Buff(Buff::Dexterity(1)),
+                    ],
+                    &[
+                        PlayEffect::DebuffSelf(Debuff::MinusFocus(2)),
+                        PlayEffect::Buff(Buff::Strength(2)),
+                        PlayEffect::Buff(Buff::Dexterity(2)),
+                    ],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::RipAndTear => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::AttackRandomEnemy(7),
+                        PlayEffect::AttackRandomEnemy(7)
+                    ],
+                    &[
+                        PlayEffect::AttackRandomEnemy(9),
+                        PlayEffect::AttackRandomEnemy(9)
+                    ],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Attack,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Scrape => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(7), PlayEffect::ScrapeDraw(4)],
+                    &[PlayEffect::Attack(10), PlayEffect::ScrapeDraw(5)],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::SelfRepair => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::SelfRepair(7))],
+                    &[PlayEffect::Buff(Buff::SelfRepair(10))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Skim => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Draw(3)],
+                    &[PlayEffect::Draw(4)],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::StaticDischarge => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::StaticDischarge(1))],
+                    &[PlayEffect::Buff(Buff::StaticDischarge(2))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Storm => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Storm(1))],
+                    &[PlayEffect::Buff(Buff::Storm(1))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::DEFECT
+                )
+                .with_innate(Innate::Upgraded)
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Sunder => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::AttackLethalEffect(
+                        24,
+                        LethalEffect::Gain3Energy
+                    )],
+                    &[PlayEffect::AttackLethalEffect(
+                        32,
+                        LethalEffect::Gain3Energy
+                    )],
+                    Cost::Fixed(3),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Tempest => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::ChannelLightningX(0), PlayEffect::MarkExhaust],
+                    &[PlayEffect::ChannelLightningX(1), PlayEffect::MarkExhaust],
+                    Cost::X,
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::WhiteNoise => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::GenerateRandomPower, PlayEffect::MarkExhaust],
+                    &[PlayEffect::GenerateRandomPower, PlayEffect::MarkExhaust],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_upgraded_cost(Cost::Fixed(0))
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::AllForOne => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(10), PlayEffect::ZeroCostDiscardToHand],
+                    &[PlayEffect::Attack(14), PlayEffect::ZeroCostDiscardToHand],
+                    Cost::Fixed(2),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Amplify => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Amplify(1))],
+                    &[PlayEffect::Buff(Buff::Amplify(2))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::BiasedCognition => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Buff(Buff::Focus(4)),
+                        PlayEffect::Buff(Buff::BiasedCognition(1))
+                    ],
+                    &[
+                        PlayEffect::Buff(Buff::Focus(5)),
+                        PlayEffect::Buff(Buff::BiasedCognition(1))
+                    ],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Buffer => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Buffer(1))],
+                    &[PlayEffect::Buff(Buff::Buffer(2))],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Power,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::CoreSurge => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Attack(11),
+                        PlayEffect::Buff(Buff::Artifact(1)),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    &[
+                        PlayEffect::Attack(15),
+                        PlayEffect::Buff(Buff::Artifact(1)),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::CreativeAI => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::CreativeAI(1))],
+                    &[PlayEffect::Buff(Buff::CreativeAI(1))],
+                    Cost::Fixed(3),
+                    false,
+                    CardType::Power,
+                    CardCharachter::DEFECT
+                )
+                .with_upgraded_cost(Cost::Fixed(2))
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::EchoForm => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::EchoForm(1))],
+                    &[PlayEffect::Buff(Buff::EchoForm(1))],
+                    Cost::Fixed(3),
+                    false,
+                    CardType::Power,
+                    CardCharachter::DEFECT
+                )
+                .with_ethereal(Ethereal::NotUpgraded)
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Electrodynamics => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Buff(Buff::Electrodynamics),
+                        PlayEffect::Channel(Orb::Lightning),
+                        PlayEffect::Channel(Orb::Lightning),
+                    ],
+                    &[
+                        PlayEffect::Buff(Buff::Electrodynamics),
+                        PlayEffect::Channel(Orb::Lightning),
+                        PlayEffect::Channel(Orb::Lightning),
+                        PlayEffect::Channel(Orb::Lightning),
+                    ],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Power,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Fission => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Fission(false), PlayEffect::MarkExhaust],
+                    &[PlayEffect::Fission(true), PlayEffect::MarkExhaust],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Hyperbeam => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::AttackAll(26),
+                        PlayEffect::DebuffSelf(Debuff::MinusFocus(3))
+                    ],
+                    &[
+                        PlayEffect::AttackAll(34),
+                        PlayEffect::DebuffSelf(Debuff::MinusFocus(3))
+                    ],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Attack,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::MachineLearning => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::MachineLearning(1))],
+                    &[PlayEffect::Buff(Buff::MachineLearning(1))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::DEFECT
+                )
+                .with_innate(Innate::Upgraded)
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::MeteorStrike => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Attack(24),
+                        PlayEffect::Channel(Orb::Plasma),
+                        PlayEffect::Channel(Orb::Plasma),
+                        PlayEffect::Channel(Orb::Plasma),
+                    ],
+                    &[
+                        PlayEffect::Attack(30),
+                        PlayEffect::Channel(Orb::Plasma),
+                        PlayEffect::Channel(Orb::Plasma),
+                        PlayEffect::Channel(Orb::Plasma),
+                    ],
+                    Cost::Fixed(5),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::MultiCast => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::EvokeX(0)],
+                    &[PlayEffect::EvokeX(1)],
+                    Cost::X,
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Rainbow => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Channel(Orb::Lightning),
+                        PlayEffect::Channel(Orb::Frost),
+                        PlayEffect::Channel(Orb::DARK),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    &[
+                        PlayEffect::Channel(Orb::Lightning),
+                        PlayEffect::Channel(Orb::Frost),
+                        PlayEffect::Channel(Orb::DARK),
+                    ],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Reboot => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Reboot(4), PlayEffect::MarkExhaust],
+                    &[PlayEffect::Reboot(6), PlayEffect::MarkExhaust],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Seek => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::SelectCardEffect(SelectCardEffect::DeckToHand),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    &[
+                        PlayEffect::SelectCardEffect(SelectCardEffect::DeckToHand),
+                        PlayEffect::SelectCardEffect(SelectCardEffect::DeckToHand),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::ThunderStrike => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::AttackRandomPerLightning(7)],
+                    &[PlayEffect::AttackRandomPerLightning(9)],
+                    Cost::Fixed(3),
+                    false,
+                    CardType::Attack,
+                    CardCharachter::DEFECT
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Eruption => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Attack(9),
+                        PlayEffect::EnterStance(Stance::Wrath)
+                    ],
+                    &[
+                        PlayEffect::Attack(9),
+                        PlayEffect::EnterStance(Stance::Wrath)
+                    ],
+                    Cost::Fixed(2),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::WATCHER
+                )
+                .with_upgraded_cost(Cost::Fixed(1))
+                .with_starter()
+            ),
+            CardBody::Vigilance => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Block(8), PlayEffect::EnterStance(Stance::Calm)],
+                    &[PlayEffect::Block(12), PlayEffect::EnterStance(Stance::Calm)],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_starter()
+            ),
+            CardBody::BowlingBash => const_card!(&CardProps::new(
+                &[PlayEffect::AttackPerEnemy(7)],
+                &[PlayEffect::AttackPerEnemy(10)],
+                Cost::Fixed(1),
+                true,
+                CardType::Attack,
+                CardCharachter::WATCHER
+            )),
+            CardBody::Consecrate => const_card!(&CardProps::new(
+                &[PlayEffect::AttackAll(5)],
+                &[PlayEffect::AttackAll(8)],
+                Cost::Fixed(0),
+                false,
+                CardType::Attack,
+                CardCharachter::WATCHER
+            )),
+            CardBody::Crescendo => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::EnterStance(Stance::Wrath),
+                        PlayEffect::MarkExhaust
+                    ],
+                    &[
+                        PlayEffect::EnterStance(Stance::Wrath),
+                        PlayEffect::MarkExhaust
+                    ],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_upgraded_cost(Cost::Fixed(0))
+                .with_retain(Retain::Yes)
+            ),
+            CardBody::CrushJoints => const_card!(&CardProps::new(
+                &[PlayEffect::Attack(8), PlayEffect::VulnerableIfLastSkill(1)],
+                &[PlayEffect::Attack(10), PlayEffect::VulnerableIfLastSkill(2)],
+                Cost::Fixed(1),
+                true,
+                CardType::Attack,
+                CardCharachter::WATCHER
+            )),
+            CardBody::CutThroughFate => const_card!(&CardProps::new(
+                &[
+                    PlayEffect::Attack(7),
+                    PlayEffect::Scry(2),
+                    PlayEffect::Draw(1)
+                ],
+                &[
+                    PlayEffect::Attack(9),
+                    PlayEffect::Scry(3),
+                    PlayEffect::Draw(1)
+                ],
+                Cost::Fixed(1),
+                true,
+                CardType::Attack,
+                CardCharachter::WATCHER
+            )),
+            CardBody::EmptyBody => const_card!(&CardProps::new(
+                &[
+                    PlayEffect::Block(7),
+                    PlayEffect::EnterStance(Stance::Neutral)
+                ],
+                &[
+                    PlayEffect::Block(10),
+                    PlayEffect::EnterStance(Stance::Neutral)
+                ],
+                Cost::Fixed(1),
+                false,
+                CardType::Skill,
+                CardCharachter::WATCHER
+            )),
+            CardBody::EmptyFist => const_card!(&CardProps::new(
+                &[
+                    PlayEffect::Attack(9),
+                    PlayEffect::EnterStance(Stance::Neutral)
+                ],
+                &[
+                    PlayEffect::Attack(14),
+                    PlayEffect::EnterStance(Stance::Neutral)
+                ],
+                Cost::Fixed(1),
+                true,
+                CardType::Attack,
+                CardCharachter::WATCHER
+            )),
+            CardBody::Evaluate => const_card!(&CardProps::new(
+                &[
+                    PlayEffect::Block(6),
+                    PlayEffect::ShuffleInCard(CardBody::Insight)
+                ],
+                &[
+                    PlayEffect::Block(10),
+                    PlayEffect::ShuffleInCard(CardBody::Insight)
+                ],
+                Cost::Fixed(1),
+                false,
+                CardType::Skill,
+                CardCharachter::WATCHER
+            )),
+            //Flurry of Blows returns to the hand from the discard pile when the stance changes.
+            CardBody::FlurryOfBlows => const_card!(&CardProps::new(
+                &[PlayEffect::Attack(4)],
+                &[PlayEffect::Attack(6)],
+                Cost::Fixed(0),
+                true,
+                CardType::Attack,
+                CardCharachter::WATCHER
+            )),
+            CardBody::FlyingSleeves => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(4), PlayEffect::Attack(4)],
+                    &[PlayEffect::Attack(6), PlayEffect::Attack(6)],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::WATCHER
+                )
+                .with_retain(Retain::Yes)
+            ),
+            CardBody::FollowUp => const_card!(&CardProps::new(
+                &[PlayEffect::Attack(7), PlayEffect::EnergyIfLastAttack(1)],
+                &[PlayEffect::Attack(11), PlayEffect::EnergyIfLastAttack(1)],
+                Cost::Fixed(1),
+                true,
+                CardType::Attack,
+                CardCharachter::WATCHER
+            )),
+            CardBody::Halt => const_card!(&CardProps::new(
+                &[PlayEffect::Block(3), PlayEffect::BlockIfWrath(9)],
+                &[PlayEffect::Block(4), PlayEffect::BlockIfWrath(14)],
+                Cost::Fixed(0),
+                false,
+                CardType::Skill,
+                CardCharachter::WATCHER
+            )),
+            CardBody::JustLucky => const_card!(&CardProps::new(
+                &[
+                    PlayEffect::Scry(1),
+                    PlayEffect::Block(2),
+                    PlayEffect::Attack(3)
+                ],
+                &[
+                    PlayEffect::Scry(2),
+                    PlayEffect::Block(3),
+                    PlayEffect::Attack(4)
+                ],
+                Cost::Fixed(0),
+                true,
+                CardType::Attack,
+                CardCharachter::WATCHER
+            )),
+            CardBody::PressurePoints => const_card!(&CardProps::new(
+                &[
+                    PlayEffect::DebuffEnemy(Debuff::Mark(8)),
+                    PlayEffect::TriggerMarks
+                ],
+                &[
+                    PlayEffect::DebuffEnemy(Debuff::Mark(11)),
+                    PlayEffect::TriggerMarks
+                ],
+                Cost::Fixed(1),
+                true,
+                CardType::Skill,
+                CardCharachter::WATCHER
+            )),
+            CardBody::Prostrate => const_card!(&CardProps::new(
+                &[PlayEffect::GainMantra(2), PlayEffect::Block(4)],
+                &[PlayEffect::GainMantra(3), PlayEffect::Block(4)],
+                Cost::Fixed(0),
+                false,
+                CardType::Skill,
+                CardCharachter::WATCHER
+            )),
+            CardBody::Protect => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Block(12)],
+                    &[PlayEffect::Block(16)],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_retain(Retain::Yes)
+            ),
+            CardBody::SashWhip => const_card!(&CardProps::new(
+                &[PlayEffect::Attack(8), PlayEffect::WeakIfLastAttack(1)],
+                &[PlayEffect::Attack(10), PlayEffect::WeakIfLastAttack(2)],
+                Cost::Fixed(1),
+                true,
+                CardType::Attack,
+                CardCharachter::WATCHER
+            )),
+            CardBody::ThirdEye => const_card!(&CardProps::new(
+                &[PlayEffect::Block(7), PlayEffect::Scry(3)],
+                &[PlayEffect::Block(9), PlayEffect::Scry(5)],
+                Cost::Fixed(1),
+                false,
+                CardType::Skill,
+                CardCharachter::WATCHER
+            )),
+            CardBody::Tranquility => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::EnterStance(Stance::Calm),
+                        PlayEffect::MarkExhaust
+                    ],
+                    &[
+                        PlayEffect::EnterStance(Stance::Calm),
+                        PlayEffect::MarkExhaust
+                    ],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_upgraded_cost(Cost::Fixed(0))
+                .with_retain(Retain::Yes)
+            ),
+            CardBody::BattleHymn => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::BattleHymn(1))],
+                    &[PlayEffect::Buff(Buff::BattleHymn(1))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::WATCHER
+                )
+                .with_innate(Innate::Upgraded)
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::CarveReality => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Attack(6),
+                        PlayEffect::AddCardToHand(CardBody::Smite)
+                    ],
+                    &[
+                        PlayEffect::Attack(10),
+                        PlayEffect::AddCardToHand(CardBody::Smite)
+                    ],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Collect => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::CollectX(0), PlayEffect::MarkExhaust],
+                    &[PlayEffect::CollectX(1), PlayEffect::MarkExhaust],
+                    Cost::X,
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Conclude => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::AttackAll(12), PlayEffect::EndTurn],
+                    &[PlayEffect::AttackAll(16), PlayEffect::EndTurn],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Attack,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::DeceiveReality => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Block(4),
+                        PlayEffect::AddCardToHand(CardBody::Safety)
+                    ],
+                    &[
+                        PlayEffect::Block(7),
+                        PlayEffect::AddCardToHand(CardBody::Safety)
+                    ],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::EmptyMind => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Draw(2),
+                        PlayEffect::EnterStance(Stance::Neutral)
+                    ],
+                    &[
+                        PlayEffect::Draw(3),
+                        PlayEffect::EnterStance(Stance::Neutral)
+                    ],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Fasting => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Buff(Buff::Strength(3)),
+                        PlayEffect::Buff(Buff::Dexterity(3)),
+                        PlayEffect::DebuffSelf(Debuff::EnergyDown(1))
+                    ],
+                    &[
+                        PlayEffect::Buff(Buff::Strength(4)),
+                        PlayEffect::Buff(Buff::Dexterity(4)),
+                        PlayEffect::DebuffSelf(Debuff::EnergyDown(1))
+                    ],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Power,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::FearNoEvil => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(8), PlayEffect::CalmIfAttacking],
+                    &[PlayEffect::Attack(11), PlayEffect::CalmIfAttacking],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::ForeignInfluence => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::ChooseClassAttack(false),
+                        PlayEffect::MarkExhaust
+                    ],
+                    &[PlayEffect::ChooseClassAttack(true), PlayEffect::MarkExhaust],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Foresight => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Foresight(3))],
+                    &[PlayEffect::Buff(Buff::Foresight(4))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Indignation => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Indignation(3)],
+                    &[PlayEffect::Indignation(5)],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::InnerPeace => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::InnerPeace(3)],
+                    &[PlayEffect::InnerPeace(4)],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::LikeWater => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::LikeWater(5))],
+                    &[PlayEffect::Buff(Buff::LikeWater(7))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Meditate => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::SelectCardEffect(SelectCardEffect::DiscardToRetained),
+                        PlayEffect::EnterStance(Stance::Calm),
+                        PlayEffect::EndTurn,
+                    ],
+                    &[
+                        PlayEffect::SelectCardEffect(SelectCardEffect::DiscardToRetained),
+                        PlayEffect::SelectCardEffect(SelectCardEffect::DiscardToRetained),
+                        PlayEffect::EnterStance(Stance::Calm),
+                        PlayEffect::EndTurn,
+                    ],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::MentalFortress => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::MentalFortress(4))],
+                    &[PlayEffect::Buff(Buff::MentalFortress(6))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Nirvana => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Nirvana(3))],
+                    &[PlayEffect::Buff(Buff::Nirvana(4))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            //Perseverance's block grows each time it is retained.
+            CardBody::Perseverance => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::BlockPlusBonus(5)],
+                    &[PlayEffect::BlockPlusBonus(7)],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_retain(Retain::Yes)
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Pray => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::GainMantra(3),
+                        PlayEffect::ShuffleInCard(CardBody::Insight)
+                    ],
+                    &[
+                        PlayEffect::GainMantra(4),
+                        PlayEffect::ShuffleInCard(CardBody::Insight)
+                    ],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::ReachHeaven => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Attack(10),
+                        PlayEffect::ShuffleInCard(CardBody::ThroughViolence)
+                    ],
+                    &[
+                        PlayEffect::Attack(15),
+                        PlayEffect::ShuffleInCard(CardBody::ThroughViolence)
+                    ],
+                    Cost::Fixed(2),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Rushdown => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Rushdown(2))],
+                    &[PlayEffect::Buff(Buff::Rushdown(2))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::WATCHER
+                )
+                .with_upgraded_cost(Cost::Fixed(0))
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Sanctity => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Block(6), PlayEffect::DrawIfLastSkill(2)],
+                    &[PlayEffect::Block(9), PlayEffect::DrawIfLastSkill(2)],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            //Sands of Time costs 1 less each time it is retained.
+            CardBody::SandsOfTime => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(20)],
+                    &[PlayEffect::Attack(26)],
+                    Cost::Fixed(4),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::WATCHER
+                )
+                .with_retain(Retain::Yes)
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            //Signature Move can only be played if it is the only attack in hand.
+            CardBody::SignatureMove => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(30)],
+                    &[PlayEffect::Attack(40)],
+                    Cost::Fixed(2),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::SimmeringFury => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::SimmeringFury(2))],
+                    &[PlayEffect::Buff(Buff::SimmeringFury(3))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Study => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Study(1))],
+                    &[PlayEffect::Buff(Buff::Study(1))],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Power,
+                    CardCharachter::WATCHER
+                )
+                .with_upgraded_cost(Cost::Fixed(1))
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Swivel => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Block(8), PlayEffect::Buff(Buff::FreeAttack)],
+                    &[PlayEffect::Block(11), PlayEffect::Buff(Buff::FreeAttack)],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::TalkToTheHand => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Attack(5),
+                        PlayEffect::DebuffEnemy(Debuff::BlockReturn(2)),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    &[
+                        PlayEffect::Attack(7),
+                        PlayEffect::DebuffEnemy(Debuff::BlockReturn(3)),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Tantrum => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::Attack(3),
+                        PlayEffect::Attack(3),
+                        PlayEffect::Attack(3),
+                        PlayEffect::EnterStance(Stance::Wrath),
+                        PlayEffect::ShuffleBack,
+                    ],
+                    &[
+                        PlayEffect::Attack(3),
+                        PlayEffect::Attack(3),
+                        PlayEffect::Attack(3),
+                        PlayEffect::Attack(3),
+                        PlayEffect::EnterStance(Stance::Wrath),
+                        PlayEffect::ShuffleBack,
+                    ],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Wallop => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::AttackGainBlock(9)],
+                    &[PlayEffect::AttackGainBlock(12)],
+                    Cost::Fixed(2),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::WaveOfTheHand => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::WaveOfTheHand(1))],
+                    &[PlayEffect::Buff(Buff::WaveOfTheHand(2))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            //Weave returns to the hand from the discard pile whenever the player scries.
+            CardBody::Weave => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(4)],
+                    &[PlayEffect::Attack(6)],
+                    Cost::Fixed(0),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::WheelKick => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(15), PlayEffect::Draw(2)],
+                    &[PlayEffect::Attack(20), PlayEffect::Draw(2)],
+                    Cost::Fixed(2),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            //Windmill Strike's damage grows each time it is retained.
+            CardBody::WindmillStrike => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(7)],
+                    &[PlayEffect::Attack(10)],
+                    Cost::Fixed(2),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::WATCHER
+                )
+                .with_retain(Retain::Yes)
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Worship => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::GainMantra(5)],
+                    &[PlayEffect::GainMantra(5)],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_retain(Retain::Upgraded)
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::WreathOfFlame => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Vigor(5))],
+                    &[PlayEffect::Buff(Buff::Vigor(8))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Alpha => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::ShuffleInCard(CardBody::Beta),
+                        PlayEffect::MarkExhaust
+                    ],
+                    &[
+                        PlayEffect::ShuffleInCard(CardBody::Beta),
+                        PlayEffect::MarkExhaust
+                    ],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_innate(Innate::Upgraded)
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Blasphemy => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::EnterStance(Stance::Divinity),
+                        PlayEffect::Buff(Buff::Blasphemy),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    &[
+                        PlayEffect::EnterStance(Stance::Divinity),
+                        PlayEffect::Buff(Buff::Blasphemy),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_retain(Retain::Upgraded)
+                .with_rarity(CardRarity::Rare)
+            ),
+            //Brilliance deals extra damage for each Mantra gained this fight.
+            CardBody::Brilliance => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(12)],
+                    &[PlayEffect::Attack(16)],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::ConjureBlade => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::ConjureBlade(0), PlayEffect::MarkExhaust],
+                    &[PlayEffect::ConjureBlade(1), PlayEffect::MarkExhaust],
+                    Cost::X,
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            //Deus Ex Machina adds Miracles to the hand and exhausts itself when drawn.
+            CardBody::DeusExMachina => const_card!(
+                &CardProps::new(
+                    &[],
+                    &[],
+                    Cost::Unplayable,
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::DevaForm => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::DevaForm(1))],
+                    &[PlayEffect::Buff(Buff::DevaForm(1))],
+                    Cost::Fixed(3),
+                    false,
+                    CardType::Power,
+                    CardCharachter::WATCHER
+                )
+                .with_ethereal(Ethereal::NotUpgraded)
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Devotion => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Devotion(2))],
+                    &[PlayEffect::Buff(Buff::Devotion(3))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Establishment => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Establishment(1))],
+                    &[PlayEffect::Buff(Buff::Establishment(1))],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::WATCHER
+                )
+                .with_innate(Innate::Upgraded)
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Judgment => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Judgment(30)],
+                    &[PlayEffect::Judgment(40)],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::LessonLearned => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::AttackLethalEffect(10, LethalEffect::UpgradeRandomCard),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    &[
+                        PlayEffect::AttackLethalEffect(13, LethalEffect::UpgradeRandomCard),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    Cost::Fixed(2),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::MasterReality => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::MasterReality)],
+                    &[PlayEffect::Buff(Buff::MasterReality)],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Power,
+                    CardCharachter::WATCHER
+                )
+                .with_upgraded_cost(Cost::Fixed(0))
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Omniscience => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::SelectCardEffect(SelectCardEffect::PlayTwiceFromDeck),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    &[
+                        PlayEffect::SelectCardEffect(SelectCardEffect::PlayTwiceFromDeck),
+                        PlayEffect::MarkExhaust,
+                    ],
+                    Cost::Fixed(4),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_upgraded_cost(Cost::Fixed(3))
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Ragnarok => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::AttackRandomEnemy(5),
+                        PlayEffect::AttackRandomEnemy(5),
+                        PlayEffect::AttackRandomEnemy(5),
+                        PlayEffect::AttackRandomEnemy(5),
+                        PlayEffect::AttackRandomEnemy(5),
+                    ],
+                    &[
+                        PlayEffect::AttackRandomEnemy(6),
+                        PlayEffect::AttackRandomEnemy(6),
+                        PlayEffect::AttackRandomEnemy(6),
+                        PlayEffect::AttackRandomEnemy(6),
+                        PlayEffect::AttackRandomEnemy(6),
+                        PlayEffect::AttackRandomEnemy(6),
+                    ],
+                    Cost::Fixed(3),
+                    false,
+                    CardType::Attack,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Scrawl => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::DrawToHandSize(10), PlayEffect::MarkExhaust],
+                    &[PlayEffect::DrawToHandSize(10), PlayEffect::MarkExhaust],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_upgraded_cost(Cost::Fixed(0))
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::SpiritShield => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::BlockPerCardInHand(3)],
+                    &[PlayEffect::BlockPerCardInHand(4)],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Vault => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::ExtraTurn,
+                        PlayEffect::EndTurn,
+                        PlayEffect::MarkExhaust
+                    ],
+                    &[
+                        PlayEffect::ExtraTurn,
+                        PlayEffect::EndTurn,
+                        PlayEffect::MarkExhaust
+                    ],
+                    Cost::Fixed(3),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_upgraded_cost(Cost::Fixed(2))
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Wish => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Wish, PlayEffect::MarkExhaust],
+                    &[PlayEffect::Wish, PlayEffect::MarkExhaust],
+                    Cost::Fixed(3),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::WATCHER
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Miracle => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::GainEnergy(1), PlayEffect::MarkExhaust],
+                    &[PlayEffect::GainEnergy(2), PlayEffect::MarkExhaust],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_retain(Retain::Yes)
+                .with_rarity(CardRarity::Special)
+            ),
+            CardBody::Insight => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Draw(2), PlayEffect::MarkExhaust],
+                    &[PlayEffect::Draw(3), PlayEffect::MarkExhaust],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_retain(Retain::Yes)
+                .with_rarity(CardRarity::Special)
+            ),
+            CardBody::Smite => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(12), PlayEffect::MarkExhaust],
+                    &[PlayEffect::Attack(16), PlayEffect::MarkExhaust],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::COLORLESS
+                )
+                .with_retain(Retain::Yes)
+                .with_rarity(CardRarity::Special)
+            ),
+            CardBody::Safety => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Block(12), PlayEffect::MarkExhaust],
+                    &[PlayEffect::Block(16), PlayEffect::MarkExhaust],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_retain(Retain::Yes)
+                .with_rarity(CardRarity::Special)
+            ),
+            CardBody::ThroughViolence => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(20), PlayEffect::MarkExhaust],
+                    &[PlayEffect::Attack(30), PlayEffect::MarkExhaust],
+                    Cost::Fixed(0),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::COLORLESS
+                )
+                .with_retain(Retain::Yes)
+                .with_rarity(CardRarity::Special)
+            ),
+            CardBody::Expunger => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::AttackPerHit(9)],
+                    &[PlayEffect::AttackPerHit(15)],
+                    Cost::Fixed(1),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Special)
+            ),
+            CardBody::Beta => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::ShuffleInCard(CardBody::Omega),
+                        PlayEffect::MarkExhaust
+                    ],
+                    &[
+                        PlayEffect::ShuffleInCard(CardBody::Omega),
+                        PlayEffect::MarkExhaust
+                    ],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_upgraded_cost(Cost::Fixed(1))
+                .with_rarity(CardRarity::Special)
+            ),
+            CardBody::Omega => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::EndTurnDamageAllEnemies(50))],
+                    &[PlayEffect::Buff(Buff::EndTurnDamageAllEnemies(60))],
+                    Cost::Fixed(3),
+                    false,
+                    CardType::Power,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Special)
+            ),
+            //These are the options offered by Wish, they are never in the hand.
+            CardBody::BecomeAlmighty => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Strength(3))],
+                    &[PlayEffect::Buff(Buff::Strength(4))],
+                    Cost::Unplayable,
+                    false,
+                    CardType::Power,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Special)
+            ),
+            CardBody::FameAndFortune => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::GainGold(25)],
+                    &[PlayEffect::GainGold(30)],
+                    Cost::Unplayable,
+                    false,
+                    CardType::Power,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Special)
+            ),
+            CardBody::LiveForever => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::PlatedArmor(6))],
+                    &[PlayEffect::Buff(Buff::PlatedArmor(8))],
+                    Cost::Unplayable,
+                    false,
+                    CardType::Power,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Special)
+            ),
+            CardBody::Apotheosis => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::UpgradeAllCards, PlayEffect::MarkExhaust],
+                    &[PlayEffect::UpgradeAllCards, PlayEffect::MarkExhaust],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_upgraded_cost(Cost::Fixed(1))
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::BandageUp => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Heal(4), PlayEffect::MarkExhaust],
+                    &[PlayEffect::Heal(6), PlayEffect::MarkExhaust],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Blind => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::DebuffEnemy(Debuff::Weak(2))],
+                    &[PlayEffect::DebuffAll(Debuff::Weak(2))],
+                    Cost::Fixed(0),
+                    true,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_upgraded_requires_target(false)
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Chrysalis => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::ShuffleInFreeClassCards(CardType::Skill, 3),
+                        PlayEffect::MarkExhaust
+                    ],
+                    &[
+                        PlayEffect::ShuffleInFreeClassCards(CardType::Skill, 5),
+                        PlayEffect::MarkExhaust
+                    ],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::DarkShackles => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::DebuffEnemy(Debuff::StrengthDown(9)),
+                        PlayEffect::MarkExhaust
+                    ],
+                    &[
+                        PlayEffect::DebuffEnemy(Debuff::StrengthDown(15)),
+                        PlayEffect::MarkExhaust
+                    ],
+                    Cost::Fixed(0),
+                    true,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::DeepBreath => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::ShuffleDiscardIntoDeck, PlayEffect::Draw(1)],
+                    &[PlayEffect::ShuffleDiscardIntoDeck, PlayEffect::Draw(2)],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Discovery => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Discovery, PlayEffect::MarkExhaust],
+                    &[PlayEffect::Discovery],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::DramaticEntrance => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::AttackAll(8), PlayEffect::MarkExhaust],
+                    &[PlayEffect::AttackAll(12), PlayEffect::MarkExhaust],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Attack,
+                    CardCharachter::COLORLESS
+                )
+                .with_innate(Innate::Yes)
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Enlightenment => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Enlightenment(false)],
+                    &[PlayEffect::Enlightenment(true)],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Finesse => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Block(2), PlayEffect::Draw(1)],
+                    &[PlayEffect::Block(4), PlayEffect::Draw(1)],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::FlashOfSteel => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(3), PlayEffect::Draw(1)],
+                    &[PlayEffect::Attack(6), PlayEffect::Draw(1)],
+                    Cost::Fixed(0),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::GoodInstincts => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Block(6)],
+                    &[PlayEffect::Block(9)],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::HandOfGreed => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::AttackLethalEffect(20, LethalEffect::Gain20Gold)],
+                    &[PlayEffect::AttackLethalEffect(25, LethalEffect::Gain25Gold)],
+                    Cost::Fixed(2),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Impatience => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::DrawIfNoAttacks(2)],
+                    &[PlayEffect::DrawIfNoAttacks(3)],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::JackOfAllTrades => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::AddRandomColorless(1), PlayEffect::MarkExhaust],
+                    &[PlayEffect::AddRandomColorless(2), PlayEffect::MarkExhaust],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Madness => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Madness, PlayEffect::MarkExhaust],
+                    &[PlayEffect::Madness, PlayEffect::MarkExhaust],
+                    Cost::Fixed(1),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_upgraded_cost(Cost::Fixed(0))
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Magnetism => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Magnetism(1))],
+                    &[PlayEffect::Buff(Buff::Magnetism(1))],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Power,
+                    CardCharachter::COLORLESS
+                )
+                .with_upgraded_cost(Cost::Fixed(1))
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::MasterOfStrategy => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Draw(3), PlayEffect::MarkExhaust],
+                    &[PlayEffect::Draw(4), PlayEffect::MarkExhaust],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Mayhem => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Mayhem(1))],
+                    &[PlayEffect::Buff(Buff::Mayhem(1))],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Power,
+                    CardCharachter::COLORLESS
+                )
+                .with_upgraded_cost(Cost::Fixed(1))
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Metamorphosis => const_card!(
+                &CardProps::new(
+                    &[
+                        PlayEffect::ShuffleInFreeClassCards(CardType::Attack, 3),
+                        PlayEffect::MarkExhaust
+                    ],
+                    &[
+                        PlayEffect::ShuffleInFreeClassCards(CardType::Attack, 5),
+                        PlayEffect::MarkExhaust
+                    ],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::MindBlast => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::AttackPerCardInDeck],
+                    &[PlayEffect::AttackPerCardInDeck],
+                    Cost::Fixed(2),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::COLORLESS
+                )
+                .with_upgraded_cost(Cost::Fixed(1))
+                .with_innate(Innate::Yes)
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Panacea => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Artifact(1)), PlayEffect::MarkExhaust],
+                    &[PlayEffect::Buff(Buff::Artifact(2)), PlayEffect::MarkExhaust],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Panache => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::Panache(10))],
+                    &[PlayEffect::Buff(Buff::Panache(14))],
+                    Cost::Fixed(0),
                     false,
                     CardType::Power,
-                    CardCharachter::IRONCLAD
+                    CardCharachter::COLORLESS
                 )
-                .with_upgraded_cost(Cost::Fixed(2))
+                .with_rarity(CardRarity::Rare)
             ),
-            CardBody::Berserk => const_card!(&CardProps::new(
-                &[
-                    PlayEffect::Buff(Buff::EnergyEveryTurn),
-                    PlayEffect::DebuffSelf(Debuff::Vulnerable(2))
-                ],
-                &[
-                    PlayEffect::Buff(Buff::EnergyEveryTurn),
-                    PlayEffect::DebuffSelf(Debuff::Vulnerable(2))
-                ],
-                Cost::Fixed(0),
-                false,
-                CardType::Power,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::Bludgeon => const_card!(&CardProps::new(
-                &[PlayEffect::Attack(32)],
-                &[PlayEffect::Attack(42)],
-                Cost::Fixed(3),
-                true,
-                CardType::Attack,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::Brutality => const_card!(
+            CardBody::PanicButton => const_card!(
                 &CardProps::new(
-                    &[PlayEffect::Buff(Buff::BrutalityBuff),],
-                    &[PlayEffect::Buff(Buff::BrutalityBuff),],
+                    &[
+                        PlayEffect::Block(30),
+                        PlayEffect::DebuffSelf(Debuff::NoBlock(2)),
+                        PlayEffect::MarkExhaust
+                    ],
+                    &[
+                        PlayEffect::Block(40),
+                        PlayEffect::DebuffSelf(Debuff::NoBlock(2)),
+                        PlayEffect::MarkExhaust
+                    ],
                     Cost::Fixed(0),
                     false,
-                    CardType::Power,
-                    CardCharachter::IRONCLAD
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
                 )
-                .with_innate(Innate::Upgraded)
+                .with_rarity(CardRarity::Uncommon)
             ),
-            CardBody::Corruption => const_card!(
+            CardBody::SecretTechnique => const_card!(
                 &CardProps::new(
-                    &[PlayEffect::Buff(Buff::CorruptionBuff)],
-                    &[PlayEffect::Buff(Buff::CorruptionBuff)],
-                    Cost::Fixed(3),
+                    &[
+                        PlayEffect::SelectCardEffect(SelectCardEffect::DeckTypeToHand(
+                            CardType::Skill
+                        )),
+                        PlayEffect::MarkExhaust
+                    ],
+                    &[PlayEffect::SelectCardEffect(
+                        SelectCardEffect::DeckTypeToHand(CardType::Skill)
+                    )],
+                    Cost::Fixed(0),
                     false,
-                    CardType::Power,
-                    CardCharachter::IRONCLAD
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
                 )
-                .with_upgraded_cost(Cost::Fixed(2))
+                .with_rarity(CardRarity::Rare)
             ),
-            CardBody::DemonForm => const_card!(&CardProps::new(
-                &[PlayEffect::Buff(Buff::Ritual(2))],
-                &[PlayEffect::Buff(Buff::Ritual(3))],
-                Cost::Fixed(3),
-                false,
-                CardType::Power,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::DoubleTap => const_card!(&CardProps::new(
-                &[PlayEffect::Buff(Buff::DoubleTap(1))],
-                &[PlayEffect::Buff(Buff::DoubleTap(2))],
-                Cost::Fixed(1),
-                false,
-                CardType::Skill,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::Exhume => const_card!(
+            CardBody::SecretWeapon => const_card!(
                 &CardProps::new(
                     &[
-                        PlayEffect::SelectCardEffect(SelectCardEffect::ExhaustToHand),
+                        PlayEffect::SelectCardEffect(SelectCardEffect::DeckTypeToHand(
+                            CardType::Attack
+                        )),
                         PlayEffect::MarkExhaust
                     ],
+                    &[PlayEffect::SelectCardEffect(
+                        SelectCardEffect::DeckTypeToHand(CardType::Attack)
+                    )],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::SwiftStrike => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Attack(7)],
+                    &[PlayEffect::Attack(10)],
+                    Cost::Fixed(0),
+                    true,
+                    CardType::Attack,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::TheBomb => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::Buff(Buff::TheBomb(40))],
+                    &[PlayEffect::Buff(Buff::TheBomb(50))],
+                    Cost::Fixed(2),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::ThinkingAhead => const_card!(
+                &CardProps::new(
                     &[
-                        PlayEffect::SelectCardEffect(SelectCardEffect::ExhaustToHand),
+                        PlayEffect::Draw(2),
+                        PlayEffect::SelectCardEffect(SelectCardEffect::HandToTop),
                         PlayEffect::MarkExhaust
                     ],
-                    Cost::Fixed(1),
+                    &[
+                        PlayEffect::Draw(2),
+                        PlayEffect::SelectCardEffect(SelectCardEffect::HandToTop)
+                    ],
+                    Cost::Fixed(0),
                     false,
                     CardType::Skill,
-                    CardCharachter::IRONCLAD
+                    CardCharachter::COLORLESS
                 )
-                .with_upgraded_cost(Cost::Fixed(0))
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Transmutation => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::TransmutationX(false), PlayEffect::MarkExhaust],
+                    &[PlayEffect::TransmutationX(true), PlayEffect::MarkExhaust],
+                    Cost::X,
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Rare)
+            ),
+            CardBody::Trip => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::DebuffEnemy(Debuff::Vulnerable(2))],
+                    &[PlayEffect::DebuffAll(Debuff::Vulnerable(2))],
+                    Cost::Fixed(0),
+                    true,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_upgraded_requires_target(false)
+                .with_rarity(CardRarity::Uncommon)
+            ),
+            CardBody::Violence => const_card!(
+                &CardProps::new(
+                    &[PlayEffect::DeckAttacksToHand(3), PlayEffect::MarkExhaust],
+                    &[PlayEffect::DeckAttacksToHand(4), PlayEffect::MarkExhaust],
+                    Cost::Fixed(0),
+                    false,
+                    CardType::Skill,
+                    CardCharachter::COLORLESS
+                )
+                .with_rarity(CardRarity::Rare)
             ),
-            CardBody::Feed => const_card!(&CardProps::new(
-                &[PlayEffect::AttackLethalEffect(10, LethalEffect::Gain3MaxHP)],
-                &[PlayEffect::AttackLethalEffect(12, LethalEffect::Gain4MaxHP)],
-                Cost::Fixed(1),
-                true,
-                CardType::Attack,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::FiendFire => const_card!(&CardProps::new(
-                &[PlayEffect::AttackFiendFire(7), PlayEffect::MarkExhaust],
-                &[PlayEffect::AttackFiendFire(10), PlayEffect::MarkExhaust],
-                Cost::Fixed(2),
-                true,
-                CardType::Attack,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::Immolate => const_card!(&CardProps::new(
-                &[
-                    PlayEffect::AttackAll(21),
-                    PlayEffect::AddCardToDiscard(CardBody::Burn)
-                ],
-                &[
-                    PlayEffect::AttackAll(28),
-                    PlayEffect::AddCardToDiscard(CardBody::Burn)
-                ],
-                Cost::Fixed(2),
-                false,
-                CardType::Attack,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::Burn => const_card!(&CardProps::new(
-                &[PlayEffect::MarkExhaust],
-                &[PlayEffect::MarkExhaust],
-                Cost::Unplayable,
-                false,
-                CardType::Status,
-                CardCharachter::COLORLESS
-            )),
-            CardBody::Impervious => const_card!(&CardProps::new(
-                &[PlayEffect::Block(30), PlayEffect::MarkExhaust],
-                &[PlayEffect::Block(40), PlayEffect::MarkExhaust],
-                Cost::Fixed(2),
-                false,
-                CardType::Skill,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::Juggernaut => const_card!(&CardProps::new(
-                &[PlayEffect::Buff(Buff::Juggernaut(5))],
-                &[PlayEffect::Buff(Buff::Juggernaut(7))],
-                Cost::Fixed(2),
-                false,
-                CardType::Power,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::LimitBreak => const_card!(&CardProps::new(
-                &[PlayEffect::DoubleStrength, PlayEffect::MarkExhaust],
-                &[PlayEffect::DoubleStrength],
-                Cost::Fixed(1),
-                false,
-                CardType::Skill,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::Offering => const_card!(&CardProps::new(
-                &[
-                    PlayEffect::LoseHP(6),
-                    PlayEffect::GainEnergy(2),
-                    PlayEffect::Draw(3),
-                    PlayEffect::MarkExhaust
-                ],
-                &[
-                    PlayEffect::LoseHP(6),
-                    PlayEffect::GainEnergy(2),
-                    PlayEffect::Draw(5),
-                    PlayEffect::MarkExhaust
-                ],
-                Cost::Fixed(0),
-                false,
-                CardType::Skill,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::Reaper => const_card!(&CardProps::new(
-                &[PlayEffect::AttackAllForHP(4), PlayEffect::MarkExhaust],
-                &[PlayEffect::AttackAllForHP(5), PlayEffect::MarkExhaust],
-                Cost::Fixed(2),
-                false,
-                CardType::Attack,
-                CardCharachter::IRONCLAD
-            )),
-            CardBody::Regret => const_card!(&CardProps::new(
-                &[PlayEffect::MarkExhaust],
-                &[PlayEffect::MarkExhaust],
-                Cost::Unplayable,
-                false,
-                CardType::Curse,
-                CardCharachter::COLORLESS
-            )),
-            CardBody::Injury => const_card!(&CardProps::new(
-                &[PlayEffect::MarkExhaust],
-                &[PlayEffect::MarkExhaust],
-                Cost::Unplayable,
-                false,
-                CardType::Curse,
-                CardCharachter::COLORLESS
-            )),
-            CardBody::Parasite => const_card!(&CardProps::new(
-                &[PlayEffect::MarkExhaust],
-                &[PlayEffect::MarkExhaust],
-                Cost::Unplayable,
-                false,
-                CardType::Curse,
-                CardCharachter::COLORLESS
-            )),
-            CardBody::Doubt => const_card!(&CardProps::new(
-                &[PlayEffect::MarkExhaust],
-                &[PlayEffect::MarkExhaust],
-                Cost::Unplayable,
-                false,
-                CardType::Curse,
-                CardCharachter::COLORLESS
-            )),
         };
     }
     pub const fn to_card(&self) -> Card {
         let assoc_data = match self {
             Self::SearingBlow => CardAssoc::UnlimitedUpgrade(0),
+            Self::Rampage | Self::RitualDagger | Self::GlassKnife => CardAssoc::BonusDamage(0),
+            Self::SteamBarrier | Self::GeneticAlgorithm | Self::Perseverance => {
+                CardAssoc::BonusBlock(0)
+            }
+            Self::WindmillStrike => CardAssoc::BonusDamage(0),
+            Self::Expunger => CardAssoc::Hits(0),
             _ => CardAssoc::None,
         };
         Card {
@@ -1218,6 +5399,7 @@ impl CardBody {
             assoc_data,
             upgraded: false,
             temp_cost: None,
+            bottled: false,
         }
     }
     pub const fn default_cost(&self) -> Cost {
@@ -1229,15 +5411,19 @@ impl CardBody {
     pub fn removable(&self) -> bool {
         self.props().removable
     }
+    pub fn rarity(&self) -> CardRarity {
+        self.props().rarity
+    }
     pub fn is_strike(&self) -> bool {
-        match self {
+        matches!(
+            self,
             Self::Strike
-            | Self::PerfectedStrike
-            | Self::PommelStrike
-            | Self::TwinStrike
-            | Self::WildStrike => true,
-            _ => false,
-        }
+                | Self::PerfectedStrike
+                | Self::PommelStrike
+                | Self::TwinStrike
+                | Self::WildStrike
+                | Self::SneakyStrike
+        )
     }
 }
 macro_rules! filtered_cards {
@@ -1279,26 +5465,134 @@ const fn ironclad_attack_filter(props: &'static CardProps) -> bool {
         && matches!(props.charachter, CardCharachter::IRONCLAD)
         && !props.starter
 }
-pub const IRONCLAD_ATTACK_CARDS: &'static [CardBody] = filtered_cards!(ironclad_attack_filter);
+pub const IRONCLAD_ATTACK_CARDS: &[CardBody] = filtered_cards!(ironclad_attack_filter);
 
 const fn ironclad_filter(props: &'static CardProps) -> bool {
     matches!(props.charachter, CardCharachter::IRONCLAD) && !props.starter
 }
-pub const IRONCLAD_CARDS: &'static [CardBody] = filtered_cards!(ironclad_filter);
+pub const IRONCLAD_CARDS: &[CardBody] = filtered_cards!(ironclad_filter);
+
+const fn ironclad_common_filter(props: &'static CardProps) -> bool {
+    ironclad_filter(props) && matches!(props.rarity, CardRarity::Common)
+}
+pub const IRONCLAD_COMMON_CARDS: &[CardBody] = filtered_cards!(ironclad_common_filter);
+
+const fn ironclad_uncommon_filter(props: &'static CardProps) -> bool {
+    ironclad_filter(props) && matches!(props.rarity, CardRarity::Uncommon)
+}
+pub const IRONCLAD_UNCOMMON_CARDS: &[CardBody] = filtered_cards!(ironclad_uncommon_filter);
+
+const fn ironclad_rare_filter(props: &'static CardProps) -> bool {
+    ironclad_filter(props) && matches!(props.rarity, CardRarity::Rare)
+}
+pub const IRONCLAD_RARE_CARDS: &[CardBody] = filtered_cards!(ironclad_rare_filter);
+
+const fn silent_filter(props: &'static CardProps) -> bool {
+    matches!(props.charachter, CardCharachter::SILENT) && !props.starter
+}
+pub const SILENT_CARDS: &[CardBody] = filtered_cards!(silent_filter);
+
+const fn silent_skill_filter(props: &'static CardProps) -> bool {
+    silent_filter(props) && matches!(props.card_type, CardType::Skill)
+}
+pub const SILENT_SKILL_CARDS: &[CardBody] = filtered_cards!(silent_skill_filter);
+
+const fn silent_common_filter(props: &'static CardProps) -> bool {
+    silent_filter(props) && matches!(props.rarity, CardRarity::Common)
+}
+pub const SILENT_COMMON_CARDS: &[CardBody] = filtered_cards!(silent_common_filter);
+
+const fn silent_uncommon_filter(props: &'static CardProps) -> bool {
+    silent_filter(props) && matches!(props.rarity, CardRarity::Uncommon)
+}
+pub const SILENT_UNCOMMON_CARDS: &[CardBody] = filtered_cards!(silent_uncommon_filter);
+
+const fn silent_rare_filter(props: &'static CardProps) -> bool {
+    silent_filter(props) && matches!(props.rarity, CardRarity::Rare)
+}
+pub const SILENT_RARE_CARDS: &[CardBody] = filtered_cards!(silent_rare_filter);
+
+const fn defect_filter(props: &'static CardProps) -> bool {
+    matches!(props.charachter, CardCharachter::DEFECT) && !props.starter
+}
+pub const DEFECT_CARDS: &[CardBody] = filtered_cards!(defect_filter);
+
+const fn defect_power_filter(props: &'static CardProps) -> bool {
+    defect_filter(props) && matches!(props.card_type, CardType::Power)
+}
+pub const DEFECT_POWER_CARDS: &[CardBody] = filtered_cards!(defect_power_filter);
+
+const fn defect_common_filter(props: &'static CardProps) -> bool {
+    defect_filter(props) && matches!(props.rarity, CardRarity::Common)
+}
+pub const DEFECT_COMMON_CARDS: &[CardBody] = filtered_cards!(defect_common_filter);
+
+const fn defect_uncommon_filter(props: &'static CardProps) -> bool {
+    defect_filter(props) && matches!(props.rarity, CardRarity::Uncommon)
+}
+pub const DEFECT_UNCOMMON_CARDS: &[CardBody] = filtered_cards!(defect_uncommon_filter);
+
+const fn defect_rare_filter(props: &'static CardProps) -> bool {
+    defect_filter(props) && matches!(props.rarity, CardRarity::Rare)
+}
+pub const DEFECT_RARE_CARDS: &[CardBody] = filtered_cards!(defect_rare_filter);
+
+const fn watcher_filter(props: &'static CardProps) -> bool {
+    matches!(props.charachter, CardCharachter::WATCHER) && !props.starter
+}
+pub const WATCHER_CARDS: &[CardBody] = filtered_cards!(watcher_filter);
+
+const fn watcher_common_filter(props: &'static CardProps) -> bool {
+    watcher_filter(props) && matches!(props.rarity, CardRarity::Common)
+}
+pub const WATCHER_COMMON_CARDS: &[CardBody] = filtered_cards!(watcher_common_filter);
+
+const fn watcher_uncommon_filter(props: &'static CardProps) -> bool {
+    watcher_filter(props) && matches!(props.rarity, CardRarity::Uncommon)
+}
+pub const WATCHER_UNCOMMON_CARDS: &[CardBody] = filtered_cards!(watcher_uncommon_filter);
+
+const fn watcher_rare_filter(props: &'static CardProps) -> bool {
+    watcher_filter(props) && matches!(props.rarity, CardRarity::Rare)
+}
+pub const WATCHER_RARE_CARDS: &[CardBody] = filtered_cards!(watcher_rare_filter);
+
+//Foreign Influence offers attacks from every class.
+const fn class_attack_filter(props: &'static CardProps) -> bool {
+    matches!(props.card_type, CardType::Attack)
+        && !matches!(props.charachter, CardCharachter::COLORLESS)
+        && matches!(
+            props.rarity,
+            CardRarity::Common | CardRarity::Uncommon | CardRarity::Rare
+        )
+}
+pub const CLASS_ATTACK_CARDS: &[CardBody] = filtered_cards!(class_attack_filter);
 
+//Curses which can't be removed, like Ascender's Bane, are never given out at random.
 const fn curse_filter(props: &'static CardProps) -> bool {
-    matches!(props.card_type, CardType::Curse)
+    matches!(props.card_type, CardType::Curse) && props.removable
 }
-pub const CURSE_CARDS: &'static [CardBody] = filtered_cards!(curse_filter);
+pub const CURSE_CARDS: &[CardBody] = filtered_cards!(curse_filter);
 
 const fn colorless_filter(props: &'static CardProps) -> bool {
     (matches!(props.card_type, CardType::Power)
         || matches!(props.card_type, CardType::Skill)
         || matches!(props.card_type, CardType::Attack))
         && matches!(props.charachter, CardCharachter::COLORLESS)
+        && !matches!(props.rarity, CardRarity::Special)
 }
 
-pub const COLORLESS_CARDS: &'static [CardBody] = filtered_cards!(colorless_filter);
+pub const COLORLESS_CARDS: &[CardBody] = filtered_cards!(colorless_filter);
+
+const fn colorless_uncommon_filter(props: &'static CardProps) -> bool {
+    colorless_filter(props) && matches!(props.rarity, CardRarity::Uncommon)
+}
+pub const COLORLESS_UNCOMMON_CARDS: &[CardBody] = filtered_cards!(colorless_uncommon_filter);
+
+const fn colorless_rare_filter(props: &'static CardProps) -> bool {
+    colorless_filter(props) && matches!(props.rarity, CardRarity::Rare)
+}
+pub const COLORLESS_RARE_CARDS: &[CardBody] = filtered_cards!(colorless_rare_filter);
 
 pub fn sample_card(cards: &'static [CardBody], rng: &mut Rng) -> CardBody {
     let idx = rng.sample(cards.len());
@@ -1331,20 +5625,30 @@ impl Card {
             let amount = self.assoc_data.get_unlimited_upgrade();
             self.assoc_data = CardAssoc::UnlimitedUpgrade(amount + 1);
         }
+        //Upgrades lower the current cost by the difference in base costs so
+        //cost changes from Confusion or Blood for Blood's HP losses carry over.
         let props = self.props();
-        if let Cost::Fixed(old) = props.cost
-            && let Cost::Fixed(new) = props.upgraded_cost
-            && let Cost::Fixed(current) = self.cost
-        {
-            self.cost = Cost::Fixed(max(current + new - old, 0));
-        }
-        //TODO - handle Blood for Blood and temp upgrades
-        //TODO - Blood for Blood is incorrect when there is Snecko Eye.
-        if self.body == CardBody::BloodForBlood {
-            self.cost = props.upgraded_cost;
+        match (props.cost, props.upgraded_cost, self.cost) {
+            (Cost::Fixed(old), Cost::Fixed(new), Cost::Fixed(current)) => {
+                self.cost = Cost::Fixed(max(current + new - old, 0));
+            }
+            (
+                Cost::NumMinusHpLoss(old),
+                Cost::NumMinusHpLoss(new),
+                Cost::NumMinusHpLoss(current),
+            ) => {
+                self.cost = Cost::NumMinusHpLoss(current + new - old);
+            }
+            _ => {}
         }
         self.upgraded = true;
     }
+    //Burns are statuses so they can't normally be upgraded, but Hexaghost's Inferno upgrades them.
+    pub fn upgrade_burn(&mut self) {
+        if self.body == CardBody::Burn {
+            self.upgraded = true;
+        }
+    }
     pub fn actions(&self) -> &'static [PlayEffect] {
         if self.upgraded {
             self.props().upgraded_actions
@@ -1362,6 +5666,9 @@ impl Card {
     }
 
     pub fn innate(&self) -> bool {
+        if self.bottled {
+            return true;
+        }
         match self.body.props().innate {
             Innate::No => false,
             Innate::Yes => true,
@@ -1369,7 +5676,33 @@ impl Card {
         }
     }
 
+    pub fn retain(&self) -> bool {
+        match self.body.props().retain {
+            Retain::No => false,
+            Retain::Yes => true,
+            Retain::Upgraded => self.upgraded,
+        }
+    }
+
+    //Used for Streamline and retained cards like Sands of Time.
+    pub fn reduce_cost(&mut self, amount: i32) {
+        if let Cost::Fixed(cost) = self.cost {
+            self.cost = Cost::Fixed(max(cost - amount, 0));
+        }
+    }
+
     pub fn charachter(&self) -> CardCharachter {
         self.body.props().charachter
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn random_curses_are_removable() {
+        assert!(!CURSE_CARDS.contains(&CardBody::AscendersBane));
+        assert!(CURSE_CARDS.iter().all(|body| body.removable()));
+    }
+}
@@ -1,4 +1,4 @@
-use std::{hash::Hash, mem};
+use std::{cmp::min, hash::Hash, mem};
 
 use serde::{Deserialize, Serialize};
 
@@ -79,6 +79,14 @@ impl Deck {
         }
     }
 
+    //Used for Scry, this takes up to count cards off the top of the deck with the top card first.
+    //Drawing fixes the order of the cards, so when the kept ones are put back on top
+    //that part of the deck becomes known.
+    pub fn take_top(&mut self, count: usize, rng: &mut Rng) -> Vec<Card> {
+        let count = min(count, self.num_cards);
+        (0..count).map(|_| self.draw(rng)).collect()
+    }
+
     pub fn shuffled(mut cards: Vec<Card>) -> Deck {
         cards.sort();
         Deck {
@@ -105,6 +113,7 @@ impl Deck {
 
     pub fn shuffle_in(&mut self, mut cards: Vec<Card>) {
         if let DeckSegment::Shuffled(existing) = &mut self.segment {
+            self.num_cards += cards.len();
             //TODO - make this not quadratic.
             for card in cards {
                 insert_sorted(card, existing);
@@ -126,10 +135,68 @@ impl Deck {
         }
     }
 
+    //Shuffled segments are kept sorted so they are re-sorted after being modified.
+    pub fn for_each_mut(&mut self, f: &mut impl FnMut(&mut Card)) {
+        match &mut self.segment {
+            DeckSegment::Shuffled(cards) => {
+                cards.iter_mut().for_each(&mut *f);
+                cards.sort();
+            }
+            DeckSegment::Known(cards) => cards.iter_mut().for_each(&mut *f),
+            DeckSegment::Composite(decks) => decks.iter_mut().for_each(|deck| deck.for_each_mut(f)),
+            DeckSegment::ShuffleInto { primary, shuffled } => {
+                primary.for_each_mut(f);
+                shuffled.for_each_mut(f);
+            }
+        }
+    }
+
     pub fn len(&self) -> usize {
         self.num_cards
     }
 
+    //Removes a card matching the filter, used by Bronze Orbs' Stasis. Shuffled segments
+    //are kept sorted, so the card taken from them is deterministic.
+    pub fn take(&mut self, f: &impl Fn(&Card) -> bool) -> Option<Card> {
+        let card = match &mut self.segment {
+            DeckSegment::Shuffled(cards) | DeckSegment::Known(cards) => {
+                let idx = cards.iter().position(f)?;
+                Some(cards.remove(idx))
+            }
+            DeckSegment::Composite(decks) => decks.iter_mut().rev().find_map(|deck| deck.take(f)),
+            DeckSegment::ShuffleInto { primary, shuffled } => {
+                primary.take(f).or_else(|| shuffled.take(f))
+            }
+        };
+        if card.is_some() {
+            self.num_cards -= 1;
+        }
+        card
+    }
+
+    //Used for Seek, which can take any card from the draw pile. Identical cards are
+    //the same choice, so duplicates are removed.
+    pub fn distinct_cards(&self) -> Vec<Card> {
+        let mut cards = Vec::new();
+        self.collect_cards(&mut cards);
+        cards.sort();
+        cards.dedup();
+        cards
+    }
+
+    fn collect_cards(&self, out: &mut Vec<Card>) {
+        match &self.segment {
+            DeckSegment::Shuffled(cards) | DeckSegment::Known(cards) => {
+                out.extend(cards.iter().cloned())
+            }
+            DeckSegment::Composite(decks) => decks.iter().for_each(|deck| deck.collect_cards(out)),
+            DeckSegment::ShuffleInto { primary, shuffled } => {
+                primary.collect_cards(out);
+                shuffled.collect_cards(out);
+            }
+        }
+    }
+
     //This could probably be replaced by an IntoIter implementation but that
     //would be complicated due to this being a recursive data structure.
     pub fn count(&self, f: fn(&&Card) -> bool) -> usize {
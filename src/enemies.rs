@@ -3,28 +3,71 @@ use crate::{
     rng::Rng,
 };
 
+pub mod awakened_one;
+pub mod bear;
 pub mod blue_slaver;
+pub mod book_of_stabbing;
+pub mod bronze_automaton;
+pub mod bronze_orb;
+pub mod byrd;
+pub mod centurion;
+pub mod champ;
+pub mod chosen;
+pub mod corrupt_heart;
 pub mod cultist;
+pub mod dagger;
+pub mod darkling;
+pub mod deca;
+pub mod donu;
+pub mod exploder;
 pub mod fungi_beast;
+pub mod giant_head;
 pub mod green_louse;
 pub mod gremlin_fat;
+pub mod gremlin_leader;
 pub mod gremlin_mad;
 pub mod gremlin_nob;
 pub mod gremlin_shield;
 pub mod gremlin_sneaky;
 pub mod gremlin_wizard;
+pub mod hexaghost;
 pub mod jaw_worm;
 pub mod lagavulin;
 pub mod large_black_slime;
 pub mod large_green_slime;
 pub mod looter;
+pub mod maw;
 pub mod med_black_slime;
 pub mod med_green_slime;
+pub mod mugger;
+pub mod mystic;
+pub mod nemesis;
+pub mod orb_walker;
+pub mod pointy;
 pub mod red_louse;
 pub mod red_slaver;
+pub mod reptomancer;
+pub mod repulsor;
+pub mod romeo;
 pub mod sentry;
+pub mod shelled_parasite;
+pub mod slime_boss;
 pub mod small_black_slime;
 pub mod small_green_slime;
+pub mod snake_plant;
+pub mod snecko;
+pub mod spheric_guardian;
+pub mod spiker;
+pub mod spire_growth;
+pub mod spire_shield;
+pub mod spire_spear;
+pub mod taskmaster;
+pub mod the_collector;
+pub mod the_guardian;
+pub mod time_eater;
+pub mod torch_head;
+pub mod transient;
+pub mod writhing_mass;
 
 struct StateEntry {
     actions: &'static [EnemyAction],
@@ -54,27 +97,111 @@ pub fn behavior(
     state: u32,
 ) -> (u32, &'static [EnemyAction]) {
     match enemy.name {
+        EnemyName::AwakenedOne => awakened_one::ai(rng, fight, enemy, state),
+        EnemyName::Bear => bear::ai(rng, fight, enemy, state),
         EnemyName::BlueSlaver => blue_slaver::ai(rng, fight, enemy, state),
+        EnemyName::BookOfStabbing => book_of_stabbing::ai(rng, fight, enemy, state),
+        EnemyName::BronzeAutomaton => bronze_automaton::ai(rng, fight, enemy, state),
+        EnemyName::BronzeOrb => bronze_orb::ai(rng, fight, enemy, state),
+        EnemyName::Byrd => byrd::ai(rng, fight, enemy, state),
+        EnemyName::Centurion => centurion::ai(rng, fight, enemy, state),
+        EnemyName::Champ => champ::ai(rng, fight, enemy, state),
+        EnemyName::Chosen => chosen::ai(rng, fight, enemy, state),
+        EnemyName::CorruptHeart => corrupt_heart::ai(rng, fight, enemy, state),
         EnemyName::Cultist => cultist::ai(rng, fight, enemy, state),
+        EnemyName::Dagger => dagger::ai(rng, fight, enemy, state),
+        EnemyName::Darkling => darkling::ai(rng, fight, enemy, state),
+        EnemyName::Deca => deca::ai(rng, fight, enemy, state),
+        EnemyName::Donu => donu::ai(rng, fight, enemy, state),
+        EnemyName::Exploder => exploder::ai(rng, fight, enemy, state),
         EnemyName::FungiBeast => fungi_beast::ai(rng, fight, enemy, state),
+        EnemyName::GiantHead => giant_head::ai(rng, fight, enemy, state),
         EnemyName::GreenLouse => green_louse::ai(rng, fight, enemy, state),
         EnemyName::GremlinFat => gremlin_fat::ai(rng, fight, enemy, state),
+        EnemyName::GremlinLeader => gremlin_leader::ai(rng, fight, enemy, state),
         EnemyName::GremlinMad => gremlin_mad::ai(rng, fight, enemy, state),
         EnemyName::GremlinNob => gremlin_nob::ai(rng, fight, enemy, state),
         EnemyName::GremlinShield => gremlin_shield::ai(rng, fight, enemy, state),
         EnemyName::GremlinSneaky => gremlin_sneaky::ai(rng, fight, enemy, state),
         EnemyName::GremlinWizard => gremlin_wizard::ai(rng, fight, enemy, state),
+        EnemyName::Hexaghost => hexaghost::ai(rng, fight, enemy, state),
         EnemyName::JawWorm => jaw_worm::ai(rng, fight, enemy, state),
         EnemyName::Lagavulin => lagavulin::ai(rng, fight, enemy, state),
         EnemyName::LargeBlackSlime => large_black_slime::ai(rng, fight, enemy, state),
         EnemyName::LargeGreenSlime => large_green_slime::ai(rng, fight, enemy, state),
         EnemyName::Looter => looter::ai(rng, fight, enemy, state),
+        EnemyName::Maw => maw::ai(rng, fight, enemy, state),
         EnemyName::MedBlackSlime => med_black_slime::ai(rng, fight, enemy, state),
         EnemyName::MedGreenSlime => med_green_slime::ai(rng, fight, enemy, state),
+        EnemyName::Mugger => mugger::ai(rng, fight, enemy, state),
+        EnemyName::Mystic => mystic::ai(rng, fight, enemy, state),
+        EnemyName::Nemesis => nemesis::ai(rng, fight, enemy, state),
+        EnemyName::OrbWalker => orb_walker::ai(rng, fight, enemy, state),
+        EnemyName::Pointy => pointy::ai(rng, fight, enemy, state),
         EnemyName::RedLouse => red_louse::ai(rng, fight, enemy, state),
         EnemyName::RedSlaver => red_slaver::ai(rng, fight, enemy, state),
+        EnemyName::Reptomancer => reptomancer::ai(rng, fight, enemy, state),
+        EnemyName::Repulsor => repulsor::ai(rng, fight, enemy, state),
+        EnemyName::Romeo => romeo::ai(rng, fight, enemy, state),
         EnemyName::Sentry => sentry::ai(rng, fight, enemy, state),
+        EnemyName::ShelledParasite => shelled_parasite::ai(rng, fight, enemy, state),
+        EnemyName::SlimeBoss => slime_boss::ai(rng, fight, enemy, state),
         EnemyName::SmallBlackSlime => small_black_slime::ai(rng, fight, enemy, state),
         EnemyName::SmallGreenSlime => small_green_slime::ai(rng, fight, enemy, state),
+        EnemyName::SnakePlant => snake_plant::ai(rng, fight, enemy, state),
+        EnemyName::Snecko => snecko::ai(rng, fight, enemy, state),
+        EnemyName::SphericGuardian => spheric_guardian::ai(rng, fight, enemy, state),
+        EnemyName::Spiker => spiker::ai(rng, fight, enemy, state),
+        EnemyName::SpireGrowth => spire_growth::ai(rng, fight, enemy, state),
+        EnemyName::SpireShield => spire_shield::ai(rng, fight, enemy, state),
+        EnemyName::SpireSpear => spire_spear::ai(rng, fight, enemy, state),
+        EnemyName::Taskmaster => taskmaster::ai(rng, fight, enemy, state),
+        EnemyName::TheCollector => the_collector::ai(rng, fight, enemy, state),
+        EnemyName::TheGuardian => the_guardian::ai(rng, fight, enemy, state),
+        EnemyName::TimeEater => time_eater::ai(rng, fight, enemy, state),
+        EnemyName::TorchHead => torch_head::ai(rng, fight, enemy, state),
+        EnemyName::Transient => transient::ai(rng, fight, enemy, state),
+        EnemyName::WrithingMass => writhing_mass::ai(rng, fight, enemy, state),
     }
 }
+
+//Samples a new opening state from the enemy's generator. Enemies whose opening move
+//doesn't depend on the rng keep their current state.
+pub fn opening_state(rng: &mut Rng, fight: &Fight, enemy: &Enemy) -> u32 {
+    let ascension = fight.ascension;
+    let generated = match enemy.name {
+        EnemyName::BlueSlaver => blue_slaver::generate_blue_slaver(rng, ascension),
+        EnemyName::BookOfStabbing => book_of_stabbing::generate_book_of_stabbing(rng, ascension),
+        EnemyName::BronzeOrb => bronze_orb::generate_bronze_orb(rng, ascension),
+        EnemyName::Byrd => byrd::generate_byrd(rng, ascension),
+        EnemyName::Centurion => centurion::generate_centurion(rng, ascension),
+        EnemyName::Darkling => {
+            let can_chomp = darkling::can_chomp(enemy.ai_state);
+            darkling::generate_darkling(rng, can_chomp, ascension)
+        }
+        EnemyName::FungiBeast => fungi_beast::generate_fungi_beast(rng, ascension),
+        EnemyName::GiantHead => giant_head::generate_giant_head(rng, ascension),
+        EnemyName::GreenLouse => green_louse::generate_green_louse(rng, ascension),
+        EnemyName::GremlinLeader => gremlin_leader::generate_gremlin_leader(rng, ascension),
+        EnemyName::LargeBlackSlime => large_black_slime::generate_large_black_slime(rng, ascension),
+        EnemyName::LargeGreenSlime => large_green_slime::generate_large_green_slime(rng, ascension),
+        EnemyName::MedBlackSlime => med_black_slime::generate_med_black_slime(rng, ascension),
+        EnemyName::MedGreenSlime => med_green_slime::generate_med_green_slime(rng, ascension),
+        EnemyName::Mystic => mystic::generate_mystic(rng, ascension),
+        EnemyName::Nemesis => nemesis::generate_nemesis(rng, ascension),
+        EnemyName::OrbWalker => orb_walker::generate_orb_walker(rng, ascension),
+        EnemyName::RedLouse => red_louse::generate_red_louse(rng, ascension),
+        EnemyName::Reptomancer => reptomancer::generate_reptomancer(rng, ascension),
+        EnemyName::Repulsor => repulsor::generate_repulsor(rng, ascension),
+        EnemyName::ShelledParasite => shelled_parasite::generate_shelled_parasite(rng, ascension),
+        EnemyName::SmallGreenSlime => small_green_slime::generate_small_green_slime(rng, ascension),
+        EnemyName::SnakePlant => snake_plant::generate_snake_plant(rng, ascension),
+        EnemyName::Spiker => spiker::generate_spiker(rng, ascension),
+        EnemyName::SpireGrowth => spire_growth::generate_spire_growth(rng, ascension),
+        EnemyName::SpireShield => spire_shield::generate_spire_shield(rng, ascension),
+        EnemyName::SpireSpear => spire_spear::generate_spire_spear(rng, ascension),
+        EnemyName::WrithingMass => writhing_mass::generate_writhing_mass(rng, ascension),
+        _ => return enemy.ai_state,
+    };
+    generated.ai_state
+}
@@ -0,0 +1,95 @@
+use crate::{
+    card::CardBody,
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+//The Awakened One is reborn with a new moveset the first time it dies.
+//Bits 0-2 are the current move and bits 3-5 are the previous move.
+const SLASH: u32 = 0;
+const SOUL_STRIKE: u32 = 1;
+const REBIRTH: u32 = 2;
+const DARK_ECHO: u32 = 3;
+const SLUDGE: u32 = 4;
+const TACKLE: u32 = 5;
+const NO_MOVE: u32 = 7;
+
+const ACTIONS: &[&[EnemyAction]] = &[
+    &[EnemyAction::Attack(20)],
+    &[
+        EnemyAction::Attack(6),
+        EnemyAction::Attack(6),
+        EnemyAction::Attack(6),
+        EnemyAction::Attack(6),
+    ],
+    &[EnemyAction::Rebirth],
+    &[EnemyAction::Attack(40)],
+    &[
+        EnemyAction::Attack(18),
+        EnemyAction::ShuffleIntoDraw(&[CardBody::Void]),
+    ],
+    &[
+        EnemyAction::Attack(10),
+        EnemyAction::Attack(10),
+        EnemyAction::Attack(10),
+    ],
+];
+
+//The state for an Awakened One which has just died. It is reborn on its next turn.
+pub const REBIRTH_STATE: u32 = REBIRTH | (NO_MOVE << 3);
+
+fn next_state(rng: &mut Rng, state: u32) -> u32 {
+    let last = state & 7;
+    let before = (state >> 3) & 7;
+    let twice = |mv: u32| last == mv && before == mv;
+    let num = rng.sample(100);
+    let next = match last {
+        REBIRTH => DARK_ECHO,
+        DARK_ECHO | SLUDGE | TACKLE => {
+            if num < 50 && !twice(SLUDGE) {
+                SLUDGE
+            } else if !twice(TACKLE) {
+                TACKLE
+            } else {
+                SLUDGE
+            }
+        }
+        _ => {
+            if num < 25 && last != SOUL_STRIKE {
+                SOUL_STRIKE
+            } else if !twice(SLASH) {
+                SLASH
+            } else {
+                SOUL_STRIKE
+            }
+        }
+    };
+    next | (last << 3)
+}
+
+pub fn ai(rng: &mut Rng, _: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    (next_state(rng, state), ACTIONS[(state & 7) as usize])
+}
+
+pub fn generate_awakened_one(_: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 9 { 320 } else { 300 };
+    let buffs = EnemyBuffs {
+        //Ascension 4 Awakened Ones start with strength.
+        strength: if ascension >= 4 { 2 } else { 0 },
+        curiosity: if ascension >= 19 { 2 } else { 1 },
+        regenerate: if ascension >= 19 { 15 } else { 10 },
+        unawakened: true,
+        ..Default::default()
+    };
+    Enemy {
+        name: EnemyName::AwakenedOne,
+        //The Awakened One always opens with Slash.
+        ai_state: SLASH | (NO_MOVE << 3),
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
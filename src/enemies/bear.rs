@@ -0,0 +1,62 @@
+use crate::{
+    card::Debuff,
+    enemies::{StateEntry, uniform_inclusive, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+macro_rules! make_bear_table {
+    ($bear_hug: expr, $lunge: expr, $maul: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::Debuff(Debuff::MinusDexterity($bear_hug))],
+                new_states: &[1],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($lunge), EnemyAction::Block($lunge)],
+                new_states: &[2],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($maul)],
+                new_states: &[1],
+                weights: &[1],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0) Bear Hug
+    // 1) Lunge
+    // 2) Maul
+    const ENEMY_TABLE: &[StateEntry] = make_bear_table!(2, 9, 18);
+    const A2_TABLE: &[StateEntry] = make_bear_table!(2, 10, 20);
+    const A17_TABLE: &[StateEntry] = make_bear_table!(4, 10, 20);
+    let table = match fight.ascension {
+        17.. => A17_TABLE,
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    weighted_transition(rng, state, table)
+}
+
+pub fn generate_bear(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 40, 44)
+    } else {
+        uniform_inclusive(rng, 38, 42)
+    };
+    Enemy {
+        name: EnemyName::Bear,
+        ai_state: 0,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs: EnemyBuffs::default(),
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
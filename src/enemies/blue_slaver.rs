@@ -6,15 +6,15 @@ use crate::{
 };
 
 macro_rules! make_blue_slaver_table {
-    ($attack_big: expr, $attack: expr, $weak_amount: expr) => {
+    ($attack_big: expr, $attack: expr, $weak_amount: expr, $rake_states: expr, $rake_weights: expr) => {
         &[
             StateEntry {
                 actions: &[
                     EnemyAction::Attack($attack),
                     EnemyAction::Debuff(Debuff::Weak($weak_amount)),
                 ],
-                new_states: &[1, 2],
-                weights: &[4, 6],
+                new_states: $rake_states,
+                weights: $rake_weights,
             },
             StateEntry {
                 actions: &[
@@ -38,19 +38,34 @@ macro_rules! make_blue_slaver_table {
     };
 }
 
-pub fn ai(rng: &mut Rng, _: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
     // States are
-    // 0) Playing Attack
-    // 1) Debuff
-    const ENEMY_TABLE: &'static [StateEntry] = make_blue_slaver_table!(12, 7, 1);
-    return weighted_transition(rng, state, ENEMY_TABLE);
+    // 0) Rake
+    // 1) Rake, same move prior turn.
+    // 2) Stab
+    // 3) Stab, same move prior turn.
+    const ENEMY_TABLE: &'static [StateEntry] = make_blue_slaver_table!(12, 7, 1, &[1, 2], &[4, 6]);
+    const A2_TABLE: &'static [StateEntry] = make_blue_slaver_table!(13, 8, 1, &[1, 2], &[4, 6]);
+    //Ascension 17 slavers never Rake twice in a row.
+    const A17_TABLE: &'static [StateEntry] = make_blue_slaver_table!(13, 8, 2, &[2], &[1]);
+    let table = match fight.ascension {
+        17.. => A17_TABLE,
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    return weighted_transition(rng, state, table);
 }
 
-pub fn generate_blue_slaver(rng: &mut Rng) -> Enemy {
-    let hp = uniform_inclusive(rng, 46, 50);
+pub fn generate_blue_slaver(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 48, 52)
+    } else {
+        uniform_inclusive(rng, 46, 50)
+    };
     Enemy {
         name: EnemyName::BlueSlaver,
         ai_state: rng.sample_weighted(&[4, 0, 6, 0]) as u32,
+        last_move: None,
         hp,
         max_hp: hp,
         buffs: EnemyBuffs::default(),
@@ -0,0 +1,70 @@
+use crate::{
+    enemies::{StateEntry, uniform_inclusive, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+macro_rules! make_book_of_stabbing_table {
+    ($stab: expr, $big_stab: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::MultiStab($stab)],
+                new_states: &[2, 1],
+                weights: &[15, 85],
+            },
+            StateEntry {
+                actions: &[EnemyAction::MultiStab($stab)],
+                new_states: &[2],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: $big_stab,
+                new_states: &[0],
+                weights: &[1],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0) Multi-Stab
+    // 1) Multi-Stab, second in a row
+    // 2) Single Stab
+    //Multi-Stab hits one more time every time it is used.
+    const BIG_STAB: &[EnemyAction] = &[EnemyAction::Attack(21)];
+    const A3_BIG_STAB: &[EnemyAction] = &[EnemyAction::Attack(24)];
+    const A18_BIG_STAB: &[EnemyAction] = &[EnemyAction::Attack(24), EnemyAction::AddStab];
+    const ENEMY_TABLE: &[StateEntry] = make_book_of_stabbing_table!(6, BIG_STAB);
+    const A3_TABLE: &[StateEntry] = make_book_of_stabbing_table!(7, A3_BIG_STAB);
+    const A18_TABLE: &[StateEntry] = make_book_of_stabbing_table!(7, A18_BIG_STAB);
+    let table = match fight.ascension {
+        18.. => A18_TABLE,
+        3.. => A3_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    weighted_transition(rng, state, table)
+}
+
+pub fn generate_book_of_stabbing(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 8 {
+        uniform_inclusive(rng, 168, 172)
+    } else {
+        uniform_inclusive(rng, 160, 164)
+    };
+    let buffs = EnemyBuffs {
+        painful_stabs: true,
+        stab_count: 1,
+        ..Default::default()
+    };
+    Enemy {
+        name: EnemyName::BookOfStabbing,
+        ai_state: rng.sample_weighted(&[85, 0, 15]) as u32,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{
+    card::Buff,
+    enemies::{StateEntry, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+//The Bronze Orbs go on either side of the Automaton.
+pub const AUTOMATON_SLOT: usize = 1;
+
+macro_rules! make_bronze_automaton_table {
+    ($flail: expr, $boost_strength: expr, $boost_block: expr, $hyper_beam: expr, $after_hyper_beam: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::Summon],
+                new_states: &[1],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($flail), EnemyAction::Attack($flail)],
+                new_states: &[2],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Block($boost_block),
+                    EnemyAction::Buff(Buff::Strength($boost_strength)),
+                ],
+                new_states: &[3],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($flail), EnemyAction::Attack($flail)],
+                new_states: &[4],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Block($boost_block),
+                    EnemyAction::Buff(Buff::Strength($boost_strength)),
+                ],
+                new_states: &[5],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($hyper_beam)],
+                new_states: &[6],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: $after_hyper_beam,
+                new_states: &[1],
+                weights: &[1],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0) Spawn Orbs
+    // 1) Flail
+    // 2) Boost
+    // 3) Flail
+    // 4) Boost
+    // 5) Hyper Beam
+    // 6) Stunned, or Boost from Ascension 19
+    //Ascension 19 Automatons Boost instead of being stunned after Hyper Beam.
+    const ENEMY_TABLE: &[StateEntry] = make_bronze_automaton_table!(7, 3, 9, 45, &[]);
+    const A4_TABLE: &[StateEntry] = make_bronze_automaton_table!(8, 4, 9, 50, &[]);
+    const A9_TABLE: &[StateEntry] = make_bronze_automaton_table!(8, 4, 12, 50, &[]);
+    const A19_TABLE: &[StateEntry] = make_bronze_automaton_table!(
+        8,
+        4,
+        12,
+        50,
+        &[EnemyAction::Block(12), EnemyAction::Buff(Buff::Strength(4))]
+    );
+    let table = match fight.ascension {
+        19.. => A19_TABLE,
+        9.. => A9_TABLE,
+        4.. => A4_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    weighted_transition(rng, state, table)
+}
+
+pub fn generate_bronze_automaton(ascension: i32) -> Enemy {
+    let hp = if ascension >= 9 { 320 } else { 300 };
+    let buffs = EnemyBuffs {
+        artifact: 3,
+        ..Default::default()
+    };
+    Enemy {
+        name: EnemyName::BronzeAutomaton,
+        ai_state: 0,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
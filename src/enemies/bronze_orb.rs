@@ -0,0 +1,83 @@
+use crate::{
+    enemies::{StateEntry, uniform_inclusive, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+pub fn ai(rng: &mut Rng, _: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0) Beam
+    // 1) Beam, second in a row
+    // 2) Support Beam
+    // 3) Stasis
+    // 4) Beam after Stasis
+    // 5) Beam after Stasis, second in a row
+    // 6) Support Beam after Stasis
+    // 7) Support Beam after Stasis, second in a row
+    //Each orb uses Stasis once, states 0-2 are before it has been used.
+    const ENEMY_TABLE: &[StateEntry] = &[
+        StateEntry {
+            actions: &[EnemyAction::Attack(8)],
+            new_states: &[3, 1],
+            weights: &[3, 1],
+        },
+        StateEntry {
+            actions: &[EnemyAction::Attack(8)],
+            new_states: &[3, 2],
+            weights: &[3, 1],
+        },
+        StateEntry {
+            actions: &[EnemyAction::DefendLeader(12)],
+            new_states: &[3, 0],
+            weights: &[3, 1],
+        },
+        StateEntry {
+            actions: &[EnemyAction::Stasis],
+            new_states: &[6, 4],
+            weights: &[3, 7],
+        },
+        StateEntry {
+            actions: &[EnemyAction::Attack(8)],
+            new_states: &[6, 5],
+            weights: &[3, 7],
+        },
+        StateEntry {
+            actions: &[EnemyAction::Attack(8)],
+            new_states: &[6],
+            weights: &[1],
+        },
+        StateEntry {
+            actions: &[EnemyAction::DefendLeader(12)],
+            new_states: &[7, 4],
+            weights: &[3, 7],
+        },
+        StateEntry {
+            actions: &[EnemyAction::DefendLeader(12)],
+            new_states: &[4],
+            weights: &[1],
+        },
+    ];
+    weighted_transition(rng, state, ENEMY_TABLE)
+}
+
+pub fn generate_bronze_orb(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 9 {
+        uniform_inclusive(rng, 54, 60)
+    } else {
+        uniform_inclusive(rng, 52, 58)
+    };
+    let buffs = EnemyBuffs {
+        minion: true,
+        ..Default::default()
+    };
+    Enemy {
+        name: EnemyName::BronzeOrb,
+        ai_state: rng.sample_weighted(&[1, 0, 0, 3]) as u32,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
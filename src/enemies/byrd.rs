@@ -0,0 +1,97 @@
+use crate::{
+    card::Buff,
+    enemies::{StateEntry, uniform_inclusive, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+//Byrds are knocked to the ground when they lose all their Flying.
+pub const GROUNDED_STATE: u32 = 4;
+pub const FLYING: i32 = 3;
+pub const A17_FLYING: i32 = 4;
+
+macro_rules! make_byrd_table {
+    ($peck: expr, $swoop: expr, $flying: expr) => {
+        &[
+            StateEntry {
+                actions: $peck,
+                new_states: &[1, 2, 3],
+                weights: &[5, 2, 3],
+            },
+            StateEntry {
+                actions: $peck,
+                new_states: &[2, 3],
+                weights: &[2, 3],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($swoop)],
+                new_states: &[0, 3],
+                weights: &[5, 3],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Buff(Buff::Strength(1))],
+                new_states: &[0, 2],
+                weights: &[5, 2],
+            },
+            StateEntry {
+                actions: &[],
+                new_states: &[5],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack(3)],
+                new_states: &[6],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Buff(Buff::Flying($flying))],
+                new_states: &[0, 2, 3],
+                weights: &[5, 2, 3],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0) Peck
+    // 1) Peck, second in a row
+    // 2) Swoop
+    // 3) Caw
+    // 4) Grounded
+    // 5) Headbutt
+    // 6) Fly
+    const PECK: &[EnemyAction] = &[EnemyAction::Attack(1); 5];
+    const A2_PECK: &[EnemyAction] = &[EnemyAction::Attack(1); 6];
+    const ENEMY_TABLE: &[StateEntry] = make_byrd_table!(PECK, 12, FLYING);
+    const A2_TABLE: &[StateEntry] = make_byrd_table!(A2_PECK, 14, FLYING);
+    const A17_TABLE: &[StateEntry] = make_byrd_table!(A2_PECK, 14, A17_FLYING);
+    let table = match fight.ascension {
+        17.. => A17_TABLE,
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    weighted_transition(rng, state, table)
+}
+
+pub fn generate_byrd(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 26, 33)
+    } else {
+        uniform_inclusive(rng, 25, 31)
+    };
+    let buffs = EnemyBuffs {
+        flying: if ascension >= 17 { A17_FLYING } else { FLYING },
+        ..Default::default()
+    };
+    Enemy {
+        name: EnemyName::Byrd,
+        ai_state: rng.sample_weighted(&[5, 0, 0, 3]) as u32,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
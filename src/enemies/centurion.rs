@@ -0,0 +1,100 @@
+use crate::{
+    enemies::{StateEntry, uniform_inclusive, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+macro_rules! make_centurion_table {
+    ($slash: expr, $protect: expr, $fury: expr, $guard_state: expr, $second_guard_state: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::Attack($slash)],
+                new_states: &[$guard_state, 1],
+                weights: &[35, 65],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($slash)],
+                new_states: &[$guard_state],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::DefendAlly($protect)],
+                new_states: &[$second_guard_state.0, 0],
+                weights: &[35, 65],
+            },
+            StateEntry {
+                actions: &[EnemyAction::DefendAlly($protect)],
+                new_states: &[0],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack($fury),
+                    EnemyAction::Attack($fury),
+                    EnemyAction::Attack($fury),
+                ],
+                new_states: &[$second_guard_state.1, 0],
+                weights: &[35, 65],
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack($fury),
+                    EnemyAction::Attack($fury),
+                    EnemyAction::Attack($fury),
+                ],
+                new_states: &[0],
+                weights: &[1],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0) Slash
+    // 1) Slash, second in a row
+    // 2) Protect
+    // 3) Protect, second in a row
+    // 4) Fury
+    // 5) Fury, second in a row
+    //The Centurion protects an ally when it has one, otherwise it uses Fury instead.
+    macro_rules! make_tables {
+        ($slash: expr, $protect: expr, $fury: expr) => {
+            [
+                make_centurion_table!($slash, $protect, $fury, 2, (3, 2)),
+                make_centurion_table!($slash, $protect, $fury, 4, (4, 5)),
+            ]
+        };
+    }
+    const TABLES: [&[StateEntry]; 2] = make_tables!(12, 15, 6);
+    const A2_TABLES: [&[StateEntry]; 2] = make_tables!(14, 15, 7);
+    const A17_TABLES: [&[StateEntry]; 2] = make_tables!(14, 20, 7);
+    let [with_ally_table, alone_table] = match fight.ascension {
+        17.. => A17_TABLES,
+        2.. => A2_TABLES,
+        _ => TABLES,
+    };
+    if fight.enemies.len() > 1 {
+        weighted_transition(rng, state, with_ally_table)
+    } else {
+        weighted_transition(rng, state, alone_table)
+    }
+}
+
+pub fn generate_centurion(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 78, 83)
+    } else {
+        uniform_inclusive(rng, 76, 80)
+    };
+    Enemy {
+        name: EnemyName::Centurion,
+        ai_state: rng.sample_weighted(&[65, 0, 35]) as u32,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs: EnemyBuffs::default(),
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
@@ -0,0 +1,112 @@
+use std::cmp::min;
+
+use crate::{
+    card::{Buff, Debuff},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+//The Champ's moves depend on its history and HP, so the AI state packs several counters.
+//Bits 0-2 are the current move, bits 3-5 are the previous move, bits 6-8 count turns until
+//Taunt, bits 9-10 count Defensive Stances and bit 11 is set once it is below half HP.
+const HEAVY_SLASH: u32 = 0;
+const DEFENSIVE_STANCE: u32 = 1;
+const EXECUTE: u32 = 2;
+const FACE_SLAP: u32 = 3;
+const GLOAT: u32 = 4;
+const TAUNT: u32 = 5;
+const ANGER: u32 = 6;
+const NO_MOVE: u32 = 7;
+
+macro_rules! make_champ_actions {
+    ($slash: expr, $block: expr, $metallicize: expr, $face_slap: expr, $gloat: expr, $anger: expr) => {
+        &[
+            &[EnemyAction::Attack($slash)],
+            &[
+                EnemyAction::Block($block),
+                EnemyAction::Buff(Buff::Metallicize($metallicize)),
+            ],
+            &[EnemyAction::Attack(10), EnemyAction::Attack(10)],
+            &[
+                EnemyAction::Attack($face_slap),
+                EnemyAction::Debuff(Debuff::Frail(2)),
+                EnemyAction::Debuff(Debuff::Vulnerable(2)),
+            ],
+            &[EnemyAction::Buff(Buff::Strength($gloat))],
+            &[
+                EnemyAction::Debuff(Debuff::Weak(2)),
+                EnemyAction::Debuff(Debuff::Vulnerable(2)),
+            ],
+            &[
+                EnemyAction::ClearDebuffs,
+                EnemyAction::Buff(Buff::Strength($anger)),
+            ],
+        ]
+    };
+}
+
+const ACTIONS: &[&[EnemyAction]] = make_champ_actions!(16, 15, 5, 12, 2, 6);
+const A4_ACTIONS: &[&[EnemyAction]] = make_champ_actions!(18, 15, 5, 14, 3, 9);
+const A9_ACTIONS: &[&[EnemyAction]] = make_champ_actions!(18, 18, 6, 14, 3, 9);
+const A19_ACTIONS: &[&[EnemyAction]] = make_champ_actions!(18, 20, 7, 14, 4, 12);
+
+fn next_state(rng: &mut Rng, enemy: &Enemy, state: u32) -> u32 {
+    let last = state & 7;
+    let before = (state >> 3) & 7;
+    let mut turns = min(((state >> 6) & 7) + 1, 7);
+    let mut stances = (state >> 9) & 3;
+    let mut angry = (state >> 11) & 1 == 1;
+    let num = rng.sample(100);
+    let next = if enemy.hp < enemy.max_hp / 2 && !angry {
+        angry = true;
+        ANGER
+    } else if angry && last != EXECUTE && before != EXECUTE {
+        EXECUTE
+    } else if turns == 4 && !angry {
+        turns = 0;
+        TAUNT
+    } else if last != DEFENSIVE_STANCE && stances < 2 && num <= 30 {
+        stances += 1;
+        DEFENSIVE_STANCE
+    } else if last != GLOAT && last != DEFENSIVE_STANCE && num <= 30 {
+        GLOAT
+    } else if last != FACE_SLAP && num <= 55 {
+        FACE_SLAP
+    } else if last != HEAVY_SLASH {
+        HEAVY_SLASH
+    } else {
+        FACE_SLAP
+    };
+    next | (last << 3) | (turns << 6) | (stances << 9) | ((angry as u32) << 11)
+}
+
+pub fn ai(
+    rng: &mut Rng,
+    fight: &Fight,
+    enemy: &Enemy,
+    state: u32,
+) -> (u32, &'static [EnemyAction]) {
+    let actions = match fight.ascension {
+        19.. => A19_ACTIONS,
+        9.. => A9_ACTIONS,
+        4.. => A4_ACTIONS,
+        _ => ACTIONS,
+    };
+    (next_state(rng, enemy, state), actions[(state & 7) as usize])
+}
+
+pub fn generate_champ(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 9 { 440 } else { 420 };
+    let mut enemy = Enemy {
+        name: EnemyName::Champ,
+        ai_state: 0,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs: EnemyBuffs::default(),
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    };
+    enemy.ai_state = next_state(rng, &enemy, NO_MOVE | (NO_MOVE << 3));
+    enemy
+}
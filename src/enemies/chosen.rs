@@ -0,0 +1,86 @@
+use crate::{
+    card::{Buff, Debuff},
+    enemies::{StateEntry, uniform_inclusive, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+macro_rules! make_chosen_table {
+    ($poke: expr, $debilitate: expr, $zap: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::Attack($poke), EnemyAction::Attack($poke)],
+                new_states: &[1],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Debuff(Debuff::Hex(1))],
+                new_states: &[2, 3],
+                weights: &[1, 1],
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack($debilitate),
+                    EnemyAction::Debuff(Debuff::Vulnerable(2)),
+                ],
+                new_states: &[4, 5],
+                weights: &[4, 6],
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Debuff(Debuff::Weak(3)),
+                    EnemyAction::Buff(Buff::Strength(3)),
+                ],
+                new_states: &[4, 5],
+                weights: &[4, 6],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($zap)],
+                new_states: &[2, 3],
+                weights: &[1, 1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($poke), EnemyAction::Attack($poke)],
+                new_states: &[2, 3],
+                weights: &[1, 1],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0) Opening Poke
+    // 1) Hex
+    // 2) Debilitate
+    // 3) Drain
+    // 4) Zap
+    // 5) Poke
+    //After Hex the Chosen alternates between a debuff and an attack.
+    const ENEMY_TABLE: &[StateEntry] = make_chosen_table!(5, 10, 18);
+    const A2_TABLE: &[StateEntry] = make_chosen_table!(6, 12, 21);
+    let table = match fight.ascension {
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    weighted_transition(rng, state, table)
+}
+
+pub fn generate_chosen(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 98, 103)
+    } else {
+        uniform_inclusive(rng, 95, 99)
+    };
+    Enemy {
+        name: EnemyName::Chosen,
+        //Ascension 17 Chosen open with Hex.
+        ai_state: if ascension >= 17 { 1 } else { 0 },
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs: EnemyBuffs::default(),
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
@@ -0,0 +1,124 @@
+use std::cmp::min;
+
+use crate::{
+    card::{Buff, CardBody, Debuff},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+//The Corrupt Heart opens with Debilitate, then cycles attacks with a buff every 3rd turn.
+//Each buff is stronger than the last.
+//Bits 0-2 are the current move, bits 3-4 are the position in the cycle and bits 5-7
+//count the buffs used.
+const DEBILITATE: u32 = 0;
+const BLOOD_SHOTS: u32 = 1;
+const ECHO: u32 = 2;
+//Buffs use moves 3 and up, one for each buff.
+const BUFF: u32 = 3;
+
+macro_rules! make_corrupt_heart_actions {
+    ($blood_shots: expr, $echo: expr) => {
+        &[
+            &[
+                EnemyAction::Debuff(Debuff::Vulnerable(2)),
+                EnemyAction::Debuff(Debuff::Weak(2)),
+                EnemyAction::Debuff(Debuff::Frail(2)),
+                EnemyAction::ShuffleIntoDraw(&[
+                    CardBody::Burn,
+                    CardBody::Dazed,
+                    CardBody::Slimed,
+                    CardBody::Void,
+                    CardBody::Wound,
+                ]),
+            ],
+            $blood_shots,
+            &[EnemyAction::Attack($echo)],
+            &[
+                EnemyAction::ClearNegativeStrength,
+                EnemyAction::Buff(Buff::Strength(2)),
+                EnemyAction::Buff(Buff::Artifact(2)),
+            ],
+            &[
+                EnemyAction::ClearNegativeStrength,
+                EnemyAction::Buff(Buff::Strength(2)),
+                EnemyAction::Buff(Buff::BeatOfDeath(1)),
+            ],
+            &[
+                EnemyAction::ClearNegativeStrength,
+                EnemyAction::Buff(Buff::Strength(2)),
+                EnemyAction::Buff(Buff::PainfulStabs),
+            ],
+            &[
+                EnemyAction::ClearNegativeStrength,
+                EnemyAction::Buff(Buff::Strength(12)),
+            ],
+            &[
+                EnemyAction::ClearNegativeStrength,
+                EnemyAction::Buff(Buff::Strength(52)),
+            ],
+        ]
+    };
+}
+
+const ACTIONS: &[&[EnemyAction]] = make_corrupt_heart_actions!(&[EnemyAction::Attack(2); 12], 40);
+const A4_ACTIONS: &[&[EnemyAction]] =
+    make_corrupt_heart_actions!(&[EnemyAction::Attack(2); 15], 45);
+
+fn next_state(rng: &mut Rng, state: u32) -> u32 {
+    let last = state & 7;
+    let cycle = (state >> 3) & 3;
+    let mut buffs = (state >> 5) & 7;
+    let next = match cycle {
+        0 => {
+            if rng.sample(2) == 0 {
+                BLOOD_SHOTS
+            } else {
+                ECHO
+            }
+        }
+        1 => {
+            if last != ECHO {
+                ECHO
+            } else {
+                BLOOD_SHOTS
+            }
+        }
+        _ => {
+            let next = BUFF + buffs;
+            buffs = min(buffs + 1, 4);
+            next
+        }
+    };
+    next | (((cycle + 1) % 3) << 3) | (buffs << 5)
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    let actions = if fight.ascension >= 4 {
+        A4_ACTIONS
+    } else {
+        ACTIONS
+    };
+    (next_state(rng, state), actions[(state & 7) as usize])
+}
+
+pub fn generate_corrupt_heart(_: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 9 { 800 } else { 750 };
+    //Ascension 19 lowers Invincible and doubles Beat of Death.
+    let invincible = if ascension >= 19 { 200 } else { 300 };
+    let buffs = EnemyBuffs {
+        invincible,
+        invincible_left: invincible,
+        beat_of_death: if ascension >= 19 { 2 } else { 1 },
+        ..Default::default()
+    };
+    Enemy {
+        name: EnemyName::CorruptHeart,
+        ai_state: DEBILITATE,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
@@ -5,31 +5,49 @@ use crate::{
     rng::Rng,
 };
 
-pub fn ai(rng: &mut Rng, _: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+macro_rules! make_cultist_table {
+    ($ritual: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::Buff(Buff::RitualSkipFirst($ritual))],
+                new_states: &[1],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack(6)],
+                new_states: &[1],
+                weights: &[1],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
     // States are
     // 0) Buff
     // 1) Attack for 6
-    const ENEMY_TABLE: &'static [StateEntry] = &[
-        StateEntry {
-            actions: &[EnemyAction::Buff(Buff::RitualSkipFirst(3))],
-            new_states: &[1],
-            weights: &[1],
-        },
-        StateEntry {
-            actions: &[EnemyAction::Attack(6)],
-            new_states: &[1],
-            weights: &[1],
-        },
-    ];
-    return weighted_transition(rng, state, ENEMY_TABLE);
+    const ENEMY_TABLE: &'static [StateEntry] = make_cultist_table!(3);
+    const A2_TABLE: &'static [StateEntry] = make_cultist_table!(4);
+    const A17_TABLE: &'static [StateEntry] = make_cultist_table!(5);
+    let table = match fight.ascension {
+        17.. => A17_TABLE,
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    return weighted_transition(rng, state, table);
 }
 
-pub fn generate_cultist(rng: &mut Rng) -> Enemy {
-    let hp = uniform_inclusive(rng, 48, 54);
+pub fn generate_cultist(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 50, 56)
+    } else {
+        uniform_inclusive(rng, 48, 54)
+    };
 
     Enemy {
         name: EnemyName::Cultist,
         ai_state: 0,
+        last_move: None,
         hp,
         max_hp: hp,
         buffs: EnemyBuffs::default(),
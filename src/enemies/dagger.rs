@@ -0,0 +1,46 @@
+use crate::{
+    card::CardBody,
+    enemies::{StateEntry, uniform_inclusive, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+pub fn ai(rng: &mut Rng, _: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0) Stab
+    // 1) Explode
+    const ENEMY_TABLE: &[StateEntry] = &[
+        StateEntry {
+            actions: &[
+                EnemyAction::Attack(9),
+                EnemyAction::AddToDiscard(&[CardBody::Wound]),
+            ],
+            new_states: &[1],
+            weights: &[1],
+        },
+        StateEntry {
+            actions: &[EnemyAction::Attack(25), EnemyAction::Die],
+            new_states: &[1],
+            weights: &[1],
+        },
+    ];
+    weighted_transition(rng, state, ENEMY_TABLE)
+}
+
+pub fn generate_dagger(rng: &mut Rng, _ascension: i32) -> Enemy {
+    let hp = uniform_inclusive(rng, 20, 25);
+    let buffs = EnemyBuffs {
+        minion: true,
+        ..Default::default()
+    };
+    Enemy {
+        name: EnemyName::Dagger,
+        ai_state: 0,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{
+    card::Buff,
+    enemies::uniform_inclusive,
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+//Darklings regrow half their HP if another Darkling is still alive when they die.
+//Bits 0-2 are the current move, bits 3-5 are the previous move, bits 6-8 pick Nip's damage
+//and bit 9 is set if the Darkling can Chomp. The middle Darkling can't.
+const CHOMP: u32 = 0;
+const HARDEN: u32 = 1;
+const NIP: u32 = 2;
+const REGROW: u32 = 3;
+const REINCARNATE: u32 = 4;
+const NO_MOVE: u32 = 7;
+const CAN_CHOMP: u32 = 1 << 9;
+
+macro_rules! make_darkling_actions {
+    ($chomp: expr, $harden: expr) => {
+        &[
+            &[EnemyAction::Attack($chomp), EnemyAction::Attack($chomp)],
+            $harden,
+            &[],
+            &[],
+            &[EnemyAction::Reincarnate],
+        ]
+    };
+}
+
+const ACTIONS: &[&[EnemyAction]] = make_darkling_actions!(8, &[EnemyAction::Block(12)]);
+const A2_ACTIONS: &[&[EnemyAction]] = make_darkling_actions!(9, &[EnemyAction::Block(12)]);
+//Ascension 17 Darklings also gain strength when they Harden.
+const A17_ACTIONS: &[&[EnemyAction]] = make_darkling_actions!(
+    9,
+    &[EnemyAction::Block(12), EnemyAction::Buff(Buff::Strength(2))]
+);
+
+const NIP_ACTIONS: &[&[EnemyAction]] = &[
+    &[EnemyAction::Attack(7)],
+    &[EnemyAction::Attack(8)],
+    &[EnemyAction::Attack(9)],
+    &[EnemyAction::Attack(10)],
+    &[EnemyAction::Attack(11)],
+];
+const A2_NIP_ACTIONS: &[&[EnemyAction]] = &[
+    &[EnemyAction::Attack(9)],
+    &[EnemyAction::Attack(10)],
+    &[EnemyAction::Attack(11)],
+    &[EnemyAction::Attack(12)],
+    &[EnemyAction::Attack(13)],
+];
+
+fn next_state(rng: &mut Rng, state: u32) -> u32 {
+    let last = state & 7;
+    let before = (state >> 3) & 7;
+    let flags = state & !0x3f;
+    let next = if last == REGROW {
+        REINCARNATE
+    } else if last == NO_MOVE {
+        if rng.sample(2) == 0 { HARDEN } else { NIP }
+    } else {
+        loop {
+            let num = rng.sample(100);
+            if num < 40 {
+                if last != CHOMP && flags & CAN_CHOMP != 0 {
+                    break CHOMP;
+                }
+            } else if num < 70 {
+                break if last != HARDEN { HARDEN } else { NIP };
+            } else if last != NIP || before != NIP {
+                break NIP;
+            }
+        }
+    };
+    next | (last << 3) | flags
+}
+
+//The state for a Darkling which has just died. It regrows on its next turn.
+pub fn regrow_state(state: u32) -> u32 {
+    (state & !0x3f) | REGROW | (NO_MOVE << 3)
+}
+
+pub fn can_chomp(state: u32) -> bool {
+    state & CAN_CHOMP != 0
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    let (actions, nip_actions) = match fight.ascension {
+        17.. => (A17_ACTIONS, A2_NIP_ACTIONS),
+        2.. => (A2_ACTIONS, A2_NIP_ACTIONS),
+        _ => (ACTIONS, NIP_ACTIONS),
+    };
+    let actions = if state & 7 == NIP {
+        nip_actions[((state >> 6) & 7) as usize]
+    } else {
+        actions[(state & 7) as usize]
+    };
+    (next_state(rng, state), actions)
+}
+
+pub fn generate_darkling(rng: &mut Rng, can_chomp: bool, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 50, 59)
+    } else {
+        uniform_inclusive(rng, 48, 56)
+    };
+    let buffs = EnemyBuffs {
+        regrow: true,
+        ..Default::default()
+    };
+    let flags = ((rng.sample(5) as u32) << 6) | if can_chomp { CAN_CHOMP } else { 0 };
+    Enemy {
+        name: EnemyName::Darkling,
+        ai_state: next_state(rng, NO_MOVE | (NO_MOVE << 3) | flags),
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
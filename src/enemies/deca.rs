@@ -0,0 +1,67 @@
+use crate::{
+    card::{Buff, CardBody},
+    enemies::{StateEntry, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+macro_rules! make_deca_table {
+    ($beam: expr, $square: expr) => {
+        &[
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack($beam),
+                    EnemyAction::Attack($beam),
+                    EnemyAction::AddToDiscard(&[CardBody::Dazed, CardBody::Dazed]),
+                ],
+                new_states: &[1],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: $square,
+                new_states: &[0],
+                weights: &[1],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0) Beam
+    // 1) Square of Protection
+    const SQUARE: &[EnemyAction] = &[EnemyAction::Block(16), EnemyAction::BlockOthers(16)];
+    //Ascension 19 Square of Protection also gives Plated Armor.
+    const A19_SQUARE: &[EnemyAction] = &[
+        EnemyAction::Block(16),
+        EnemyAction::BlockOthers(16),
+        EnemyAction::BuffAll(Buff::PlatedArmor(3)),
+    ];
+    const ENEMY_TABLE: &[StateEntry] = make_deca_table!(10, SQUARE);
+    const A4_TABLE: &[StateEntry] = make_deca_table!(12, SQUARE);
+    const A19_TABLE: &[StateEntry] = make_deca_table!(12, A19_SQUARE);
+    let table = match fight.ascension {
+        19.. => A19_TABLE,
+        4.. => A4_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    weighted_transition(rng, state, table)
+}
+
+pub fn generate_deca(_: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 9 { 265 } else { 250 };
+    let buffs = EnemyBuffs {
+        artifact: if ascension >= 19 { 3 } else { 2 },
+        ..Default::default()
+    };
+    Enemy {
+        name: EnemyName::Deca,
+        ai_state: 0,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
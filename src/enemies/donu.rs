@@ -0,0 +1,54 @@
+use crate::{
+    card::Buff,
+    enemies::{StateEntry, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+macro_rules! make_donu_table {
+    ($beam: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::BuffAll(Buff::Strength(3))],
+                new_states: &[1],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($beam), EnemyAction::Attack($beam)],
+                new_states: &[0],
+                weights: &[1],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0) Circle of Power
+    // 1) Beam
+    const ENEMY_TABLE: &[StateEntry] = make_donu_table!(10);
+    const A4_TABLE: &[StateEntry] = make_donu_table!(12);
+    let table = match fight.ascension {
+        4.. => A4_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    weighted_transition(rng, state, table)
+}
+
+pub fn generate_donu(_: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 9 { 265 } else { 250 };
+    let buffs = EnemyBuffs {
+        artifact: if ascension >= 19 { 3 } else { 2 },
+        ..Default::default()
+    };
+    Enemy {
+        name: EnemyName::Donu,
+        ai_state: 0,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{
+    enemies::{StateEntry, uniform_inclusive, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+macro_rules! make_exploder_table {
+    ($slam: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::Attack($slam)],
+                new_states: &[1],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($slam)],
+                new_states: &[2],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Explode(30)],
+                new_states: &[2],
+                weights: &[1],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0) Slam
+    // 1) Slam, second time
+    // 2) Explode
+    const ENEMY_TABLE: &[StateEntry] = make_exploder_table!(9);
+    const A2_TABLE: &[StateEntry] = make_exploder_table!(11);
+    let table = match fight.ascension {
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    weighted_transition(rng, state, table)
+}
+
+pub fn generate_exploder(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 30, 35)
+    } else {
+        30
+    };
+    Enemy {
+        name: EnemyName::Exploder,
+        ai_state: 0,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs: EnemyBuffs::default(),
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
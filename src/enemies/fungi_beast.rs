@@ -5,33 +5,51 @@ use crate::{
     rng::Rng,
 };
 
-pub fn ai(rng: &mut Rng, _: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
-    const ENEMY_TABLE: &'static [StateEntry] = &[
-        StateEntry {
-            actions: &[EnemyAction::Attack(6)],
-            new_states: &[1, 2],
-            weights: &[6, 4],
-        },
-        StateEntry {
-            actions: &[EnemyAction::Attack(6)],
-            new_states: &[2],
-            weights: &[1],
-        },
-        StateEntry {
-            actions: &[EnemyAction::Buff(Buff::Strength(3))],
-            new_states: &[0],
-            weights: &[1],
-        },
-    ];
-    return weighted_transition(rng, state, ENEMY_TABLE);
+macro_rules! make_fungi_beast_table {
+    ($strength: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::Attack(6)],
+                new_states: &[1, 2],
+                weights: &[6, 4],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack(6)],
+                new_states: &[2],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Buff(Buff::Strength($strength))],
+                new_states: &[0],
+                weights: &[1],
+            },
+        ]
+    };
 }
-pub fn generate_fungi_beast(rng: &mut Rng) -> Enemy {
-    let hp = uniform_inclusive(rng, 22, 28);
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    const ENEMY_TABLE: &'static [StateEntry] = make_fungi_beast_table!(3);
+    const A2_TABLE: &'static [StateEntry] = make_fungi_beast_table!(4);
+    const A17_TABLE: &'static [StateEntry] = make_fungi_beast_table!(5);
+    let table = match fight.ascension {
+        17.. => A17_TABLE,
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    return weighted_transition(rng, state, table);
+}
+pub fn generate_fungi_beast(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 24, 28)
+    } else {
+        uniform_inclusive(rng, 22, 28)
+    };
     let mut buffs = EnemyBuffs::default();
     buffs.spore_cloud = 2;
     Enemy {
         name: EnemyName::FungiBeast,
         ai_state: rng.sample_weighted(&[6, 0, 4]) as u32,
+        last_move: None,
         hp,
         max_hp: hp,
         buffs,
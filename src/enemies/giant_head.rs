@@ -0,0 +1,97 @@
+use crate::{
+    card::Debuff,
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+//The Giant Head counts down for 4 turns, then It Is Time hits harder each turn.
+//Bits 0-3 are the current move, bits 4-5 are the previous move and bits 6-8 count
+//down to It Is Time.
+const COUNT: u32 = 0;
+const GLARE: u32 = 1;
+const NO_MOVE: u32 = 2;
+//It Is Time uses moves 3 and up, one for each damage step.
+const IT_IS_TIME: u32 = 3;
+const COUNTDOWN: u32 = 5;
+const A18_COUNTDOWN: u32 = 4;
+
+const ACTIONS: &[&[EnemyAction]] = &[
+    &[EnemyAction::Attack(13)],
+    &[EnemyAction::Debuff(Debuff::Weak(1))],
+    &[],
+    &[EnemyAction::Attack(30)],
+    &[EnemyAction::Attack(35)],
+    &[EnemyAction::Attack(40)],
+    &[EnemyAction::Attack(45)],
+    &[EnemyAction::Attack(50)],
+    &[EnemyAction::Attack(55)],
+    &[EnemyAction::Attack(60)],
+];
+const A3_ACTIONS: &[&[EnemyAction]] = &[
+    &[EnemyAction::Attack(13)],
+    &[EnemyAction::Debuff(Debuff::Weak(1))],
+    &[],
+    &[EnemyAction::Attack(40)],
+    &[EnemyAction::Attack(45)],
+    &[EnemyAction::Attack(50)],
+    &[EnemyAction::Attack(55)],
+    &[EnemyAction::Attack(60)],
+    &[EnemyAction::Attack(65)],
+    &[EnemyAction::Attack(70)],
+];
+
+fn next_state(rng: &mut Rng, state: u32) -> u32 {
+    let last = state & 15;
+    let before = (state >> 4) & 3;
+    let count = state >> 6;
+    if count <= 1 {
+        let next = if last >= IT_IS_TIME {
+            (last + 1).min(ACTIONS.len() as u32 - 1)
+        } else {
+            IT_IS_TIME
+        };
+        //It Is Time doesn't use the previous move so it isn't tracked.
+        return next | (NO_MOVE << 4);
+    }
+    let twice = |mv: u32| last == mv && before == mv;
+    let next = if rng.sample(100) < 50 {
+        if !twice(GLARE) { GLARE } else { COUNT }
+    } else if !twice(COUNT) {
+        COUNT
+    } else {
+        GLARE
+    };
+    next | (last << 4) | ((count - 1) << 6)
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    let actions = if fight.ascension >= 3 {
+        A3_ACTIONS
+    } else {
+        ACTIONS
+    };
+    (next_state(rng, state), actions[(state & 15) as usize])
+}
+
+pub fn generate_giant_head(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 8 { 520 } else { 500 };
+    let countdown = if ascension >= 18 {
+        A18_COUNTDOWN
+    } else {
+        COUNTDOWN
+    };
+    let buffs = EnemyBuffs {
+        slow: true,
+        ..Default::default()
+    };
+    Enemy {
+        name: EnemyName::GiantHead,
+        ai_state: next_state(rng, NO_MOVE | (NO_MOVE << 4) | (countdown << 6)),
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
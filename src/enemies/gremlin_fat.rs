@@ -5,18 +5,37 @@ use crate::{
     rng::Rng,
 };
 
-pub fn ai(_: &mut Rng, _: &Fight, _: &Enemy, _: u32) -> (u32, &'static [EnemyAction]) {
-    return (
-        0,
-        &[EnemyAction::Attack(4), EnemyAction::Debuff(Debuff::Weak(1))],
-    );
+pub fn ai(_: &mut Rng, fight: &Fight, _: &Enemy, _: u32) -> (u32, &'static [EnemyAction]) {
+    match fight.ascension {
+        17.. => (
+            0,
+            &[
+                EnemyAction::Attack(5),
+                EnemyAction::Debuff(Debuff::Weak(1)),
+                EnemyAction::Debuff(Debuff::Frail(1)),
+            ],
+        ),
+        2.. => (
+            0,
+            &[EnemyAction::Attack(5), EnemyAction::Debuff(Debuff::Weak(1))],
+        ),
+        _ => (
+            0,
+            &[EnemyAction::Attack(4), EnemyAction::Debuff(Debuff::Weak(1))],
+        ),
+    }
 }
-pub fn generate_fat_gremlin(rng: &mut Rng) -> Enemy {
-    let hp = uniform_inclusive(rng, 13, 17);
+pub fn generate_fat_gremlin(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 14, 18)
+    } else {
+        uniform_inclusive(rng, 13, 17)
+    };
 
     Enemy {
         name: EnemyName::GremlinFat,
         ai_state: 0,
+        last_move: None,
         hp,
         max_hp: hp,
         buffs: EnemyBuffs::default(),
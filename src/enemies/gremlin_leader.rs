@@ -0,0 +1,115 @@
+use std::cmp::min;
+
+use crate::{
+    card::Buff,
+    enemies::{StateEntry, uniform_inclusive, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+//The Gremlin Leader's gremlins are kept in the first slots, the leader comes after them.
+pub const LEADER_SLOT: usize = 3;
+
+macro_rules! make_gremlin_leader_table {
+    ($strength: expr, $block: expr,
+     $rally_states: expr, $rally_weights: expr,
+     $encourage_states: expr, $encourage_weights: expr,
+     $stab_states: expr, $stab_weights: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::Summon],
+                new_states: $rally_states,
+                weights: $rally_weights,
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::BuffAll(Buff::Strength($strength)),
+                    EnemyAction::BlockOthers($block),
+                ],
+                new_states: $encourage_states,
+                weights: $encourage_weights,
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack(6),
+                    EnemyAction::Attack(6),
+                    EnemyAction::Attack(6),
+                ],
+                new_states: $stab_states,
+                weights: $stab_weights,
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0) Rally
+    // 1) Encourage
+    // 2) Stab
+    //The odds depend on how many gremlins are still alive.
+    macro_rules! make_tables {
+        ($strength: expr, $block: expr) => {
+            [
+                make_gremlin_leader_table!(
+                    $strength,
+                    $block,
+                    &[2],
+                    &[1],
+                    &[0, 2],
+                    &[75, 25],
+                    &[0],
+                    &[1]
+                ),
+                make_gremlin_leader_table!(
+                    $strength,
+                    $block,
+                    &[1, 2],
+                    &[60, 40],
+                    &[0, 2],
+                    &[1, 1],
+                    &[0, 1],
+                    &[5, 3]
+                ),
+                make_gremlin_leader_table!(
+                    $strength,
+                    $block,
+                    &[1, 2],
+                    &[66, 34],
+                    &[2],
+                    &[1],
+                    &[1],
+                    &[1]
+                ),
+            ]
+        };
+    }
+    const TABLES: [&[StateEntry]; 3] = make_tables!(3, 6);
+    const A3_TABLES: [&[StateEntry]; 3] = make_tables!(4, 6);
+    const A18_TABLES: [&[StateEntry]; 3] = make_tables!(5, 10);
+    let tables = match fight.ascension {
+        18.. => A18_TABLES,
+        3.. => A3_TABLES,
+        _ => TABLES,
+    };
+    let table = tables[min(fight.enemies.len() - 1, 2)];
+    weighted_transition(rng, state, table)
+}
+
+pub fn generate_gremlin_leader(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 8 {
+        uniform_inclusive(rng, 145, 155)
+    } else {
+        uniform_inclusive(rng, 140, 148)
+    };
+    Enemy {
+        name: EnemyName::GremlinLeader,
+        ai_state: rng.sample_weighted(&[0, 66, 34]) as u32,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs: EnemyBuffs::default(),
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
@@ -4,16 +4,24 @@ use crate::{
     rng::Rng,
 };
 
-pub fn ai(_: &mut Rng, _: &Fight, _: &Enemy, _: u32) -> (u32, &'static [EnemyAction]) {
+pub fn ai(_: &mut Rng, fight: &Fight, _: &Enemy, _: u32) -> (u32, &'static [EnemyAction]) {
+    if fight.ascension >= 2 {
+        return (0, &[EnemyAction::Attack(5)]);
+    }
     return (0, &[EnemyAction::Attack(4)]);
 }
-pub fn generate_mad_gremlin(rng: &mut Rng) -> Enemy {
-    let hp = uniform_inclusive(rng, 20, 24);
+pub fn generate_mad_gremlin(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 21, 25)
+    } else {
+        uniform_inclusive(rng, 20, 24)
+    };
     let mut buffs = EnemyBuffs::default();
-    buffs.angry = 1;
+    buffs.angry = if ascension >= 17 { 2 } else { 1 };
     Enemy {
         name: EnemyName::GremlinMad,
         ai_state: 0,
+        last_move: None,
         hp,
         max_hp: hp,
         buffs,
@@ -5,40 +5,86 @@ use crate::{
     rng::Rng,
 };
 
-pub fn ai(rng: &mut Rng, _: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
-    const ENEMY_TABLE: &'static [StateEntry] = &[
-        StateEntry {
-            actions: &[EnemyAction::Buff(Buff::Enrage(2))],
-            new_states: &[1, 2],
-            weights: &[1, 2],
-        },
-        StateEntry {
-            actions: &[
-                EnemyAction::Attack(6),
-                EnemyAction::Debuff(Debuff::Vulnerable(2)),
-            ],
-            new_states: &[1, 2],
-            weights: &[1, 2],
-        },
-        StateEntry {
-            actions: &[EnemyAction::Attack(14)],
-            new_states: &[1, 3],
-            weights: &[1, 2],
-        },
-        StateEntry {
-            actions: &[EnemyAction::Attack(14)],
-            new_states: &[1],
-            weights: &[1],
-        },
-    ];
-    return weighted_transition(rng, state, ENEMY_TABLE);
+macro_rules! make_gremlin_nob_table {
+    ($enrage: expr, $bash: expr, $rush: expr, $bellow_states: expr, $bellow_weights: expr,
+     $bash_states: expr, $bash_weights: expr, $rush_states: expr, $rush_weights: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::Buff(Buff::Enrage($enrage))],
+                new_states: $bellow_states,
+                weights: $bellow_weights,
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack($bash),
+                    EnemyAction::Debuff(Debuff::Vulnerable(2)),
+                ],
+                new_states: $bash_states,
+                weights: $bash_weights,
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($rush)],
+                new_states: $rush_states,
+                weights: $rush_weights,
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($rush)],
+                new_states: &[1],
+                weights: &[1],
+            },
+        ]
+    };
 }
-pub fn generate_gremlin_nob(rng: &mut Rng) -> Enemy {
-    let hp = uniform_inclusive(rng, 82, 86);
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0) Bellow
+    // 1) Skull Bash
+    // 2) Rush
+    // 3) Rush, same move prior turn.
+    const ENEMY_TABLE: &'static [StateEntry] = make_gremlin_nob_table!(
+        2,
+        6,
+        14,
+        &[1, 2],
+        &[1, 2],
+        &[1, 2],
+        &[1, 2],
+        &[1, 3],
+        &[1, 2]
+    );
+    const A3_TABLE: &'static [StateEntry] = make_gremlin_nob_table!(
+        2,
+        8,
+        16,
+        &[1, 2],
+        &[1, 2],
+        &[1, 2],
+        &[1, 2],
+        &[1, 3],
+        &[1, 2]
+    );
+    //Ascension 18 Nobs Skull Bash whenever they haven't in the last two turns.
+    const A18_TABLE: &'static [StateEntry] =
+        make_gremlin_nob_table!(3, 8, 16, &[1], &[1], &[2], &[1], &[3], &[1]);
+    let table = match fight.ascension {
+        18.. => A18_TABLE,
+        3.. => A3_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    return weighted_transition(rng, state, table);
+}
+pub fn generate_gremlin_nob(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 8 {
+        uniform_inclusive(rng, 85, 90)
+    } else {
+        uniform_inclusive(rng, 82, 86)
+    };
 
     Enemy {
         name: EnemyName::GremlinNob,
         ai_state: 0,
+        last_move: None,
         hp,
         max_hp: hp,
         buffs: EnemyBuffs::default(),
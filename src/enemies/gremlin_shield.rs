@@ -6,16 +6,27 @@ use crate::{
 
 pub fn ai(_: &mut Rng, fight: &Fight, _: &Enemy, _: u32) -> (u32, &'static [EnemyAction]) {
     if fight.enemies.len() > 1 {
-        return (0, &[EnemyAction::DefendAlly(6)]);
+        match fight.ascension {
+            17.. => (0, &[EnemyAction::DefendAlly(11)]),
+            7.. => (0, &[EnemyAction::DefendAlly(8)]),
+            _ => (0, &[EnemyAction::DefendAlly(7)]),
+        }
+    } else if fight.ascension >= 2 {
+        return (0, &[EnemyAction::Attack(8)]);
     } else {
         return (0, &[EnemyAction::Attack(6)]);
     }
 }
-pub fn generate_shield_gremlin(rng: &mut Rng) -> Enemy {
-    let hp = uniform_inclusive(rng, 12, 15);
+pub fn generate_shield_gremlin(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 13, 17)
+    } else {
+        uniform_inclusive(rng, 12, 15)
+    };
     Enemy {
         name: EnemyName::GremlinShield,
         ai_state: 0,
+        last_move: None,
         hp,
         max_hp: hp,
         buffs: EnemyBuffs::default(),
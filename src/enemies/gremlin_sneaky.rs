@@ -3,15 +3,23 @@ use crate::{
     fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
     rng::Rng,
 };
-pub fn ai(_: &mut Rng, _: &Fight, _: &Enemy, _: u32) -> (u32, &'static [EnemyAction]) {
+pub fn ai(_: &mut Rng, fight: &Fight, _: &Enemy, _: u32) -> (u32, &'static [EnemyAction]) {
+    if fight.ascension >= 2 {
+        return (0, &[EnemyAction::Attack(10)]);
+    }
     return (0, &[EnemyAction::Attack(9)]);
 }
 
-pub fn generate_sneaky_gremlin(rng: &mut Rng) -> Enemy {
-    let hp = uniform_inclusive(rng, 10, 14);
+pub fn generate_sneaky_gremlin(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 11, 15)
+    } else {
+        uniform_inclusive(rng, 10, 14)
+    };
     Enemy {
         name: EnemyName::GremlinSneaky,
         ai_state: 0,
+        last_move: None,
         hp,
         max_hp: hp,
         buffs: EnemyBuffs::default(),
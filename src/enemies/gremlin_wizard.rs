@@ -4,41 +4,60 @@ use crate::{
     rng::Rng,
 };
 
-pub fn ai(rng: &mut Rng, _: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+macro_rules! make_wizard_gremlin_table {
+    ($blast: expr, $after_blast: expr) => {
+        &[
+            StateEntry {
+                actions: &[],
+                new_states: &[1],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[],
+                new_states: &[2],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[],
+                new_states: &[3],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($blast)],
+                new_states: &[$after_blast],
+                weights: &[1],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
     // States are
     // 0) Charge Up Attack
-    // 0) Charge Up Attack (Starting state)
-    // 0) Charge Up Attack
-    // 0) Attack
-    const ENEMY_TABLE: &'static [StateEntry] = &[
-        StateEntry {
-            actions: &[],
-            new_states: &[1],
-            weights: &[1],
-        },
-        StateEntry {
-            actions: &[],
-            new_states: &[2],
-            weights: &[1],
-        },
-        StateEntry {
-            actions: &[],
-            new_states: &[3],
-            weights: &[1],
-        },
-        StateEntry {
-            actions: &[EnemyAction::Attack(25)],
-            new_states: &[0],
-            weights: &[1],
-        },
-    ];
-    return weighted_transition(rng, state, ENEMY_TABLE);
+    // 1) Charge Up Attack (Starting state)
+    // 2) Charge Up Attack
+    // 3) Attack
+    const ENEMY_TABLE: &'static [StateEntry] = make_wizard_gremlin_table!(25, 0);
+    const A2_TABLE: &'static [StateEntry] = make_wizard_gremlin_table!(30, 0);
+    //Ascension 17 wizards keep blasting once they are charged.
+    const A17_TABLE: &'static [StateEntry] = make_wizard_gremlin_table!(30, 3);
+    let table = match fight.ascension {
+        17.. => A17_TABLE,
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    return weighted_transition(rng, state, table);
 }
-pub fn generate_wizard_gremlin(rng: &mut Rng) -> Enemy {
-    let hp = uniform_inclusive(rng, 23, 25);
+pub fn generate_wizard_gremlin(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 22, 26)
+    } else {
+        uniform_inclusive(rng, 21, 25)
+    };
     Enemy {
         name: EnemyName::GremlinWizard,
         ai_state: 1,
+        last_move: None,
         hp,
         max_hp: hp,
         buffs: EnemyBuffs::default(),
@@ -0,0 +1,106 @@
+use crate::{
+    card::{Buff, CardBody},
+    enemies::{StateEntry, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+macro_rules! make_hexaghost_table {
+    ($tackle: expr, $inferno: expr, $strength: expr, $sear_burns: expr) => {{
+        const SEAR: StateEntry = StateEntry {
+            actions: &[
+                EnemyAction::Attack(6),
+                EnemyAction::AddToDiscard($sear_burns),
+            ],
+            new_states: &[0],
+            weights: &[1],
+        };
+        const TACKLE: &[EnemyAction] =
+            &[EnemyAction::Attack($tackle), EnemyAction::Attack($tackle)];
+        &[
+            //Activate - the Divider's damage is set from the player's hp.
+            StateEntry {
+                actions: &[EnemyAction::ChargeDivider],
+                new_states: &[1],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Divider(6)],
+                new_states: &[2],
+                weights: &[1],
+            },
+            StateEntry {
+                new_states: &[3],
+                ..SEAR
+            },
+            StateEntry {
+                actions: TACKLE,
+                new_states: &[4],
+                weights: &[1],
+            },
+            StateEntry {
+                new_states: &[5],
+                ..SEAR
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Block(12),
+                    EnemyAction::Buff(Buff::Strength($strength)),
+                ],
+                new_states: &[6],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: TACKLE,
+                new_states: &[7],
+                weights: &[1],
+            },
+            StateEntry {
+                new_states: &[8],
+                ..SEAR
+            },
+            //Inferno
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack($inferno),
+                    EnemyAction::Attack($inferno),
+                    EnemyAction::Attack($inferno),
+                    EnemyAction::Attack($inferno),
+                    EnemyAction::Attack($inferno),
+                    EnemyAction::Attack($inferno),
+                    EnemyAction::UpgradeBurns,
+                    EnemyAction::AddToDiscard(&[CardBody::Burn, CardBody::Burn, CardBody::Burn]),
+                ],
+                new_states: &[2],
+                weights: &[1],
+            },
+        ]
+    }};
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    const BURN: &[CardBody] = &[CardBody::Burn];
+    const BURNS: &[CardBody] = &[CardBody::Burn, CardBody::Burn];
+    const ENEMY_TABLE: &[StateEntry] = make_hexaghost_table!(5, 2, 2, BURN);
+    const A4_TABLE: &[StateEntry] = make_hexaghost_table!(6, 3, 2, BURN);
+    const A19_TABLE: &[StateEntry] = make_hexaghost_table!(6, 3, 3, BURNS);
+    let table = match fight.ascension {
+        19.. => A19_TABLE,
+        4.. => A4_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    weighted_transition(rng, state, table)
+}
+pub fn generate_hexaghost(ascension: i32) -> Enemy {
+    let hp = if ascension >= 9 { 264 } else { 250 };
+    Enemy {
+        name: EnemyName::Hexaghost,
+        ai_state: 0,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs: EnemyBuffs::default(),
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
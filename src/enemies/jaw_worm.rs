@@ -5,7 +5,37 @@ use crate::{
     rng::Rng,
 };
 
-pub fn ai(rng: &mut Rng, _: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+macro_rules! make_jaw_worm_table {
+    ($chomp: expr, $bellow_strength: expr, $bellow_block: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::Attack($chomp)],
+                new_states: &[1, 3],
+                weights: &[131, 189],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack(7), EnemyAction::Block(5)],
+                new_states: &[0, 2, 3],
+                weights: &[25, 30, 45],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack(7), EnemyAction::Block(5)],
+                new_states: &[0, 3],
+                weights: &[3571, 6429],
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Buff(Buff::Strength($bellow_strength)),
+                    EnemyAction::Block($bellow_block),
+                ],
+                new_states: &[0, 1],
+                weights: &[1093, 1407],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
     // States are
     // 0) Playing Attack
     // 1) Playing Defend+Attack, different move first.
@@ -14,35 +44,26 @@ pub fn ai(rng: &mut Rng, _: &Fight, _: &Enemy, state: u32) -> (u32, &'static [En
     // Jaw Worm's actions are a bit weird. The code samples a boolean if the same
     // action is chosen too many times in a row. The devs then changed the AI but didn't
     // update the boolean values so the percentages are now strange, but the values are accurate.
-    const ENEMY_TABLE: &'static [StateEntry] = &[
-        StateEntry {
-            actions: &[EnemyAction::Attack(11)],
-            new_states: &[1, 3],
-            weights: &[131, 189],
-        },
-        StateEntry {
-            actions: &[EnemyAction::Attack(7), EnemyAction::Block(5)],
-            new_states: &[0, 2, 3],
-            weights: &[25, 30, 45],
-        },
-        StateEntry {
-            actions: &[EnemyAction::Attack(7), EnemyAction::Block(5)],
-            new_states: &[0, 3],
-            weights: &[3571, 6429],
-        },
-        StateEntry {
-            actions: &[EnemyAction::Buff(Buff::Strength(3)), EnemyAction::Block(6)],
-            new_states: &[0, 1],
-            weights: &[1093, 1407],
-        },
-    ];
-    return weighted_transition(rng, state, ENEMY_TABLE);
+    const ENEMY_TABLE: &'static [StateEntry] = make_jaw_worm_table!(11, 3, 6);
+    const A2_TABLE: &'static [StateEntry] = make_jaw_worm_table!(12, 4, 6);
+    const A17_TABLE: &'static [StateEntry] = make_jaw_worm_table!(12, 5, 9);
+    let table = match fight.ascension {
+        17.. => A17_TABLE,
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    return weighted_transition(rng, state, table);
 }
-pub fn generate_jaw_worm(rng: &mut Rng) -> Enemy {
-    let hp = uniform_inclusive(rng, 40, 44);
+pub fn generate_jaw_worm(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 42, 46)
+    } else {
+        uniform_inclusive(rng, 40, 44)
+    };
     Enemy {
         name: EnemyName::JawWorm,
         ai_state: 0,
+        last_move: None,
         hp,
         max_hp: hp,
         buffs: EnemyBuffs::default(),
@@ -50,3 +71,15 @@ pub fn generate_jaw_worm(rng: &mut Rng) -> Enemy {
         block: 0,
     }
 }
+
+//Jaw Worms in the Act 3 horde start with their Bellow already applied.
+pub fn generate_hard_jaw_worm(rng: &mut Rng, ascension: i32) -> Enemy {
+    let mut enemy = generate_jaw_worm(rng, ascension);
+    (enemy.buffs.strength, enemy.block) = match ascension {
+        17.. => (5, 9),
+        2.. => (4, 6),
+        _ => (3, 6),
+    };
+    enemy.ai_state = rng.sample_weighted(&[25, 30, 0, 45]) as u32;
+    enemy
+}
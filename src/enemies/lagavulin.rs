@@ -5,46 +5,63 @@ use crate::{
     rng::Rng,
 };
 
-pub fn ai(rng: &mut Rng, _: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
-    const ENEMY_TABLE: &'static [StateEntry] = &[
-        StateEntry {
-            actions: &[],
-            new_states: &[1],
-            weights: &[1],
-        },
-        StateEntry {
-            actions: &[],
-            new_states: &[2],
-            weights: &[1],
-        },
-        StateEntry {
-            actions: &[],
-            new_states: &[3],
-            weights: &[1],
-        },
-        StateEntry {
-            actions: &[EnemyAction::Attack(18)],
-            new_states: &[4],
-            weights: &[1],
-        },
-        StateEntry {
-            actions: &[EnemyAction::Attack(18)],
-            new_states: &[5],
-            weights: &[1],
-        },
-        StateEntry {
-            actions: &[
-                EnemyAction::Debuff(Debuff::MinusDexterity(1)),
-                EnemyAction::Debuff(Debuff::MinusStrength(1)),
-            ],
-            new_states: &[3],
-            weights: &[1],
-        },
-    ];
-    return weighted_transition(rng, state, ENEMY_TABLE);
+macro_rules! make_lagavulin_table {
+    ($attack: expr, $siphon: expr) => {
+        &[
+            StateEntry {
+                actions: &[],
+                new_states: &[1],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[],
+                new_states: &[2],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[],
+                new_states: &[3],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($attack)],
+                new_states: &[4],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($attack)],
+                new_states: &[5],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Debuff(Debuff::MinusDexterity($siphon)),
+                    EnemyAction::Debuff(Debuff::MinusStrength($siphon)),
+                ],
+                new_states: &[3],
+                weights: &[1],
+            },
+        ]
+    };
 }
-pub fn generate_lagavulin(rng: &mut Rng) -> Enemy {
-    let hp = uniform_inclusive(rng, 109, 111);
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    const ENEMY_TABLE: &'static [StateEntry] = make_lagavulin_table!(18, 1);
+    const A3_TABLE: &'static [StateEntry] = make_lagavulin_table!(20, 1);
+    const A18_TABLE: &'static [StateEntry] = make_lagavulin_table!(20, 2);
+    let table = match fight.ascension {
+        18.. => A18_TABLE,
+        3.. => A3_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    return weighted_transition(rng, state, table);
+}
+pub fn generate_lagavulin(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 8 {
+        uniform_inclusive(rng, 112, 115)
+    } else {
+        uniform_inclusive(rng, 109, 111)
+    };
 
     let mut buffs = EnemyBuffs::default();
     buffs.metallicize = 8;
@@ -52,6 +69,7 @@ pub fn generate_lagavulin(rng: &mut Rng) -> Enemy {
     Enemy {
         name: EnemyName::Lagavulin,
         ai_state: 0,
+        last_move: None,
         hp,
         max_hp: hp,
         buffs: buffs,
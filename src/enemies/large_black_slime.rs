@@ -7,20 +7,40 @@ use crate::{
     rng::Rng,
 };
 
-pub fn ai(rng: &mut Rng, _: &Fight, enemy: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+pub fn ai(
+    rng: &mut Rng,
+    fight: &Fight,
+    enemy: &Enemy,
+    state: u32,
+) -> (u32, &'static [EnemyAction]) {
     const SLIMEDS: &'static [CardBody] = &[CardBody::Slimed, CardBody::Slimed];
-    const ENEMY_TABLE: &'static [StateEntry] = make_black_slime_table!(16, 2, SLIMEDS);
+    const ENEMY_TABLE: &'static [StateEntry] =
+        make_black_slime_table!(16, 2, SLIMEDS, &[0, 3], &[3, 7]);
+    const A2_TABLE: &'static [StateEntry] =
+        make_black_slime_table!(18, 2, SLIMEDS, &[0, 3], &[3, 7]);
+    //Ascension 17 slimes frail for more and never twice in a row.
+    const A17_TABLE: &'static [StateEntry] = make_black_slime_table!(18, 3, SLIMEDS, &[0], &[1]);
     if enemy.hp * 2 <= enemy.max_hp {
         return (0, &[EnemyAction::Split]);
     }
-    return weighted_transition(rng, state, ENEMY_TABLE);
+    let table = match fight.ascension {
+        17.. => A17_TABLE,
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    return weighted_transition(rng, state, table);
 }
-pub fn generate_large_black_slime(rng: &mut Rng) -> Enemy {
-    let hp = uniform_inclusive(rng, 64, 70);
+pub fn generate_large_black_slime(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 67, 73)
+    } else {
+        uniform_inclusive(rng, 64, 70)
+    };
     let starting_state = rng.sample_weighted(&[3, 0, 7, 0]);
     Enemy {
         name: EnemyName::LargeBlackSlime,
         ai_state: starting_state as u32,
+        last_move: None,
         hp,
         max_hp: hp,
         buffs: EnemyBuffs::default(),
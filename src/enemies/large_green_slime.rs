@@ -1,32 +1,59 @@
 use crate::{
     card::{CardBody, Debuff},
     enemies::{
-        StateEntry, med_green_slime::make_green_slime_table, uniform_inclusive, weighted_transition,
+        StateEntry,
+        med_green_slime::{make_a17_green_slime_table, make_green_slime_table},
+        uniform_inclusive, weighted_transition,
     },
     fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
     rng::Rng,
 };
 
-pub fn ai(rng: &mut Rng, _: &Fight, enemy: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
-    // States are
-    // 0) Attack + Slimed inserted
-    // 1) Attack + Slimed inserted (second)
-    // 2) Attack
-    // 3) Debuff
-    // 4) Debuff (second)
+pub fn ai(
+    rng: &mut Rng,
+    fight: &Fight,
+    enemy: &Enemy,
+    state: u32,
+) -> (u32, &'static [EnemyAction]) {
+    // States are the same as the medium green slime.
     const SLIMEDS: &'static [CardBody] = &[CardBody::Slimed, CardBody::Slimed];
     const ENEMY_TABLE: &'static [StateEntry] = make_green_slime_table!(11, 16, 2, SLIMEDS);
+    const A2_TABLE: &'static [StateEntry] = make_green_slime_table!(12, 18, 2, SLIMEDS);
+    const A17_TABLE: &'static [StateEntry] = make_a17_green_slime_table!(
+        12,
+        18,
+        2,
+        SLIMEDS,
+        &[40, 30, 30],
+        &[54, 46],
+        &[58, 42],
+        &[52, 48]
+    );
     if enemy.hp * 2 <= enemy.max_hp {
         return (0, &[EnemyAction::Split]);
     }
-    return weighted_transition(rng, state, ENEMY_TABLE);
+    let table = match fight.ascension {
+        17.. => A17_TABLE,
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    return weighted_transition(rng, state, table);
 }
-pub fn generate_large_green_slime(rng: &mut Rng) -> Enemy {
-    let hp = uniform_inclusive(rng, 65, 69);
-    let starting_state = rng.sample_weighted(&[3, 0, 4, 3, 0]);
+pub fn generate_large_green_slime(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 68, 72)
+    } else {
+        uniform_inclusive(rng, 65, 69)
+    };
+    let starting_state = if ascension >= 17 {
+        rng.sample_weighted(&[40, 0, 30, 0, 30])
+    } else {
+        rng.sample_weighted(&[3, 0, 4, 3, 0])
+    };
     Enemy {
         name: EnemyName::LargeGreenSlime,
         ai_state: starting_state as u32,
+        last_move: None,
         hp,
         max_hp: hp,
         buffs: EnemyBuffs::default(),
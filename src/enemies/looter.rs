@@ -4,44 +4,62 @@ use crate::{
     rng::Rng,
 };
 
-pub fn ai(rng: &mut Rng, _: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+macro_rules! make_looter_table {
+    ($swipe: expr, $lunge: expr, $gold: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::Attack($swipe), EnemyAction::StealGold($gold)],
+                new_states: &[1],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($swipe), EnemyAction::StealGold($gold)],
+                new_states: &[2, 3],
+                weights: &[1, 1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($lunge), EnemyAction::StealGold($gold)],
+                new_states: &[3],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Block(6)],
+                new_states: &[4],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Escape],
+                new_states: &[5],
+                weights: &[1],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
     // States are
     // 0) Playing Attack
     // 1) Debuff
-    const ENEMY_TABLE: &'static [StateEntry] = &[
-        StateEntry {
-            actions: &[EnemyAction::Attack(10), EnemyAction::StealGold(15)],
-            new_states: &[1],
-            weights: &[1],
-        },
-        StateEntry {
-            actions: &[EnemyAction::Attack(10), EnemyAction::StealGold(15)],
-            new_states: &[2, 3],
-            weights: &[1, 1],
-        },
-        StateEntry {
-            actions: &[EnemyAction::Attack(12), EnemyAction::StealGold(15)],
-            new_states: &[3],
-            weights: &[1],
-        },
-        StateEntry {
-            actions: &[EnemyAction::Block(6)],
-            new_states: &[4],
-            weights: &[1],
-        },
-        StateEntry {
-            actions: &[EnemyAction::Escape],
-            new_states: &[5],
-            weights: &[1],
-        },
-    ];
-    return weighted_transition(rng, state, ENEMY_TABLE);
+    const ENEMY_TABLE: &'static [StateEntry] = make_looter_table!(10, 12, 15);
+    const A2_TABLE: &'static [StateEntry] = make_looter_table!(11, 14, 15);
+    const A17_TABLE: &'static [StateEntry] = make_looter_table!(11, 14, 20);
+    let table = match fight.ascension {
+        17.. => A17_TABLE,
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    return weighted_transition(rng, state, table);
 }
-pub fn generate_looter(rng: &mut Rng) -> Enemy {
-    let hp = uniform_inclusive(rng, 44, 48);
+pub fn generate_looter(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 46, 50)
+    } else {
+        uniform_inclusive(rng, 44, 48)
+    };
     Enemy {
         name: EnemyName::Looter,
         ai_state: 0,
+        last_move: None,
         hp,
         max_hp: hp,
         buffs: EnemyBuffs::default(),
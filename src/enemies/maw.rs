@@ -0,0 +1,90 @@
+use std::cmp::min;
+
+use crate::{
+    card::{Buff, Debuff},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+//Nom hits once for every 2 turns the Maw has been alive.
+//Bits 0-1 are the current move and bits 2-6 count turns.
+const ROAR: u32 = 0;
+const SLAM: u32 = 1;
+const NOM: u32 = 2;
+const DROOL: u32 = 3;
+const MAX_TURNS: u32 = 31;
+
+macro_rules! make_maw_actions {
+    ($roar: expr, $slam: expr, $drool: expr) => {
+        &[
+            &[
+                EnemyAction::Debuff(Debuff::Weak($roar)),
+                EnemyAction::Debuff(Debuff::Frail($roar)),
+            ],
+            &[EnemyAction::Attack($slam)],
+            &[],
+            &[EnemyAction::Buff(Buff::Strength($drool))],
+        ]
+    };
+}
+
+const ACTIONS: &[&[EnemyAction]] = make_maw_actions!(3, 25, 3);
+const A2_ACTIONS: &[&[EnemyAction]] = make_maw_actions!(3, 30, 3);
+const A17_ACTIONS: &[&[EnemyAction]] = make_maw_actions!(5, 30, 5);
+
+const BITE: EnemyAction = EnemyAction::Attack(5);
+const NOM_ACTIONS: &[&[EnemyAction]] = &[
+    &[BITE],
+    &[BITE, BITE],
+    &[BITE, BITE, BITE],
+    &[BITE, BITE, BITE, BITE],
+    &[BITE, BITE, BITE, BITE, BITE],
+    &[BITE, BITE, BITE, BITE, BITE, BITE],
+    &[BITE, BITE, BITE, BITE, BITE, BITE, BITE],
+    &[BITE, BITE, BITE, BITE, BITE, BITE, BITE, BITE],
+    &[BITE, BITE, BITE, BITE, BITE, BITE, BITE, BITE, BITE],
+    &[BITE, BITE, BITE, BITE, BITE, BITE, BITE, BITE, BITE, BITE],
+];
+
+fn next_state(rng: &mut Rng, state: u32) -> u32 {
+    let last = state & 3;
+    let turns = min((state >> 2) + 1, MAX_TURNS);
+    let next = if rng.sample(100) < 50 && last != NOM {
+        NOM
+    } else if last == SLAM || last == NOM {
+        DROOL
+    } else {
+        SLAM
+    };
+    next | (turns << 2)
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    let actions = match fight.ascension {
+        17.. => A17_ACTIONS,
+        2.. => A2_ACTIONS,
+        _ => ACTIONS,
+    };
+    let actions = if state & 3 == NOM {
+        let hits = ((state >> 2) / 2) as usize;
+        NOM_ACTIONS[min(hits, NOM_ACTIONS.len()) - 1]
+    } else {
+        actions[(state & 3) as usize]
+    };
+    (next_state(rng, state), actions)
+}
+
+pub fn generate_maw(_: &mut Rng, _ascension: i32) -> Enemy {
+    let hp = 300;
+    Enemy {
+        name: EnemyName::Maw,
+        //The Maw always Roars first.
+        ai_state: ROAR | (1 << 2),
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs: EnemyBuffs::default(),
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
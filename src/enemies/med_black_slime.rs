@@ -5,7 +5,7 @@ use crate::{
     rng::Rng,
 };
 macro_rules! make_black_slime_table {
-    ($attack: expr, $frail_amount: expr, $slimeds: expr) => {
+    ($attack: expr, $frail_amount: expr, $slimeds: expr, $frail_states: expr, $frail_weights: expr) => {
         &[
             StateEntry {
                 actions: &[
@@ -25,8 +25,8 @@ macro_rules! make_black_slime_table {
             },
             StateEntry {
                 actions: &[EnemyAction::Debuff(Debuff::Frail($frail_amount))],
-                new_states: &[0, 3],
-                weights: &[3, 7],
+                new_states: $frail_states,
+                weights: $frail_weights,
             },
             StateEntry {
                 actions: &[EnemyAction::Debuff(Debuff::Frail($frail_amount))],
@@ -38,18 +38,33 @@ macro_rules! make_black_slime_table {
 }
 pub(crate) use make_black_slime_table;
 
-pub fn ai(rng: &mut Rng, _: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
     const SLIMEDS: &'static [CardBody] = &[CardBody::Slimed];
-    const ENEMY_TABLE: &'static [StateEntry] = make_black_slime_table!(8, 1, SLIMEDS);
-    return weighted_transition(rng, state, ENEMY_TABLE);
+    const ENEMY_TABLE: &'static [StateEntry] =
+        make_black_slime_table!(8, 1, SLIMEDS, &[0, 3], &[3, 7]);
+    const A2_TABLE: &'static [StateEntry] =
+        make_black_slime_table!(10, 1, SLIMEDS, &[0, 3], &[3, 7]);
+    //Ascension 17 slimes never debuff twice in a row.
+    const A17_TABLE: &'static [StateEntry] = make_black_slime_table!(10, 1, SLIMEDS, &[0], &[1]);
+    let table = match fight.ascension {
+        17.. => A17_TABLE,
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    return weighted_transition(rng, state, table);
 }
-pub fn generate_med_black_slime(rng: &mut Rng) -> Enemy {
-    let hp = uniform_inclusive(rng, 28, 32);
+pub fn generate_med_black_slime(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 29, 34)
+    } else {
+        uniform_inclusive(rng, 28, 32)
+    };
 
     let starting_state = rng.sample_weighted(&[3, 0, 7, 0]);
     Enemy {
         name: EnemyName::MedBlackSlime,
         ai_state: starting_state as u32,
+        last_move: None,
         hp,
         max_hp: hp,
         buffs: EnemyBuffs::default(),
@@ -44,23 +44,94 @@ macro_rules! make_green_slime_table {
 }
 pub(crate) use make_green_slime_table;
 
-pub fn ai(rng: &mut Rng, _: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+//Ascension 17 slimes never debuff twice in a row and the attack can be used twice like spitting.
+macro_rules! make_a17_green_slime_table {
+    ($attack: expr, $attack_big: expr, $weak_amount: expr, $slimeds: expr, $weights: expr, $after_two_spits: expr, $after_two_attacks: expr, $after_debuff: expr) => {
+        &[
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack($attack),
+                    EnemyAction::AddToDiscard($slimeds),
+                ],
+                new_states: &[1, 2, 4],
+                weights: $weights,
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack($attack),
+                    EnemyAction::AddToDiscard($slimeds),
+                ],
+                new_states: &[2, 4],
+                weights: $after_two_spits,
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($attack_big)],
+                new_states: &[0, 3, 4],
+                weights: $weights,
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($attack_big)],
+                new_states: &[0, 4],
+                weights: $after_two_attacks,
+            },
+            StateEntry {
+                actions: &[EnemyAction::Debuff(Debuff::Weak($weak_amount))],
+                new_states: &[0, 2],
+                weights: $after_debuff,
+            },
+        ]
+    };
+}
+pub(crate) use make_a17_green_slime_table;
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
     // States are
     // 0) Attack + Slimed inserted
     // 1) Attack + Slimed inserted (second)
     // 2) Attack
     // 3) Debuff
     // 4) Debuff (second)
+    // From Ascension 17 they are
+    // 0) Attack + Slimed inserted
+    // 1) Attack + Slimed inserted (second)
+    // 2) Attack
+    // 3) Attack (second)
+    // 4) Debuff
     const SLIMEDS: &'static [CardBody] = &[CardBody::Slimed];
     const ENEMY_TABLE: &'static [StateEntry] = make_green_slime_table!(7, 10, 1, SLIMEDS);
-    return weighted_transition(rng, state, ENEMY_TABLE);
+    const A2_TABLE: &'static [StateEntry] = make_green_slime_table!(8, 12, 1, SLIMEDS);
+    const A17_TABLE: &'static [StateEntry] = make_a17_green_slime_table!(
+        8,
+        12,
+        1,
+        SLIMEDS,
+        &[40, 40, 20],
+        &[60, 40],
+        &[60, 40],
+        &[48, 52]
+    );
+    let table = match fight.ascension {
+        17.. => A17_TABLE,
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    return weighted_transition(rng, state, table);
 }
-pub fn generate_med_green_slime(rng: &mut Rng) -> Enemy {
-    let hp = uniform_inclusive(rng, 28, 32);
-    let starting_state = rng.sample_weighted(&[3, 0, 4, 3, 0]);
+pub fn generate_med_green_slime(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 29, 34)
+    } else {
+        uniform_inclusive(rng, 28, 32)
+    };
+    let starting_state = if ascension >= 17 {
+        rng.sample_weighted(&[40, 0, 40, 0, 20])
+    } else {
+        rng.sample_weighted(&[3, 0, 4, 3, 0])
+    };
     Enemy {
         name: EnemyName::MedGreenSlime,
         ai_state: starting_state as u32,
+        last_move: None,
         hp,
         max_hp: hp,
         buffs: EnemyBuffs::default(),
@@ -0,0 +1,76 @@
+use crate::{
+    enemies::{StateEntry, uniform_inclusive, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+macro_rules! make_mugger_table {
+    ($swipe: expr, $big_swipe: expr, $gold: expr, $smoke_bomb: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::Attack($swipe), EnemyAction::StealGold($gold)],
+                new_states: &[1],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($swipe), EnemyAction::StealGold($gold)],
+                new_states: &[2, 3],
+                weights: &[1, 1],
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack($big_swipe),
+                    EnemyAction::StealGold($gold),
+                ],
+                new_states: &[3],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Block($smoke_bomb)],
+                new_states: &[4],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Escape],
+                new_states: &[5],
+                weights: &[1],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0) Mug
+    // 1) Mug, then Lunge or Smoke Bomb
+    // 2) Lunge
+    // 3) Smoke Bomb
+    // 4) Escape
+    const ENEMY_TABLE: &[StateEntry] = make_mugger_table!(10, 16, 15, 11);
+    const A2_TABLE: &[StateEntry] = make_mugger_table!(11, 18, 15, 11);
+    const A17_TABLE: &[StateEntry] = make_mugger_table!(11, 18, 20, 17);
+    let table = match fight.ascension {
+        17.. => A17_TABLE,
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    weighted_transition(rng, state, table)
+}
+
+pub fn generate_mugger(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 50, 54)
+    } else {
+        uniform_inclusive(rng, 48, 52)
+    };
+    Enemy {
+        name: EnemyName::Mugger,
+        ai_state: 0,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs: EnemyBuffs::default(),
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
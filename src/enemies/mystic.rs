@@ -0,0 +1,74 @@
+use crate::{
+    card::{Buff, Debuff},
+    enemies::uniform_inclusive,
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+const ATTACK: &[EnemyAction] = &[
+    EnemyAction::Attack(8),
+    EnemyAction::Debuff(Debuff::Frail(2)),
+];
+const A2_ATTACK: &[EnemyAction] = &[
+    EnemyAction::Attack(9),
+    EnemyAction::Debuff(Debuff::Frail(2)),
+];
+const HEAL: &[EnemyAction] = &[EnemyAction::HealAll(16)];
+const A17_HEAL: &[EnemyAction] = &[EnemyAction::HealAll(20)];
+const BUFF: &[EnemyAction] = &[EnemyAction::BuffAll(Buff::Strength(2))];
+const A2_BUFF: &[EnemyAction] = &[EnemyAction::BuffAll(Buff::Strength(3))];
+const A17_BUFF: &[EnemyAction] = &[EnemyAction::BuffAll(Buff::Strength(4))];
+
+//The Mystic heals whenever its allies are missing enough HP, so it can't use a fixed table.
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0) Attack
+    // 1) Attack, second in a row
+    // 2) Heal
+    // 3) Heal, second in a row
+    // 4) Buff
+    let mut missing_hp = 0;
+    for i in fight.enemies.indicies() {
+        let enemy = &fight.enemies[i];
+        missing_hp += enemy.max_hp - enemy.hp;
+    }
+    let new_state = if missing_hp > 15 && state != 3 {
+        if state == 2 { 3 } else { 2 }
+    } else if rng.sample_weighted(&[40, 60]) == 1 && state != 4 {
+        4
+    } else if state != 1 {
+        if state == 0 { 1 } else { 0 }
+    } else {
+        4
+    };
+    let (attack, heal, buff) = match fight.ascension {
+        17.. => (A2_ATTACK, A17_HEAL, A17_BUFF),
+        2.. => (A2_ATTACK, HEAL, A2_BUFF),
+        _ => (ATTACK, HEAL, BUFF),
+    };
+    let actions = match state {
+        0 | 1 => attack,
+        2 | 3 => heal,
+        4 => buff,
+        _ => panic!("Invalid state {}", state),
+    };
+    (new_state, actions)
+}
+
+pub fn generate_mystic(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 50, 58)
+    } else {
+        uniform_inclusive(rng, 48, 56)
+    };
+    Enemy {
+        name: EnemyName::Mystic,
+        ai_state: rng.sample_weighted(&[40, 0, 0, 0, 60]) as u32,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs: EnemyBuffs::default(),
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{
+    card::CardBody,
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+//Nemesis can't Scythe again for 2 turns after using it.
+//Bits 0-1 are the current move, bits 2-3 are the previous move and bits 4-5 are the
+//Scythe cooldown.
+const TRI_ATTACK: u32 = 0;
+const SCYTHE: u32 = 1;
+const BURN: u32 = 2;
+const NO_MOVE: u32 = 3;
+
+macro_rules! make_nemesis_actions {
+    ($tri_attack: expr, $burns: expr) => {
+        &[
+            &[
+                EnemyAction::Attack($tri_attack),
+                EnemyAction::Attack($tri_attack),
+                EnemyAction::Attack($tri_attack),
+            ],
+            &[EnemyAction::Attack(45)],
+            &[EnemyAction::AddToDiscard($burns)],
+        ]
+    };
+}
+
+const ACTIONS: &[&[EnemyAction]] = make_nemesis_actions!(6, &[CardBody::Burn; 3]);
+const A3_ACTIONS: &[&[EnemyAction]] = make_nemesis_actions!(7, &[CardBody::Burn; 3]);
+const A18_ACTIONS: &[&[EnemyAction]] = make_nemesis_actions!(7, &[CardBody::Burn; 5]);
+
+fn next_state(rng: &mut Rng, state: u32) -> u32 {
+    let last = state & 3;
+    let before = (state >> 2) & 3;
+    let mut cooldown = (state >> 4).saturating_sub(1);
+    let twice = |mv: u32| last == mv && before == mv;
+    let num = rng.sample(100);
+    let next = if last == NO_MOVE {
+        if num < 50 { TRI_ATTACK } else { BURN }
+    } else if num < 30 {
+        if last != SCYTHE && cooldown == 0 {
+            SCYTHE
+        } else if rng.sample(2) == 0 {
+            if !twice(TRI_ATTACK) { TRI_ATTACK } else { BURN }
+        } else if last != BURN {
+            BURN
+        } else {
+            TRI_ATTACK
+        }
+    } else if num < 65 {
+        if !twice(TRI_ATTACK) {
+            TRI_ATTACK
+        } else if rng.sample(2) == 0 && cooldown == 0 {
+            SCYTHE
+        } else {
+            BURN
+        }
+    } else if last != BURN {
+        BURN
+    } else if rng.sample(2) == 0 && cooldown == 0 {
+        SCYTHE
+    } else {
+        TRI_ATTACK
+    };
+    if next == SCYTHE {
+        cooldown = 2;
+    }
+    next | (last << 2) | (cooldown << 4)
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    let actions = match fight.ascension {
+        18.. => A18_ACTIONS,
+        3.. => A3_ACTIONS,
+        _ => ACTIONS,
+    };
+    (next_state(rng, state), actions[(state & 3) as usize])
+}
+
+pub fn generate_nemesis(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 8 { 200 } else { 185 };
+    let buffs = EnemyBuffs {
+        phasing: true,
+        ..Default::default()
+    };
+    Enemy {
+        name: EnemyName::Nemesis,
+        ai_state: next_state(rng, NO_MOVE | (NO_MOVE << 2)),
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{
+    card::CardBody,
+    enemies::{StateEntry, uniform_inclusive, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+const LASER: &[EnemyAction] = &[
+    EnemyAction::Attack(10),
+    EnemyAction::AddToDiscard(&[CardBody::Burn]),
+    EnemyAction::ShuffleIntoDraw(&[CardBody::Burn]),
+];
+const A2_LASER: &[EnemyAction] = &[
+    EnemyAction::Attack(11),
+    EnemyAction::AddToDiscard(&[CardBody::Burn]),
+    EnemyAction::ShuffleIntoDraw(&[CardBody::Burn]),
+];
+
+macro_rules! make_orb_walker_table {
+    ($laser: expr, $claw: expr) => {
+        &[
+            StateEntry {
+                actions: $laser,
+                new_states: &[1, 2],
+                weights: &[60, 40],
+            },
+            StateEntry {
+                actions: $laser,
+                new_states: &[2],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($claw)],
+                new_states: &[0, 3],
+                weights: &[60, 40],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($claw)],
+                new_states: &[0],
+                weights: &[1],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0) Laser
+    // 1) Laser, second in a row
+    // 2) Claw
+    // 3) Claw, second in a row
+    const ENEMY_TABLE: &[StateEntry] = make_orb_walker_table!(LASER, 15);
+    const A2_TABLE: &[StateEntry] = make_orb_walker_table!(A2_LASER, 16);
+    let table = match fight.ascension {
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    weighted_transition(rng, state, table)
+}
+
+pub fn generate_orb_walker(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 92, 102)
+    } else {
+        uniform_inclusive(rng, 90, 96)
+    };
+    let buffs = EnemyBuffs {
+        //Orb Walkers gain 3 strength at the end of each turn, 5 from Ascension 17.
+        ritual: if ascension >= 17 { 5 } else { 3 },
+        ..Default::default()
+    };
+    Enemy {
+        name: EnemyName::OrbWalker,
+        ai_state: rng.sample_weighted(&[60, 0, 40]) as u32,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
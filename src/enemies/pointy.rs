@@ -0,0 +1,40 @@
+use crate::{
+    enemies::{StateEntry, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+macro_rules! make_pointy_table {
+    ($attack: expr) => {
+        &[StateEntry {
+            actions: &[EnemyAction::Attack($attack), EnemyAction::Attack($attack)],
+            new_states: &[0],
+            weights: &[1],
+        }]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    const ENEMY_TABLE: &[StateEntry] = make_pointy_table!(5);
+    const A2_TABLE: &[StateEntry] = make_pointy_table!(6);
+    let table = if fight.ascension >= 2 {
+        A2_TABLE
+    } else {
+        ENEMY_TABLE
+    };
+    weighted_transition(rng, state, table)
+}
+
+pub fn generate_pointy(ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 { 34 } else { 30 };
+    Enemy {
+        name: EnemyName::Pointy,
+        ai_state: 0,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs: EnemyBuffs::default(),
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
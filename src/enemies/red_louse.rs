@@ -5,45 +5,67 @@ use crate::{
     rng::Rng,
 };
 
-pub fn ai(rng: &mut Rng, _: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+macro_rules! make_red_louse_table {
+    ($strength: expr, $buff_states: expr, $buff_weights: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::Attack(5)],
+                new_states: &[1, 2],
+                weights: &[3, 1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack(5)],
+                new_states: &[2],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Buff(Buff::Strength($strength))],
+                new_states: $buff_states,
+                weights: $buff_weights,
+            },
+            StateEntry {
+                actions: &[EnemyAction::Buff(Buff::Strength($strength))],
+                new_states: &[0],
+                weights: &[1],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
     // States are
     // 0) Playing Attack
     // 1) Playing Attack, same move prior turn.
     // 2) Playing Buff.
     // 3) Playing Buff, same move prior turn.
-    const ENEMY_TABLE: &'static [StateEntry] = &[
-        StateEntry {
-            actions: &[EnemyAction::Attack(5)],
-            new_states: &[1, 2],
-            weights: &[3, 1],
-        },
-        StateEntry {
-            actions: &[EnemyAction::Attack(5)],
-            new_states: &[2],
-            weights: &[1],
-        },
-        StateEntry {
-            actions: &[EnemyAction::Buff(Buff::Strength(3))],
-            new_states: &[0, 3],
-            weights: &[3, 1],
-        },
-        StateEntry {
-            actions: &[EnemyAction::Buff(Buff::Strength(3))],
-            new_states: &[0],
-            weights: &[1],
-        },
-    ];
-    return weighted_transition(rng, state, ENEMY_TABLE);
+    const ENEMY_TABLE: &'static [StateEntry] = make_red_louse_table!(3, &[0, 3], &[3, 1]);
+    //Ascension 17 louses never buff twice in a row.
+    const A17_TABLE: &'static [StateEntry] = make_red_louse_table!(4, &[0], &[1]);
+    let table = if fight.ascension >= 17 {
+        A17_TABLE
+    } else {
+        ENEMY_TABLE
+    };
+    return weighted_transition(rng, state, table);
 }
-pub fn generate_red_louse(rng: &mut Rng) -> Enemy {
-    let hp = uniform_inclusive(rng, 10, 15);
+pub fn generate_red_louse(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 11, 16)
+    } else {
+        uniform_inclusive(rng, 10, 15)
+    };
     let mut buffs = EnemyBuffs::default();
-    buffs.curl_up = uniform_inclusive(rng, 3, 7);
-    //Red louses attack damage is between 5 and 7 chosen at the start of combat.
-    buffs.implicit_strength = uniform_inclusive(rng, 0, 2);
+    buffs.curl_up = match ascension {
+        17.. => uniform_inclusive(rng, 9, 12),
+        7.. => uniform_inclusive(rng, 4, 8),
+        _ => uniform_inclusive(rng, 3, 7),
+    };
+    //Louses attack damage is between 5 and 7 chosen at the start of combat, 6 and 8 from Ascension 2.
+    buffs.implicit_strength = uniform_inclusive(rng, 0, 2) + if ascension >= 2 { 1 } else { 0 };
     Enemy {
         name: EnemyName::RedLouse,
         ai_state: rng.sample_weighted(&[75, 0, 25, 0]) as u32,
+        last_move: None,
         hp,
         max_hp: hp,
         buffs: buffs,
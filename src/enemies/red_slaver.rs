@@ -5,60 +5,79 @@ use crate::{
     rng::Rng,
 };
 
-pub fn ai(rng: &mut Rng, _: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
-    const ENEMY_TABLE: &'static [StateEntry] = &[
-        StateEntry {
-            actions: &[EnemyAction::Attack(13)],
-            new_states: &[1, 3],
-            weights: &[3, 1],
-        },
-        StateEntry {
-            actions: &[EnemyAction::Attack(13)],
-            new_states: &[2, 3],
-            weights: &[3, 1],
-        },
-        StateEntry {
-            actions: &[
-                EnemyAction::Attack(8),
-                EnemyAction::Debuff(Debuff::Vulnerable(1)),
-            ],
-            new_states: &[0, 3],
-            weights: &[3, 1],
-        },
-        StateEntry {
-            actions: &[EnemyAction::Debuff(Debuff::Entangled)],
-            new_states: &[4, 6],
-            weights: &[55, 45],
-        },
-        StateEntry {
-            actions: &[
-                EnemyAction::Attack(8),
-                EnemyAction::Debuff(Debuff::Vulnerable(1)),
-            ],
-            new_states: &[5, 6],
-            weights: &[55, 45],
-        },
-        StateEntry {
-            actions: &[
-                EnemyAction::Attack(8),
-                EnemyAction::Debuff(Debuff::Vulnerable(1)),
-            ],
-            new_states: &[6],
-            weights: &[1],
-        },
-        StateEntry {
-            actions: &[EnemyAction::Attack(13)],
-            new_states: &[4, 6],
-            weights: &[55, 45],
-        },
-    ];
-    return weighted_transition(rng, state, ENEMY_TABLE);
+macro_rules! make_red_slaver_table {
+    ($stab: expr, $scrape: expr, $vulnerable: expr, $scrape_states: expr, $scrape_weights: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::Attack($stab)],
+                new_states: &[1, 3],
+                weights: &[3, 1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($stab)],
+                new_states: &[2, 3],
+                weights: &[3, 1],
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack($scrape),
+                    EnemyAction::Debuff(Debuff::Vulnerable($vulnerable)),
+                ],
+                new_states: &[0, 3],
+                weights: &[3, 1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Debuff(Debuff::Entangled)],
+                new_states: &[4, 6],
+                weights: &[55, 45],
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack($scrape),
+                    EnemyAction::Debuff(Debuff::Vulnerable($vulnerable)),
+                ],
+                new_states: $scrape_states,
+                weights: $scrape_weights,
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack($scrape),
+                    EnemyAction::Debuff(Debuff::Vulnerable($vulnerable)),
+                ],
+                new_states: &[6],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($stab)],
+                new_states: &[4, 6],
+                weights: &[55, 45],
+            },
+        ]
+    };
 }
-pub fn generate_red_slaver(rng: &mut Rng) -> Enemy {
-    let hp = uniform_inclusive(rng, 46, 50);
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    const ENEMY_TABLE: &'static [StateEntry] = make_red_slaver_table!(13, 8, 1, &[5, 6], &[55, 45]);
+    const A2_TABLE: &'static [StateEntry] = make_red_slaver_table!(14, 9, 1, &[5, 6], &[55, 45]);
+    //Ascension 17 slavers never Scrape twice in a row.
+    const A17_TABLE: &'static [StateEntry] = make_red_slaver_table!(14, 9, 2, &[6], &[1]);
+    let table = match fight.ascension {
+        17.. => A17_TABLE,
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    return weighted_transition(rng, state, table);
+}
+pub fn generate_red_slaver(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 48, 52)
+    } else {
+        uniform_inclusive(rng, 46, 50)
+    };
     Enemy {
         name: EnemyName::RedSlaver,
         ai_state: 0,
+        last_move: None,
         hp,
         max_hp: hp,
         buffs: EnemyBuffs::default(),
@@ -0,0 +1,84 @@
+use crate::{
+    card::Debuff,
+    enemies::uniform_inclusive,
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    game::Game,
+    rng::Rng,
+};
+
+//Reptomancer can only summon Daggers if there is room for them.
+//Bits 0-1 are the current move and bits 2-3 are the previous move.
+const SNAKE_STRIKE: u32 = 0;
+const SUMMON: u32 = 1;
+const BIG_BITE: u32 = 2;
+const NO_MOVE: u32 = 3;
+
+macro_rules! make_reptomancer_actions {
+    ($snake_strike: expr, $big_bite: expr) => {
+        &[
+            &[
+                EnemyAction::Attack($snake_strike),
+                EnemyAction::Attack($snake_strike),
+                EnemyAction::Debuff(Debuff::Weak(1)),
+            ],
+            &[EnemyAction::Summon],
+            &[EnemyAction::Attack($big_bite)],
+        ]
+    };
+}
+
+const ACTIONS: &[&[EnemyAction]] = make_reptomancer_actions!(13, 30);
+const A3_ACTIONS: &[&[EnemyAction]] = make_reptomancer_actions!(16, 34);
+
+fn next_state(rng: &mut Rng, fight: &Fight, state: u32) -> u32 {
+    let last = state & 3;
+    let before = (state >> 2) & 3;
+    let can_summon = fight.enemies.len() < Game::MAX_ENEMIES;
+    let next = if last == NO_MOVE {
+        SUMMON
+    } else {
+        loop {
+            let num = rng.sample(100);
+            if num < 33 {
+                if last != SNAKE_STRIKE {
+                    break SNAKE_STRIKE;
+                }
+            } else if num < 66 {
+                if (last != SUMMON || before != SUMMON) && can_summon {
+                    break SUMMON;
+                }
+                break SNAKE_STRIKE;
+            } else if last != BIG_BITE {
+                break BIG_BITE;
+            }
+        }
+    };
+    next | (last << 2)
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    let actions = if fight.ascension >= 3 {
+        A3_ACTIONS
+    } else {
+        ACTIONS
+    };
+    (next_state(rng, fight, state), actions[(state & 3) as usize])
+}
+
+pub fn generate_reptomancer(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 8 {
+        uniform_inclusive(rng, 190, 200)
+    } else {
+        uniform_inclusive(rng, 180, 190)
+    };
+    Enemy {
+        name: EnemyName::Reptomancer,
+        ai_state: next_state(rng, &Fight::default(), NO_MOVE | (NO_MOVE << 2)),
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs: EnemyBuffs::default(),
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{
+    card::CardBody,
+    enemies::{StateEntry, uniform_inclusive, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+macro_rules! make_repulsor_table {
+    ($bash: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::ShuffleIntoDraw(&[
+                    CardBody::Dazed,
+                    CardBody::Dazed,
+                ])],
+                new_states: &[0, 1],
+                weights: &[80, 20],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($bash)],
+                new_states: &[0],
+                weights: &[1],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0) Repulse
+    // 1) Bash
+    const ENEMY_TABLE: &[StateEntry] = make_repulsor_table!(11);
+    const A2_TABLE: &[StateEntry] = make_repulsor_table!(13);
+    let table = match fight.ascension {
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    weighted_transition(rng, state, table)
+}
+
+pub fn generate_repulsor(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 31, 38)
+    } else {
+        uniform_inclusive(rng, 29, 35)
+    };
+    Enemy {
+        name: EnemyName::Repulsor,
+        ai_state: rng.sample_weighted(&[80, 20]) as u32,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs: EnemyBuffs::default(),
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{
+    card::Debuff,
+    enemies::{StateEntry, uniform_inclusive, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+macro_rules! make_romeo_table {
+    ($agonizing_slash: expr, $weak: expr, $cross_slash: expr) => {
+        &[
+            StateEntry {
+                actions: &[],
+                new_states: &[1],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack($agonizing_slash),
+                    EnemyAction::Debuff(Debuff::Weak($weak)),
+                ],
+                new_states: &[2],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($cross_slash)],
+                new_states: &[3],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($cross_slash)],
+                new_states: &[1],
+                weights: &[1],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0) Mock
+    // 1) Agonizing Slash
+    // 2) Cross Slash
+    // 3) Cross Slash, second in a row
+    const ENEMY_TABLE: &[StateEntry] = make_romeo_table!(10, 2, 15);
+    const A2_TABLE: &[StateEntry] = make_romeo_table!(12, 2, 17);
+    const A17_TABLE: &[StateEntry] = make_romeo_table!(12, 3, 17);
+    let table = match fight.ascension {
+        17.. => A17_TABLE,
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    weighted_transition(rng, state, table)
+}
+
+pub fn generate_romeo(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 37, 41)
+    } else {
+        uniform_inclusive(rng, 35, 39)
+    };
+    Enemy {
+        name: EnemyName::Romeo,
+        ai_state: 0,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs: EnemyBuffs::default(),
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
@@ -5,32 +5,53 @@ use crate::{
     rng::Rng,
 };
 
-pub fn ai(rng: &mut Rng, _: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
-    const ENEMY_TABLE: &'static [StateEntry] = &[
-        StateEntry {
-            actions: &[EnemyAction::Attack(9)],
-            new_states: &[1],
-            weights: &[1],
-        },
-        StateEntry {
-            actions: &[EnemyAction::AddToDiscard(&[
-                CardBody::Dazed,
-                CardBody::Dazed,
-            ])],
-            new_states: &[0],
-            weights: &[1],
-        },
-    ];
-    return weighted_transition(rng, state, ENEMY_TABLE);
+macro_rules! make_sentry_table {
+    ($beam: expr, $dazeds: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::Attack($beam)],
+                new_states: &[1],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::AddToDiscard($dazeds)],
+                new_states: &[0],
+                weights: &[1],
+            },
+        ]
+    };
 }
-pub fn generate_sentry(rng: &mut Rng, start_state: u32) -> Enemy {
-    let hp = uniform_inclusive(rng, 38, 42);
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    const DAZEDS: &'static [CardBody] = &[CardBody::Dazed, CardBody::Dazed];
+    const A18_DAZEDS: &'static [CardBody] = &[CardBody::Dazed, CardBody::Dazed, CardBody::Dazed];
+    const ENEMY_TABLE: &'static [StateEntry] = make_sentry_table!(9, DAZEDS);
+    const A3_TABLE: &'static [StateEntry] = make_sentry_table!(10, DAZEDS);
+    const A18_TABLE: &'static [StateEntry] = make_sentry_table!(10, A18_DAZEDS);
+    let table = match fight.ascension {
+        18.. => A18_TABLE,
+        3.. => A3_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    return weighted_transition(rng, state, table);
+}
+pub fn generate_sentry(rng: &mut Rng, start_state: u32, ascension: i32) -> Enemy {
+    let hp = if ascension >= 8 {
+        uniform_inclusive(rng, 39, 45)
+    } else {
+        uniform_inclusive(rng, 38, 42)
+    };
+    let buffs = EnemyBuffs {
+        artifact: 1,
+        ..Default::default()
+    };
     Enemy {
         name: EnemyName::Sentry,
         ai_state: start_state,
+        last_move: None,
         hp,
         max_hp: hp,
-        buffs: EnemyBuffs::default(),
+        buffs,
         debuffs: EnemyDebuffs::default(),
         block: 0,
     }
@@ -0,0 +1,99 @@
+use crate::{
+    card::Debuff,
+    enemies::{StateEntry, uniform_inclusive, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+//Shelled Parasite is stunned when its Plated Armor is broken.
+pub const STUNNED_STATE: u32 = 5;
+
+macro_rules! make_shelled_parasite_table {
+    ($double_strike: expr, $suck: expr, $fell: expr) => {
+        &[
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack($double_strike),
+                    EnemyAction::Attack($double_strike),
+                ],
+                new_states: &[4, 1, 2],
+                weights: &[2, 4, 4],
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack($double_strike),
+                    EnemyAction::Attack($double_strike),
+                ],
+                new_states: &[4, 2],
+                weights: &[1, 4],
+            },
+            StateEntry {
+                actions: &[EnemyAction::LifestealAttack($suck)],
+                new_states: &[4, 0, 3],
+                weights: &[2, 4, 4],
+            },
+            StateEntry {
+                actions: &[EnemyAction::LifestealAttack($suck)],
+                new_states: &[4, 0],
+                weights: &[1, 4],
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack($fell),
+                    EnemyAction::Debuff(Debuff::Frail(2)),
+                ],
+                new_states: &[0, 2],
+                weights: &[1, 1],
+            },
+            StateEntry {
+                actions: &[],
+                new_states: &[4, 0, 2],
+                weights: &[2, 4, 4],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0) Double Strike
+    // 1) Double Strike, second in a row
+    // 2) Suck
+    // 3) Suck, second in a row
+    // 4) Fell
+    // 5) Stunned
+    const ENEMY_TABLE: &[StateEntry] = make_shelled_parasite_table!(6, 10, 18);
+    const A2_TABLE: &[StateEntry] = make_shelled_parasite_table!(7, 12, 21);
+    let table = match fight.ascension {
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    weighted_transition(rng, state, table)
+}
+
+pub fn generate_shelled_parasite(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 70, 75)
+    } else {
+        uniform_inclusive(rng, 68, 72)
+    };
+    let buffs = EnemyBuffs {
+        plated_armor: 14,
+        ..Default::default()
+    };
+    Enemy {
+        name: EnemyName::ShelledParasite,
+        //Ascension 17 parasites open with Fell.
+        ai_state: if ascension >= 17 {
+            4
+        } else {
+            rng.sample_weighted(&[1, 0, 1]) as u32
+        },
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 14,
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{
+    card::CardBody,
+    enemies::{StateEntry, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+macro_rules! make_slime_boss_table {
+    ($slimeds: expr, $slam: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::AddToDiscard($slimeds)],
+                new_states: &[1],
+                weights: &[1],
+            },
+            //Preparing
+            StateEntry {
+                actions: &[],
+                new_states: &[2],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($slam)],
+                new_states: &[0],
+                weights: &[1],
+            },
+        ]
+    };
+}
+
+pub fn ai(
+    rng: &mut Rng,
+    fight: &Fight,
+    enemy: &Enemy,
+    state: u32,
+) -> (u32, &'static [EnemyAction]) {
+    const SLIMEDS: &[CardBody] = &[CardBody::Slimed; 3];
+    const A19_SLIMEDS: &[CardBody] = &[CardBody::Slimed; 5];
+    const ENEMY_TABLE: &[StateEntry] = make_slime_boss_table!(SLIMEDS, 35);
+    const A4_TABLE: &[StateEntry] = make_slime_boss_table!(SLIMEDS, 38);
+    const A19_TABLE: &[StateEntry] = make_slime_boss_table!(A19_SLIMEDS, 38);
+    if enemy.hp * 2 <= enemy.max_hp {
+        return (0, &[EnemyAction::Split]);
+    }
+    let table = match fight.ascension {
+        19.. => A19_TABLE,
+        4.. => A4_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    weighted_transition(rng, state, table)
+}
+pub fn generate_slime_boss(ascension: i32) -> Enemy {
+    let hp = if ascension >= 9 { 150 } else { 140 };
+    Enemy {
+        name: EnemyName::SlimeBoss,
+        ai_state: 0,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs: EnemyBuffs::default(),
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
     rng::Rng,
 };
 
-pub fn ai(rng: &mut Rng, _: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
     // States are
     // 0) Playing Attack
     const ENEMY_TABLE: &'static [StateEntry] = &[StateEntry {
@@ -12,13 +12,28 @@ pub fn ai(rng: &mut Rng, _: &Fight, _: &Enemy, state: u32) -> (u32, &'static [En
         new_states: &[0],
         weights: &[1],
     }];
-    return weighted_transition(rng, state, ENEMY_TABLE);
+    const A2_TABLE: &'static [StateEntry] = &[StateEntry {
+        actions: &[EnemyAction::Attack(6)],
+        new_states: &[0],
+        weights: &[1],
+    }];
+    let table = if fight.ascension >= 2 {
+        A2_TABLE
+    } else {
+        ENEMY_TABLE
+    };
+    return weighted_transition(rng, state, table);
 }
-pub fn generate_small_black_slime(rng: &mut Rng) -> Enemy {
-    let hp = uniform_inclusive(rng, 10, 14);
+pub fn generate_small_black_slime(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 11, 15)
+    } else {
+        uniform_inclusive(rng, 10, 14)
+    };
     Enemy {
         name: EnemyName::SmallBlackSlime,
         ai_state: 0,
+        last_move: None,
         hp,
         max_hp: hp,
         buffs: EnemyBuffs::default(),
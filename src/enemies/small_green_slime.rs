@@ -5,29 +5,51 @@ use crate::{
     rng::Rng,
 };
 
-pub fn ai(rng: &mut Rng, _: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+macro_rules! make_small_green_slime_table {
+    ($attack: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::Attack($attack)],
+                new_states: &[1],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Debuff(Debuff::Weak(1))],
+                new_states: &[0],
+                weights: &[1],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
     // States are
     // 0) Playing Attack
     // 1) Debuff
-    const ENEMY_TABLE: &'static [StateEntry] = &[
-        StateEntry {
-            actions: &[EnemyAction::Attack(3)],
-            new_states: &[1],
-            weights: &[1],
-        },
-        StateEntry {
-            actions: &[EnemyAction::Debuff(Debuff::Weak(1))],
-            new_states: &[0],
-            weights: &[1],
-        },
-    ];
-    return weighted_transition(rng, state, ENEMY_TABLE);
+    const ENEMY_TABLE: &'static [StateEntry] = make_small_green_slime_table!(3);
+    const A2_TABLE: &'static [StateEntry] = make_small_green_slime_table!(4);
+    let table = if fight.ascension >= 2 {
+        A2_TABLE
+    } else {
+        ENEMY_TABLE
+    };
+    return weighted_transition(rng, state, table);
 }
-pub fn generate_small_green_slime(rng: &mut Rng) -> Enemy {
-    let hp = uniform_inclusive(rng, 8, 12);
+pub fn generate_small_green_slime(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 9, 13)
+    } else {
+        uniform_inclusive(rng, 8, 12)
+    };
     Enemy {
         name: EnemyName::SmallGreenSlime,
-        ai_state: uniform_inclusive(rng, 0, 1) as u32,
+        //Ascension 17 small slimes always open with their attack.
+        ai_state: if ascension >= 17 {
+            0
+        } else {
+            uniform_inclusive(rng, 0, 1) as u32
+        },
+        last_move: None,
         hp,
         max_hp: hp,
         buffs: EnemyBuffs::default(),
@@ -0,0 +1,88 @@
+use crate::{
+    card::Debuff,
+    enemies::{StateEntry, uniform_inclusive, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+macro_rules! make_snake_plant_table {
+    ($chomp: expr, $spores_state: expr) => {
+        &[
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack($chomp),
+                    EnemyAction::Attack($chomp),
+                    EnemyAction::Attack($chomp),
+                ],
+                new_states: &[1, 2],
+                weights: &[65, 35],
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack($chomp),
+                    EnemyAction::Attack($chomp),
+                    EnemyAction::Attack($chomp),
+                ],
+                new_states: &[2],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Debuff(Debuff::Frail(2)),
+                    EnemyAction::Debuff(Debuff::Weak(2)),
+                ],
+                new_states: &[$spores_state],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack($chomp),
+                    EnemyAction::Attack($chomp),
+                    EnemyAction::Attack($chomp),
+                ],
+                new_states: &[1],
+                weights: &[1],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0) Chomp
+    // 1) Chomp, second in a row
+    // 2) Enfeebling Spores
+    // 3) Chomp, which must be followed by another
+    const ENEMY_TABLE: &[StateEntry] = make_snake_plant_table!(7, 0);
+    const A2_TABLE: &[StateEntry] = make_snake_plant_table!(8, 0);
+    //Ascension 17 Snake Plants always Chomp twice after their spores.
+    const A17_TABLE: &[StateEntry] = make_snake_plant_table!(8, 3);
+    let table = match fight.ascension {
+        17.. => A17_TABLE,
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    weighted_transition(rng, state, table)
+}
+
+pub fn generate_snake_plant(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 78, 82)
+    } else {
+        uniform_inclusive(rng, 75, 79)
+    };
+    let buffs = EnemyBuffs {
+        malleable: 3,
+        ..Default::default()
+    };
+    Enemy {
+        name: EnemyName::SnakePlant,
+        ai_state: rng.sample_weighted(&[65, 0, 35]) as u32,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{
+    card::Debuff,
+    enemies::{StateEntry, uniform_inclusive, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+macro_rules! make_snecko_table {
+    ($tail_whip: expr, $bite: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::Debuff(Debuff::Confused)],
+                new_states: &[1, 2],
+                weights: &[4, 6],
+            },
+            StateEntry {
+                actions: $tail_whip,
+                new_states: &[1, 2],
+                weights: &[4, 6],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($bite)],
+                new_states: &[1, 3],
+                weights: &[4, 6],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($bite)],
+                new_states: &[1],
+                weights: &[1],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0) Perplexing Glare
+    // 1) Tail Whip
+    // 2) Bite
+    // 3) Bite, second in a row
+    const TAIL_WHIP: &[EnemyAction] = &[
+        EnemyAction::Attack(8),
+        EnemyAction::Debuff(Debuff::Vulnerable(2)),
+    ];
+    const A2_TAIL_WHIP: &[EnemyAction] = &[
+        EnemyAction::Attack(10),
+        EnemyAction::Debuff(Debuff::Vulnerable(2)),
+    ];
+    //Ascension 17 Tail Whips also weaken the player.
+    const A17_TAIL_WHIP: &[EnemyAction] = &[
+        EnemyAction::Attack(10),
+        EnemyAction::Debuff(Debuff::Vulnerable(2)),
+        EnemyAction::Debuff(Debuff::Weak(2)),
+    ];
+    const ENEMY_TABLE: &[StateEntry] = make_snecko_table!(TAIL_WHIP, 15);
+    const A2_TABLE: &[StateEntry] = make_snecko_table!(A2_TAIL_WHIP, 18);
+    const A17_TABLE: &[StateEntry] = make_snecko_table!(A17_TAIL_WHIP, 18);
+    let table = match fight.ascension {
+        17.. => A17_TABLE,
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    weighted_transition(rng, state, table)
+}
+
+pub fn generate_snecko(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 120, 125)
+    } else {
+        uniform_inclusive(rng, 114, 120)
+    };
+    Enemy {
+        name: EnemyName::Snecko,
+        ai_state: 0,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs: EnemyBuffs::default(),
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    card::Debuff,
+    enemies::{StateEntry, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+macro_rules! make_spheric_guardian_table {
+    ($activate: expr, $attack: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::Block($activate)],
+                new_states: &[1],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack($attack),
+                    EnemyAction::Debuff(Debuff::Frail(5)),
+                ],
+                new_states: &[2],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($attack), EnemyAction::Attack($attack)],
+                new_states: &[3],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Block(15), EnemyAction::Attack($attack)],
+                new_states: &[2],
+                weights: &[1],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0) Activate
+    // 1) Attack and Frail
+    // 2) Slam
+    // 3) Harden
+    const ENEMY_TABLE: &[StateEntry] = make_spheric_guardian_table!(25, 10);
+    const A2_TABLE: &[StateEntry] = make_spheric_guardian_table!(25, 11);
+    const A17_TABLE: &[StateEntry] = make_spheric_guardian_table!(35, 11);
+    let table = match fight.ascension {
+        17.. => A17_TABLE,
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    weighted_transition(rng, state, table)
+}
+
+pub fn generate_spheric_guardian(_ascension: i32) -> Enemy {
+    let hp = 20;
+    let buffs = EnemyBuffs {
+        barricade: true,
+        artifact: 3,
+        ..Default::default()
+    };
+    Enemy {
+        name: EnemyName::SphericGuardian,
+        ai_state: 0,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 40,
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{
+    card::Buff,
+    enemies::uniform_inclusive,
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+//Spikers stop buffing their Thorns after doing it 6 times.
+//Bit 0 is the current move and bits 1-3 count how many times Spike was used.
+const CUT: u32 = 0;
+const SPIKE: u32 = 1;
+
+const ACTIONS: &[&[EnemyAction]] = &[
+    &[EnemyAction::Attack(7)],
+    &[EnemyAction::Buff(Buff::Thorns(2))],
+];
+const A2_ACTIONS: &[&[EnemyAction]] = &[
+    &[EnemyAction::Attack(9)],
+    &[EnemyAction::Buff(Buff::Thorns(2))],
+];
+
+fn next_state(rng: &mut Rng, state: u32) -> u32 {
+    let last = state & 1;
+    let spikes = state >> 1;
+    let next = if spikes > 5 || (rng.sample(2) == 0 && last != CUT) {
+        CUT
+    } else {
+        SPIKE
+    };
+    next | ((spikes + next) << 1)
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    let actions = if fight.ascension >= 2 {
+        A2_ACTIONS
+    } else {
+        ACTIONS
+    };
+    (next_state(rng, state), actions[(state & 1) as usize])
+}
+
+pub fn generate_spiker(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 {
+        uniform_inclusive(rng, 44, 60)
+    } else {
+        uniform_inclusive(rng, 42, 56)
+    };
+    let buffs = EnemyBuffs {
+        thorns: match ascension {
+            17.. => 7,
+            2.. => 4,
+            _ => 3,
+        },
+        ..Default::default()
+    };
+    let first = rng.sample(2) as u32;
+    Enemy {
+        name: EnemyName::Spiker,
+        ai_state: first | (first << 1),
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
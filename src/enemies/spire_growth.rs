@@ -0,0 +1,72 @@
+use crate::{
+    card::Debuff,
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+//Spire Growth only Constricts if the player isn't already Constricted.
+//Bits 0-1 are the current move and bits 2-3 are the previous move.
+const TACKLE: u32 = 0;
+const SMASH: u32 = 1;
+const CONSTRICT: u32 = 2;
+const NO_MOVE: u32 = 3;
+
+macro_rules! make_spire_growth_actions {
+    ($tackle: expr, $smash: expr, $constrict: expr) => {
+        &[
+            &[EnemyAction::Attack($tackle)],
+            &[EnemyAction::Attack($smash)],
+            &[EnemyAction::Debuff(Debuff::Constricted($constrict))],
+        ]
+    };
+}
+
+const ACTIONS: &[&[EnemyAction]] = make_spire_growth_actions!(16, 22, 10);
+const A2_ACTIONS: &[&[EnemyAction]] = make_spire_growth_actions!(18, 25, 10);
+const A17_ACTIONS: &[&[EnemyAction]] = make_spire_growth_actions!(18, 25, 12);
+
+fn next_state(rng: &mut Rng, fight: &Fight, state: u32) -> u32 {
+    let last = state & 3;
+    let before = (state >> 2) & 3;
+    let can_constrict = fight.player_debuffs.constricted == 0 && last != CONSTRICT;
+    //Ascension 17 Spire Growths Constrict whenever they can.
+    let next = if fight.ascension >= 17 && can_constrict {
+        CONSTRICT
+    } else if rng.sample(100) < 50 && (last != TACKLE || before != TACKLE) {
+        TACKLE
+    } else if can_constrict {
+        CONSTRICT
+    } else if last != SMASH || before != SMASH {
+        SMASH
+    } else {
+        TACKLE
+    };
+    next | (last << 2)
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    let actions = match fight.ascension {
+        17.. => A17_ACTIONS,
+        2.. => A2_ACTIONS,
+        _ => ACTIONS,
+    };
+    (next_state(rng, fight, state), actions[(state & 3) as usize])
+}
+
+pub fn generate_spire_growth(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 { 190 } else { 170 };
+    let fight = Fight {
+        ascension,
+        ..Default::default()
+    };
+    Enemy {
+        name: EnemyName::SpireGrowth,
+        ai_state: next_state(rng, &fight, NO_MOVE | (NO_MOVE << 2)),
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs: EnemyBuffs::default(),
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{
+    card::Debuff,
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+//The Spire Shield's moves follow a 3 turn cycle.
+//Bits 0-1 are the current move and bits 2-3 are the position in the cycle.
+const BASH: u32 = 0;
+const FORTIFY: u32 = 1;
+const SMASH: u32 = 2;
+const NO_MOVE: u32 = 3;
+
+macro_rules! make_spire_shield_actions {
+    ($bash: expr, $smash: expr) => {
+        &[
+            &[
+                EnemyAction::Attack($bash),
+                EnemyAction::Debuff(Debuff::MinusStrength(1)),
+            ],
+            &[EnemyAction::Block(30), EnemyAction::BlockOthers(30)],
+            $smash,
+        ]
+    };
+}
+
+const ACTIONS: &[&[EnemyAction]] =
+    make_spire_shield_actions!(12, &[EnemyAction::AttackGainBlock(34)]);
+const A3_ACTIONS: &[&[EnemyAction]] =
+    make_spire_shield_actions!(14, &[EnemyAction::AttackGainBlock(38)]);
+//Ascension 18 Smash always gains 99 block.
+const A18_ACTIONS: &[&[EnemyAction]] =
+    make_spire_shield_actions!(14, &[EnemyAction::Attack(38), EnemyAction::Block(99)]);
+
+fn next_state(rng: &mut Rng, state: u32) -> u32 {
+    let last = state & 3;
+    let cycle = (state >> 2) & 3;
+    let next = match cycle {
+        0 => {
+            if rng.sample(2) == 0 {
+                FORTIFY
+            } else {
+                BASH
+            }
+        }
+        1 => {
+            if last != BASH {
+                BASH
+            } else {
+                FORTIFY
+            }
+        }
+        _ => SMASH,
+    };
+    next | (((cycle + 1) % 3) << 2)
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    let actions = match fight.ascension {
+        18.. => A18_ACTIONS,
+        3.. => A3_ACTIONS,
+        _ => ACTIONS,
+    };
+    (next_state(rng, state), actions[(state & 3) as usize])
+}
+
+pub fn generate_spire_shield(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 8 { 125 } else { 110 };
+    //TODO - The Spire Shield and Spear surround the player.
+    let buffs = EnemyBuffs {
+        artifact: if ascension >= 18 { 2 } else { 1 },
+        ..Default::default()
+    };
+    Enemy {
+        name: EnemyName::SpireShield,
+        ai_state: next_state(rng, NO_MOVE),
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
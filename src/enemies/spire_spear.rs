@@ -0,0 +1,94 @@
+use crate::{
+    card::{Buff, CardBody},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+//The Spire Spear's moves follow a 3 turn cycle.
+//Bits 0-1 are the current move and bits 2-3 are the position in the cycle.
+const BURN_STRIKE: u32 = 0;
+const SKEWER: u32 = 1;
+const PIERCER: u32 = 2;
+const NO_MOVE: u32 = 3;
+
+macro_rules! make_spire_spear_actions {
+    ($burn_strike: expr, $burns: expr, $skewer: expr) => {
+        &[
+            &[
+                EnemyAction::Attack($burn_strike),
+                EnemyAction::Attack($burn_strike),
+                $burns,
+            ],
+            $skewer,
+            &[EnemyAction::BuffAll(Buff::Strength(2))],
+        ]
+    };
+}
+
+const BURNS: &[CardBody] = &[CardBody::Burn, CardBody::Burn];
+const ACTIONS: &[&[EnemyAction]] = make_spire_spear_actions!(
+    5,
+    EnemyAction::AddToDiscard(BURNS),
+    &[EnemyAction::Attack(10); 3]
+);
+const A3_ACTIONS: &[&[EnemyAction]] = make_spire_spear_actions!(
+    6,
+    EnemyAction::AddToDiscard(BURNS),
+    &[EnemyAction::Attack(10); 4]
+);
+//Ascension 18 Burn Strikes shuffle the Burns into the draw pile.
+const A18_ACTIONS: &[&[EnemyAction]] = make_spire_spear_actions!(
+    6,
+    EnemyAction::ShuffleIntoDraw(BURNS),
+    &[EnemyAction::Attack(10); 4]
+);
+
+fn next_state(rng: &mut Rng, state: u32) -> u32 {
+    let last = state & 3;
+    let cycle = (state >> 2) & 3;
+    let next = match cycle {
+        0 => {
+            if last != BURN_STRIKE {
+                BURN_STRIKE
+            } else {
+                PIERCER
+            }
+        }
+        1 => SKEWER,
+        _ => {
+            if rng.sample(2) == 0 {
+                PIERCER
+            } else {
+                BURN_STRIKE
+            }
+        }
+    };
+    next | (((cycle + 1) % 3) << 2)
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    let actions = match fight.ascension {
+        18.. => A18_ACTIONS,
+        3.. => A3_ACTIONS,
+        _ => ACTIONS,
+    };
+    (next_state(rng, state), actions[(state & 3) as usize])
+}
+
+pub fn generate_spire_spear(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 8 { 180 } else { 160 };
+    let buffs = EnemyBuffs {
+        artifact: if ascension >= 18 { 2 } else { 1 },
+        ..Default::default()
+    };
+    Enemy {
+        name: EnemyName::SpireSpear,
+        ai_state: next_state(rng, NO_MOVE),
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
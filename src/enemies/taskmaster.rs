@@ -0,0 +1,47 @@
+use crate::{
+    card::CardBody,
+    enemies::{StateEntry, uniform_inclusive, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+macro_rules! make_taskmaster_table {
+    ($wounds: expr) => {
+        &[StateEntry {
+            actions: &[EnemyAction::Attack(7), EnemyAction::AddToDiscard($wounds)],
+            new_states: &[0],
+            weights: &[1],
+        }]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    //The Taskmaster only uses Scouring Whip.
+    const ENEMY_TABLE: &[StateEntry] = make_taskmaster_table!(&[CardBody::Wound]);
+    const A3_TABLE: &[StateEntry] = make_taskmaster_table!(&[CardBody::Wound; 2]);
+    const A18_TABLE: &[StateEntry] = make_taskmaster_table!(&[CardBody::Wound; 3]);
+    let table = match fight.ascension {
+        18.. => A18_TABLE,
+        3.. => A3_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    weighted_transition(rng, state, table)
+}
+
+pub fn generate_taskmaster(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 8 {
+        uniform_inclusive(rng, 57, 64)
+    } else {
+        uniform_inclusive(rng, 54, 60)
+    };
+    Enemy {
+        name: EnemyName::Taskmaster,
+        ai_state: 0,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs: EnemyBuffs::default(),
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
@@ -0,0 +1,84 @@
+use std::cmp::min;
+
+use crate::{
+    card::{Buff, Debuff},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+//The Collector's Torch Heads are kept in the first two slots.
+pub const COLLECTOR_SLOT: usize = 2;
+
+//Bits 0-2 of the AI state are the current move, bits 3-5 are the previous move,
+//bits 6-7 count turns taken and bit 8 is set once Mega Debuff has been used.
+const SPAWN: u32 = 0;
+const FIREBALL: u32 = 1;
+const BUFF: u32 = 2;
+const MEGA_DEBUFF: u32 = 3;
+const NO_MOVE: u32 = 7;
+
+macro_rules! make_collector_actions {
+    ($fireball: expr, $block: expr, $strength: expr, $mega_debuff: expr) => {
+        &[
+            &[EnemyAction::Summon],
+            &[EnemyAction::Attack($fireball)],
+            &[
+                EnemyAction::Block($block),
+                EnemyAction::BuffAll(Buff::Strength($strength)),
+            ],
+            &[
+                EnemyAction::Debuff(Debuff::Weak($mega_debuff)),
+                EnemyAction::Debuff(Debuff::Vulnerable($mega_debuff)),
+                EnemyAction::Debuff(Debuff::Frail($mega_debuff)),
+            ],
+        ]
+    };
+}
+
+const ACTIONS: &[&[EnemyAction]] = make_collector_actions!(18, 15, 3, 3);
+const A4_ACTIONS: &[&[EnemyAction]] = make_collector_actions!(21, 15, 4, 3);
+const A9_ACTIONS: &[&[EnemyAction]] = make_collector_actions!(21, 18, 4, 3);
+const A19_ACTIONS: &[&[EnemyAction]] = make_collector_actions!(21, 18, 5, 5);
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    let last = state & 7;
+    let before = (state >> 3) & 7;
+    let turns = min(((state >> 6) & 3) + 1, 3);
+    let mut used_debuff = (state >> 8) & 1 == 1;
+    let torch_dead = fight.enemies.len() < 3;
+    let num = rng.sample(100);
+    let next = if turns >= 3 && !used_debuff {
+        used_debuff = true;
+        MEGA_DEBUFF
+    } else if num <= 25 && torch_dead && last != SPAWN {
+        SPAWN
+    } else if num <= 70 && !(last == FIREBALL && before == FIREBALL) {
+        FIREBALL
+    } else if last != BUFF {
+        BUFF
+    } else {
+        FIREBALL
+    };
+    let new_state = next | (last << 3) | (turns << 6) | ((used_debuff as u32) << 8);
+    let actions = match fight.ascension {
+        19.. => A19_ACTIONS,
+        9.. => A9_ACTIONS,
+        4.. => A4_ACTIONS,
+        _ => ACTIONS,
+    };
+    (new_state, actions[last as usize])
+}
+
+pub fn generate_the_collector(ascension: i32) -> Enemy {
+    let hp = if ascension >= 9 { 300 } else { 282 };
+    Enemy {
+        name: EnemyName::TheCollector,
+        ai_state: SPAWN | (NO_MOVE << 3),
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs: EnemyBuffs::default(),
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
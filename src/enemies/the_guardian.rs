@@ -0,0 +1,102 @@
+use crate::{
+    card::{Buff, Debuff},
+    enemies::{StateEntry, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+//The Guardian jumps to this state when enough damage triggers Mode Shift.
+pub const DEFENSIVE_MODE_STATE: u32 = 4;
+pub const MODE_SHIFT_INCREASE: i32 = 10;
+pub const MODE_SHIFT_BLOCK: i32 = 20;
+
+macro_rules! make_guardian_table {
+    ($bash: expr, $roll: expr, $sharp_hide: expr) => {
+        &[
+            //Offensive mode
+            StateEntry {
+                actions: &[EnemyAction::Block(9)],
+                new_states: &[1],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($bash)],
+                new_states: &[2],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Debuff(Debuff::Weak(2)),
+                    EnemyAction::Debuff(Debuff::Vulnerable(2)),
+                ],
+                new_states: &[3],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack(5),
+                    EnemyAction::Attack(5),
+                    EnemyAction::Attack(5),
+                    EnemyAction::Attack(5),
+                ],
+                new_states: &[0],
+                weights: &[1],
+            },
+            //Defensive mode
+            StateEntry {
+                actions: &[EnemyAction::Buff(Buff::SharpHide($sharp_hide))],
+                new_states: &[5],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($roll)],
+                new_states: &[6],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[
+                    EnemyAction::Attack(8),
+                    EnemyAction::Attack(8),
+                    EnemyAction::OffensiveMode,
+                ],
+                new_states: &[3],
+                weights: &[1],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    const ENEMY_TABLE: &[StateEntry] = make_guardian_table!(32, 9, 3);
+    const A4_TABLE: &[StateEntry] = make_guardian_table!(36, 10, 3);
+    const A19_TABLE: &[StateEntry] = make_guardian_table!(36, 10, 4);
+    let table = match fight.ascension {
+        19.. => A19_TABLE,
+        4.. => A4_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    weighted_transition(rng, state, table)
+}
+pub fn generate_the_guardian(ascension: i32) -> Enemy {
+    let hp = if ascension >= 9 { 250 } else { 240 };
+    let mode_shift = match ascension {
+        19.. => 40,
+        9.. => 35,
+        _ => 30,
+    };
+    let buffs = EnemyBuffs {
+        mode_shift,
+        mode_shift_threshold: mode_shift,
+        ..Default::default()
+    };
+    Enemy {
+        name: EnemyName::TheGuardian,
+        ai_state: 0,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{
+    card::{CardBody, Debuff},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+//The Time Eater uses Haste once when it falls below half HP.
+//Bits 0-1 are the current move, bits 2-3 are the previous move and bit 4 is set once
+//Haste has been used.
+const REVERBERATE: u32 = 0;
+const RIPPLE: u32 = 1;
+const HEAD_SLAM: u32 = 2;
+const HASTE: u32 = 3;
+const USED_HASTE: u32 = 1 << 4;
+
+macro_rules! make_time_eater_actions {
+    ($reverberate: expr, $ripple: expr, $head_slam: expr, $haste: expr) => {
+        &[
+            &[
+                EnemyAction::Attack($reverberate),
+                EnemyAction::Attack($reverberate),
+                EnemyAction::Attack($reverberate),
+            ],
+            $ripple,
+            $head_slam,
+            $haste,
+        ]
+    };
+}
+
+const RIPPLE_ACTIONS: &[EnemyAction] = &[
+    EnemyAction::Block(20),
+    EnemyAction::Debuff(Debuff::Vulnerable(1)),
+    EnemyAction::Debuff(Debuff::Weak(1)),
+];
+const HASTE_ACTIONS: &[EnemyAction] = &[EnemyAction::ClearDebuffs, EnemyAction::HealToHalf];
+const ACTIONS: &[&[EnemyAction]] = make_time_eater_actions!(
+    7,
+    RIPPLE_ACTIONS,
+    &[
+        EnemyAction::Attack(26),
+        EnemyAction::Debuff(Debuff::DrawReduction(1)),
+    ],
+    HASTE_ACTIONS
+);
+const A4_ACTIONS: &[&[EnemyAction]] = make_time_eater_actions!(
+    8,
+    RIPPLE_ACTIONS,
+    &[
+        EnemyAction::Attack(32),
+        EnemyAction::Debuff(Debuff::DrawReduction(1)),
+    ],
+    HASTE_ACTIONS
+);
+//Ascension 19 adds Frail to Ripple, Slimed to Head Slam and block to Haste.
+const A19_ACTIONS: &[&[EnemyAction]] = make_time_eater_actions!(
+    8,
+    &[
+        EnemyAction::Block(20),
+        EnemyAction::Debuff(Debuff::Vulnerable(1)),
+        EnemyAction::Debuff(Debuff::Weak(1)),
+        EnemyAction::Debuff(Debuff::Frail(1)),
+    ],
+    &[
+        EnemyAction::Attack(32),
+        EnemyAction::Debuff(Debuff::DrawReduction(1)),
+        EnemyAction::AddToDiscard(&[CardBody::Slimed, CardBody::Slimed]),
+    ],
+    &[
+        EnemyAction::ClearDebuffs,
+        EnemyAction::HealToHalf,
+        EnemyAction::Block(32),
+    ]
+);
+
+fn next_state(rng: &mut Rng, enemy: &Enemy, state: u32) -> u32 {
+    let last = state & 3;
+    let before = (state >> 2) & 3;
+    let mut used_haste = state & USED_HASTE;
+    let next = if enemy.hp < enemy.max_hp / 2 && used_haste == 0 {
+        used_haste = USED_HASTE;
+        HASTE
+    } else {
+        loop {
+            let num = rng.sample(100);
+            if num < 45 {
+                if last != REVERBERATE || before != REVERBERATE {
+                    break REVERBERATE;
+                }
+            } else if num < 80 {
+                if last != HEAD_SLAM {
+                    break HEAD_SLAM;
+                }
+                break if rng.sample(100) < 66 {
+                    REVERBERATE
+                } else {
+                    RIPPLE
+                };
+            } else if last != RIPPLE {
+                break RIPPLE;
+            }
+        }
+    };
+    next | (last << 2) | used_haste
+}
+
+pub fn ai(
+    rng: &mut Rng,
+    fight: &Fight,
+    enemy: &Enemy,
+    state: u32,
+) -> (u32, &'static [EnemyAction]) {
+    let actions = match fight.ascension {
+        19.. => A19_ACTIONS,
+        4.. => A4_ACTIONS,
+        _ => ACTIONS,
+    };
+    (next_state(rng, enemy, state), actions[(state & 3) as usize])
+}
+
+pub fn generate_time_eater(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 9 { 480 } else { 456 };
+    let buffs = EnemyBuffs {
+        time_warp: true,
+        ..Default::default()
+    };
+    let mut enemy = Enemy {
+        name: EnemyName::TimeEater,
+        ai_state: 0,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    };
+    //Haste is never the previous move, so this works as the starting state.
+    enemy.ai_state = next_state(rng, &enemy, HASTE | (HASTE << 2));
+    enemy
+}
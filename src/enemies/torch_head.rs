@@ -0,0 +1,36 @@
+use crate::{
+    enemies::{StateEntry, uniform_inclusive, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+pub fn ai(rng: &mut Rng, _: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    const ENEMY_TABLE: &[StateEntry] = &[StateEntry {
+        actions: &[EnemyAction::Attack(7)],
+        new_states: &[0],
+        weights: &[1],
+    }];
+    weighted_transition(rng, state, ENEMY_TABLE)
+}
+
+pub fn generate_torch_head(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 9 {
+        uniform_inclusive(rng, 40, 45)
+    } else {
+        uniform_inclusive(rng, 38, 40)
+    };
+    let buffs = EnemyBuffs {
+        minion: true,
+        ..Default::default()
+    };
+    Enemy {
+        name: EnemyName::TorchHead,
+        ai_state: 0,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{
+    enemies::{StateEntry, weighted_transition},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+macro_rules! make_transient_table {
+    ($attack: expr) => {
+        &[
+            StateEntry {
+                actions: &[EnemyAction::Attack($attack)],
+                new_states: &[1],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($attack + 10)],
+                new_states: &[2],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($attack + 20)],
+                new_states: &[3],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($attack + 30)],
+                new_states: &[4],
+                weights: &[1],
+            },
+            StateEntry {
+                actions: &[EnemyAction::Attack($attack + 40)],
+                new_states: &[4],
+                weights: &[1],
+            },
+        ]
+    };
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    // States are
+    // 0-4) Attack, hitting 10 harder each turn.
+    // The Transient fades away after its 5th turn from Fading.
+    const ENEMY_TABLE: &[StateEntry] = make_transient_table!(30);
+    const A2_TABLE: &[StateEntry] = make_transient_table!(40);
+    let table = match fight.ascension {
+        2.. => A2_TABLE,
+        _ => ENEMY_TABLE,
+    };
+    weighted_transition(rng, state, table)
+}
+
+pub fn generate_transient(_: &mut Rng, ascension: i32) -> Enemy {
+    let hp = 999;
+    let buffs = EnemyBuffs {
+        shifting: true,
+        fading: if ascension >= 17 { 6 } else { 5 },
+        ..Default::default()
+    };
+    Enemy {
+        name: EnemyName::Transient,
+        ai_state: 0,
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{
+    card::{CardBody, Debuff},
+    fight::{Enemy, EnemyAction, EnemyBuffs, EnemyDebuffs, EnemyName, Fight},
+    rng::Rng,
+};
+
+//Writhing Mass picks a new move whenever it is hit, so the move is rolled from its own function.
+//Bits 0-2 are the current move and bit 3 is set once Implant has been used.
+const BIG_HIT: u32 = 0;
+const MULTI_STRIKE: u32 = 1;
+const FLAIL: u32 = 2;
+const WITHER: u32 = 3;
+const IMPLANT: u32 = 4;
+const NO_MOVE: u32 = 7;
+const IMPLANTED: u32 = 1 << 3;
+
+macro_rules! make_writhing_mass_actions {
+    ($big_hit: expr, $multi_strike: expr, $flail: expr, $wither: expr) => {
+        &[
+            &[EnemyAction::Attack($big_hit)],
+            &[
+                EnemyAction::Attack($multi_strike),
+                EnemyAction::Attack($multi_strike),
+                EnemyAction::Attack($multi_strike),
+            ],
+            &[EnemyAction::Attack($flail), EnemyAction::Block(16)],
+            &[
+                EnemyAction::Attack($wither),
+                EnemyAction::Debuff(Debuff::Weak(2)),
+                EnemyAction::Debuff(Debuff::Vulnerable(2)),
+            ],
+            &[EnemyAction::AddToDeck(CardBody::Parasite)],
+        ]
+    };
+}
+
+const ACTIONS: &[&[EnemyAction]] = make_writhing_mass_actions!(32, 7, 15, 10);
+const A2_ACTIONS: &[&[EnemyAction]] = make_writhing_mass_actions!(38, 9, 16, 12);
+
+//Picks a move other than the current one.
+fn roll_move(rng: &mut Rng, state: u32) -> u32 {
+    let last = state & 7;
+    let first = last == NO_MOVE;
+    let mut weights = [0; 5];
+    weights[BIG_HIT as usize] = if first { 0 } else { 10 };
+    weights[MULTI_STRIKE as usize] = if first { 33 } else { 30 };
+    weights[FLAIL as usize] = if first { 33 } else { 30 };
+    weights[WITHER as usize] = if first { 34 } else { 20 };
+    weights[IMPLANT as usize] = if first || state & IMPLANTED != 0 {
+        0
+    } else {
+        10
+    };
+    if !first {
+        weights[last as usize] = 0;
+    }
+    rng.sample_weighted(&weights) as u32 | (state & IMPLANTED)
+}
+
+//Called when the Writhing Mass is hit.
+pub fn reroll(rng: &mut Rng, state: u32) -> u32 {
+    roll_move(rng, state)
+}
+
+pub fn ai(rng: &mut Rng, fight: &Fight, _: &Enemy, state: u32) -> (u32, &'static [EnemyAction]) {
+    let current = state & 7;
+    let state = if current == IMPLANT {
+        state | IMPLANTED
+    } else {
+        state
+    };
+    let actions = if fight.ascension >= 2 {
+        A2_ACTIONS
+    } else {
+        ACTIONS
+    };
+    (roll_move(rng, state), actions[current as usize])
+}
+
+pub fn generate_writhing_mass(rng: &mut Rng, ascension: i32) -> Enemy {
+    let hp = if ascension >= 7 { 175 } else { 160 };
+    let buffs = EnemyBuffs {
+        malleable: 3,
+        reactive: true,
+        ..Default::default()
+    };
+    Enemy {
+        name: EnemyName::WrithingMass,
+        ai_state: roll_move(rng, NO_MOVE),
+        last_move: None,
+        hp,
+        max_hp: hp,
+        buffs,
+        debuffs: EnemyDebuffs::default(),
+        block: 0,
+    }
+}
@@ -9,11 +9,13 @@ use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    card::{Buff, Card, CardBody, CardType, Cost, Debuff},
+    card::{Buff, Card, CardBody, CardType, Cost, Debuff, SelectCardEffect},
     deck::Deck,
-    game::Game,
+    game::{Game, event::Event, reward::CardRewardRoom},
+    orb::Orb,
     relic::Relic,
     rng::Rng,
+    stance::Stance,
     util::insert_sorted,
 };
 
@@ -35,6 +37,44 @@ pub struct Fight {
     pub attacks_played_this_turn: i32,
     pub turn_count: i32,
     pub triggered_centennial_puzzle: bool,
+    //Time Warp ends the player's turn once it is set.
+    pub end_turn_early: bool,
+    pub cards_discarded_this_turn: i32,
+    //Cards held back by Well-Laid Plans, they go back to the hand after the end of turn discard.
+    pub retained: Vec<Card>,
+    //Copies made by Nightmare which are added to the hand next turn.
+    pub nightmare: Vec<Card>,
+    //The first orb is the next to be evoked, new orbs are channeled at the end.
+    pub orbs: Vec<Orb>,
+    pub orb_slots: i32,
+    //Used for Blizzard and Thunder Strike.
+    pub frost_channeled: i32,
+    pub lightning_channeled: i32,
+    //Every Claw gets stronger each time one is played.
+    pub claw_bonus: i32,
+    pub powers_played: i32,
+    pub cards_played_this_turn: i32,
+    //Rebound puts the next card played on top of the draw pile.
+    pub rebound: bool,
+    pub stance: Stance,
+    pub mantra: i32,
+    //Used for Brilliance.
+    pub mantra_gained: i32,
+    //Cards being scried, the first is the next to be kept or discarded.
+    pub scrying: Vec<Card>,
+    //Kept cards go back on top of the draw pile in order once the scry is over.
+    pub scry_kept: Vec<Card>,
+    //The cards offered by Foreign Influence and Wish.
+    pub offered: Vec<Card>,
+    //Used for cards like Follow-Up which care about the previous card played.
+    pub last_card_type: Option<CardType>,
+    //Vault skips the enemies' next turn.
+    pub extra_turn: bool,
+    //Blue Candle and Medical Kit make curses and statuses playable.
+    pub blue_candle: bool,
+    pub medical_kit: bool,
+    //Enemy AIs pick harder movesets at higher ascensions.
+    pub ascension: i32,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
@@ -42,7 +82,12 @@ pub struct FightRewards {
     pub gold_min: i32,
     pub gold_max: i32,
     pub relic_count: i32,
-    pub fixed_relic: Option<Relic>,
+    pub fixed_relics: Vec<Relic>,
+    pub card_room: CardRewardRoom,
+    //Events like the Colosseum continue after a fight instead of giving rewards.
+    pub resume_event: Option<Event>,
+    //Burning elites drop the Emerald Key.
+    pub emerald_key: bool,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -55,6 +100,12 @@ pub struct PlayerDebuffs {
     pub strength_down: i32,
     pub dexterity_down: i32,
     pub no_draw: bool,
+    pub hex: i32,
+    pub confused: bool,
+    pub constricted: i32,
+    pub draw_reduction: i32,
+    pub energy_down: i32,
+    pub no_block: i32,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -81,6 +132,66 @@ pub struct PlayerBuffs {
     pub dexterity: i32,
     pub vigor: i32,
     pub thorns: i32,
+    pub intangible: i32,
+    pub energy_next_turn: i32,
+    pub draw_next_turn: i32,
+    pub block_next_turn: i32,
+    pub blur: i32,
+    pub accuracy: i32,
+    pub infinite_blades: i32,
+    pub noxious_fumes: i32,
+    pub envenom: i32,
+    pub after_image: i32,
+    pub thousand_cuts: i32,
+    pub burst: i32,
+    //Phantasmal Killer turns into Double Damage at the start of the next turn.
+    pub phantasmal: i32,
+    pub double_damage: i32,
+    pub tools_of_the_trade: i32,
+    pub wraith_form: i32,
+    pub well_laid_plans: i32,
+    pub focus: i32,
+    pub artifact: i32,
+    pub buffer: i32,
+    pub amplify: i32,
+    pub biased_cognition: i32,
+    pub creative_ai: i32,
+    pub echo_form: i32,
+    pub electrodynamics: bool,
+    pub heatsinks: i32,
+    pub hello_world: i32,
+    pub loop_orbs: i32,
+    pub machine_learning: i32,
+    pub self_repair: i32,
+    pub static_discharge: i32,
+    pub storm: i32,
+    pub equilibrium: i32,
+    pub mental_fortress: i32,
+    pub rushdown: i32,
+    pub nirvana: i32,
+    pub establishment: i32,
+    pub master_reality: bool,
+    pub like_water: i32,
+    pub wave_of_the_hand: i32,
+    pub battle_hymn: i32,
+    pub foresight: i32,
+    pub study: i32,
+    pub devotion: i32,
+    //Deva Form's energy grows by the number of Deva Forms played each turn.
+    pub deva_form: i32,
+    pub deva_energy: i32,
+    pub collect: i32,
+    pub blasphemy: bool,
+    pub simmering_fury: i32,
+    pub free_attack: i32,
+    pub plated_armor: i32,
+    pub magnetism: i32,
+    pub mayhem: i32,
+    pub panache: i32,
+    //The damage of The Bombs indexed by how many more turns they take to go off.
+    pub the_bomb: [i32; 3],
+    //Pen Nib doubles the damage of the attack being played.
+    pub pen_nib: bool,
 }
 
 //This holds effects that happen after a card finishes resolving.
@@ -93,6 +204,14 @@ pub enum PostCardItem {
     DamageAll(i32),
     GainEnergy(i32),
     DamageRandomEnemy(i32),
+    //Used for start of turn effects which need a selection, like Tools of the Trade.
+    SelectCard(SelectCardEffect),
+    Channel(Orb),
+    //Used for Foresight's scry at the start of the turn.
+    Scry(i32),
+    DeusExMachina(Card),
+    //The discard pile was shuffled into the draw pile, used for Sundial.
+    Reshuffle,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -103,6 +222,10 @@ pub struct PlayCardContext {
     pub real_card: bool,
     pub effect_index: usize,
     pub x: i32,
+    //The card was played after Rebound so it goes on top of the draw pile.
+    pub rebound: bool,
+    //Tantrum shuffles itself back into the draw pile.
+    pub shuffles_back: bool,
 }
 
 impl Fight {
@@ -115,6 +238,9 @@ impl Fight {
         if self.deck.len() == 0 {
             let mut old_discard = vec![];
             mem::swap(&mut old_discard, &mut self.discard_pile);
+            if !old_discard.is_empty() {
+                self.post_card_queue.push_back(PostCardItem::Reshuffle);
+            }
             self.deck = Deck::shuffled(old_discard);
         }
         if self.deck.len() > 0 {
@@ -127,11 +253,28 @@ impl Fight {
         }
         None
     }
-    pub fn draw(&mut self, rng: &mut Rng) {
+    //Returns the card drawn, if any.
+    pub fn draw(&mut self, rng: &mut Rng) -> Option<Card> {
         if self.hand.len() >= 10 || self.player_debuffs.no_draw {
-            return;
+            return None;
         }
-        self.remove_top_of_deck(rng).map(|card| {
+        self.remove_top_of_deck(rng).map(|mut card| {
+            //Confusion randomizes the cost of each card when it is drawn. Blood for Blood
+            //still gets cheaper for each time HP is lost after it was drawn.
+            if self.player_debuffs.confused {
+                match card.cost {
+                    Cost::Fixed(_) => card.cost = Cost::Fixed(rng.sample(4) as i32),
+                    Cost::NumMinusHpLoss(_) => {
+                        card.cost = Cost::NumMinusHpLoss(
+                            rng.sample(4) as i32 + self.player_buffs.num_times_lost_hp,
+                        )
+                    }
+                    _ => {}
+                }
+            }
+            if card.body == CardBody::Void {
+                self.energy = max(0, self.energy - 1);
+            }
             if card.body.card_type() == CardType::Status && self.player_buffs.evolve > 0 {
                 self.post_card_queue
                     .push_back(PostCardItem::Draw(self.player_buffs.evolve));
@@ -143,8 +286,18 @@ impl Fight {
                 self.post_card_queue
                     .push_back(PostCardItem::DamageAll(self.player_buffs.fire_breathing));
             }
-            insert_sorted(card, &mut self.hand);
-        });
+            //Deus Ex Machina exhausts itself instead of going to the hand.
+            if card.body == CardBody::DeusExMachina {
+                self.post_card_queue
+                    .push_back(PostCardItem::DeusExMachina(card.clone()));
+                return card;
+            }
+            if card.body == CardBody::EndlessAgony && self.hand.len() < 9 {
+                insert_sorted(card.clone(), &mut self.hand);
+            }
+            insert_sorted(card.clone(), &mut self.hand);
+            card
+        })
     }
 }
 
@@ -233,12 +386,21 @@ impl Fight {
         if idx >= self.hand.len() {
             false
         } else {
-            //TODO handle Blue Candle and Medical kit.
             //TODO handle can't play attack effects (Entangled, Awakened One dead)
             let card = self.hand[idx].clone();
             if self.player_debuffs.entangled && card.body.card_type() == CardType::Attack {
                 return false;
             }
+            if card.body == CardBody::GrandFinale && self.deck.len() > 0 {
+                return false;
+            }
+            if card.body == CardBody::SignatureMove
+                && self.hand.iter().enumerate().any(|(other_idx, other)| {
+                    other_idx != idx && other.body.card_type() == CardType::Attack
+                })
+            {
+                return false;
+            }
             if card.body == CardBody::Clash {
                 for card in &self.hand {
                     if card.body.card_type() != CardType::Attack {
@@ -255,10 +417,17 @@ impl Fight {
 
     pub fn evaluate_cost(&self, card: &Card) -> Option<i32> {
         let base = match card.cost {
-            Cost::Unplayable => None,
+            Cost::Unplayable => match card.body.card_type() {
+                CardType::Curse if self.blue_candle => Some(0),
+                CardType::Status if self.medical_kit => Some(0),
+                _ => None,
+            },
             Cost::Fixed(x) => Some(x),
             Cost::X => Some(self.energy),
             Cost::NumMinusHpLoss(x) => Some(max(0, x - self.player_buffs.num_times_lost_hp)),
+            Cost::NumPlusHpLoss(x) => Some(x + self.player_buffs.num_times_lost_hp),
+            Cost::NumMinusDiscards(x) => Some(max(0, x - self.cards_discarded_this_turn)),
+            Cost::NumMinusPowers(x) => Some(max(0, x - self.powers_played)),
         };
         if self.player_buffs.corruption
             && base.is_some()
@@ -266,6 +435,12 @@ impl Fight {
         {
             return Some(0);
         };
+        if self.player_buffs.free_attack > 0
+            && base.is_some()
+            && card.body.card_type() == CardType::Attack
+        {
+            return Some(0);
+        }
         if let Some(temp) = card.temp_cost {
             if let Some(base) = base
                 && !matches!(card.cost, Cost::X)
@@ -291,32 +466,111 @@ pub enum EnemyAction {
     DefendAlly(i32),
     Escape,
     StealGold(i32),
+    //Hexaghost's Divider damage is set from the player's hp when it activates.
+    ChargeDivider,
+    Divider(i32),
+    UpgradeBurns,
+    //The Guardian leaves defensive mode.
+    OffensiveMode,
+    //Heals the enemy by the unblocked damage dealt.
+    LifestealAttack(i32),
+    //Spawns the enemy's minions into empty slots.
+    Summon,
+    HealAll(i32),
+    BuffAll(Buff),
+    BlockOthers(i32),
+    //Used for Bronze Orbs to block for the Bronze Automaton.
+    DefendLeader(i32),
+    //Book of Stabbing hits one more time each time this is used.
+    MultiStab(i32),
+    //Ascension 18 Books of Stabbing also stab one more time after a single stab.
+    AddStab,
+    ClearDebuffs,
+    //Bronze Orbs take a card from the draw pile and return it when they die.
+    Stasis,
+    ShuffleIntoDraw(&'static [CardBody]),
+    //Deals damage that ignores strength and weak, then the enemy dies.
+    Explode(i32),
+    //The enemy dies as part of its move, like the Dagger or a faded Transient.
+    Die,
+    //Darklings and the Awakened One come back from being half dead.
+    Reincarnate,
+    Rebirth,
+    HealToHalf,
+    //Adds a card to the player's deck for the rest of the run.
+    AddToDeck(CardBody),
+    //Gains block equal to the damage of the attack.
+    AttackGainBlock(i32),
+    ClearNegativeStrength,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum EnemyName {
+    AwakenedOne,
+    Bear,
     BlueSlaver,
+    BookOfStabbing,
+    BronzeAutomaton,
+    BronzeOrb,
+    Byrd,
+    Centurion,
+    Champ,
+    Chosen,
+    CorruptHeart,
     Cultist,
+    Dagger,
+    Darkling,
+    Deca,
+    Donu,
+    Exploder,
     FungiBeast,
+    GiantHead,
     GreenLouse,
     GremlinFat,
+    GremlinLeader,
     GremlinMad,
     GremlinNob,
     GremlinShield,
     GremlinSneaky,
     GremlinWizard,
+    Hexaghost,
     JawWorm,
     Lagavulin,
     LargeBlackSlime,
     LargeGreenSlime,
     Looter,
+    Maw,
     MedBlackSlime,
     MedGreenSlime,
+    Mugger,
+    Mystic,
+    Nemesis,
+    OrbWalker,
+    Pointy,
     RedLouse,
     RedSlaver,
+    Reptomancer,
+    Repulsor,
+    Romeo,
     Sentry,
+    ShelledParasite,
+    SlimeBoss,
     SmallBlackSlime,
     SmallGreenSlime,
+    SnakePlant,
+    Snecko,
+    SphericGuardian,
+    Spiker,
+    SpireGrowth,
+    SpireShield,
+    SpireSpear,
+    Taskmaster,
+    TheCollector,
+    TheGuardian,
+    TimeEater,
+    TorchHead,
+    Transient,
+    WrithingMass,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -330,6 +584,10 @@ pub struct Enemy {
     //which is raised on Mode shift.
     pub name: EnemyName,
     pub ai_state: u32,
+    //The state the enemy last acted from. Since the AI is a state machine this is
+    //all of the move history needed to sample its intent when intents are hidden.
+    //It is cleared when the intent is forced to a known state.
+    pub last_move: Option<u32>,
     //A function from the current state to the new ai state and the actions to take.
     pub hp: i32,
     pub max_hp: i32,
@@ -339,6 +597,8 @@ pub struct Enemy {
     pub block: i32,
 }
 
+//Each enemy power is its own field. Powers that track extra state, like Malleable's
+//hits this turn, keep it in a second field next to the power.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub struct EnemyBuffs {
     pub strength: i32,
@@ -356,10 +616,67 @@ pub struct EnemyBuffs {
     pub metallicize: i32,
     pub asleep: bool,
     pub thorns: i32,
+    //The Guardian shifts to defensive mode after taking this much more damage.
+    pub mode_shift: i32,
+    pub mode_shift_threshold: i32,
+    pub sharp_hide: i32,
+    pub divider_damage: i32,
+    pub upgraded_burns: bool,
+    pub barricade: bool,
+    pub plated_armor: i32,
+    //Flying halves attack damage, each hit removes a stack.
+    pub flying: i32,
+    //Malleable gives more block each time it is hit in a turn.
+    pub malleable: i32,
+    pub malleable_hits: i32,
+    //Minions flee once every non-minion enemy is dead.
+    pub minion: bool,
+    pub painful_stabs: bool,
+    pub stab_count: i32,
+    pub stasis: Option<Card>,
+    //Half dead enemies can't be targeted or damaged until they revive.
+    pub half_dead: bool,
+    //Darklings regrow while another Darkling is alive.
+    pub regrow: bool,
+    //Writhing Mass changes its intent whenever it is hit.
+    pub reactive: bool,
+    //Shifting loses strength equal to HP lost, it comes back at the end of the turn.
+    pub shifting: bool,
+    pub shifted: i32,
+    //Slow increases damage taken by 10% for each card played this turn.
+    pub slow: bool,
+    pub slow_stacks: i32,
+    pub intangible: i32,
+    //Nemesis is Intangible every other turn.
+    pub phasing: bool,
+    pub curiosity: i32,
+    pub regenerate: i32,
+    //The Awakened One is reborn the first time it dies.
+    pub unawakened: bool,
+    //Time Warp ends the player's turn after 12 cards.
+    pub time_warp: bool,
+    pub time_warp_cards: i32,
+    //Invincible caps the damage taken each turn.
+    pub invincible: i32,
+    pub invincible_left: i32,
+    pub beat_of_death: i32,
+    //Artifact blocks the next debuff.
+    pub artifact: i32,
+    //Fading enemies die at the end of their turn once it reaches 0.
+    pub fading: i32,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub struct EnemyDebuffs {
     pub vulnerable: i32,
     pub weak: i32,
+    pub poison: i32,
+    pub choked: i32,
+    pub corpse_explosion: i32,
+    //Strength lost until the end of the turn, like from Piercing Wail.
+    pub strength_down: i32,
+    //Lock-On makes orbs deal 50% more damage.
+    pub lock_on: i32,
+    pub mark: i32,
+    pub block_return: i32,
 }
@@ -4,9 +4,20 @@ mod choice_handler;
 pub mod encounter;
 pub mod event;
 mod goto_state;
+mod intents;
+mod orb_slots;
 mod perform_action;
+mod potion_belt;
+pub mod reward;
+mod scry;
+pub mod shop;
+mod stances;
+pub mod treasure;
 
-use std::{cmp::min, mem, vec};
+use std::{
+    cmp::{max, min},
+    mem, vec,
+};
 
 use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
@@ -17,17 +28,38 @@ use crate::fight::EnemyName;
 use crate::game::choice::{
     Choice, ChoiceState, ChooseEnemyAction, PlayCardAction, SelectCardAction, SelectionPile,
 };
+use crate::game::potion_belt::POTION_CHANCE_START;
+use crate::game::reward::CARD_RARITY_OFFSET_START;
 use crate::map::{ActMap, RoomType};
+use crate::potion::Potion;
 use crate::relic::Relic;
 use crate::{
-    card::{Buff, Card, CardBody, CardType, Debuff, SelectCardEffect},
+    card::{
+        Buff, COLORLESS_CARDS, Card, CardBody, CardRarity, CardType, Cost, DEFECT_CARDS,
+        DEFECT_COMMON_CARDS, DEFECT_POWER_CARDS, Debuff, IRONCLAD_CARDS, SILENT_CARDS,
+        SelectCardEffect, WATCHER_CARDS, sample_card,
+    },
     deck::Deck,
     enemies::{
-        med_black_slime::generate_med_black_slime, med_green_slime::generate_med_green_slime,
+        awakened_one,
+        bronze_orb::generate_bronze_orb,
+        byrd::GROUNDED_STATE,
+        dagger::generate_dagger,
+        darkling,
+        large_black_slime::generate_large_black_slime,
+        large_green_slime::generate_large_green_slime,
+        med_black_slime::generate_med_black_slime,
+        med_green_slime::generate_med_green_slime,
+        shelled_parasite::STUNNED_STATE,
+        the_guardian::{DEFENSIVE_MODE_STATE, MODE_SHIFT_BLOCK, MODE_SHIFT_INCREASE},
+        torch_head::generate_torch_head,
+        writhing_mass,
     },
     fight::{Enemy, EnemyAction, EnemyIdx, Fight, PlayCardContext, PostCardItem},
+    orb::Orb,
     relic::{RelicPool, Relics},
     rng::Rng,
+    stance::Stance,
     util::insert_sorted,
 };
 
@@ -41,6 +73,8 @@ pub struct Game {
     player_max_hp: i32,
     max_potion_slots: i32,
     charachter: Character,
+    //Ascension goes from 0 to 20, each level includes the ones below it.
+    ascension: i32,
     fight: Fight,
     base_deck: Vec<Card>,
     relics: Relics,
@@ -49,6 +83,32 @@ pub struct Game {
     map: ActMap,
     act: Act,
     state_counter: u32,
+    card_rarity_offset: i32,
+    cards_removed_at_shop: i32,
+    potions: Vec<Potion>,
+    //The percent chance of a potion dropping after a fight.
+    potion_chance: i32,
+    keys: Keys,
+    //Hides the enemies' intents from agents even without Runic Dome.
+    hidden_intents: bool,
+    //A Bottled relic was just picked up. Choosing its card interrupts the screen
+    //it came from, which is kept to return to.
+    bottling: Option<CardType>,
+    bottle_return: Option<Box<Choice>>,
+}
+
+//Keys are kept across acts. All three are needed to enter Act 4.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Keys {
+    pub ruby: bool,
+    pub emerald: bool,
+    pub sapphire: bool,
+}
+
+impl Keys {
+    pub fn all(&self) -> bool {
+        self.ruby && self.emerald && self.sapphire
+    }
 }
 
 //Some cards, like Armaments, may require interrupting the execution of a
@@ -61,6 +121,8 @@ pub struct Game {
 pub enum ActionControlFlow {
     Continue,
     SelectCards(Vec<SelectCardAction>, SelectCardEffect, SelectionPile),
+    //The cards being scried are stored in the fight.
+    Scry,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
@@ -78,6 +140,9 @@ impl Game {
     }
 
     fn play_card_choice(&mut self) -> Choice {
+        if self.fight.hand.is_empty() && self.relics.has_relic(Relic::UnceasingTop) {
+            self.fight.draw(&mut self.rng);
+        }
         let fight = &self.fight;
         let mut actions = vec![PlayCardAction::EndTurn];
         for i in 0..fight.hand.len() {
@@ -85,19 +150,40 @@ impl Game {
                 actions.push(PlayCardAction::PlayCard(i as u8));
             }
         }
+        for i in 0..self.potions.len() {
+            if self.can_use_potion(self.potions[i]) {
+                actions.push(PlayCardAction::UsePotion(i as u8));
+            }
+            actions.push(PlayCardAction::DiscardPotion(i as u8));
+        }
         Choice::PlayCardState(actions)
     }
 
     fn choose_enemy_choice(&mut self, chosen_card_idx: usize) -> Choice {
-        let fight = &self.fight;
-        let mut actions = vec![];
-        for i in fight.enemies.indicies() {
-            actions.push(ChooseEnemyAction { enemy: i.0 });
+        Choice::ChooseEnemyState(self.target_actions(), chosen_card_idx)
+    }
+
+    //Half dead enemies can't be targeted unless there is nothing else to target.
+    fn target_actions(&self) -> Vec<ChooseEnemyAction> {
+        let enemies = &self.fight.enemies;
+        let mut actions: Vec<ChooseEnemyAction> = enemies
+            .indicies()
+            .filter(|i| !enemies[*i].buffs.half_dead)
+            .map(|i| ChooseEnemyAction { enemy: i.0 })
+            .collect();
+        if actions.is_empty() {
+            actions = enemies
+                .indicies()
+                .map(|i| ChooseEnemyAction { enemy: i.0 })
+                .collect();
         }
-        Choice::ChooseEnemyState(actions, chosen_card_idx)
+        actions
     }
 
-    fn damage_player(&mut self, damage: i32, from_card: bool) -> Option<Choice> {
+    fn damage_player(&mut self, mut damage: i32, from_card: bool) -> Option<Choice> {
+        if self.fight.player_buffs.intangible > 0 {
+            damage = min(damage, 1);
+        }
         if damage > self.fight.player_block {
             let dealt = damage - self.fight.player_block;
             self.fight.player_block = 0;
@@ -111,43 +197,128 @@ impl Game {
         }
         return None;
     }
+
+    fn enemy_attack_damage(&self, i: EnemyIdx, damage: i32) -> i32 {
+        let enemy = &self.fight.enemies[i];
+        let damage = damage + enemy.buffs.strength + enemy.buffs.implicit_strength;
+        let mut damage = damage as f32;
+        //Weak and vulnerable calculations require using floats then rounding down afterwards.
+        if enemy.debuffs.weak > 0 {
+            damage *= 0.75;
+        }
+        damage *= self.fight.stance.damage_taken_multiplier();
+        if self.fight.player_debuffs.vulnerable > 0 {
+            damage *= 1.5;
+        }
+        max(damage as i32, 0)
+    }
+    fn enemy_attack(&mut self, i: EnemyIdx, damage: i32) -> Option<Choice> {
+        let mut damage = self.enemy_attack_damage(i, damage);
+        //Torii reduces small unblocked attacks to 1 damage.
+        let unblocked = damage - self.fight.player_block;
+        if self.relics.has_relic(Relic::Torii) && unblocked > 1 && unblocked <= 5 {
+            damage = self.fight.player_block + 1;
+        }
+        let hp = self.player_hp;
+        if let Some(choice) = self.damage_player(damage, false) {
+            return Some(choice);
+        }
+        if self.player_hp < hp && self.fight.enemies[i].buffs.painful_stabs {
+            insert_sorted(CardBody::Wound.to_card(), &mut self.fight.discard_pile);
+        }
+        if self.player_hp < hp {
+            for _ in 0..self.fight.player_buffs.static_discharge {
+                self.channel(Orb::Lightning);
+            }
+            decrement(&mut self.fight.player_buffs.plated_armor);
+        }
+        let player_spikiness = self.fight.player_buffs.temp_spikes + self.fight.player_buffs.thorns;
+        if player_spikiness > 0 {
+            self.damage_enemy(player_spikiness, i.0 as usize, false);
+        }
+        None
+    }
+    //Well-Laid Plans picks the cards to retain one at a time before the turn ends.
+    //Retaining is almost always better than discarding, so it always retains as many as it can.
+    fn end_turn(&mut self) -> Choice {
+        let retain_limit = self.fight.player_buffs.well_laid_plans as usize;
+        if self.fight.retained.len() < retain_limit && !self.fight.hand.is_empty() {
+            let (targets, pile) = self.select_card_targets(SelectCardEffect::Retain);
+            return Choice::SelectCardState(None, SelectCardEffect::Retain, targets, pile);
+        }
+        self.enemy_phase()
+    }
+
     fn enemy_phase(&mut self) -> Choice {
         self.discard_hand_end_of_turn();
+        if self.player_hp <= 0 {
+            return Choice::Loss;
+        }
+        let extra_turn = mem::take(&mut self.fight.extra_turn);
         for i in self.fight.enemies.indicies() {
+            //Vault skips the enemies' turn.
+            if extra_turn {
+                break;
+            }
+            //Minions flee if their leader dies to thorns.
+            if self.fight.enemies.enemies[i.0 as usize].is_none() {
+                continue;
+            }
+            //Poison triggers at the start of the enemy's turn.
+            if self.poison_enemy(i) {
+                continue;
+            }
             let enemy_actions;
+            let was_half_dead;
             {
                 let enemy = &self.fight.enemies[i];
+                was_half_dead = enemy.buffs.half_dead;
                 enemy_actions = behavior(&mut self.rng, &self.fight, enemy, enemy.ai_state);
+                self.fight.enemies[i].last_move = Some(self.fight.enemies[i].ai_state);
                 self.fight.enemies[i].ai_state = enemy_actions.0;
             }
 
             for action in enemy_actions.1 {
+                //An enemy dying from spikes can interrupt its remaining actions.
+                match &self.fight.enemies.enemies[i.0 as usize] {
+                    None => break,
+                    Some(enemy) if enemy.buffs.half_dead && !was_half_dead => break,
+                    _ => {}
+                }
                 match action {
                     EnemyAction::Attack(damage) => {
-                        let enemy = &self.fight.enemies[i];
-                        let damage = *damage + enemy.buffs.strength + enemy.buffs.implicit_strength;
-                        let mut damage = damage as f32;
-                        //Weak and vulnerable calculations require using floats then rounding down afterwards.
-                        if enemy.debuffs.weak > 0 {
-                            damage *= 0.75;
-                        }
-                        if self.fight.player_debuffs.vulnerable > 0 {
-                            damage *= 1.5;
-                        }
-                        let damage = damage as i32;
-                        if let Some(choice) = self.damage_player(damage, false) {
+                        if let Some(choice) = self.enemy_attack(i, *damage) {
                             return choice;
                         }
-                        let player_spikiness =
-                            self.fight.player_buffs.temp_spikes + self.fight.player_buffs.thorns;
-                        if player_spikiness > 0 {
-                            self.damage_enemy(player_spikiness, i.0 as usize, false);
+                    }
+                    EnemyAction::ChargeDivider => {
+                        self.fight.enemies[i].buffs.divider_damage = self.player_hp / 12 + 1;
+                    }
+                    EnemyAction::Divider(hits) => {
+                        let damage = self.fight.enemies[i].buffs.divider_damage;
+                        for _ in 0..*hits {
+                            if let Some(choice) = self.enemy_attack(i, damage) {
+                                return choice;
+                            }
+                            if self.fight.enemies.enemies[i.0 as usize].is_none() {
+                                break;
+                            }
+                        }
+                    }
+                    EnemyAction::UpgradeBurns => {
+                        self.fight.enemies[i].buffs.upgraded_burns = true;
+                        for card in &mut self.fight.discard_pile {
+                            card.upgrade_burn();
                         }
-                        let enemy = &self.fight.enemies[i];
-                        //An enemy dying from spikes can interrupt a multi-attack.
-                        if enemy.hp <= 0 {
-                            break;
-                        };
+                        self.fight.discard_pile.sort();
+                        self.fight
+                            .deck
+                            .for_each_mut(&mut |card| card.upgrade_burn());
+                    }
+                    EnemyAction::OffensiveMode => {
+                        let enemy = &mut self.fight.enemies[i];
+                        enemy.buffs.mode_shift = enemy.buffs.mode_shift_threshold;
+                        enemy.buffs.sharp_hide = 0;
                     }
                     EnemyAction::Block(block) => {
                         self.fight.enemies[i].block += block;
@@ -159,9 +330,14 @@ impl Game {
                         self.apply_debuff_to_player(*debuff);
                     }
                     EnemyAction::AddToDiscard(cards) => {
-                        self.fight
-                            .discard_pile
-                            .extend(cards.into_iter().map(|c| c.to_card()));
+                        let upgraded_burns = self.fight.enemies[i].buffs.upgraded_burns;
+                        self.fight.discard_pile.extend(cards.iter().map(|c| {
+                            let mut card = c.to_card();
+                            if upgraded_burns {
+                                card.upgrade_burn();
+                            }
+                            card
+                        }));
                         //Sort for greater MCTS efficiency. Technically, this is different from STS
                         //with regards to All For One, but I will accept this for now.
                         self.fight.discard_pile.sort();
@@ -180,6 +356,123 @@ impl Game {
                         self.gold -= steal_amount;
                         self.fight.enemies[i].buffs.stolen_gold += steal_amount;
                     }
+                    EnemyAction::LifestealAttack(damage) => {
+                        let hp = self.player_hp;
+                        if let Some(choice) = self.enemy_attack(i, *damage) {
+                            return choice;
+                        }
+                        let stolen = hp - self.player_hp;
+                        if let Some(enemy) = &mut self.fight.enemies[i.0 as usize] {
+                            enemy.hp = min(enemy.max_hp, enemy.hp + stolen);
+                        }
+                    }
+                    EnemyAction::Summon => {
+                        self.summon(i);
+                    }
+                    EnemyAction::HealAll(amount) => {
+                        for idx in self.fight.enemies.indicies() {
+                            let enemy = &mut self.fight.enemies[idx];
+                            enemy.hp = min(enemy.max_hp, enemy.hp + amount);
+                        }
+                    }
+                    EnemyAction::BuffAll(buff) => {
+                        for idx in self.fight.enemies.indicies() {
+                            Self::buff_enemy(&mut self.fight.enemies[idx], *buff);
+                        }
+                    }
+                    EnemyAction::BlockOthers(amount) => {
+                        for idx in self.fight.enemies.indicies() {
+                            if idx.0 != i.0 {
+                                self.fight.enemies[idx].block += amount;
+                            }
+                        }
+                    }
+                    EnemyAction::DefendLeader(amount) => {
+                        let leader = self
+                            .fight
+                            .enemies
+                            .indicies()
+                            .into_iter()
+                            .find(|idx| !self.fight.enemies[*idx].buffs.minion)
+                            .unwrap_or(i);
+                        self.fight.enemies[leader].block += amount;
+                    }
+                    EnemyAction::MultiStab(damage) => {
+                        self.fight.enemies[i].buffs.stab_count += 1;
+                        for _ in 0..self.fight.enemies[i].buffs.stab_count {
+                            if let Some(choice) = self.enemy_attack(i, *damage) {
+                                return choice;
+                            }
+                            if self.fight.enemies.enemies[i.0 as usize].is_none() {
+                                break;
+                            }
+                        }
+                    }
+                    EnemyAction::ClearDebuffs => {
+                        let enemy = &mut self.fight.enemies[i];
+                        enemy.debuffs = Default::default();
+                        enemy.buffs.strength = max(enemy.buffs.strength, 0);
+                    }
+                    EnemyAction::Stasis => {
+                        self.stasis(i);
+                    }
+                    EnemyAction::ShuffleIntoDraw(cards) => {
+                        self.fight
+                            .deck
+                            .shuffle_in(cards.iter().map(|c| c.to_card()).collect());
+                    }
+                    EnemyAction::Explode(damage) => {
+                        if let Some(choice) = self.damage_player(*damage, false) {
+                            return choice;
+                        }
+                        self.kill_enemy(i.0 as usize);
+                    }
+                    EnemyAction::Die => {
+                        self.kill_enemy(i.0 as usize);
+                    }
+                    EnemyAction::Reincarnate => {
+                        let enemy = &mut self.fight.enemies[i];
+                        enemy.hp = enemy.max_hp / 2;
+                        enemy.buffs.half_dead = false;
+                    }
+                    EnemyAction::Rebirth => {
+                        let enemy = &mut self.fight.enemies[i];
+                        enemy.hp = enemy.max_hp;
+                        enemy.buffs.half_dead = false;
+                        enemy.buffs.unawakened = false;
+                        enemy.buffs.curiosity = 0;
+                        enemy.debuffs = Default::default();
+                        enemy.buffs.strength = max(enemy.buffs.strength, 0);
+                    }
+                    EnemyAction::HealToHalf => {
+                        let enemy = &mut self.fight.enemies[i];
+                        enemy.hp = max(enemy.hp, enemy.max_hp / 2);
+                    }
+                    EnemyAction::AddToDeck(card) => {
+                        self.add_card_to_deck(*card);
+                    }
+                    EnemyAction::AttackGainBlock(damage) => {
+                        self.fight.enemies[i].block += self.enemy_attack_damage(i, *damage);
+                        if let Some(choice) = self.enemy_attack(i, *damage) {
+                            return choice;
+                        }
+                    }
+                    EnemyAction::AddStab => {
+                        self.fight.enemies[i].buffs.stab_count += 1;
+                    }
+                    EnemyAction::ClearNegativeStrength => {
+                        let enemy = &mut self.fight.enemies[i];
+                        enemy.buffs.strength = max(enemy.buffs.strength, 0);
+                    }
+                }
+            }
+            //Fading enemies die at the end of their turn once it runs out.
+            if let Some(enemy) = &mut self.fight.enemies.enemies[i.0 as usize]
+                && enemy.buffs.fading > 0
+            {
+                enemy.buffs.fading -= 1;
+                if enemy.buffs.fading == 0 {
+                    self.kill_enemy(i.0 as usize);
                 }
             }
         }
@@ -187,9 +480,29 @@ impl Game {
             return self.win_battle();
         }
         self.reset_for_next_turn();
+        //Start of turn effects like Tools of the Trade can queue up actions.
+        if let Some(choice) = self.resolve_actions(None) {
+            return choice;
+        }
         self.play_card_choice()
     }
 
+    //Returns if the enemy died or went half dead, in which case it doesn't act.
+    fn poison_enemy(&mut self, i: EnemyIdx) -> bool {
+        let enemy = &mut self.fight.enemies[i];
+        enemy.debuffs.choked = 0;
+        let poison = enemy.debuffs.poison;
+        if poison == 0 || enemy.buffs.half_dead {
+            return false;
+        }
+        enemy.debuffs.poison -= 1;
+        self.enemy_lose_hp(poison, i.0 as usize);
+        match &self.fight.enemies.enemies[i.0 as usize] {
+            None => true,
+            Some(enemy) => enemy.buffs.half_dead,
+        }
+    }
+
     fn reset_for_next_turn(&mut self) {
         self.fight.turn_count += 1;
         for enemy_idx in self.fight.enemies.indicies() {
@@ -200,40 +513,194 @@ impl Game {
             enemy.buffs.ritual_skip_first = 0;
             decrement(&mut enemy.debuffs.vulnerable);
             decrement(&mut enemy.debuffs.weak);
+            decrement(&mut enemy.debuffs.lock_on);
             if enemy.buffs.metallicize > 0 {
                 enemy.block += enemy.buffs.metallicize;
             }
+            enemy.block += enemy.buffs.plated_armor;
+            enemy.buffs.malleable_hits = 0;
+            enemy.buffs.strength += enemy.buffs.shifted;
+            enemy.buffs.shifted = 0;
+            enemy.buffs.strength += mem::take(&mut enemy.debuffs.strength_down);
+            enemy.buffs.slow_stacks = 0;
+            enemy.buffs.invincible_left = enemy.buffs.invincible;
+            if enemy.buffs.phasing {
+                enemy.buffs.intangible = if enemy.buffs.intangible > 0 { 0 } else { 1 };
+            } else {
+                decrement(&mut enemy.buffs.intangible);
+            }
+            if !enemy.buffs.half_dead {
+                enemy.hp = min(enemy.max_hp, enemy.hp + enemy.buffs.regenerate);
+            }
         }
         decrement(&mut self.fight.player_debuffs.vulnerable);
         decrement(&mut self.fight.player_debuffs.weak);
         decrement(&mut self.fight.player_debuffs.frail);
+        decrement(&mut self.fight.player_debuffs.no_block);
+        decrement(&mut self.fight.player_buffs.intangible);
         self.fight.player_buffs.temp_spikes = 0;
         self.fight.player_buffs.rage = 0;
-        let draw_amount = 5 + self.fight.player_buffs.brutality;
+        let snecko_eye = if self.relics.has_relic(Relic::SneckoEye) {
+            2
+        } else {
+            0
+        };
+        let draw_amount = 5
+            + snecko_eye
+            + self.fight.player_buffs.brutality
+            + self.fight.player_buffs.machine_learning
+            + mem::take(&mut self.fight.player_buffs.draw_next_turn)
+            - mem::take(&mut self.fight.player_debuffs.draw_reduction);
         for _ in 0..draw_amount {
             self.fight.draw(&mut self.rng);
         }
         if self.fight.player_buffs.brutality > 0 {
             self.player_lose_hp(self.fight.player_buffs.brutality, true);
         }
-        if !self.fight.player_buffs.barricade {
+        if self.fight.player_buffs.barricade || self.fight.player_buffs.blur > 0 {
+            //Block is kept.
+        } else if self.relics.has_relic(Relic::Calipers) {
+            self.fight.player_block = max(self.fight.player_block - 15, 0);
+        } else {
             self.fight.player_block = 0;
         }
-        self.fight.energy = 3 + self.fight.player_buffs.energy_every_turn;
+        decrement(&mut self.fight.player_buffs.blur);
+        let block_next_turn = mem::take(&mut self.fight.player_buffs.block_next_turn);
+        self.player_gain_block(block_next_turn, false);
+        //Ice Cream keeps unspent energy.
+        let conserved = if self.relics.has_relic(Relic::IceCream) {
+            self.fight.energy
+        } else {
+            0
+        };
+        self.fight.energy = self.base_energy()
+            + conserved
+            + self.fight.player_buffs.energy_every_turn
+            + mem::take(&mut self.fight.player_buffs.energy_next_turn)
+            - self.fight.player_debuffs.energy_down;
         if self.relics.has_relic(Relic::ArtofWar) && self.fight.attacks_played_this_turn == 0 {
             self.fight.energy += 1;
         }
         self.fight.attacks_played_this_turn = 0;
+        self.fight.cards_discarded_this_turn = 0;
+        self.fight.cards_played_this_turn = 0;
+        self.start_of_turn_relics();
+        self.start_of_turn_powers();
+    }
+
+    fn start_of_turn_powers(&mut self) {
+        let buffs = &mut self.fight.player_buffs;
+        if buffs.phantasmal > 0 {
+            buffs.phantasmal -= 1;
+            buffs.double_damage += 1;
+        }
+        buffs.deva_energy += buffs.deva_form;
+        self.fight.energy += buffs.deva_energy;
+        if self.fight.stance == Stance::Divinity {
+            self.change_stance(Stance::Neutral);
+        }
+        if mem::take(&mut self.fight.player_buffs.blasphemy) {
+            self.player_lose_hp(99999, false);
+        }
+        let simmering_fury = mem::take(&mut self.fight.player_buffs.simmering_fury);
+        if simmering_fury > 0 {
+            self.change_stance(Stance::Wrath);
+            self.fight
+                .post_card_queue
+                .push_back(PostCardItem::Draw(simmering_fury));
+        }
+        self.gain_mantra(self.fight.player_buffs.devotion);
+        for _ in 0..self.fight.player_buffs.battle_hymn {
+            self.gen_temp_card(CardBody::Smite, false);
+        }
+        if self.fight.player_buffs.collect > 0 {
+            self.fight.player_buffs.collect -= 1;
+            let mut miracle = self.create_card(CardBody::Miracle);
+            if miracle.can_upgrade() {
+                miracle.upgrade();
+            }
+            self.add_card_to_hand(miracle);
+        }
+        //The scry happens after the draw, unlike in STS.
+        if self.fight.player_buffs.foresight > 0 {
+            self.fight
+                .post_card_queue
+                .push_back(PostCardItem::Scry(self.fight.player_buffs.foresight));
+        }
+        for card in mem::take(&mut self.fight.nightmare) {
+            self.add_card_to_hand(card);
+        }
+        for _ in 0..self.fight.player_buffs.infinite_blades {
+            self.gen_temp_card(CardBody::Shiv, false);
+        }
+        let noxious_fumes = self.fight.player_buffs.noxious_fumes;
+        if noxious_fumes > 0 {
+            for idx in self.fight.enemies.indicies() {
+                apply_debuff_to_enemy(&mut self.fight.enemies[idx], Debuff::Poison(noxious_fumes));
+            }
+        }
+        self.trigger_start_of_turn_orbs();
+        let biased_cognition = self.fight.player_buffs.biased_cognition;
+        if biased_cognition > 0 {
+            self.apply_debuff_to_player(Debuff::MinusFocus(biased_cognition));
+        }
+        for _ in 0..self.fight.player_buffs.creative_ai {
+            let body = sample_card(DEFECT_POWER_CARDS, &mut self.rng);
+            self.gen_temp_card(body, false);
+        }
+        for _ in 0..self.fight.player_buffs.hello_world {
+            let body = sample_card(DEFECT_COMMON_CARDS, &mut self.rng);
+            self.gen_temp_card(body, false);
+        }
+        let tools_of_the_trade = self.fight.player_buffs.tools_of_the_trade;
+        if tools_of_the_trade > 0 {
+            self.fight
+                .post_card_queue
+                .push_back(PostCardItem::Draw(tools_of_the_trade));
+            for _ in 0..tools_of_the_trade {
+                self.fight
+                    .post_card_queue
+                    .push_back(PostCardItem::SelectCard(SelectCardEffect::DiscardChosen));
+            }
+        }
+        for _ in 0..self.fight.player_buffs.magnetism {
+            let body = sample_card(COLORLESS_CARDS, &mut self.rng);
+            self.gen_temp_card(body, false);
+        }
+        //Mayhem plays the top card of the deck without exhausting it.
+        for _ in 0..self.fight.player_buffs.mayhem {
+            //Start of turn relics like Mercury Hourglass can already have won the fight.
+            if self.fight.enemies.len() == 0 {
+                break;
+            }
+            if let Some(card) = self.fight.remove_top_of_deck(&mut self.rng) {
+                let target = self.select_random_target(&card);
+                self.fight
+                    .post_card_queue
+                    .push_back(PostCardItem::PlayCard(PlayCardContext {
+                        card,
+                        target,
+                        real_card: true,
+                        exhausts: false,
+                        effect_index: 0,
+                        x: self.fight.energy,
+                        rebound: false,
+                        shuffles_back: false,
+                    }));
+            }
+        }
     }
 
     fn discard_hand_end_of_turn(&mut self) {
-        let hand_size = self.fight.hand.len();
+        self.trigger_end_of_turn_orbs();
+        let hand_size = self.fight.hand.len() + self.fight.retained.len();
         let mut old_hand = Vec::new();
         mem::swap(&mut old_hand, &mut self.fight.hand);
         for mut card in old_hand {
             card.temp_cost = None;
             if card.body == CardBody::Burn {
-                self.damage_player(2, true);
+                let damage = if card.is_upgraded() { 4 } else { 2 };
+                self.damage_player(damage, true);
             }
             if card.body == CardBody::Regret {
                 self.player_lose_hp(hand_size as i32, true);
@@ -241,27 +708,69 @@ impl Game {
             if card.body == CardBody::Doubt {
                 self.apply_debuff_to_player(Debuff::Weak(1));
             }
-            if card.ethereal() {
+            if card.body == CardBody::Decay {
+                self.damage_player(2, true);
+            }
+            if card.body == CardBody::Shame {
+                self.apply_debuff_to_player(Debuff::Frail(1));
+            }
+            if card.retain() || (self.fight.player_buffs.equilibrium > 0 && !card.ethereal()) {
+                self.fight.retained.push(card);
+            } else if card.ethereal() {
                 self.exhaust(card);
             } else {
                 insert_sorted(card, &mut self.fight.discard_pile);
             }
         }
-        //TODO handle artifact.
-        self.fight.player_buffs.strength -= self.fight.player_debuffs.strength_down;
-        self.fight.player_debuffs.strength_down = 0;
-        self.fight.player_buffs.dexterity -= self.fight.player_debuffs.dexterity_down;
-        self.fight.player_debuffs.dexterity_down = 0;
+        for mut card in mem::take(&mut self.fight.retained) {
+            self.on_retain(&mut card);
+            insert_sorted(card, &mut self.fight.hand);
+        }
+        if self.fight.player_debuffs.constricted > 0 {
+            self.damage_player(self.fight.player_debuffs.constricted, false);
+        }
+        //Losing strength or dexterity at the end of the turn is a new debuff, so Artifact can block it.
+        let strength_down = mem::take(&mut self.fight.player_debuffs.strength_down);
+        if strength_down > 0 {
+            self.apply_debuff_to_player(Debuff::MinusStrength(strength_down));
+        }
+        let dexterity_down = mem::take(&mut self.fight.player_debuffs.dexterity_down);
+        if dexterity_down > 0 {
+            self.apply_debuff_to_player(Debuff::MinusDexterity(dexterity_down));
+        }
         self.fight.player_buffs.strength += self.fight.player_buffs.ritual;
         self.fight.player_debuffs.entangled = false;
         self.fight.player_debuffs.no_draw = false;
         self.fight.player_buffs.double_tap = 0;
+        self.fight.player_buffs.burst = 0;
+        self.fight.player_buffs.amplify = 0;
+        self.fight.player_buffs.wave_of_the_hand = 0;
+        decrement(&mut self.fight.player_buffs.equilibrium);
+        decrement(&mut self.fight.player_buffs.double_damage);
+        if self.fight.player_buffs.wraith_form > 0 {
+            self.apply_debuff_to_player(Debuff::MinusDexterity(
+                self.fight.player_buffs.wraith_form,
+            ));
+        }
         for i in self.fight.enemies.indicies() {
-            self.fight.enemies[i].block = 0;
+            if !self.fight.enemies[i].buffs.barricade {
+                self.fight.enemies[i].block = 0;
+            }
+        }
+        if self.fight.player_block == 0 && self.relics.has_relic(Relic::Orichalum) {
+            self.player_gain_block(6, false);
         }
         if self.fight.player_buffs.metallicize > 0 {
             self.player_gain_block(self.fight.player_buffs.metallicize, false);
         }
+        self.player_gain_block(self.fight.player_buffs.plated_armor, false);
+        if self.fight.stance == Stance::Calm {
+            self.player_gain_block(self.fight.player_buffs.like_water, false);
+        }
+        for _ in 0..self.fight.player_buffs.study {
+            let insight = self.create_card(CardBody::Insight);
+            self.fight.deck.shuffle_in(vec![insight]);
+        }
         if self.fight.player_buffs.end_turn_lose_hp > 0 {
             self.player_lose_hp(self.fight.player_buffs.end_turn_lose_hp, true);
         }
@@ -271,15 +780,66 @@ impl Game {
                 self.damage_enemy(damage_all_enemies, idx.0 as usize, false);
             }
         }
+        //Stone Calendar hits at the end of the 7th turn. The turn count starts at 0.
+        if self.fight.turn_count == 6 && self.relics.has_relic(Relic::StoneCalendar) {
+            for idx in self.fight.enemies.indicies() {
+                self.damage_enemy(52, idx.0 as usize, false);
+            }
+        }
+        if self.fight.cards_played_this_turn <= 3 && self.relics.has_relic(Relic::Pocketwatch) {
+            self.fight.player_buffs.draw_next_turn += 3;
+        }
+        let [bomb, next, last] = self.fight.player_buffs.the_bomb;
+        self.fight.player_buffs.the_bomb = [next, last, 0];
+        if bomb > 0 {
+            for idx in self.fight.enemies.indicies() {
+                self.damage_enemy(bomb, idx.0 as usize, false);
+            }
+        }
+    }
+
+    //Some retained cards get better each turn they are held.
+    fn on_retain(&self, card: &mut Card) {
+        let upgraded = card.is_upgraded();
+        match card.body {
+            CardBody::Perseverance => {
+                card.assoc_data = card.assoc_data.grow(if upgraded { 3 } else { 2 });
+            }
+            CardBody::WindmillStrike => {
+                card.assoc_data = card.assoc_data.grow(if upgraded { 5 } else { 4 });
+            }
+            CardBody::SandsOfTime => card.reduce_cost(1),
+            _ => {}
+        }
+        card.reduce_cost(self.fight.player_buffs.establishment);
     }
 
     //TODO handle various effects of HP loss.
-    fn player_lose_hp(&mut self, amount: i32, from_card: bool) {
+    fn player_lose_hp(&mut self, mut amount: i32, from_card: bool) {
         if amount <= 0 {
             return;
         }
+        if self.fight.player_buffs.buffer > 0 {
+            self.fight.player_buffs.buffer -= 1;
+            return;
+        }
+        if self.relics.has_relic(Relic::TungstenRod) {
+            amount -= 1;
+            if amount == 0 {
+                return;
+            }
+        }
         self.fight.player_buffs.num_times_lost_hp += 1;
         self.player_hp -= amount;
+        if self.player_hp <= 0 {
+            self.try_use_fairy();
+        }
+        //Lizard Tail's counter is set once it has been used.
+        if self.player_hp <= 0 && self.relics.counter(Relic::LizardTail) == Some(0) {
+            self.relics.set_counter(Relic::LizardTail, 1);
+            self.player_hp = 0;
+            self.heal(self.player_max_hp / 2);
+        }
         if from_card && self.fight.player_buffs.rupture > 0 {
             self.apply_buff_to_player(Buff::Strength(self.fight.player_buffs.rupture));
         }
@@ -311,6 +871,9 @@ impl Game {
         //Record the cost of an X spell before it is spent.
         let x = fight.energy;
         fight.energy -= cost;
+        if card.body.card_type() == CardType::Attack {
+            decrement(&mut fight.player_buffs.free_attack);
+        }
         let mut context = PlayCardContext {
             card,
             target,
@@ -318,7 +881,16 @@ impl Game {
             exhausts: false,
             effect_index: 0,
             x,
+            rebound: mem::take(&mut fight.rebound),
+            shuffles_back: false,
         };
+        //Curses and statuses played with Blue Candle or Medical Kit are exhausted.
+        if matches!(context.card.cost, Cost::Unplayable) {
+            context.exhausts = true;
+            if context.card.body.card_type() == CardType::Curse {
+                self.player_lose_hp(1, true);
+            }
+        }
         self.trigger_play_card_effects(&mut context);
         if let Some(choice) = self.resolve_actions(Some(context)) {
             return choice;
@@ -353,13 +925,19 @@ impl Game {
                     //If the player needs to make a selection, break out of the loop. It will be
                     //resumed by calling resolve_actions again once the player makes their choice
                     //and the in-progress action is handled.
-                    if let ActionControlFlow::SelectCards(select, select_action, t) = next {
-                        return Some(Choice::SelectCardState(
-                            card_context,
-                            select_action,
-                            select,
-                            t,
-                        ));
+                    match next {
+                        ActionControlFlow::Continue => {}
+                        ActionControlFlow::SelectCards(select, select_action, t) => {
+                            return Some(Choice::SelectCardState(
+                                Some(card_context),
+                                select_action,
+                                select,
+                                t,
+                            ));
+                        }
+                        ActionControlFlow::Scry => {
+                            return Some(self.scry_choice(Some(card_context)));
+                        }
                     }
                     context = Some(card_context);
                 } else {
@@ -370,17 +948,34 @@ impl Game {
                             enemy.block += enemy.buffs.queued_block;
                             enemy.buffs.queued_block = 0;
                         }
+                        //Slow counts cards after they are played.
+                        if enemy.buffs.slow {
+                            enemy.buffs.slow_stacks += 1;
+                        }
                     }
                     card_context.card.temp_cost = None;
                     if card_context.card.body.card_type() == CardType::Attack {
                         self.fight.player_buffs.vigor = 0;
+                        self.fight.player_buffs.pen_nib = false;
+                        self.fight.attacks_played_this_turn += 1;
+                    }
+                    if card_context.card.body.card_type() == CardType::Power {
+                        self.fight.powers_played += 1;
                     }
+                    if card_context.real_card {
+                        self.fight.cards_played_this_turn += 1;
+                    }
+                    self.fight.last_card_type = Some(card_context.card.body.card_type());
                     if card_context.card.body.card_type() == CardType::Power
                         || !card_context.real_card
                     {
                         //Do nothing for powers or duplicated cards, they just go away after playing.
                     } else if card_context.exhausts {
                         self.exhaust(card_context.card);
+                    } else if card_context.rebound {
+                        self.put_on_top(card_context.card);
+                    } else if card_context.shuffles_back {
+                        self.fight.deck.shuffle_in(vec![card_context.card]);
                     } else {
                         insert_sorted(card_context.card, &mut self.fight.discard_pile);
                     }
@@ -415,7 +1010,36 @@ impl Game {
                             let enemy = self.choose_random_enemy();
                             self.damage_enemy(amount, enemy, false);
                         }
+                        PostCardItem::SelectCard(effect) => {
+                            let (targets, pile) = self.select_card_targets(effect);
+                            if !targets.is_empty() {
+                                return Some(Choice::SelectCardState(None, effect, targets, pile));
+                            }
+                        }
+                        PostCardItem::Channel(orb) => {
+                            self.channel(orb);
+                        }
+                        PostCardItem::Scry(amount) => {
+                            if self.scry(amount) {
+                                return Some(self.scry_choice(None));
+                            }
+                        }
+                        PostCardItem::Reshuffle => {
+                            if self.relics.tick(Relic::Sundial, 3) {
+                                self.fight.energy += 2;
+                            }
+                        }
+                        PostCardItem::DeusExMachina(card) => {
+                            let miracles = if card.is_upgraded() { 3 } else { 2 };
+                            self.exhaust(card);
+                            for _ in 0..miracles {
+                                self.gen_temp_card(CardBody::Miracle, false);
+                            }
+                        }
                     }
+                } else if self.fight.end_turn_early {
+                    self.fight.end_turn_early = false;
+                    return Some(self.enemy_phase());
                 } else {
                     return None;
                 }
@@ -437,11 +1061,106 @@ impl Game {
             && context.real_card
         {
             self.fight.player_buffs.double_tap -= 1;
-            let mut new_context = context.clone();
-            new_context.real_card = false;
+            self.queue_replay(context);
+        }
+        if self.fight.player_buffs.burst > 0
+            && context.card.body.card_type() == CardType::Skill
+            && context.real_card
+        {
+            self.fight.player_buffs.burst -= 1;
+            self.queue_replay(context);
+        }
+        if self.fight.player_buffs.amplify > 0
+            && context.card.body.card_type() == CardType::Power
+            && context.real_card
+        {
+            self.fight.player_buffs.amplify -= 1;
+            self.queue_replay(context);
+        }
+        if self.fight.cards_played_this_turn < self.fight.player_buffs.echo_form
+            && context.real_card
+        {
+            self.queue_replay(context);
+        }
+        if context.card.body.card_type() == CardType::Power {
+            let heatsinks = self.fight.player_buffs.heatsinks;
+            if heatsinks > 0 {
+                self.fight
+                    .post_card_queue
+                    .push_back(PostCardItem::Draw(heatsinks));
+            }
+            for _ in 0..self.fight.player_buffs.storm {
+                self.fight
+                    .post_card_queue
+                    .push_back(PostCardItem::Channel(Orb::Lightning));
+            }
+        }
+        if self.fight.player_buffs.after_image > 0 {
+            self.fight
+                .post_card_queue
+                .push_back(PostCardItem::GainBlock(self.fight.player_buffs.after_image));
+        }
+        if context.real_card {
+            if context.card.body.card_type() == CardType::Attack {
+                if self.relics.tick(Relic::PenNib, 10) {
+                    self.fight.player_buffs.pen_nib = true;
+                }
+                if self.relics.tick(Relic::Nunchaku, 10) {
+                    self.fight.energy += 1;
+                }
+            }
+            if self.relics.tick(Relic::InkBottle, 10) {
+                self.fight.post_card_queue.push_back(PostCardItem::Draw(1));
+            }
+            self.card_type_relics(context.card.body.card_type());
+        }
+        //Panache goes off on every fifth card played each turn.
+        let panache = self.fight.player_buffs.panache;
+        if panache > 0 && context.real_card && (self.fight.cards_played_this_turn + 1) % 5 == 0 {
+            self.fight
+                .post_card_queue
+                .push_back(PostCardItem::DamageAll(panache));
+        }
+        if self.fight.player_buffs.thousand_cuts > 0 {
             self.fight
                 .post_card_queue
-                .push_back(PostCardItem::PlayCard(new_context));
+                .push_back(PostCardItem::DamageAll(
+                    self.fight.player_buffs.thousand_cuts,
+                ));
+        }
+        //Losing HP can remove other enemies, like minions fleeing or Corpse Explosion.
+        for idx in self.fight.enemies.indicies() {
+            let Some(enemy) = &self.fight.enemies[idx.0 as usize] else {
+                continue;
+            };
+            let choked = enemy.debuffs.choked;
+            if choked > 0 {
+                self.enemy_lose_hp(choked, idx.0 as usize);
+            }
+            if self.fight.enemies.len() == 0 {
+                return;
+            }
+        }
+        let pain_count = self
+            .fight
+            .hand
+            .iter()
+            .filter(|card| card.body == CardBody::Pain)
+            .count();
+        self.player_lose_hp(pain_count as i32, true);
+        if context.card.body.card_type() == CardType::Attack {
+            for idx in self.fight.enemies.indicies() {
+                let sharp_hide = self.fight.enemies[idx].buffs.sharp_hide;
+                if sharp_hide > 0 {
+                    self.damage_player(sharp_hide, false);
+                }
+            }
+        }
+        let hex = self.fight.player_debuffs.hex;
+        if hex > 0 && context.card.body.card_type() != CardType::Attack {
+            self.fight
+                .deck
+                .shuffle_in(vec![CardBody::Dazed.to_card(); hex as usize]);
         }
         if context.card.body.card_type() == CardType::Skill {
             for idx in self.fight.enemies.indicies() {
@@ -451,12 +1170,87 @@ impl Game {
                 }
             }
         }
+        for idx in self.fight.enemies.indicies() {
+            let beat_of_death = self.fight.enemies[idx].buffs.beat_of_death;
+            if beat_of_death > 0 {
+                self.damage_player(beat_of_death, false);
+            }
+            let enemy = &mut self.fight.enemies[idx];
+            if context.card.body.card_type() == CardType::Power && !enemy.buffs.half_dead {
+                enemy.buffs.strength += enemy.buffs.curiosity;
+            }
+            if enemy.buffs.time_warp {
+                enemy.buffs.time_warp_cards += 1;
+                if enemy.buffs.time_warp_cards == 12 {
+                    enemy.buffs.time_warp_cards = 0;
+                    enemy.buffs.strength += 2;
+                    self.fight.end_turn_early = true;
+                }
+            }
+        }
+    }
+
+    //Relics which count the cards of a type played each turn, or trigger on powers.
+    fn card_type_relics(&mut self, card_type: CardType) {
+        match card_type {
+            CardType::Attack => {
+                if self.relics.tick(Relic::Kunai, 3) {
+                    self.apply_buff_to_player(Buff::Dexterity(1));
+                }
+                if self.relics.tick(Relic::Shuriken, 3) {
+                    self.apply_buff_to_player(Buff::Strength(1));
+                }
+                if self.relics.tick(Relic::OrnamentalFan, 3) {
+                    self.player_gain_block(4, false);
+                }
+            }
+            CardType::Skill => {
+                if self.relics.tick(Relic::LetterOpener, 3) {
+                    self.fight
+                        .post_card_queue
+                        .push_back(PostCardItem::DamageAll(5));
+                }
+            }
+            CardType::Power => {
+                if self.relics.has_relic(Relic::BirdFacedUrn) {
+                    self.heal(2);
+                }
+                if self.relics.has_relic(Relic::MummifiedHand) {
+                    let costly: Vec<usize> = (0..self.fight.hand.len())
+                        .filter(|i| {
+                            let card = &self.fight.hand[*i];
+                            !matches!(card.cost, Cost::X)
+                                && self.fight.evaluate_cost(card).is_some_and(|cost| cost > 0)
+                        })
+                        .collect();
+                    if let Some(idx) = self.rng.try_sample(costly.len()) {
+                        self.fight.hand[costly[idx]].temp_cost = Some(0);
+                        self.fight.hand.sort();
+                    }
+                }
+            }
+            CardType::Status | CardType::Curse => {}
+        }
+    }
+
+    //The copy is played after the original card resolves, but doesn't go to a pile.
+    fn queue_replay(&mut self, context: &PlayCardContext) {
+        let mut new_context = context.clone();
+        new_context.real_card = false;
+        self.fight
+            .post_card_queue
+            .push_back(PostCardItem::PlayCard(new_context));
     }
 
     fn exhaust(&mut self, card: Card) {
         let body = card.body;
         let upgraded = card.is_upgraded();
         insert_sorted(card, &mut self.fight.exhaust);
+        if self.relics.has_relic(Relic::DeadBranch) {
+            let body = sample_card(self.class_cards(), &mut self.rng);
+            let card = self.create_card(body);
+            self.add_card_to_hand(card);
+        }
         if self.fight.player_buffs.dark_embrace > 0 {
             self.fight
                 .post_card_queue
@@ -475,8 +1269,27 @@ impl Game {
     }
 
     fn win_battle(&mut self) -> Choice {
+        if self.relics.has_relic(Relic::BurningBlood) {
+            self.heal(6);
+        }
+        self.heal(self.fight.player_buffs.self_repair);
+        if self.relics.has_relic(Relic::MeatontheBone) && self.player_hp * 2 <= self.player_max_hp {
+            self.heal(12);
+        }
+        if self.fight.rewards.emerald_key {
+            self.keys.emerald = true;
+        }
+        //Beating the Heart wins the run.
+        if self.act.number == 4 && self.act.room_history.last() == Some(&RoomType::Boss) {
+            return Choice::Win;
+        }
+        if let Some(event) = self.fight.rewards.resume_event.take() {
+            self.fight = Fight::default();
+            return event.into_choice(self);
+        }
+        let rewards = self.generate_fight_rewards();
         self.fight = Fight::default();
-        self.goto_map()
+        self.goto_rewards(rewards)
     }
 
     //Used for Shield Gremlin.
@@ -502,29 +1315,157 @@ impl Game {
         }
     }
 
+    //Returns if the kill counts for effects like Feed. Minions don't.
+    fn kill_enemy(&mut self, idx: usize) -> bool {
+        let Some(enemy) = &self.fight.enemies[idx] else {
+            return false;
+        };
+        let minion = enemy.buffs.minion;
+        if enemy.buffs.spore_cloud > 0 {
+            self.fight.player_debuffs.vulnerable += 2;
+        }
+        let explosion = enemy.max_hp * enemy.debuffs.corpse_explosion;
+        self.fight.stolen_back_gold += enemy.buffs.stolen_gold;
+        self.remove_enemy(idx);
+        if self.fight.enemies.len() > 0 && self.relics.has_relic(Relic::GremlinHorn) {
+            self.fight.energy += 1;
+            self.fight.post_card_queue.push_back(PostCardItem::Draw(1));
+        }
+        if explosion > 0 {
+            for other in self.fight.enemies.indicies() {
+                self.damage_enemy(explosion, other.0 as usize, false);
+            }
+        }
+        //Minions flee and half dead Darklings die once there is nothing else left.
+        let leaders_alive =
+            self.fight.enemies.enemies.iter().flatten().any(|enemy| {
+                !(enemy.buffs.minion || (enemy.buffs.regrow && enemy.buffs.half_dead))
+            });
+        if !leaders_alive {
+            for idx in self.fight.enemies.indicies() {
+                self.remove_enemy(idx.0 as usize);
+            }
+        }
+        !minion
+    }
+
+    //Bronze Orbs return the card they took with Stasis when they are removed.
+    fn remove_enemy(&mut self, idx: usize) {
+        if let Some(enemy) = self.fight.enemies[idx].take()
+            && let Some(card) = enemy.buffs.stasis
+        {
+            self.add_card_to_hand(card);
+        }
+    }
+
+    //Summoned minions fill the leader's empty slots.
+    fn summon(&mut self, i: EnemyIdx) {
+        let name = self.fight.enemies[i].name;
+        let slots: &[usize] = match name {
+            EnemyName::GremlinLeader => &[0, 1, 2],
+            EnemyName::TheCollector => &[0, 1],
+            EnemyName::BronzeAutomaton => &[0, 2],
+            EnemyName::Reptomancer => &[1, 3, 0, 4],
+            _ => panic!("Summoning not implemented for {:?}", name),
+        };
+        //Reptomancer summons one Dagger at a time, two from Ascension 18.
+        let mut count = if name == EnemyName::Reptomancer {
+            if self.ascension >= 18 { 2 } else { 1 }
+        } else {
+            slots.len()
+        };
+        for slot in slots {
+            if self.fight.enemies[*slot].is_some() || count == 0 {
+                continue;
+            }
+            count -= 1;
+            let minion = match name {
+                EnemyName::GremlinLeader => self.generate_minion_gremlin(),
+                EnemyName::TheCollector => generate_torch_head(&mut self.rng, self.ascension),
+                EnemyName::Reptomancer => generate_dagger(&mut self.rng, self.ascension),
+                _ => generate_bronze_orb(&mut self.rng, self.ascension),
+            };
+            self.spawn_enemy(*slot, minion);
+        }
+    }
+
+    //Stasis takes the rarest card from the draw pile, or the discard pile if the draw pile is empty.
+    fn stasis(&mut self, i: EnemyIdx) {
+        fn priority(card: &Card) -> i32 {
+            match card.body.rarity() {
+                CardRarity::Rare => 3,
+                CardRarity::Uncommon => 2,
+                CardRarity::Common => 1,
+                _ => 0,
+            }
+        }
+        let card = if self.fight.deck.len() > 0 {
+            (0..=3)
+                .rev()
+                .find_map(|p| self.fight.deck.take(&|card: &Card| priority(card) == p))
+        } else {
+            (0..self.fight.discard_pile.len())
+                .max_by_key(|idx| priority(&self.fight.discard_pile[*idx]))
+                .map(|idx| self.fight.discard_pile.remove(idx))
+        };
+        self.fight.enemies[i].buffs.stasis = card;
+    }
+
     fn split(&mut self, i: EnemyIdx) {
         let hp = self.fight.enemies[i].hp;
         let name = self.fight.enemies[i].name;
-        if name == EnemyName::LargeBlackSlime {
-            let mut med_slime_1 = generate_med_black_slime(&mut self.rng);
-            med_slime_1.max_hp = hp;
-            med_slime_1.hp = hp;
-            let mut med_slime_2 = generate_med_black_slime(&mut self.rng);
-            med_slime_2.max_hp = hp;
-            med_slime_2.hp = hp;
-            self.fight.enemies[(i.0) as usize] = Some(med_slime_1);
-            self.fight.enemies[(i.0 + 1) as usize] = Some(med_slime_2);
-        } else if name == EnemyName::LargeGreenSlime {
-            let mut med_slime_1 = generate_med_green_slime(&mut self.rng);
-            med_slime_1.max_hp = hp;
-            med_slime_1.hp = hp;
-            let mut med_slime_2 = generate_med_green_slime(&mut self.rng);
-            med_slime_2.max_hp = hp;
-            med_slime_2.hp = hp;
-            self.fight.enemies[(i.0) as usize] = Some(med_slime_1);
-            self.fight.enemies[(i.0 + 1) as usize] = Some(med_slime_2);
-        } else {
-            panic!("Splitting not implemented for {:?}", name);
+        let (mut first, mut second) = match name {
+            EnemyName::LargeBlackSlime => (
+                generate_med_black_slime(&mut self.rng, self.ascension),
+                generate_med_black_slime(&mut self.rng, self.ascension),
+            ),
+            EnemyName::LargeGreenSlime => (
+                generate_med_green_slime(&mut self.rng, self.ascension),
+                generate_med_green_slime(&mut self.rng, self.ascension),
+            ),
+            EnemyName::SlimeBoss => (
+                generate_large_green_slime(&mut self.rng, self.ascension),
+                generate_large_black_slime(&mut self.rng, self.ascension),
+            ),
+            _ => panic!("Splitting not implemented for {:?}", name),
+        };
+        first.max_hp = hp;
+        first.hp = hp;
+        second.max_hp = hp;
+        second.hp = hp;
+        self.spawn_enemy(i.0 as usize, first);
+        self.spawn_enemy((i.0 + 1) as usize, second);
+    }
+
+    //Discarding from the hand triggers cards like Reflex and Tactician, unlike the end of turn discard.
+    fn discard_from_hand(&mut self, idx: usize) {
+        let mut card = self.fight.hand.remove(idx);
+        card.temp_cost = None;
+        self.fight.cards_discarded_this_turn += 1;
+        let bonus = card.is_upgraded() as i32;
+        match card.body {
+            CardBody::Reflex => {
+                self.fight
+                    .post_card_queue
+                    .push_back(PostCardItem::Draw(2 + bonus));
+            }
+            CardBody::Tactician => {
+                self.fight
+                    .post_card_queue
+                    .push_back(PostCardItem::GainEnergy(1 + bonus));
+            }
+            _ => {}
+        }
+        insert_sorted(card, &mut self.fight.discard_pile);
+    }
+
+    fn add_shivs(&mut self, count: i32, upgraded: bool) {
+        for _ in 0..count {
+            let mut card = self.create_card(CardBody::Shiv);
+            if upgraded && card.can_upgrade() {
+                card.upgrade();
+            }
+            self.add_card_to_hand(card);
         }
     }
 
@@ -552,42 +1493,109 @@ impl Game {
                 }
                 _ => 1,
             });
-        let mut damage: f32 = (amount + strength + self.fight.player_buffs.vigor) as f32;
+        let strike_dummy = if card.body.is_strike() && self.relics.has_relic(Relic::StrikeDummy) {
+            3
+        } else {
+            0
+        };
+        let mut damage: f32 =
+            (amount + strength + self.fight.player_buffs.vigor + strike_dummy) as f32;
+        damage *= self.fight.stance.damage_dealt_multiplier();
         let Some(enemy) = &mut self.fight.enemies[target] else {
             return AttackResult::default();
         };
+        let block_return = enemy.debuffs.block_return;
         if enemy.debuffs.vulnerable > 0 {
             damage *= 1.5;
         }
         if self.fight.player_debuffs.weak > 0 {
             damage *= 0.75;
         }
+        if enemy.buffs.flying > 0 {
+            damage *= 0.5;
+        }
+        if enemy.buffs.slow {
+            damage *= 1.0 + 0.1 * enemy.buffs.slow_stacks as f32;
+        }
+        if self.fight.player_buffs.double_damage > 0 {
+            damage *= 2.0;
+        }
+        if self.fight.player_buffs.pen_nib {
+            damage *= 2.0;
+        }
         let damage = damage as i32;
-        self.damage_enemy(damage, target, true)
+        let result = self.damage_enemy(damage, target, true);
+        self.player_gain_block(block_return, false);
+        let envenom = self.fight.player_buffs.envenom;
+        if result.damage_dealt > 0
+            && envenom > 0
+            && let Some(enemy) = &mut self.fight.enemies[target]
+        {
+            apply_debuff_to_enemy(enemy, Debuff::Poison(envenom));
+        }
+        result
+    }
+
+    fn damage_enemy(&mut self, damage: i32, target: usize, from_card: bool) -> AttackResult {
+        self.hurt_enemy(damage, target, from_card, true)
+    }
+
+    //Losing HP, like from poison, ignores block.
+    fn enemy_lose_hp(&mut self, amount: i32, target: usize) -> AttackResult {
+        self.hurt_enemy(amount, target, false, false)
     }
 
-    fn damage_enemy(&mut self, mut damage: i32, target: usize, from_card: bool) -> AttackResult {
+    fn hurt_enemy(
+        &mut self,
+        mut damage: i32,
+        target: usize,
+        from_card: bool,
+        blockable: bool,
+    ) -> AttackResult {
+        //Darklings only regrow if another Darkling is still standing.
+        let can_regrow = self.fight.enemies.indicies().any(|idx| {
+            let enemy = &self.fight.enemies[idx];
+            idx.0 as usize != target && enemy.buffs.regrow && !enemy.buffs.half_dead
+        });
+        let the_boot = from_card && self.relics.has_relic(Relic::TheBoot);
         let Some(enemy) = &mut self.fight.enemies[target] else {
             return AttackResult::default();
         };
-        if damage <= 0 {
+        if damage <= 0 || enemy.buffs.half_dead {
             return AttackResult::default();
         }
-        if damage < enemy.block {
-            enemy.block -= damage;
-            damage = 0;
-        } else {
-            damage -= enemy.block;
-            enemy.block = 0;
+        if enemy.buffs.intangible > 0 {
+            damage = min(damage, 1);
+        }
+        if blockable {
+            if damage < enemy.block {
+                enemy.block -= damage;
+                damage = 0;
+            } else {
+                damage -= enemy.block;
+                enemy.block = 0;
+            }
+        }
+        if the_boot && damage > 0 && damage < 5 {
+            damage = 5;
         }
         damage = min(damage, enemy.hp);
+        if enemy.buffs.invincible > 0 {
+            damage = min(damage, enemy.buffs.invincible_left);
+            enemy.buffs.invincible_left -= damage;
+        }
         enemy.hp -= damage as i32;
+        if damage > 0 && enemy.buffs.shifting {
+            enemy.buffs.strength -= damage;
+            enemy.buffs.shifted += damage;
+        }
         if damage > 0 && enemy.buffs.asleep {
             enemy.buffs.asleep = false;
             enemy.buffs.metallicize = 0;
             //This is the AI state for Lagabulin when it wakes up. No other
             //enemies sleep so this is
             enemy.ai_state = 2;
+            enemy.last_move = None;
         }
         if damage > 0 && from_card {
             if enemy.buffs.curl_up > 0 {
@@ -595,14 +1603,55 @@ impl Game {
                 enemy.buffs.curl_up = 0;
             }
             enemy.buffs.strength += enemy.buffs.angry;
+            if enemy.buffs.flying > 0 {
+                enemy.buffs.flying -= 1;
+                if enemy.buffs.flying == 0 {
+                    enemy.ai_state = GROUNDED_STATE;
+                    enemy.last_move = None;
+                }
+            }
+            if enemy.buffs.plated_armor > 0 {
+                enemy.buffs.plated_armor -= 1;
+                if enemy.buffs.plated_armor == 0 && enemy.name == EnemyName::ShelledParasite {
+                    enemy.ai_state = STUNNED_STATE;
+                    enemy.last_move = None;
+                }
+            }
+            if enemy.buffs.malleable > 0 && enemy.hp > 0 {
+                enemy.buffs.queued_block += enemy.buffs.malleable + enemy.buffs.malleable_hits;
+                enemy.buffs.malleable_hits += 1;
+            }
+            if enemy.buffs.reactive && enemy.hp > 0 {
+                enemy.ai_state = writhing_mass::reroll(&mut self.rng, enemy.ai_state);
+            }
+        }
+        if damage > 0 && enemy.buffs.mode_shift > 0 {
+            enemy.buffs.mode_shift -= damage;
+            if enemy.buffs.mode_shift <= 0 {
+                enemy.buffs.mode_shift = 0;
+                enemy.buffs.mode_shift_threshold += MODE_SHIFT_INCREASE;
+                enemy.block += MODE_SHIFT_BLOCK;
+                enemy.ai_state = DEFENSIVE_MODE_STATE;
+                enemy.last_move = None;
+            }
         }
         let lethal = if enemy.hp <= 0 {
-            if enemy.buffs.spore_cloud > 0 {
-                self.fight.player_debuffs.vulnerable += 2;
+            if enemy.buffs.unawakened {
+                //The Awakened One is reborn on its next turn.
+                enemy.buffs.half_dead = true;
+                enemy.debuffs = Default::default();
+                enemy.ai_state = awakened_one::REBIRTH_STATE;
+                enemy.last_move = None;
+                false
+            } else if enemy.buffs.regrow && can_regrow {
+                enemy.buffs.half_dead = true;
+                enemy.debuffs = Default::default();
+                enemy.ai_state = darkling::regrow_state(enemy.ai_state);
+                enemy.last_move = None;
+                false
+            } else {
+                self.kill_enemy(target)
             }
-            self.fight.stolen_back_gold += enemy.buffs.stolen_gold;
-            self.fight.enemies[target] = None;
-            true
         } else {
             false
         };
@@ -638,11 +1687,31 @@ impl Game {
             CardBody::PerfectedStrike => {
                 self.num_strikes() * (if card.is_upgraded() { 3 } else { 2 })
             }
-            CardBody::Rampage => card.assoc_data.get_bonus_damage(),
+            CardBody::Rampage
+            | CardBody::RitualDagger
+            | CardBody::GlassKnife
+            | CardBody::WindmillStrike => card.assoc_data.get_bonus_damage(),
+            CardBody::Shiv => self.fight.player_buffs.accuracy,
+            CardBody::Claw => self.fight.claw_bonus,
+            CardBody::Brilliance => self.fight.mantra_gained,
             _ => 0,
         }
     }
 
+    //Ritual Dagger and Genetic Algorithm grow permanently, so the matching card in the deck grows too.
+    fn grow_card_permanently(&mut self, card: &mut Card, amount: i32) {
+        //Costs can change during a fight, so the card is matched without them.
+        if let Some(deck_card) = self.base_deck.iter_mut().find(|deck_card| {
+            deck_card.body == card.body
+                && deck_card.is_upgraded() == card.is_upgraded()
+                && deck_card.assoc_data == card.assoc_data
+        }) {
+            deck_card.assoc_data = deck_card.assoc_data.grow(amount);
+            self.base_deck.sort();
+        }
+        card.assoc_data = card.assoc_data.grow(amount);
+    }
+
     fn gain_max_hp(&mut self, amount: i32) {
         self.player_max_hp += amount;
         self.player_hp += amount;
@@ -656,15 +1725,47 @@ impl Game {
         if let Some(enemy) = &self.fight.enemies[target] {
             let behavior = behavior(&mut self.rng, &self.fight, &enemy, enemy.ai_state);
             for behave in behavior.1 {
-                if let EnemyAction::Attack(_) = *behave {
+                if let EnemyAction::Attack(_) | EnemyAction::Divider(_) = *behave {
                     return true;
                 }
             }
         }
         false
     }
-    fn gen_temp_card(&mut self, body: CardBody, costs_0_this_turn: bool) {
+    //Master Reality upgrades cards created during combat.
+    fn create_card(&self, body: CardBody) -> Card {
         let mut card = body.to_card();
+        if self.fight.player_buffs.master_reality && card.can_upgrade() {
+            card.upgrade();
+        }
+        card
+    }
+
+    //The cards of the player's class which can be found during a run.
+    fn class_cards(&self) -> &'static [CardBody] {
+        match self.charachter {
+            Character::IRONCLAD => IRONCLAD_CARDS,
+            Character::SILENT => SILENT_CARDS,
+            Character::DEFECT => DEFECT_CARDS,
+            Character::WATCHER => WATCHER_CARDS,
+        }
+    }
+
+    //Cards offered to pick from are distinct, so pools need at least count cards.
+    fn offer_cards(&mut self, pool: &'static [CardBody], count: usize) -> Vec<Card> {
+        let mut offered: Vec<Card> = Vec::new();
+        while offered.len() < count {
+            let body = sample_card(pool, &mut self.rng);
+            let card = self.create_card(body);
+            if !offered.contains(&card) {
+                offered.push(card);
+            }
+        }
+        offered
+    }
+
+    fn gen_temp_card(&mut self, body: CardBody, costs_0_this_turn: bool) {
+        let mut card = self.create_card(body);
         if self.fight.hand.len() < 10 {
             if costs_0_this_turn {
                 card.temp_cost = Some(0);
@@ -675,16 +1776,11 @@ impl Game {
         }
     }
     fn choose_random_enemy(&mut self) -> usize {
-        let num_targets = self.fight.enemies.len();
-        let mut sample = self.rng.sample(num_targets);
-        for idx in self.fight.enemies.indicies() {
-            if sample == 0 {
-                return idx.0 as usize;
-            } else {
-                sample -= 1;
-            }
-        }
-        panic!("Something went wrong when selecting a target");
+        let targets = self.target_actions();
+        let Some(sample) = self.rng.try_sample(targets.len()) else {
+            panic!("Something went wrong when selecting a target");
+        };
+        targets[sample].enemy as usize
     }
 
     fn select_random_target(&mut self, card: &Card) -> usize {
@@ -695,18 +1791,34 @@ impl Game {
         }
     }
 
+    fn block_from_card(&self, block: i32) -> i32 {
+        let block = block + self.fight.player_buffs.dexterity;
+        let mut block = block as f32;
+        if self.fight.player_debuffs.frail > 0 {
+            block *= 0.75;
+        }
+        block as i32
+    }
+
     fn player_gain_block(&mut self, block: i32, from_card: bool) {
+        if from_card && self.fight.player_debuffs.no_block > 0 {
+            return;
+        }
         let block = if from_card {
-            let block = block + self.fight.player_buffs.dexterity;
-            let mut block = block as f32;
-            if self.fight.player_debuffs.frail > 0 {
-                block *= 0.75;
-            }
-            block as i32
+            self.block_from_card(block)
         } else {
             block
         };
         if block > 0 {
+            let wave_of_the_hand = self.fight.player_buffs.wave_of_the_hand;
+            if wave_of_the_hand > 0 {
+                for idx in self.fight.enemies.indicies() {
+                    apply_debuff_to_enemy(
+                        &mut self.fight.enemies[idx],
+                        Debuff::Weak(wave_of_the_hand),
+                    );
+                }
+            }
             if self.fight.player_buffs.juggernaut > 0 {
                 self.fight
                     .post_card_queue
@@ -719,22 +1831,125 @@ impl Game {
     }
 
     fn add_card_to_deck(&mut self, card: CardBody) {
-        insert_sorted(card.to_card(), &mut self.base_deck);
+        self.obtain_card(card.to_card());
+    }
+
+    //Every card added to the deck goes through here so relics can react to it.
+    fn obtain_card(&mut self, mut card: Card) {
+        if card.body.card_type() == CardType::Curse
+            && let Some(negated) = self.relics.counter(Relic::Omamori)
+            && negated < 2
+        {
+            self.relics.set_counter(Relic::Omamori, negated + 1);
+            return;
+        }
+        if card.body.card_type() == CardType::Curse
+            && self.relics.has_relic(Relic::DarkstonePeriapt)
+        {
+            self.gain_max_hp(6);
+        }
+        if self.relics.has_relic(Relic::CeramicFish) {
+            self.gain_gold(9);
+        }
+        let egg = match card.body.card_type() {
+            CardType::Attack => Some(Relic::MoltenEgg),
+            CardType::Skill => Some(Relic::ToxicEgg),
+            CardType::Power => Some(Relic::FrozenEgg),
+            CardType::Status | CardType::Curse => None,
+        };
+        if let Some(egg) = egg
+            && self.relics.has_relic(egg)
+            && card.can_upgrade()
+        {
+            card.upgrade();
+        }
+        insert_sorted(card, &mut self.base_deck);
+    }
+
+    //Relics with an effect when they are picked up are handled here.
+    fn gain_relic(&mut self, relic: Relic) {
+        self.relics.add(relic.clone());
+        match relic {
+            Relic::PotionBelt => self.max_potion_slots += 2,
+            Relic::Strawberry => self.gain_max_hp(7),
+            Relic::WarPaint => {
+                self.upgrade_random_matching(2, |card| card.body.card_type() == CardType::Skill)
+            }
+            Relic::Whetstone => {
+                self.upgrade_random_matching(2, |card| card.body.card_type() == CardType::Attack)
+            }
+            Relic::Pear => self.gain_max_hp(10),
+            Relic::Mango => self.gain_max_hp(14),
+            Relic::OldCoin => self.gain_gold(300),
+            Relic::BottledFlame => self.bottling = Some(CardType::Attack),
+            Relic::BottledLightning => self.bottling = Some(CardType::Skill),
+            Relic::BottledTornado => self.bottling = Some(CardType::Power),
+            _ => {}
+        }
+    }
+
+    fn upgrade_random_cards(&mut self, count: usize) {
+        self.upgrade_random_matching(count, |_| true);
+    }
+
+    fn upgrade_random_matching(&mut self, count: usize, filter: impl Fn(&Card) -> bool) {
+        let mut items = Vec::new();
+        for i in 0..self.base_deck.len() {
+            if self.base_deck[i].can_upgrade() && filter(&self.base_deck[i]) {
+                items.push(i);
+            }
+        }
+        for _ in 0..count {
+            if items.is_empty() {
+                break;
+            }
+            let idx = self.rng.sample(items.len());
+            let card_idx = items.swap_remove(idx);
+            self.base_deck[card_idx].upgrade();
+        }
     }
 
     fn lose_gold(&mut self, amount: i32) {
-        assert!(amount > 0);
-        self.gold -= amount;
-        //It's a bug to have an option to pay more gold than the charachter has.
-        assert!(self.gold > 0);
+        //Events like World of Goop or Masked Bandits can take gold the player doesn't have.
+        assert!(amount >= 0);
+        self.gold = max(self.gold - amount, 0);
     }
 
     fn gain_gold(&mut self, amount: i32) {
+        if self.relics.has_relic(Relic::Ectoplasm) {
+            return;
+        }
         self.gold += amount;
     }
 
+    //Boss relics which give energy every turn, usually with a drawback.
+    fn base_energy(&self) -> i32 {
+        let energy_relics = [
+            Relic::Ectoplasm,
+            Relic::Sozu,
+            Relic::CoffeeDripper,
+            Relic::FusionHammer,
+            Relic::BustedCrown,
+            Relic::PhilosophersStone,
+            Relic::RunicDome,
+        ];
+        3 + energy_relics
+            .into_iter()
+            .filter(|relic| self.relics.has_relic(relic.clone()))
+            .count() as i32
+    }
+
+    //Enemies summoned or split during the fight get Philosopher's Stone's strength too.
+    fn spawn_enemy(&mut self, slot: usize, mut enemy: Enemy) {
+        if self.relics.has_relic(Relic::PhilosophersStone) {
+            enemy.buffs.strength += 1;
+        }
+        self.fight.enemies[slot] = Some(enemy);
+    }
+
     fn setup_fight(&mut self) {
         self.fight = Default::default();
+        self.fight.ascension = self.ascension;
         let mut deck_cards = Vec::new();
         for card in self.base_deck.clone() {
             if card.innate() {
@@ -744,33 +1959,161 @@ impl Game {
             }
         }
         self.fight.deck = Deck::shuffled(deck_cards);
-        self.fight.energy = 3;
+        self.fight.energy = self.base_energy();
+        self.fight.blue_candle = self.relics.has_relic(Relic::BlueCandle);
+        self.fight.medical_kit = self.relics.has_relic(Relic::MedicalKit);
+        if self.charachter == Character::DEFECT {
+            self.fight.orb_slots = 3;
+        }
+        if self.relics.has_relic(Relic::CrackedCore) {
+            self.channel(Orb::Lightning);
+        }
         let mut initial_size = 5_usize;
         if self.relics.has_relic(Relic::BagofPreparation) {
             initial_size += 2;
         }
-        //TODO handle relics that affect initial hand size.
+        if self.relics.has_relic(Relic::RingoftheSnake) {
+            initial_size += 2;
+        }
+        //Snecko Eye confuses the player before the opening hand is drawn.
+        if self.relics.has_relic(Relic::SneckoEye) {
+            self.fight.player_debuffs.confused = true;
+            initial_size += 2;
+        }
         for _ in 0..(initial_size.saturating_sub(self.fight.hand.len())) {
             self.fight.draw(&mut self.rng);
         }
+        //Pure Water's Miracle doesn't take the place of a drawn card.
+        if self.relics.has_relic(Relic::PureWater) {
+            self.gen_temp_card(CardBody::Miracle, false);
+        }
+        self.start_of_turn_relics();
+    }
+
+    //This runs after the player's energy is set for the turn.
+    fn start_of_turn_relics(&mut self) {
+        for relic in [
+            Relic::Kunai,
+            Relic::Shuriken,
+            Relic::OrnamentalFan,
+            Relic::LetterOpener,
+        ] {
+            self.relics.set_counter(relic, 0);
+        }
+        //The turn count starts at 0 on the first turn.
+        if self.fight.turn_count == 1 && self.relics.has_relic(Relic::HornCleat) {
+            self.player_gain_block(14, false);
+        }
+        if self.fight.turn_count == 2 && self.relics.has_relic(Relic::CaptainsWheel) {
+            self.player_gain_block(18, false);
+        }
+        if self.relics.has_relic(Relic::MercuryHourglass) {
+            self.fight
+                .post_card_queue
+                .push_back(PostCardItem::DamageAll(3));
+        }
+        if self.relics.tick(Relic::HappyFlower, 3) {
+            self.fight.energy += 1;
+        }
+        if self.relics.tick(Relic::IncenseBurner, 6) {
+            self.apply_buff_to_player(Buff::Intangible(1));
+        }
+        if self.relics.has_relic(Relic::WarpedTongs) {
+            let upgradable: Vec<usize> = (0..self.fight.hand.len())
+                .filter(|i| self.fight.hand[*i].can_upgrade())
+                .collect();
+            if let Some(idx) = self.rng.try_sample(upgradable.len()) {
+                self.fight.hand[upgradable[idx]].upgrade();
+                self.fight.hand.sort();
+            }
+        }
+    }
+}
+
+//Artifact blocks the next debuff.
+fn blocked_by_artifact(enemy: &mut Enemy) -> bool {
+    if enemy.buffs.artifact > 0 {
+        enemy.buffs.artifact -= 1;
+        return true;
     }
+    false
 }
 
 pub fn apply_debuff_to_enemy(enemy: &mut Enemy, debuff: Debuff) {
     match debuff {
         Debuff::Vulnerable(amount) => {
+            if blocked_by_artifact(enemy) {
+                return;
+            }
             enemy.debuffs.vulnerable += amount;
         }
         Debuff::Weak(amount) => {
+            if blocked_by_artifact(enemy) {
+                return;
+            }
             enemy.debuffs.weak += amount;
         }
+        Debuff::Poison(amount) => {
+            if blocked_by_artifact(enemy) {
+                return;
+            }
+            enemy.debuffs.poison += amount;
+        }
+        Debuff::Choked(amount) => {
+            if blocked_by_artifact(enemy) {
+                return;
+            }
+            enemy.debuffs.choked += amount;
+        }
+        Debuff::CorpseExplosion => {
+            if blocked_by_artifact(enemy) {
+                return;
+            }
+            enemy.debuffs.corpse_explosion += 1;
+        }
+        Debuff::StrengthDown(amount) => {
+            if blocked_by_artifact(enemy) {
+                return;
+            }
+            enemy.buffs.strength -= amount;
+            enemy.debuffs.strength_down += amount;
+        }
+        Debuff::MinusStrength(amount) => {
+            if blocked_by_artifact(enemy) {
+                return;
+            }
+            enemy.buffs.strength -= amount;
+        }
+        Debuff::LockOn(amount) => {
+            if blocked_by_artifact(enemy) {
+                return;
+            }
+            enemy.debuffs.lock_on += amount;
+        }
+        Debuff::Mark(amount) => {
+            if blocked_by_artifact(enemy) {
+                return;
+            }
+            enemy.debuffs.mark += amount;
+        }
+        Debuff::BlockReturn(amount) => {
+            if blocked_by_artifact(enemy) {
+                return;
+            }
+            enemy.debuffs.block_return += amount;
+        }
         Debuff::Frail(_)
         | Debuff::Entangled
-        | Debuff::StrengthDown(_)
         | Debuff::NoDraw
         | Debuff::DexterityDown(_)
-        | Debuff::MinusStrength(_)
-        | Debuff::MinusDexterity(_) => {
+        | Debuff::MinusDexterity(_)
+        | Debuff::Hex(_)
+        | Debuff::Confused
+        | Debuff::Constricted(_)
+        | Debuff::DrawReduction(_)
+        | Debuff::MinusFocus(_)
+        | Debuff::EnergyDown(_)
+        | Debuff::NoBlock(_) => {
             panic!("{:?} cannot be applied to enemies!", debuff);
         }
     }
@@ -804,18 +2147,15 @@ fn debuff_player_turn_wind_down(x: &mut i32, amount: i32) {
 }
 
 impl Game {
-    pub fn new(character: Character) -> Self {
+    pub fn new(character: Character, ascension: i32) -> Self {
         let mut rng = Rng::new();
-        let map = ActMap::standard(&mut rng);
-        match character {
-            Character::IRONCLAD => Game {
-                player_hp: 80,
-                player_max_hp: 80,
-                max_potion_slots: 3,
-                charachter: character,
-                fight: Fight::new(),
-                gold: 99,
-                base_deck: vec![
+        let mut map = ActMap::standard(&mut rng, ascension);
+        map.mark_burning_elite(&mut rng);
+        let act = Act::new(&mut rng, 1, ascension);
+        let (max_hp, mut base_deck) = match character {
+            Character::IRONCLAD => (
+                80,
+                vec![
                     CardBody::Bash.to_card(),
                     CardBody::Defend.to_card(),
                     CardBody::Defend.to_card(),
@@ -827,15 +2167,96 @@ impl Game {
                     CardBody::Strike.to_card(),
                     CardBody::Strike.to_card(),
                 ],
-                relics: Relics::new(character),
-                rng,
-                map,
-                act: Act::new(),
-                state_counter: 0,
-            },
-            Character::SILENT => todo!(),
-            Character::DEFECT => todo!(),
-            Character::WATCHER => todo!(),
+            ),
+            Character::SILENT => (
+                70,
+                vec![
+                    CardBody::Strike.to_card(),
+                    CardBody::Strike.to_card(),
+                    CardBody::Strike.to_card(),
+                    CardBody::Strike.to_card(),
+                    CardBody::Strike.to_card(),
+                    CardBody::Defend.to_card(),
+                    CardBody::Defend.to_card(),
+                    CardBody::Defend.to_card(),
+                    CardBody::Defend.to_card(),
+                    CardBody::Defend.to_card(),
+                    CardBody::Neutralize.to_card(),
+                    CardBody::Survivor.to_card(),
+                ],
+            ),
+            Character::DEFECT => (
+                75,
+                vec![
+                    CardBody::Strike.to_card(),
+                    CardBody::Strike.to_card(),
+                    CardBody::Strike.to_card(),
+                    CardBody::Strike.to_card(),
+                    CardBody::Defend.to_card(),
+                    CardBody::Defend.to_card(),
+                    CardBody::Defend.to_card(),
+                    CardBody::Defend.to_card(),
+                    CardBody::Zap.to_card(),
+                    CardBody::Dualcast.to_card(),
+                ],
+            ),
+            Character::WATCHER => (
+                72,
+                vec![
+                    CardBody::Strike.to_card(),
+                    CardBody::Strike.to_card(),
+                    CardBody::Strike.to_card(),
+                    CardBody::Strike.to_card(),
+                    CardBody::Defend.to_card(),
+                    CardBody::Defend.to_card(),
+                    CardBody::Defend.to_card(),
+                    CardBody::Defend.to_card(),
+                    CardBody::Eruption.to_card(),
+                    CardBody::Vigilance.to_card(),
+                ],
+            ),
+        };
+        //Ascension 14 lowers max HP and 6 starts the run damaged.
+        let max_hp = if ascension >= 14 {
+            max_hp
+                - if character == Character::IRONCLAD {
+                    5
+                } else {
+                    4
+                }
+        } else {
+            max_hp
+        };
+        let hp = if ascension >= 6 {
+            (max_hp * 9 + 5) / 10
+        } else {
+            max_hp
+        };
+        if ascension >= 10 {
+            base_deck.push(CardBody::AscendersBane.to_card());
+        }
+        Game {
+            player_hp: hp,
+            player_max_hp: max_hp,
+            max_potion_slots: if ascension >= 11 { 2 } else { 3 },
+            charachter: character,
+            ascension,
+            fight: Fight::new(),
+            gold: 99,
+            base_deck,
+            relics: Relics::new(character),
+            rng,
+            map,
+            act,
+            state_counter: 0,
+            card_rarity_offset: CARD_RARITY_OFFSET_START,
+            cards_removed_at_shop: 0,
+            potions: Vec::new(),
+            potion_chance: POTION_CHANCE_START,
+            keys: Keys::default(),
+            hidden_intents: false,
+            bottling: None,
+            bottle_return: None,
         }
     }
 
@@ -847,8 +2268,8 @@ impl Game {
         self.rng.set_seed(seed);
     }
 
-    pub fn start(self) -> ChoiceState {
-        let choice = self.goto_map();
+    pub fn start(mut self) -> ChoiceState {
+        let choice = self.goto_neow();
         ChoiceState {
             game: Box::new(self),
             choice,
@@ -856,6 +2277,32 @@ impl Game {
     }
 }
 
+//Sets up a fight against the given enemies without going through the map.
+#[cfg(test)]
+impl Game {
+    fn test_fight(character: Character, enemies: Vec<Enemy>) -> Game {
+        let mut game = Game::new(character, 0);
+        game.setup_fight();
+        for (slot, enemy) in enemies.into_iter().enumerate() {
+            game.fight.enemies[slot] = Some(enemy);
+        }
+        game
+    }
+
+    fn test_context(body: CardBody) -> PlayCardContext {
+        PlayCardContext {
+            card: body.to_card(),
+            target: 0,
+            real_card: true,
+            exhausts: false,
+            effect_index: 0,
+            x: 0,
+            rebound: false,
+            shuffles_back: false,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Character {
     IRONCLAD,
@@ -873,4 +2320,87 @@ impl Character {
             Character::WATCHER => "Watcher",
         }
     }
+
+    pub fn from_name(name: &str) -> Option<Character> {
+        [
+            Character::IRONCLAD,
+            Character::SILENT,
+            Character::DEFECT,
+            Character::WATCHER,
+        ]
+        .into_iter()
+        .find(|character| character.name().eq_ignore_ascii_case(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        card::CardBody,
+        deck::Deck,
+        enemies::jaw_worm::generate_jaw_worm,
+        game::{Character, Game},
+        relic::Relic,
+        rng::Rng,
+    };
+
+    //Corpse Explosion on the first enemy kills the second before its Choke is checked.
+    #[test]
+    fn choke_skips_enemies_killed_earlier() {
+        let mut rng = Rng::new();
+        let mut first = generate_jaw_worm(&mut rng, 0);
+        first.hp = 1;
+        first.debuffs.choked = 3;
+        first.debuffs.corpse_explosion = 1;
+        let explosion = first.max_hp;
+        let mut second = generate_jaw_worm(&mut rng, 0);
+        second.hp = 1;
+        second.debuffs.choked = 3;
+        let mut third = generate_jaw_worm(&mut rng, 0);
+        third.hp = 100;
+        third.debuffs.choked = 3;
+        let mut game = Game::test_fight(Character::SILENT, vec![first, second, third]);
+        let mut context = Game::test_context(CardBody::Neutralize);
+        game.trigger_play_card_effects(&mut context);
+        assert_eq!(game.fight.enemies.len(), 1);
+        assert_eq!(
+            game.fight.enemies[2].as_ref().unwrap().hp,
+            100 - explosion - 3
+        );
+    }
+
+    #[test]
+    fn mayhem_with_no_enemies_left() {
+        let mut game = Game::test_fight(Character::SILENT, vec![]);
+        game.fight.deck = Deck::shuffled(vec![CardBody::Strike.to_card()]);
+        game.fight.player_buffs.mayhem = 1;
+        game.start_of_turn_powers();
+        assert!(game.fight.post_card_queue.is_empty());
+    }
+
+    #[test]
+    fn lose_gold_with_no_gold() {
+        let mut game = Game::new(Character::IRONCLAD, 0);
+        game.gold = 0;
+        game.lose_gold(0);
+        assert_eq!(game.gold, 0);
+        game.lose_gold(10);
+        assert_eq!(game.gold, 0);
+    }
+
+    #[test]
+    fn stone_calendar_hits_on_the_7th_turn() {
+        let mut rng = Rng::new();
+        let mut enemy = generate_jaw_worm(&mut rng, 0);
+        enemy.hp = 100;
+        let mut game = Game::test_fight(Character::IRONCLAD, vec![enemy]);
+        game.relics.add(Relic::StoneCalendar);
+        //The 6th turn.
+        game.fight.turn_count = 5;
+        game.discard_hand_end_of_turn();
+        assert_eq!(game.fight.enemies[0].as_ref().unwrap().hp, 100);
+        game.fight.turn_count = 6;
+        game.discard_hand_end_of_turn();
+        assert_eq!(game.fight.enemies[0].as_ref().unwrap().hp, 48);
+    }
 }
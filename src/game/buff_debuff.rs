@@ -2,6 +2,7 @@ use crate::{
     card::{Buff, Debuff},
     fight::Enemy,
     game::{Game, debuff_player_turn_wind_down},
+    relic::Relic,
 };
 
 impl Game {
@@ -25,6 +26,30 @@ impl Game {
             Buff::Thorns(x) => {
                 enemy.buffs.thorns += x;
             }
+            Buff::SharpHide(x) => {
+                enemy.buffs.sharp_hide += x;
+            }
+            Buff::Metallicize(x) => {
+                enemy.buffs.metallicize += x;
+            }
+            Buff::Flying(x) => {
+                enemy.buffs.flying = x;
+            }
+            Buff::BeatOfDeath(x) => {
+                enemy.buffs.beat_of_death += x;
+            }
+            Buff::PlatedArmor(x) => {
+                enemy.buffs.plated_armor += x;
+            }
+            Buff::PainfulStabs => {
+                enemy.buffs.painful_stabs = true;
+            }
+            Buff::Artifact(x) => {
+                enemy.buffs.artifact += x;
+            }
+            Buff::Intangible(x) => {
+                enemy.buffs.intangible += x;
+            }
             Buff::EndTurnDamageAllEnemies(_)
             | Buff::EndTurnLoseHP(_)
             | Buff::DarkEmbraceBuff
@@ -32,7 +57,6 @@ impl Game {
             | Buff::FNPBuff(_)
             | Buff::FireBreathingBuff(_)
             | Buff::TempSpikes(_)
-            | Buff::Metallicize(_)
             | Buff::RageBuff(_)
             | Buff::RuptureBuff(_)
             | Buff::BarricadeBuff
@@ -41,13 +65,74 @@ impl Game {
             | Buff::CorruptionBuff
             | Buff::DoubleTap(_)
             | Buff::Juggernaut(_)
-            | Buff::Vigor(_) => {
+            | Buff::Vigor(_)
+            | Buff::Dexterity(_)
+            | Buff::EnergyNextTurn(_)
+            | Buff::DrawNextTurn(_)
+            | Buff::Blur(_)
+            | Buff::Accuracy(_)
+            | Buff::InfiniteBlades(_)
+            | Buff::NoxiousFumes(_)
+            | Buff::Envenom(_)
+            | Buff::AfterImage(_)
+            | Buff::ThousandCuts(_)
+            | Buff::Burst(_)
+            | Buff::Phantasmal(_)
+            | Buff::ToolsOfTheTrade(_)
+            | Buff::WraithForm(_)
+            | Buff::WellLaidPlans(_)
+            | Buff::Focus(_)
+            | Buff::Buffer(_)
+            | Buff::Amplify(_)
+            | Buff::BiasedCognition(_)
+            | Buff::CreativeAI(_)
+            | Buff::EchoForm(_)
+            | Buff::Electrodynamics
+            | Buff::Heatsinks(_)
+            | Buff::HelloWorld(_)
+            | Buff::Loop(_)
+            | Buff::MachineLearning(_)
+            | Buff::SelfRepair(_)
+            | Buff::StaticDischarge(_)
+            | Buff::Storm(_)
+            | Buff::Equilibrium(_)
+            | Buff::MentalFortress(_)
+            | Buff::Rushdown(_)
+            | Buff::Nirvana(_)
+            | Buff::Establishment(_)
+            | Buff::MasterReality
+            | Buff::LikeWater(_)
+            | Buff::WaveOfTheHand(_)
+            | Buff::BattleHymn(_)
+            | Buff::Foresight(_)
+            | Buff::Study(_)
+            | Buff::Devotion(_)
+            | Buff::DevaForm(_)
+            | Buff::Collect(_)
+            | Buff::Blasphemy
+            | Buff::SimmeringFury(_)
+            | Buff::FreeAttack
+            | Buff::Magnetism(_)
+            | Buff::Mayhem(_)
+            | Buff::Panache(_)
+            | Buff::TheBomb(_) => {
                 panic_not_apply_enemies(buff);
             }
         }
     }
 
     pub(super) fn apply_debuff_to_player(&mut self, debuff: Debuff) {
+        //Ginger and Turnip stop Weak and Frail without using up Artifact.
+        match debuff {
+            Debuff::Weak(_) if self.relics.has_relic(Relic::Ginger) => return,
+            Debuff::Frail(_) if self.relics.has_relic(Relic::Turnip) => return,
+            _ => {}
+        }
+        //Artifact blocks the next debuff.
+        if self.fight.player_buffs.artifact > 0 {
+            self.fight.player_buffs.artifact -= 1;
+            return;
+        }
         match debuff {
             Debuff::Vulnerable(amount) => {
                 debuff_player_turn_wind_down(&mut self.fight.player_debuffs.vulnerable, amount);
@@ -76,6 +161,35 @@ impl Game {
             Debuff::MinusDexterity(x) => {
                 self.fight.player_buffs.dexterity -= x;
             }
+            Debuff::Hex(x) => {
+                self.fight.player_debuffs.hex += x;
+            }
+            Debuff::Confused => {
+                self.fight.player_debuffs.confused = true;
+            }
+            Debuff::Constricted(x) => {
+                self.fight.player_debuffs.constricted += x;
+            }
+            Debuff::DrawReduction(x) => {
+                self.fight.player_debuffs.draw_reduction += x;
+            }
+            Debuff::MinusFocus(x) => {
+                self.fight.player_buffs.focus -= x;
+            }
+            Debuff::EnergyDown(x) => {
+                self.fight.player_debuffs.energy_down += x;
+            }
+            Debuff::NoBlock(x) => {
+                self.fight.player_debuffs.no_block += x;
+            }
+            Debuff::Poison(_)
+            | Debuff::Choked(_)
+            | Debuff::CorpseExplosion
+            | Debuff::LockOn(_)
+            | Debuff::Mark(_)
+            | Debuff::BlockReturn(_) => {
+                panic!("{:?} cannot be applied to the player!", debuff);
+            }
         }
     }
 
@@ -110,7 +224,64 @@ impl Game {
             Buff::Juggernaut(x) => self.fight.player_buffs.juggernaut += x,
             Buff::Vigor(x) => self.fight.player_buffs.vigor += x,
             Buff::Thorns(x) => self.fight.player_buffs.thorns += x,
-            Buff::Enrage(_) => panic_not_apply_player(buff),
+            Buff::Intangible(x) => self.fight.player_buffs.intangible += x,
+            Buff::Dexterity(x) => self.fight.player_buffs.dexterity += x,
+            Buff::EnergyNextTurn(x) => self.fight.player_buffs.energy_next_turn += x,
+            Buff::DrawNextTurn(x) => self.fight.player_buffs.draw_next_turn += x,
+            Buff::Blur(x) => self.fight.player_buffs.blur += x,
+            Buff::Accuracy(x) => self.fight.player_buffs.accuracy += x,
+            Buff::InfiniteBlades(x) => self.fight.player_buffs.infinite_blades += x,
+            Buff::NoxiousFumes(x) => self.fight.player_buffs.noxious_fumes += x,
+            Buff::Envenom(x) => self.fight.player_buffs.envenom += x,
+            Buff::AfterImage(x) => self.fight.player_buffs.after_image += x,
+            Buff::ThousandCuts(x) => self.fight.player_buffs.thousand_cuts += x,
+            Buff::Burst(x) => self.fight.player_buffs.burst += x,
+            Buff::Phantasmal(x) => self.fight.player_buffs.phantasmal += x,
+            Buff::ToolsOfTheTrade(x) => self.fight.player_buffs.tools_of_the_trade += x,
+            Buff::WraithForm(x) => self.fight.player_buffs.wraith_form += x,
+            Buff::WellLaidPlans(x) => self.fight.player_buffs.well_laid_plans += x,
+            Buff::Focus(x) => self.fight.player_buffs.focus += x,
+            Buff::Artifact(x) => self.fight.player_buffs.artifact += x,
+            Buff::Buffer(x) => self.fight.player_buffs.buffer += x,
+            Buff::Amplify(x) => self.fight.player_buffs.amplify += x,
+            Buff::BiasedCognition(x) => self.fight.player_buffs.biased_cognition += x,
+            Buff::CreativeAI(x) => self.fight.player_buffs.creative_ai += x,
+            Buff::EchoForm(x) => self.fight.player_buffs.echo_form += x,
+            Buff::Electrodynamics => self.fight.player_buffs.electrodynamics = true,
+            Buff::Heatsinks(x) => self.fight.player_buffs.heatsinks += x,
+            Buff::HelloWorld(x) => self.fight.player_buffs.hello_world += x,
+            Buff::Loop(x) => self.fight.player_buffs.loop_orbs += x,
+            Buff::MachineLearning(x) => self.fight.player_buffs.machine_learning += x,
+            Buff::SelfRepair(x) => self.fight.player_buffs.self_repair += x,
+            Buff::StaticDischarge(x) => self.fight.player_buffs.static_discharge += x,
+            Buff::Storm(x) => self.fight.player_buffs.storm += x,
+            Buff::Equilibrium(x) => self.fight.player_buffs.equilibrium += x,
+            Buff::MentalFortress(x) => self.fight.player_buffs.mental_fortress += x,
+            Buff::Rushdown(x) => self.fight.player_buffs.rushdown += x,
+            Buff::Nirvana(x) => self.fight.player_buffs.nirvana += x,
+            Buff::Establishment(x) => self.fight.player_buffs.establishment += x,
+            Buff::MasterReality => self.fight.player_buffs.master_reality = true,
+            Buff::LikeWater(x) => self.fight.player_buffs.like_water += x,
+            Buff::WaveOfTheHand(x) => self.fight.player_buffs.wave_of_the_hand += x,
+            Buff::BattleHymn(x) => self.fight.player_buffs.battle_hymn += x,
+            Buff::Foresight(x) => self.fight.player_buffs.foresight += x,
+            Buff::Study(x) => self.fight.player_buffs.study += x,
+            Buff::Devotion(x) => self.fight.player_buffs.devotion += x,
+            Buff::DevaForm(x) => self.fight.player_buffs.deva_form += x,
+            Buff::Collect(x) => self.fight.player_buffs.collect += x,
+            Buff::Blasphemy => self.fight.player_buffs.blasphemy = true,
+            Buff::SimmeringFury(x) => self.fight.player_buffs.simmering_fury += x,
+            Buff::FreeAttack => self.fight.player_buffs.free_attack += 1,
+            Buff::PlatedArmor(x) => self.fight.player_buffs.plated_armor += x,
+            Buff::Magnetism(x) => self.fight.player_buffs.magnetism += x,
+            Buff::Mayhem(x) => self.fight.player_buffs.mayhem += x,
+            Buff::Panache(x) => self.fight.player_buffs.panache += x,
+            Buff::TheBomb(x) => self.fight.player_buffs.the_bomb[2] += x,
+            Buff::Enrage(_)
+            | Buff::SharpHide(_)
+            | Buff::Flying(_)
+            | Buff::BeatOfDeath(_)
+            | Buff::PainfulStabs => panic_not_apply_player(buff),
         }
     }
 }
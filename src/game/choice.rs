@@ -1,4 +1,8 @@
-use std::{fmt::Display, mem};
+use std::{
+    fmt::Display,
+    hash::{Hash, Hasher},
+    mem,
+};
 
 use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
@@ -6,7 +10,8 @@ use serde::{Deserialize, Serialize};
 use crate::{
     card::SelectCardEffect,
     fight::{Enemy, PlayCardContext},
-    game::{Game, event::Event},
+    game::{Character, Game, event::Event, reward::Rewards, shop::Shop, treasure::ChestSize},
+    relic::Relic,
     rng::Rng,
 };
 
@@ -21,6 +26,10 @@ pub enum SelectionPile {
     Hand,
     Discard,
     Exhaust,
+    //Indexes into the deck's distinct cards.
+    Deck,
+    //Cards offered by Foreign Influence and Wish.
+    Offered,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -32,9 +41,20 @@ pub struct SelectCardAction(pub usize);
 pub enum PlayCardAction {
     //Play the i'th card in hand
     PlayCard(u8),
+    //Drink the potion in the i'th slot
+    UsePotion(u8),
+    //Throw away the potion in the i'th slot
+    DiscardPotion(u8),
     //End the turn
     EndTurn,
 }
+//Scry decides on the top card of the draw pile one at a time.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ScryAction {
+    Keep,
+    Discard,
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ChooseEnemyAction {
     //Target the i'th enemy
@@ -59,6 +79,66 @@ pub struct EventAction(pub usize);
 pub enum RestSiteAction {
     Heal,
     Upgrade,
+    //Take the Ruby Key instead of resting.
+    Recall,
+    //Girya gives a point of strength, up to 3 times.
+    Lift,
+    //Peace Pipe removes a card.
+    Toke,
+    //Shovel digs up a relic.
+    Dig,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RewardAction {
+    TakeGold,
+    //Take the i'th relic
+    TakeRelic(u8),
+    //Take the j'th card of the i'th card reward
+    TakeCard(u8, u8),
+    //Take the i'th potion
+    TakePotion(u8),
+    //Throw away the potion in the i'th slot to make room
+    DiscardPotion(u8),
+    //Skip the i'th card reward for 2 max HP with Singing Bowl
+    SingingBowl(u8),
+    //Leave the rest of the rewards behind
+    Proceed,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ShopAction {
+    //Buy the i'th card or relic in the shop
+    BuyCard(u8),
+    BuyRelic(u8),
+    BuyPotion(u8),
+    //Pay to remove the i'th card in the deck
+    RemoveCard(u8),
+    Leave,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TreasureAction {
+    Open,
+    //Take the Sapphire Key instead of the chest's relic.
+    OpenForSapphireKey,
+    Skip,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BossRelicAction {
+    //Take the i'th boss relic
+    Take(u8),
+    Skip,
+}
+
+impl BossRelicAction {
+    pub fn action_str(&self, relics: &[Relic]) -> String {
+        match self {
+            BossRelicAction::Take(idx) => format!("Take {:?}", relics[*idx as usize]),
+            BossRelicAction::Skip => "Skip".to_owned(),
+        }
+    }
 }
 
 //Rest Site Actions
@@ -67,6 +147,12 @@ pub enum SelectDeckCardReason {
     Remove,
     Transform,
     Upgrade,
+    RemoveTwo,
+    TransformTwo,
+    Offer,
+    Duplicate,
+    RemoveAndUpgrade,
+    Bottle,
 }
 
 #[must_use]
@@ -75,18 +161,27 @@ pub enum Choice {
     //See if this can be improved for more allocation reuse.
     PlayCardState(Vec<PlayCardAction>),
     ChooseEnemyState(Vec<ChooseEnemyAction>, usize),
+    ChoosePotionTargetState(Vec<ChooseEnemyAction>, usize),
     Win,
     Loss,
     MapState(Vec<MapStateAction>),
+    //The card being played is paused while the player selects. Selections from
+    //start or end of turn effects have no card.
     SelectCardState(
-        PlayCardContext,
+        Option<PlayCardContext>,
         SelectCardEffect,
         Vec<SelectCardAction>,
         SelectionPile,
     ),
+    //The cards being scried are stored in the fight.
+    ScryState(Option<PlayCardContext>, Vec<ScryAction>),
     Event(Event, Vec<EventAction>),
     SelectDeckCardState(SelectDeckCardReason, Vec<SelectCardAction>),
     RestSite(Vec<RestSiteAction>),
+    Rewards(Rewards, Vec<RewardAction>),
+    Shop(Shop, Vec<ShopAction>),
+    Treasure(ChestSize, Vec<TreasureAction>),
+    BossRelic(Vec<Relic>, Vec<BossRelicAction>),
 }
 
 impl ChoiceState {
@@ -104,6 +199,22 @@ impl ChoiceState {
         *other.game = (*self.game).clone();
         other.game.rng = Rng::new();
         other.choice = self.choice.clone();
+        if let Choice::Event(event, _) = &mut other.choice {
+            event.reseed(&mut other.game.rng);
+        }
+        other.game.resample_hidden_intents();
+    }
+
+    //Hashes what the player can observe. When intents are hidden, states that
+    //only differ in the enemies' intents hash the same.
+    pub fn hash_observation<H: Hasher>(&self, state: &mut H) {
+        if !self.game.intents_hidden() {
+            self.hash(state);
+            return;
+        }
+        let mut masked = self.clone();
+        masked.game.mask_intents();
+        masked.hash(state);
     }
 
     //This function handles an action being taken.
@@ -120,6 +231,9 @@ impl ChoiceState {
             Choice::ChooseEnemyState(choose_enemy_actions, card_idx) => {
                 game.handle_choose_enemy_action(card_idx, choose_enemy_actions[action_idx])
             }
+            Choice::ChoosePotionTargetState(choose_enemy_actions, slot) => {
+                game.handle_choose_potion_target_action(slot, choose_enemy_actions[action_idx])
+            }
             Choice::Win => {
                 panic!("The game is won, no actions can be taken");
             }
@@ -138,6 +252,9 @@ impl ChoiceState {
                 let action = select_card_actions[action_idx];
                 game.handle_select_card_action(play_card_context, effect, action)
             }
+            Choice::ScryState(play_card_context, actions) => {
+                game.handle_scry_action(play_card_context, actions[action_idx])
+            }
             Choice::Event(event, actions) => {
                 event.handle_action(&mut self.game, actions[action_idx])
             }
@@ -147,12 +264,39 @@ impl ChoiceState {
                     SelectDeckCardReason::Remove => game.handle_remove_card_action(action.0),
                     SelectDeckCardReason::Transform => game.handle_transform_card_action(action.0),
                     SelectDeckCardReason::Upgrade => game.handle_upgrade_card_action(action.0),
+                    SelectDeckCardReason::RemoveTwo => {
+                        game.handle_remove_two_cards_action(action.0)
+                    }
+                    SelectDeckCardReason::TransformTwo => {
+                        game.handle_transform_two_cards_action(action.0)
+                    }
+                    SelectDeckCardReason::Offer => game.handle_offer_card_action(action.0),
+                    SelectDeckCardReason::Duplicate => game.handle_duplicate_card_action(action.0),
+                    SelectDeckCardReason::RemoveAndUpgrade => {
+                        game.handle_remove_and_upgrade_action(action.0)
+                    }
+                    SelectDeckCardReason::Bottle => game.handle_bottle_card_action(action.0),
                 }
             }
             Choice::RestSite(rest_site_actions) => {
                 game.handle_rest_site_action(rest_site_actions[action_idx])
             }
+            Choice::Rewards(rewards, actions) => {
+                game.handle_reward_action(rewards, actions[action_idx])
+            }
+            Choice::Shop(shop, actions) => game.handle_shop_action(shop, actions[action_idx]),
+            Choice::Treasure(size, actions) => {
+                game.handle_treasure_action(size, actions[action_idx])
+            }
+            Choice::BossRelic(relics, actions) => {
+                game.handle_boss_relic_action(relics, actions[action_idx])
+            }
         };
+        //Bottled relics interrupt whatever screen they were picked up from.
+        if let Some(card_type) = self.game.bottling.take() {
+            let next = mem::replace(&mut self.choice, Choice::Loss);
+            self.choice = self.game.goto_bottle_card(card_type, next);
+        }
     }
 
     pub fn action_str(&self, action_idx: usize) -> String {
@@ -163,10 +307,17 @@ impl ChoiceState {
                     PlayCardAction::PlayCard(card_idx) => {
                         format!("{:?}", self.game.fight.hand[card_idx as usize].body)
                     }
+                    PlayCardAction::UsePotion(slot) => {
+                        format!("Use {:?}", self.game.potions[slot as usize])
+                    }
+                    PlayCardAction::DiscardPotion(slot) => {
+                        format!("Discard {:?}", self.game.potions[slot as usize])
+                    }
                     PlayCardAction::EndTurn => "End Turn".to_owned(),
                 }
             }
-            Choice::ChooseEnemyState(choose_enemy_actions, _) => {
+            Choice::ChooseEnemyState(choose_enemy_actions, _)
+            | Choice::ChoosePotionTargetState(choose_enemy_actions, _) => {
                 format!(
                     "Target {:?}",
                     self.game.fight.enemies[choose_enemy_actions[action_idx].enemy as usize]
@@ -199,8 +350,23 @@ impl ChoiceState {
                     SelectionPile::Exhaust => {
                         format!("Select {:?}", self.game.fight.exhaust[action.0].body)
                     }
+                    SelectionPile::Deck => {
+                        format!(
+                            "Select {:?}",
+                            self.game.fight.deck.distinct_cards()[action.0].body
+                        )
+                    }
+                    SelectionPile::Offered => {
+                        format!("Select {:?}", self.game.fight.offered[action.0].body)
+                    }
                 }
             }
+            Choice::ScryState(_play_card_context, actions) => {
+                format!(
+                    "{:?} {:?}",
+                    actions[action_idx], self.game.fight.scrying[0].body
+                )
+            }
             Choice::Event(event, event_actions) => {
                 event.action_str(&self.game, event_actions[action_idx])
             }
@@ -212,13 +378,22 @@ impl ChoiceState {
                 let action = actions[action_idx];
                 format!("{:?}", action)
             }
+            Choice::Rewards(rewards, actions) => rewards.action_str(actions[action_idx]),
+            Choice::Shop(shop, actions) => shop.action_str(&self.game, actions[action_idx]),
+            Choice::Treasure(size, actions) => {
+                format!("{:?} {:?} chest", actions[action_idx], size)
+            }
+            Choice::BossRelic(relics, actions) => actions[action_idx].action_str(relics),
         }
     }
 
     pub fn num_actions(&self) -> usize {
         match &self.choice {
             Choice::PlayCardState(play_card_actions) => play_card_actions.len(),
-            Choice::ChooseEnemyState(choose_enemy_actions, _) => choose_enemy_actions.len(),
+            Choice::ChooseEnemyState(choose_enemy_actions, _)
+            | Choice::ChoosePotionTargetState(choose_enemy_actions, _) => {
+                choose_enemy_actions.len()
+            }
             Choice::Win => 0,
             Choice::Loss => 0,
             Choice::MapState(map_state_actions) => map_state_actions.len(),
@@ -228,9 +403,14 @@ impl ChoiceState {
                 select_card_actions,
                 _selection_type,
             ) => select_card_actions.len(),
+            Choice::ScryState(_play_card_context, actions) => actions.len(),
             Choice::Event(_event, event_actions) => event_actions.len(),
             Choice::SelectDeckCardState(_reason, actions) => actions.len(),
             Choice::RestSite(actions) => actions.len(),
+            Choice::Rewards(_rewards, actions) => actions.len(),
+            Choice::Shop(_shop, actions) => actions.len(),
+            Choice::Treasure(_size, actions) => actions.len(),
+            Choice::BossRelic(_relics, actions) => actions.len(),
         }
     }
 }
@@ -241,10 +421,18 @@ impl Display for ChoiceState {
             write!(f, "{:-<80}\n", "")?;
             Ok(())
         }
-        fn fmt_enemy(enemy: &Enemy, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn fmt_enemy(
+            enemy: &Enemy,
+            show_intent: bool,
+            f: &mut std::fmt::Formatter<'_>,
+        ) -> std::fmt::Result {
             write!(f, "| ")?;
             write!(f, "{:?} | ", enemy.name)?;
-            write!(f, "AI {} | ", enemy.ai_state)?;
+            if show_intent {
+                write!(f, "AI {} | ", enemy.ai_state)?;
+            } else if let Some(last_move) = enemy.last_move {
+                write!(f, "Last {} | ", last_move)?;
+            }
             write!(f, "{}/{} hp | ", enemy.hp, enemy.max_hp)?;
             if enemy.block > 0 {
                 write!(f, "{} block | ", enemy.block)?;
@@ -262,9 +450,21 @@ impl Display for ChoiceState {
             if enemy.buffs.curl_up > 0 {
                 write!(f, "{} curl up | ", enemy.buffs.curl_up)?;
             }
+            if enemy.buffs.mode_shift > 0 {
+                write!(f, "{} mode shift | ", enemy.buffs.mode_shift)?;
+            }
+            if enemy.buffs.sharp_hide > 0 {
+                write!(f, "{} sharp hide | ", enemy.buffs.sharp_hide)?;
+            }
+            if enemy.buffs.artifact > 0 {
+                write!(f, "{} artifact | ", enemy.buffs.artifact)?;
+            }
             if enemy.debuffs.vulnerable > 0 {
                 write!(f, "{} vuln | ", enemy.debuffs.vulnerable)?;
             }
+            if enemy.debuffs.mark > 0 {
+                write!(f, "{} mark | ", enemy.debuffs.mark)?;
+            }
             write!(f, "\n")?;
             dash_line(f)?;
             Ok(())
@@ -273,17 +473,29 @@ impl Display for ChoiceState {
         let state_name = match &self.choice {
             Choice::PlayCardState(_) => "PlayCard",
             Choice::ChooseEnemyState(_, _) => "ChooseEnemy",
+            Choice::ChoosePotionTargetState(_, _) => "ChoosePotionTarget",
             Choice::Win => "Win",
             Choice::Loss => "Loss",
             Choice::MapState(_) => "MapState",
             Choice::SelectCardState(_ctx, __effect, _actions, _type) => "SelectCard",
+            Choice::ScryState(_ctx, _actions) => "Scry",
             Choice::Event(event, _actions) => event.name(),
             Choice::SelectDeckCardState(reason, _) => match reason {
                 SelectDeckCardReason::Remove => "RemoveCard",
                 SelectDeckCardReason::Transform => "TransformCard",
                 SelectDeckCardReason::Upgrade => "UpgradeCard",
+                SelectDeckCardReason::RemoveTwo => "RemoveTwoCards",
+                SelectDeckCardReason::TransformTwo => "TransformTwoCards",
+                SelectDeckCardReason::Offer => "OfferCard",
+                SelectDeckCardReason::Duplicate => "DuplicateCard",
+                SelectDeckCardReason::RemoveAndUpgrade => "RemoveAndUpgradeCard",
+                SelectDeckCardReason::Bottle => "BottleCard",
             },
             Choice::RestSite(_) => "RestSite",
+            Choice::Rewards(_, _) => "Rewards",
+            Choice::Shop(_, _) => "Shop",
+            Choice::Treasure(_, _) => "Treasure",
+            Choice::BossRelic(_, _) => "BossRelic",
         };
         dash_line(f)?;
         write!(f, "| ")?;
@@ -292,6 +504,27 @@ impl Display for ChoiceState {
         write!(f, "{}/{} hp | ", game.player_hp, game.player_max_hp)?;
         write!(f, "{}⚡︎ | ", game.fight.energy)?;
         write!(f, "{} block | ", game.fight.player_block)?;
+        if game.fight.player_buffs.focus != 0 {
+            write!(f, "{} focus | ", game.fight.player_buffs.focus)?;
+        }
+        if game.fight.orb_slots > 0 {
+            write!(f, "orbs {:?}/{} | ", game.fight.orbs, game.fight.orb_slots)?;
+        }
+        if game.charachter == Character::WATCHER {
+            write!(f, "{:?} | ", game.fight.stance)?;
+            write!(f, "{} mantra | ", game.fight.mantra)?;
+        }
+        for potion in &game.potions {
+            write!(f, "{:?} | ", potion)?;
+        }
+        //Only relics which are counting are shown.
+        for relic in game.relics.list() {
+            if let Some(counter) = game.relics.counter(relic.clone())
+                && counter > 0
+            {
+                write!(f, "{:?} {} | ", relic, counter)?;
+            }
+        }
         write!(f, "\n")?;
         write!(f, "{:.<80}\n", "")?;
         write!(f, "| ")?;
@@ -305,10 +538,76 @@ impl Display for ChoiceState {
         }
         write!(f, "\n")?;
         dash_line(f)?;
+        let show_intent = !game.intents_hidden();
         for enemy_idx in game.fight.enemies.indicies() {
             let enemy = &game.fight.enemies[enemy_idx];
-            fmt_enemy(enemy, f)?;
+            fmt_enemy(enemy, show_intent, f)?;
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{card::CardType, enemies::jaw_worm::generate_jaw_worm};
+    use std::hash::DefaultHasher;
+
+    //Picking up a Bottled relic selects a card, then goes back to the rewards.
+    #[test]
+    fn bottle_returns_to_rewards() {
+        let mut game = Game::new(Character::IRONCLAD, 0);
+        let rewards = Rewards {
+            gold: 10,
+            relics: vec![Relic::BottledFlame],
+            ..Default::default()
+        };
+        let choice = game.goto_rewards(rewards);
+        let mut state = ChoiceState {
+            game: Box::new(game),
+            choice,
+        };
+        //Gold is the first reward, then the relic.
+        state.take_action(1);
+        let Choice::SelectDeckCardState(SelectDeckCardReason::Bottle, actions) = &state.choice
+        else {
+            panic!("Expected to bottle a card, got {:?}", state.choice);
+        };
+        assert!(
+            actions.iter().all(|action| {
+                state.game.base_deck[action.0].body.card_type() == CardType::Attack
+            })
+        );
+        state.take_action(0);
+        assert!(matches!(state.choice, Choice::Rewards(_, _)));
+        assert_eq!(
+            state
+                .game
+                .base_deck
+                .iter()
+                .filter(|card| card.bottled)
+                .count(),
+            1
+        );
+    }
+
+    //Enemies that haven't acted yet have their opening intent hidden too.
+    #[test]
+    fn hidden_opening_intents_hash_the_same() {
+        let mut rng = Rng::new();
+        let mut game = Game::test_fight(Character::IRONCLAD, vec![generate_jaw_worm(&mut rng, 0)]);
+        game.set_hidden_intents(true);
+        let hash = |ai_state: u32| {
+            let mut game = game.clone();
+            game.fight.enemies[0].as_mut().unwrap().ai_state = ai_state;
+            let state = ChoiceState {
+                game: Box::new(game),
+                choice: Choice::PlayCardState(Vec::new()),
+            };
+            let mut hasher = DefaultHasher::new();
+            state.hash_observation(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(0), hash(1));
+    }
+}
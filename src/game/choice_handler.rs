@@ -1,22 +1,23 @@
-use smallvec::SmallVec;
-
 use crate::{
     act::MapPosition,
     card::{
-        COLORLESS_CARDS, CURSE_CARDS, CardCharachter, CardType, IRONCLAD_CARDS, SelectCardEffect,
-        sample_card,
+        COLORLESS_CARDS, CURSE_CARDS, Card, CardBody, CardCharachter, CardRarity, CardType,
+        SelectCardEffect, sample_card,
     },
     fight::PlayCardContext,
     game::{
         Game, QUESTION_MONSTER_BASE_WEIGHT, QUESTION_SHOP_BASE_WEIGHT,
         QUESTION_TREASURE_BASE_WEIGHT,
         choice::{
-            Choice, ChooseEnemyAction, MapStateAction, PlayCardAction, RestSiteAction,
-            SelectCardAction,
+            BossRelicAction, Choice, ChooseEnemyAction, MapStateAction, PlayCardAction,
+            RestSiteAction, RewardAction, SelectCardAction, ShopAction, TreasureAction,
         },
-        encounter::Encounter,
+        reward::{CardRewardRoom, Rewards},
+        shop::Shop,
+        treasure::ChestSize,
     },
     map::RoomType,
+    relic::Relic,
 };
 
 impl Game {
@@ -25,31 +26,117 @@ impl Game {
         self.goto_map()
     }
 
+    pub(super) fn handle_offer_card_action(&mut self, idx: usize) -> Choice {
+        let card = self.base_deck.remove(idx);
+        match card.body.rarity() {
+            CardRarity::Curse => self.gain_relic(Relic::SpiritPoop),
+            CardRarity::Basic => {}
+            CardRarity::Common | CardRarity::Special => self.heal(5),
+            CardRarity::Uncommon => self.heal(self.player_max_hp),
+            CardRarity::Rare => {
+                self.gain_max_hp(10);
+                self.heal(self.player_max_hp);
+            }
+        }
+        self.goto_map()
+    }
+
     pub(super) fn handle_rest_site_action(&mut self, action: RestSiteAction) -> Choice {
         match action {
             RestSiteAction::Heal => {
-                self.heal((self.player_max_hp * 3) / 10);
+                let mut amount = (self.player_max_hp * 3) / 10;
+                if self.relics.has_relic(Relic::RegalPillow) {
+                    amount += 15;
+                }
+                self.heal(amount);
+                if self.relics.has_relic(Relic::DreamCatcher) {
+                    let rewards =
+                        Rewards {
+                            cards: vec![self.generate_card_reward(
+                                self.card_reward_size(),
+                                CardRewardRoom::Normal,
+                            )],
+                            ..Default::default()
+                        };
+                    return self.goto_rewards(rewards);
+                }
                 self.goto_map()
             }
             RestSiteAction::Upgrade => self.goto_upgrade_card(),
+            RestSiteAction::Recall => {
+                self.keys.ruby = true;
+                self.goto_map()
+            }
+            RestSiteAction::Lift => {
+                let lifted = self.relics.counter(Relic::Girya).expect("Player has Girya");
+                self.relics.set_counter(Relic::Girya, lifted + 1);
+                self.goto_map()
+            }
+            RestSiteAction::Toke => self.goto_remove_card(),
+            RestSiteAction::Dig => {
+                let rewards = Rewards {
+                    relics: vec![self.relics.pool.get_random_tier_relic(&mut self.rng)],
+                    ..Default::default()
+                };
+                self.goto_rewards(rewards)
+            }
         }
     }
 
+    pub(super) fn handle_remove_two_cards_action(&mut self, idx: usize) -> Choice {
+        self.base_deck.remove(idx);
+        self.goto_remove_card()
+    }
+
     pub(super) fn handle_transform_card_action(&mut self, idx: usize) -> Choice {
+        self.transform_card(idx);
+        self.goto_map()
+    }
+
+    pub(super) fn handle_transform_two_cards_action(&mut self, idx: usize) -> Choice {
+        self.transform_card(idx);
+        self.goto_transform_card()
+    }
+
+    pub(super) fn transform_card(&mut self, idx: usize) {
         let card = self.base_deck.remove(idx);
-        let transformed = if card.body.card_type() == CardType::Curse {
+        let transformed = self.transformed_card(&card);
+        self.add_card_to_deck(transformed);
+    }
+
+    //The cards are all removed before any are added so the new cards can't be picked.
+    pub(super) fn transform_random_cards(&mut self, count: usize) {
+        let mut items: Vec<usize> = (0..self.base_deck.len())
+            .filter(|i| self.base_deck[*i].body.removable())
+            .collect();
+        let mut picked = Vec::new();
+        for _ in 0..count {
+            let Some(idx) = self.rng.try_sample(items.len()) else {
+                break;
+            };
+            picked.push(items.swap_remove(idx));
+        }
+        picked.sort();
+        let cards: Vec<Card> = picked
+            .into_iter()
+            .rev()
+            .map(|idx| self.base_deck.remove(idx))
+            .collect();
+        for card in cards {
+            let transformed = self.transformed_card(&card);
+            self.add_card_to_deck(transformed);
+        }
+    }
+
+    fn transformed_card(&mut self, card: &Card) -> CardBody {
+        if card.body.card_type() == CardType::Curse {
             sample_card(CURSE_CARDS, &mut self.rng)
+        } else if card.charachter() == CardCharachter::COLORLESS {
+            sample_card(COLORLESS_CARDS, &mut self.rng)
         } else {
-            match card.charachter() {
-                CardCharachter::IRONCLAD => sample_card(IRONCLAD_CARDS, &mut self.rng),
-                CardCharachter::SILENT => todo!(),
-                CardCharachter::DEFECT => todo!(),
-                CardCharachter::WATCHER => todo!(),
-                CardCharachter::COLORLESS => sample_card(COLORLESS_CARDS, &mut self.rng),
-            }
-        };
-        self.add_card_to_deck(transformed);
-        self.goto_map()
+            //Class cards, including the shared Strike and Defend, transform into the player's class.
+            sample_card(self.class_cards(), &mut self.rng)
+        }
     }
 
     pub(super) fn handle_upgrade_card_action(&mut self, idx: usize) -> Choice {
@@ -57,14 +144,161 @@ impl Game {
         self.goto_map()
     }
 
+    pub(super) fn handle_duplicate_card_action(&mut self, idx: usize) -> Choice {
+        let card = self.base_deck[idx].clone();
+        self.obtain_card(card);
+        self.goto_map()
+    }
+
+    pub(super) fn handle_bottle_card_action(&mut self, idx: usize) -> Choice {
+        self.base_deck[idx].bottled = true;
+        self.base_deck.sort();
+        *self
+            .bottle_return
+            .take()
+            .expect("Bottling returns to a screen")
+    }
+
+    pub(super) fn handle_remove_and_upgrade_action(&mut self, idx: usize) -> Choice {
+        self.base_deck.remove(idx);
+        self.upgrade_random_cards(1);
+        self.goto_map()
+    }
+
+    pub(super) fn handle_reward_action(
+        &mut self,
+        mut rewards: Rewards,
+        action: RewardAction,
+    ) -> Choice {
+        match action {
+            RewardAction::TakeGold => {
+                self.gain_gold(rewards.gold);
+                rewards.gold = 0;
+            }
+            RewardAction::TakeRelic(idx) => {
+                let relic = rewards.relics.remove(idx as usize);
+                self.gain_relic(relic);
+            }
+            RewardAction::TakeCard(reward, card) => {
+                let mut cards = rewards.cards.remove(reward as usize);
+                self.obtain_card(cards.swap_remove(card as usize));
+            }
+            RewardAction::TakePotion(idx) => {
+                let potion = rewards.potions.remove(idx as usize);
+                self.gain_potion(potion);
+            }
+            RewardAction::DiscardPotion(slot) => {
+                self.potions.remove(slot as usize);
+            }
+            RewardAction::SingingBowl(reward) => {
+                rewards.cards.remove(reward as usize);
+                self.gain_max_hp(2);
+            }
+            RewardAction::Proceed => {
+                //The boss drops a chest of boss relics after its rewards.
+                //There is no chest after Act 3.
+                if self.act.room_history.last() == Some(&RoomType::Boss) {
+                    if let Some(boss) = self.act.second_boss.take() {
+                        return self.setup_encounter(boss);
+                    }
+                    if self.act.number >= 3 {
+                        return self.goto_next_act();
+                    }
+                    return self.goto_boss_relic();
+                }
+                return self.goto_map();
+            }
+        }
+        self.goto_rewards(rewards)
+    }
+
+    pub(super) fn handle_shop_action(&mut self, mut shop: Shop, action: ShopAction) -> Choice {
+        match action {
+            ShopAction::BuyCard(idx) => {
+                let card = shop.cards.remove(idx as usize);
+                self.lose_gold(card.price);
+                if self.relics.has_relic(Relic::TheCourier)
+                    && let Some(restocked) = self.restock_card(&card.item, &shop.cards)
+                {
+                    shop.cards.insert(idx as usize, restocked);
+                }
+                self.obtain_card(card.item);
+            }
+            ShopAction::BuyRelic(idx) => {
+                let relic = shop.relics.remove(idx as usize);
+                self.lose_gold(relic.price);
+                if self.relics.has_relic(Relic::TheCourier) {
+                    let restocked = self.restock_relic();
+                    shop.relics.insert(idx as usize, restocked);
+                }
+                self.gain_relic(relic.item);
+            }
+            ShopAction::BuyPotion(idx) => {
+                let potion = shop.potions.remove(idx as usize);
+                self.lose_gold(potion.price);
+                if self.relics.has_relic(Relic::TheCourier) {
+                    let restocked = self.shop_potion();
+                    shop.potions.insert(idx as usize, restocked);
+                }
+                self.gain_potion(potion.item);
+            }
+            ShopAction::RemoveCard(idx) => {
+                let price = shop.removal_price.take().expect("Removal is available");
+                self.lose_gold(price);
+                self.base_deck.remove(idx as usize);
+                self.cards_removed_at_shop += 1;
+            }
+            ShopAction::Leave => {
+                return self.goto_map();
+            }
+        }
+        //Maw Bank stops working once gold is spent at a shop.
+        self.relics.set_counter(Relic::MawBank, 1);
+        self.goto_shop_with(shop)
+    }
+
+    pub(super) fn handle_treasure_action(
+        &mut self,
+        size: ChestSize,
+        action: TreasureAction,
+    ) -> Choice {
+        match action {
+            TreasureAction::Open => {
+                let rewards = self.open_chest(size);
+                self.goto_rewards(rewards)
+            }
+            TreasureAction::OpenForSapphireKey => {
+                let mut rewards = self.open_chest(size);
+                rewards.relics.clear();
+                self.keys.sapphire = true;
+                self.goto_rewards(rewards)
+            }
+            TreasureAction::Skip => self.goto_map(),
+        }
+    }
+
+    pub(super) fn handle_boss_relic_action(
+        &mut self,
+        mut relics: Vec<Relic>,
+        action: BossRelicAction,
+    ) -> Choice {
+        if let BossRelicAction::Take(idx) = action {
+            self.gain_relic(relics.swap_remove(idx as usize));
+        }
+        self.goto_next_act()
+    }
+
     pub(super) fn handle_select_card_action(
         &mut self,
-        mut context: PlayCardContext,
+        mut context: Option<PlayCardContext>,
         effect: SelectCardEffect,
         action: SelectCardAction,
     ) -> Choice {
         self.perform_selected_action(&mut context, effect, action);
-        if let Some(choice) = self.resolve_actions(Some(context)) {
+        if effect == SelectCardEffect::Retain {
+            return self.end_turn();
+        }
+        if let Some(choice) = self.resolve_actions(context) {
             return choice;
         }
         self.play_card_choice()
@@ -80,7 +314,17 @@ impl Game {
                 //If a card doesn't require targets supply 0 as a target since it won't matter.
                 return self.play_card_targets(idx as usize, 0);
             }
-            PlayCardAction::EndTurn => self.enemy_phase(),
+            PlayCardAction::UsePotion(slot) => {
+                if self.potions[slot as usize].requires_target() {
+                    return self.choose_potion_target_choice(slot as usize);
+                }
+                self.use_potion(slot as usize, 0)
+            }
+            PlayCardAction::DiscardPotion(slot) => {
+                self.potions.remove(slot as usize);
+                self.play_card_choice()
+            }
+            PlayCardAction::EndTurn => self.end_turn(),
         }
     }
 
@@ -92,8 +336,26 @@ impl Game {
         self.play_card_targets(card_idx, action.enemy as usize)
     }
 
+    pub(super) fn handle_choose_potion_target_action(
+        &mut self,
+        slot: usize,
+        action: ChooseEnemyAction,
+    ) -> Choice {
+        self.use_potion(slot, action.enemy as usize)
+    }
+
     pub(super) fn handle_map_state_action(&mut self, action: MapStateAction) -> Choice {
         let prior_floor_shop = self.act.room_history.last() == Some(&RoomType::Shop);
+        //Jumps after the first floor are Wing Boots flights.
+        if matches!(action, MapStateAction::Jump(_))
+            && self.act.position.is_some()
+            && let Some(flights) = self.relics.counter(Relic::WingBoots)
+        {
+            self.relics.set_counter(Relic::WingBoots, flights + 1);
+        }
+        if self.relics.counter(Relic::MawBank) == Some(0) {
+            self.gain_gold(12);
+        }
         if let Some(position) = &mut self.act.position {
             position.y += 1;
         }
@@ -113,7 +375,11 @@ impl Game {
                 position.x += 1;
             }
         };
-        let room_type = self.map.rooms[position.y as usize][position.x as usize].room_type;
+        let room_type = if position.y as usize == self.map.num_floors {
+            RoomType::Boss
+        } else {
+            self.map.rooms[position.y as usize][position.x as usize].room_type
+        };
         self.act.room_history.push(room_type);
         match room_type {
             RoomType::QuestionMark => {
@@ -125,43 +391,45 @@ impl Game {
                 } else {
                     self.act.question_shop_weight
                 };
-                if roll < monster_weight {
+                //An event is represented by the QuestionMark room type.
+                let mut outcome = if roll < monster_weight {
                     self.act.question_monster_weight = QUESTION_MONSTER_BASE_WEIGHT;
                     self.act.question_shop_weight += QUESTION_SHOP_BASE_WEIGHT;
                     self.act.question_treasure_weight += QUESTION_TREASURE_BASE_WEIGHT;
-                    self.goto_fight()
+                    RoomType::Monster
                 } else if roll < monster_weight + shop_weight {
                     self.act.question_monster_weight += QUESTION_MONSTER_BASE_WEIGHT;
                     self.act.question_shop_weight = QUESTION_SHOP_BASE_WEIGHT;
                     self.act.question_treasure_weight += QUESTION_TREASURE_BASE_WEIGHT;
-                    self.goto_shop()
+                    RoomType::Shop
                 } else if roll < monster_weight + shop_weight + self.act.question_treasure_weight {
                     self.act.question_monster_weight += QUESTION_MONSTER_BASE_WEIGHT;
                     self.act.question_shop_weight += QUESTION_SHOP_BASE_WEIGHT;
                     self.act.question_treasure_weight = QUESTION_TREASURE_BASE_WEIGHT;
-                    self.goto_treasure()
+                    RoomType::Treasure
                 } else {
-                    self.goto_event()
+                    RoomType::QuestionMark
+                };
+                //The relics change the outcome after the weights are updated.
+                if outcome == RoomType::Monster && self.relics.has_relic(Relic::JuzuBracelet) {
+                    outcome = RoomType::QuestionMark;
+                }
+                if self.relics.tick(Relic::TinyChest, 4) {
+                    outcome = RoomType::Treasure;
+                }
+                match outcome {
+                    RoomType::Monster => self.goto_fight(),
+                    RoomType::Shop => self.goto_shop(),
+                    RoomType::Treasure => self.goto_treasure(),
+                    _ => self.goto_event(),
                 }
             }
             RoomType::Shop => self.goto_shop(),
             RoomType::Treasure => self.goto_treasure(),
             RoomType::Rest => self.goto_rest_site(),
             RoomType::Monster => self.goto_fight(),
-            RoomType::Elite => {
-                let mut elites: SmallVec<[Encounter; 3]> = SmallVec::new();
-                for encounter in &[
-                    Encounter::GremlinNob,
-                    Encounter::Lagavulin,
-                    Encounter::Sentries,
-                ] {
-                    if self.act.prior_elite != Some(*encounter) {
-                        elites.push(*encounter);
-                    }
-                }
-                let encounter_idx = self.rng.sample(elites.len());
-                self.setup_encounter(elites[encounter_idx])
-            }
+            RoomType::Elite => self.goto_elite(),
+            RoomType::Boss => self.setup_encounter(self.act.boss),
             RoomType::Unassigned => {
                 panic!("Somehow reached an unassigned room!")
             }
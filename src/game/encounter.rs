@@ -1,23 +1,74 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    card::{Buff, Debuff},
+    card::{Buff, CardType, Debuff},
     enemies::{
-        blue_slaver::generate_blue_slaver, cultist::generate_cultist,
-        fungi_beast::generate_fungi_beast, green_louse::generate_green_louse,
-        gremlin_fat::generate_fat_gremlin, gremlin_mad::generate_mad_gremlin,
-        gremlin_nob::generate_gremlin_nob, gremlin_shield::generate_shield_gremlin,
-        gremlin_sneaky::generate_sneaky_gremlin, gremlin_wizard::generate_wizard_gremlin,
-        jaw_worm::generate_jaw_worm, lagavulin::generate_lagavulin,
+        awakened_one::generate_awakened_one,
+        bear::generate_bear,
+        blue_slaver::generate_blue_slaver,
+        book_of_stabbing::generate_book_of_stabbing,
+        bronze_automaton::{AUTOMATON_SLOT, generate_bronze_automaton},
+        byrd::generate_byrd,
+        centurion::generate_centurion,
+        champ::generate_champ,
+        chosen::generate_chosen,
+        corrupt_heart::generate_corrupt_heart,
+        cultist::generate_cultist,
+        dagger::generate_dagger,
+        darkling::generate_darkling,
+        deca::generate_deca,
+        donu::generate_donu,
+        exploder::generate_exploder,
+        fungi_beast::generate_fungi_beast,
+        giant_head::generate_giant_head,
+        green_louse::generate_green_louse,
+        gremlin_fat::generate_fat_gremlin,
+        gremlin_leader::{LEADER_SLOT, generate_gremlin_leader},
+        gremlin_mad::generate_mad_gremlin,
+        gremlin_nob::generate_gremlin_nob,
+        gremlin_shield::generate_shield_gremlin,
+        gremlin_sneaky::generate_sneaky_gremlin,
+        gremlin_wizard::generate_wizard_gremlin,
+        hexaghost::generate_hexaghost,
+        jaw_worm::{generate_hard_jaw_worm, generate_jaw_worm},
+        lagavulin::generate_lagavulin,
         large_black_slime::generate_large_black_slime,
-        large_green_slime::generate_large_green_slime, looter::generate_looter,
-        med_black_slime::generate_med_black_slime, med_green_slime::generate_med_green_slime,
-        red_louse::generate_red_louse, red_slaver::generate_red_slaver, sentry::generate_sentry,
+        large_green_slime::generate_large_green_slime,
+        looter::generate_looter,
+        maw::generate_maw,
+        med_black_slime::generate_med_black_slime,
+        med_green_slime::generate_med_green_slime,
+        mugger::generate_mugger,
+        mystic::generate_mystic,
+        nemesis::generate_nemesis,
+        orb_walker::generate_orb_walker,
+        pointy::generate_pointy,
+        red_louse::generate_red_louse,
+        red_slaver::generate_red_slaver,
+        reptomancer::generate_reptomancer,
+        repulsor::generate_repulsor,
+        romeo::generate_romeo,
+        sentry::generate_sentry,
+        shelled_parasite::generate_shelled_parasite,
+        slime_boss::generate_slime_boss,
         small_black_slime::generate_small_black_slime,
         small_green_slime::generate_small_green_slime,
+        snake_plant::generate_snake_plant,
+        snecko::generate_snecko,
+        spheric_guardian::generate_spheric_guardian,
+        spiker::generate_spiker,
+        spire_growth::generate_spire_growth,
+        spire_shield::generate_spire_shield,
+        spire_spear::generate_spire_spear,
+        taskmaster::generate_taskmaster,
+        the_collector::{COLLECTOR_SLOT, generate_the_collector},
+        the_guardian::generate_the_guardian,
+        time_eater::generate_time_eater,
+        transient::generate_transient,
+        writhing_mass::generate_writhing_mass,
     },
-    fight::Enemy,
-    game::{Choice, Game, apply_debuff_to_enemy},
+    fight::{Enemy, FightRewards},
+    game::{Choice, Game, apply_debuff_to_enemy, reward::CardRewardRoom},
     map::RoomType,
     relic::Relic,
 };
@@ -42,38 +93,302 @@ pub enum Encounter {
     ExordiumThugs,
     ExordiumWildlife,
     Looter,
+    TheGuardian,
+    Hexaghost,
+    SlimeBoss,
+    //Act 2
+    SphericGuardian,
+    Chosen,
+    ShellParasite,
+    ThreeByrds,
+    TwoThieves,
+    ChosenAndByrds,
+    SentryAndSphere,
+    CultistAndChosen,
+    ThreeCultists,
+    ShelledParasiteAndFungi,
+    Snecko,
+    SnakePlant,
+    CenturionAndMystic,
+    GremlinLeader,
+    Slavers,
+    BookOfStabbing,
+    Champ,
+    TheCollector,
+    BronzeAutomaton,
+    //Act 2 event fights
+    MaskedBandits,
+    ColosseumSlavers,
+    ColosseumNobs,
+    //Act 3
+    ThreeDarklings,
+    OrbWalker,
+    ThreeShapes,
+    FourShapes,
+    Maw,
+    SphereAndTwoShapes,
+    JawWormHorde,
+    WrithingMass,
+    SpireGrowth,
+    Transient,
+    GiantHead,
+    Nemesis,
+    Reptomancer,
+    AwakenedOne,
+    TimeEater,
+    DonuAndDeca,
+    //Act 4
+    ShieldAndSpear,
+    CorruptHeart,
+}
+
+//The first fights of each act are drawn from an easier pool.
+pub fn weak_encounters(act: i32) -> &'static [Encounter] {
+    match act {
+        1 => &[
+            Encounter::StarterCultist,
+            Encounter::StarterJawWorm,
+            Encounter::StarterLouse,
+            Encounter::StarterSlimes,
+        ],
+        2 => &[
+            Encounter::SphericGuardian,
+            Encounter::Chosen,
+            Encounter::ShellParasite,
+            Encounter::ThreeByrds,
+            Encounter::TwoThieves,
+        ],
+        3 => &[
+            Encounter::ThreeDarklings,
+            Encounter::OrbWalker,
+            Encounter::ThreeShapes,
+        ],
+        _ => panic!("Act {} isn't implemented", act),
+    }
+}
+
+//Strong encounters with their weights.
+pub fn strong_encounters(act: i32) -> (&'static [Encounter], &'static [u32]) {
+    match act {
+        1 => (
+            &[
+                Encounter::BlueSlaver,
+                Encounter::GremlinGang,
+                Encounter::Looter,
+                Encounter::LargeSlime,
+                Encounter::FiveSmallSlimes,
+                Encounter::ExordiumThugs,
+                Encounter::ExordiumWildlife,
+                Encounter::RedSlaver,
+                Encounter::ThreeLouse,
+                Encounter::TwoMushrooms,
+            ],
+            &[4, 2, 4, 4, 2, 3, 3, 2, 4, 4],
+        ),
+        2 => (
+            &[
+                Encounter::ChosenAndByrds,
+                Encounter::SentryAndSphere,
+                Encounter::CultistAndChosen,
+                Encounter::ThreeCultists,
+                Encounter::ShelledParasiteAndFungi,
+                Encounter::Snecko,
+                Encounter::SnakePlant,
+                Encounter::CenturionAndMystic,
+            ],
+            &[2, 2, 3, 3, 3, 4, 6, 6],
+        ),
+        3 => (
+            &[
+                Encounter::FourShapes,
+                Encounter::Maw,
+                Encounter::SphereAndTwoShapes,
+                Encounter::JawWormHorde,
+                Encounter::ThreeDarklings,
+                Encounter::WrithingMass,
+                Encounter::SpireGrowth,
+                Encounter::Transient,
+            ],
+            &[1, 1, 1, 1, 1, 1, 1, 1],
+        ),
+        _ => panic!("Act {} isn't implemented", act),
+    }
+}
+
+pub fn elite_encounters(act: i32) -> &'static [Encounter] {
+    match act {
+        1 => &[
+            Encounter::GremlinNob,
+            Encounter::Lagavulin,
+            Encounter::Sentries,
+        ],
+        2 => &[
+            Encounter::GremlinLeader,
+            Encounter::Slavers,
+            Encounter::BookOfStabbing,
+        ],
+        3 => &[
+            Encounter::GiantHead,
+            Encounter::Nemesis,
+            Encounter::Reptomancer,
+        ],
+        4 => &[Encounter::ShieldAndSpear],
+        _ => panic!("Act {} isn't implemented", act),
+    }
+}
+
+//The first strong fight can't have the same enemies as the last weak fight.
+pub fn repeats_weak_fight(encounter: Encounter, prior: Option<Encounter>) -> bool {
+    let Some(prior) = prior else {
+        return false;
+    };
+    match prior {
+        Encounter::StarterLouse => encounter == Encounter::ThreeLouse,
+        Encounter::StarterSlimes => {
+            encounter == Encounter::LargeSlime || encounter == Encounter::FiveSmallSlimes
+        }
+        Encounter::SphericGuardian => encounter == Encounter::SentryAndSphere,
+        Encounter::ThreeByrds => encounter == Encounter::ChosenAndByrds,
+        Encounter::Chosen => {
+            encounter == Encounter::ChosenAndByrds || encounter == Encounter::CultistAndChosen
+        }
+        Encounter::ThreeShapes => encounter == Encounter::FourShapes,
+        Encounter::ThreeDarklings => encounter == Encounter::ThreeDarklings,
+        _ => false,
+    }
+}
+
+impl Encounter {
+    pub fn is_elite(&self) -> bool {
+        matches!(
+            self,
+            Encounter::Lagavulin
+                | Encounter::GremlinNob
+                | Encounter::Sentries
+                | Encounter::GremlinLeader
+                | Encounter::Slavers
+                | Encounter::BookOfStabbing
+                | Encounter::GiantHead
+                | Encounter::Nemesis
+                | Encounter::Reptomancer
+                | Encounter::ShieldAndSpear
+        )
+    }
+
+    pub fn is_boss(&self) -> bool {
+        matches!(
+            self,
+            Encounter::TheGuardian
+                | Encounter::Hexaghost
+                | Encounter::SlimeBoss
+                | Encounter::Champ
+                | Encounter::TheCollector
+                | Encounter::BronzeAutomaton
+                | Encounter::AwakenedOne
+                | Encounter::TimeEater
+                | Encounter::DonuAndDeca
+                | Encounter::CorruptHeart
+        )
+    }
+
+    //Events which start fights may adjust these after the fight is set up.
+    fn rewards(&self) -> FightRewards {
+        if self.is_boss() {
+            FightRewards {
+                gold_min: 95,
+                gold_max: 105,
+                relic_count: 0,
+                fixed_relics: vec![],
+                resume_event: None,
+                emerald_key: false,
+                card_room: CardRewardRoom::Boss,
+            }
+        } else if self.is_elite() {
+            FightRewards {
+                gold_min: 25,
+                gold_max: 35,
+                relic_count: 1,
+                fixed_relics: vec![],
+                resume_event: None,
+                emerald_key: false,
+                card_room: CardRewardRoom::Elite,
+            }
+        } else {
+            FightRewards {
+                gold_min: 10,
+                gold_max: 20,
+                relic_count: 0,
+                fixed_relics: vec![],
+                resume_event: None,
+                emerald_key: false,
+                card_room: CardRewardRoom::Normal,
+            }
+        }
+    }
 }
 
 impl Game {
     fn generate_random_louse(&mut self) -> Enemy {
         if self.rng.sample(2) == 0 {
-            generate_green_louse(&mut self.rng)
+            generate_green_louse(&mut self.rng, self.ascension)
         } else {
-            generate_red_louse(&mut self.rng)
+            generate_red_louse(&mut self.rng, self.ascension)
         }
     }
 
     fn generate_slaver(&mut self) -> Enemy {
         if self.rng.sample(2) == 0 {
-            generate_blue_slaver(&mut self.rng)
+            generate_blue_slaver(&mut self.rng, self.ascension)
         } else {
-            generate_red_slaver(&mut self.rng)
+            generate_red_slaver(&mut self.rng, self.ascension)
+        }
+    }
+
+    pub(super) fn generate_random_gremlin(&mut self) -> Enemy {
+        const WEIGHTS: &[u32] = &[2, 2, 2, 1, 1];
+        match self.rng.sample_weighted(WEIGHTS) {
+            0 => generate_mad_gremlin(&mut self.rng, self.ascension),
+            1 => generate_sneaky_gremlin(&mut self.rng, self.ascension),
+            2 => generate_fat_gremlin(&mut self.rng, self.ascension),
+            3 => generate_wizard_gremlin(&mut self.rng, self.ascension),
+            _ => generate_shield_gremlin(&mut self.rng, self.ascension),
         }
     }
 
+    //Gremlins summoned by the Gremlin Leader flee when it dies.
+    pub(super) fn generate_minion_gremlin(&mut self) -> Enemy {
+        let mut gremlin = self.generate_random_gremlin();
+        gremlin.buffs.minion = true;
+        gremlin
+    }
+
+    //Shapes are drawn without replacement from a pool with 2 of each.
+    fn generate_shapes(&mut self, count: usize) -> Vec<Enemy> {
+        let mut pool = [0, 0, 1, 1, 2, 2];
+        self.rng.shuffle(&mut pool);
+        pool[0..count]
+            .iter()
+            .map(|shape| match shape {
+                0 => generate_repulsor(&mut self.rng, self.ascension),
+                1 => generate_exploder(&mut self.rng, self.ascension),
+                _ => generate_spiker(&mut self.rng, self.ascension),
+            })
+            .collect()
+    }
+
     fn wildlife_1(&mut self) -> Enemy {
         let sample = self.rng.sample(3);
         match sample {
             0 => self.generate_random_louse(),
-            1 => generate_med_black_slime(&mut self.rng),
-            2 => generate_med_green_slime(&mut self.rng),
+            1 => generate_med_black_slime(&mut self.rng, self.ascension),
+            2 => generate_med_green_slime(&mut self.rng, self.ascension),
             _ => {
                 panic!("Unexpected rng result!")
             }
         }
     }
 
-    fn start_of_fight_relics(&mut self) {
+    fn start_of_fight_relics(&mut self, encounter: Encounter) {
         if self.relics.has_relic(Relic::Akabeko) {
             self.apply_buff_to_player(Buff::Vigor(8));
         }
@@ -91,38 +406,96 @@ impl Game {
                 apply_debuff_to_enemy(&mut self.fight.enemies[i], Debuff::Vulnerable(1));
             }
         }
+        if self.relics.has_relic(Relic::PhilosophersStone) {
+            for i in self.fight.enemies.indicies() {
+                self.fight.enemies[i].buffs.strength += 1;
+            }
+        }
         if self.relics.has_relic(Relic::BloodVial) {
             self.heal(2);
         }
         if self.relics.has_relic(Relic::BronzeScales) {
             self.apply_buff_to_player(Buff::Thorns(3));
         }
+        if self.relics.has_relic(Relic::Lantern) {
+            self.fight.energy += 1;
+        }
+        if self.relics.has_relic(Relic::Vajra) {
+            self.apply_buff_to_player(Buff::Strength(1));
+        }
+        if self.relics.has_relic(Relic::OddlySmoothStone) {
+            self.apply_buff_to_player(Buff::Dexterity(1));
+        }
+        if self.relics.has_relic(Relic::FossilizedHelix) {
+            self.apply_buff_to_player(Buff::Buffer(1));
+        }
+        if self.relics.has_relic(Relic::ThreadandNeedle) {
+            self.apply_buff_to_player(Buff::PlatedArmor(4));
+        }
+        //Girya's counter is the number of times it was lifted.
+        if let Some(lifted) = self.relics.counter(Relic::Girya)
+            && lifted > 0
+        {
+            self.apply_buff_to_player(Buff::Strength(lifted));
+        }
+        if self.relics.has_relic(Relic::DuVuDoll) {
+            let curses = self
+                .base_deck
+                .iter()
+                .filter(|card| card.body.card_type() == CardType::Curse)
+                .count() as i32;
+            if curses > 0 {
+                self.apply_buff_to_player(Buff::Strength(curses));
+            }
+        }
+        if self.relics.has_relic(Relic::Pantograph)
+            && self.act.room_history.last() == Some(&RoomType::Boss)
+        {
+            self.heal(25);
+        }
+        //Elites start with 25% less HP, including elites fought in events.
+        if self.relics.has_relic(Relic::PreservedInsect) && encounter.is_elite() {
+            for i in self.fight.enemies.indicies() {
+                let enemy = &mut self.fight.enemies[i];
+                enemy.hp = enemy.max_hp * 3 / 4;
+            }
+        }
+    }
+
+    //Enemy HP, damage and movesets are scaled by each enemy's generator and AI.
+    fn apply_ascension(&mut self, encounter: Encounter) {
+        //Ascension 13 bosses drop 25% less gold.
+        if encounter.is_boss() && self.ascension >= 13 {
+            self.fight.rewards.gold_min = self.fight.rewards.gold_min * 3 / 4;
+            self.fight.rewards.gold_max = self.fight.rewards.gold_max * 3 / 4;
+        }
     }
 
     pub(super) fn setup_encounter(&mut self, encounter: Encounter) -> Choice {
         self.setup_fight();
+        self.fight.rewards = encounter.rewards();
         match encounter {
             Encounter::Lagavulin => {
-                self.fight.enemies[0] = Some(generate_lagavulin(&mut self.rng));
+                self.fight.enemies[0] = Some(generate_lagavulin(&mut self.rng, self.ascension));
             }
             Encounter::GremlinNob => {
-                self.fight.enemies[0] = Some(generate_gremlin_nob(&mut self.rng));
+                self.fight.enemies[0] = Some(generate_gremlin_nob(&mut self.rng, self.ascension));
             }
             Encounter::Sentries => {
-                self.fight.enemies[0] = Some(generate_sentry(&mut self.rng, 0));
-                self.fight.enemies[1] = Some(generate_sentry(&mut self.rng, 1));
-                self.fight.enemies[2] = Some(generate_sentry(&mut self.rng, 0));
+                self.fight.enemies[0] = Some(generate_sentry(&mut self.rng, 0, self.ascension));
+                self.fight.enemies[1] = Some(generate_sentry(&mut self.rng, 1, self.ascension));
+                self.fight.enemies[2] = Some(generate_sentry(&mut self.rng, 0, self.ascension));
             }
             Encounter::EventMushrooms => {
-                self.fight.enemies[0] = Some(generate_fungi_beast(&mut self.rng));
-                self.fight.enemies[1] = Some(generate_fungi_beast(&mut self.rng));
-                self.fight.enemies[2] = Some(generate_fungi_beast(&mut self.rng));
+                self.fight.enemies[0] = Some(generate_fungi_beast(&mut self.rng, self.ascension));
+                self.fight.enemies[1] = Some(generate_fungi_beast(&mut self.rng, self.ascension));
+                self.fight.enemies[2] = Some(generate_fungi_beast(&mut self.rng, self.ascension));
             }
             Encounter::StarterCultist => {
-                self.fight.enemies[0] = Some(generate_cultist(&mut self.rng));
+                self.fight.enemies[0] = Some(generate_cultist(&mut self.rng, self.ascension));
             }
             Encounter::StarterJawWorm => {
-                self.fight.enemies[0] = Some(generate_jaw_worm(&mut self.rng))
+                self.fight.enemies[0] = Some(generate_jaw_worm(&mut self.rng, self.ascension))
             }
             Encounter::StarterLouse => {
                 for i in 0..2 {
@@ -131,23 +504,27 @@ impl Game {
             }
             Encounter::StarterSlimes => {
                 if self.rng.sample(2) == 0 {
-                    self.fight.enemies[0] = Some(generate_small_black_slime(&mut self.rng));
-                    self.fight.enemies[1] = Some(generate_med_green_slime(&mut self.rng));
+                    self.fight.enemies[0] =
+                        Some(generate_small_black_slime(&mut self.rng, self.ascension));
+                    self.fight.enemies[1] =
+                        Some(generate_med_green_slime(&mut self.rng, self.ascension));
                 } else {
-                    self.fight.enemies[0] = Some(generate_small_green_slime(&mut self.rng));
-                    self.fight.enemies[1] = Some(generate_med_black_slime(&mut self.rng));
+                    self.fight.enemies[0] =
+                        Some(generate_small_green_slime(&mut self.rng, self.ascension));
+                    self.fight.enemies[1] =
+                        Some(generate_med_black_slime(&mut self.rng, self.ascension));
                 }
             }
             Encounter::BlueSlaver => {
-                self.fight.enemies[0] = Some(generate_blue_slaver(&mut self.rng));
+                self.fight.enemies[0] = Some(generate_blue_slaver(&mut self.rng, self.ascension));
             }
             Encounter::ExordiumThugs => {
                 let front_enemy = self.wildlife_1();
                 self.fight.enemies[0] = Some(front_enemy);
                 let sample = self.rng.sample(3);
                 let back_enemy = match sample {
-                    0 => generate_cultist(&mut self.rng),
-                    1 => generate_looter(&mut self.rng),
+                    0 => generate_cultist(&mut self.rng, self.ascension),
+                    1 => generate_looter(&mut self.rng, self.ascension),
                     2 => self.generate_slaver(),
                     _ => {
                         panic!("Unexpected rng result!")
@@ -159,18 +536,23 @@ impl Game {
                 let front_enemy = self.wildlife_1();
                 self.fight.enemies[0] = Some(front_enemy);
                 let enemy = if self.rng.sample(2) == 0 {
-                    generate_fungi_beast(&mut self.rng)
+                    generate_fungi_beast(&mut self.rng, self.ascension)
                 } else {
-                    generate_jaw_worm(&mut self.rng)
+                    generate_jaw_worm(&mut self.rng, self.ascension)
                 };
                 self.fight.enemies[1] = Some(enemy);
             }
             Encounter::FiveSmallSlimes => {
-                self.fight.enemies[0] = Some(generate_small_black_slime(&mut self.rng));
-                self.fight.enemies[1] = Some(generate_small_green_slime(&mut self.rng));
-                self.fight.enemies[2] = Some(generate_small_black_slime(&mut self.rng));
-                self.fight.enemies[3] = Some(generate_small_green_slime(&mut self.rng));
-                self.fight.enemies[4] = Some(generate_small_black_slime(&mut self.rng));
+                self.fight.enemies[0] =
+                    Some(generate_small_black_slime(&mut self.rng, self.ascension));
+                self.fight.enemies[1] =
+                    Some(generate_small_green_slime(&mut self.rng, self.ascension));
+                self.fight.enemies[2] =
+                    Some(generate_small_black_slime(&mut self.rng, self.ascension));
+                self.fight.enemies[3] =
+                    Some(generate_small_green_slime(&mut self.rng, self.ascension));
+                self.fight.enemies[4] =
+                    Some(generate_small_black_slime(&mut self.rng, self.ascension));
             }
             Encounter::GremlinGang => {
                 enum GremlinEnemy {
@@ -193,24 +575,32 @@ impl Game {
                 self.rng.shuffle(&mut enemy_pool);
                 for i in 0..4 {
                     self.fight.enemies[i] = Some(match enemy_pool[i] {
-                        GremlinEnemy::Mad => generate_mad_gremlin(&mut self.rng),
-                        GremlinEnemy::Sneaky => generate_sneaky_gremlin(&mut self.rng),
-                        GremlinEnemy::Fat => generate_fat_gremlin(&mut self.rng),
-                        GremlinEnemy::Wizard => generate_wizard_gremlin(&mut self.rng),
-                        GremlinEnemy::Shield => generate_shield_gremlin(&mut self.rng),
+                        GremlinEnemy::Mad => generate_mad_gremlin(&mut self.rng, self.ascension),
+                        GremlinEnemy::Sneaky => {
+                            generate_sneaky_gremlin(&mut self.rng, self.ascension)
+                        }
+                        GremlinEnemy::Fat => generate_fat_gremlin(&mut self.rng, self.ascension),
+                        GremlinEnemy::Wizard => {
+                            generate_wizard_gremlin(&mut self.rng, self.ascension)
+                        }
+                        GremlinEnemy::Shield => {
+                            generate_shield_gremlin(&mut self.rng, self.ascension)
+                        }
                     })
                 }
             }
             Encounter::LargeSlime => {
                 self.fight.enemies[0] = Some(if self.rng.sample(2) == 0 {
-                    generate_large_black_slime(&mut self.rng)
+                    generate_large_black_slime(&mut self.rng, self.ascension)
                 } else {
-                    generate_large_green_slime(&mut self.rng)
+                    generate_large_green_slime(&mut self.rng, self.ascension)
                 })
             }
-            Encounter::Looter => self.fight.enemies[0] = Some(generate_looter(&mut self.rng)),
+            Encounter::Looter => {
+                self.fight.enemies[0] = Some(generate_looter(&mut self.rng, self.ascension))
+            }
             Encounter::RedSlaver => {
-                self.fight.enemies[0] = Some(generate_red_slaver(&mut self.rng));
+                self.fight.enemies[0] = Some(generate_red_slaver(&mut self.rng, self.ascension));
             }
             Encounter::ThreeLouse => {
                 for i in 0..3 {
@@ -219,11 +609,196 @@ impl Game {
             }
             Encounter::TwoMushrooms => {
                 for i in 0..2 {
-                    self.fight.enemies[i] = Some(generate_fungi_beast(&mut self.rng))
+                    self.fight.enemies[i] =
+                        Some(generate_fungi_beast(&mut self.rng, self.ascension))
                 }
             }
+            Encounter::TheGuardian => {
+                self.fight.enemies[0] = Some(generate_the_guardian(self.ascension))
+            }
+            Encounter::Hexaghost => {
+                self.fight.enemies[0] = Some(generate_hexaghost(self.ascension))
+            }
+            Encounter::SlimeBoss => {
+                self.fight.enemies[0] = Some(generate_slime_boss(self.ascension))
+            }
+            Encounter::SphericGuardian => {
+                self.fight.enemies[0] = Some(generate_spheric_guardian(self.ascension));
+            }
+            Encounter::Chosen => {
+                self.fight.enemies[0] = Some(generate_chosen(&mut self.rng, self.ascension))
+            }
+            Encounter::ShellParasite => {
+                self.fight.enemies[0] =
+                    Some(generate_shelled_parasite(&mut self.rng, self.ascension));
+            }
+            Encounter::ThreeByrds => {
+                for i in 0..3 {
+                    self.fight.enemies[i] = Some(generate_byrd(&mut self.rng, self.ascension));
+                }
+            }
+            Encounter::TwoThieves => {
+                self.fight.enemies[0] = Some(generate_looter(&mut self.rng, self.ascension));
+                self.fight.enemies[1] = Some(generate_mugger(&mut self.rng, self.ascension));
+            }
+            Encounter::ChosenAndByrds => {
+                self.fight.enemies[0] = Some(generate_byrd(&mut self.rng, self.ascension));
+                self.fight.enemies[1] = Some(generate_chosen(&mut self.rng, self.ascension));
+            }
+            Encounter::SentryAndSphere => {
+                self.fight.enemies[0] = Some(generate_sentry(&mut self.rng, 0, self.ascension));
+                self.fight.enemies[1] = Some(generate_spheric_guardian(self.ascension));
+            }
+            Encounter::CultistAndChosen => {
+                self.fight.enemies[0] = Some(generate_cultist(&mut self.rng, self.ascension));
+                self.fight.enemies[1] = Some(generate_chosen(&mut self.rng, self.ascension));
+            }
+            Encounter::ThreeCultists => {
+                for i in 0..3 {
+                    self.fight.enemies[i] = Some(generate_cultist(&mut self.rng, self.ascension));
+                }
+            }
+            Encounter::ShelledParasiteAndFungi => {
+                self.fight.enemies[0] =
+                    Some(generate_shelled_parasite(&mut self.rng, self.ascension));
+                self.fight.enemies[1] = Some(generate_fungi_beast(&mut self.rng, self.ascension));
+            }
+            Encounter::Snecko => {
+                self.fight.enemies[0] = Some(generate_snecko(&mut self.rng, self.ascension))
+            }
+            Encounter::SnakePlant => {
+                self.fight.enemies[0] = Some(generate_snake_plant(&mut self.rng, self.ascension));
+            }
+            Encounter::CenturionAndMystic => {
+                self.fight.enemies[0] = Some(generate_centurion(&mut self.rng, self.ascension));
+                self.fight.enemies[1] = Some(generate_mystic(&mut self.rng, self.ascension));
+            }
+            Encounter::GremlinLeader => {
+                for i in 0..2 {
+                    self.fight.enemies[i] = Some(self.generate_minion_gremlin());
+                }
+                self.fight.enemies[LEADER_SLOT] =
+                    Some(generate_gremlin_leader(&mut self.rng, self.ascension));
+            }
+            Encounter::Slavers => {
+                self.fight.enemies[0] = Some(generate_blue_slaver(&mut self.rng, self.ascension));
+                self.fight.enemies[1] = Some(generate_taskmaster(&mut self.rng, self.ascension));
+                self.fight.enemies[2] = Some(generate_red_slaver(&mut self.rng, self.ascension));
+            }
+            Encounter::BookOfStabbing => {
+                self.fight.enemies[0] =
+                    Some(generate_book_of_stabbing(&mut self.rng, self.ascension));
+            }
+            Encounter::Champ => {
+                self.fight.enemies[0] = Some(generate_champ(&mut self.rng, self.ascension))
+            }
+            //The Collector and the Automaton summon their minions on their first turn.
+            Encounter::TheCollector => {
+                self.fight.enemies[COLLECTOR_SLOT] = Some(generate_the_collector(self.ascension));
+            }
+            Encounter::BronzeAutomaton => {
+                self.fight.enemies[AUTOMATON_SLOT] =
+                    Some(generate_bronze_automaton(self.ascension));
+            }
+            Encounter::MaskedBandits => {
+                self.fight.enemies[0] = Some(generate_pointy(self.ascension));
+                self.fight.enemies[1] = Some(generate_romeo(&mut self.rng, self.ascension));
+                self.fight.enemies[2] = Some(generate_bear(&mut self.rng, self.ascension));
+            }
+            Encounter::ColosseumSlavers => {
+                self.fight.enemies[0] = Some(generate_blue_slaver(&mut self.rng, self.ascension));
+                self.fight.enemies[1] = Some(generate_red_slaver(&mut self.rng, self.ascension));
+            }
+            Encounter::ColosseumNobs => {
+                self.fight.enemies[0] = Some(generate_taskmaster(&mut self.rng, self.ascension));
+                self.fight.enemies[1] = Some(generate_gremlin_nob(&mut self.rng, self.ascension));
+            }
+            Encounter::ThreeDarklings => {
+                self.fight.enemies[0] =
+                    Some(generate_darkling(&mut self.rng, true, self.ascension));
+                self.fight.enemies[1] =
+                    Some(generate_darkling(&mut self.rng, false, self.ascension));
+                self.fight.enemies[2] =
+                    Some(generate_darkling(&mut self.rng, true, self.ascension));
+            }
+            Encounter::OrbWalker => {
+                self.fight.enemies[0] = Some(generate_orb_walker(&mut self.rng, self.ascension));
+            }
+            Encounter::ThreeShapes | Encounter::FourShapes => {
+                let count = if encounter == Encounter::ThreeShapes {
+                    3
+                } else {
+                    4
+                };
+                for (i, shape) in self.generate_shapes(count).into_iter().enumerate() {
+                    self.fight.enemies[i] = Some(shape);
+                }
+            }
+            Encounter::Maw => {
+                self.fight.enemies[0] = Some(generate_maw(&mut self.rng, self.ascension))
+            }
+            Encounter::SphereAndTwoShapes => {
+                for (i, shape) in self.generate_shapes(2).into_iter().enumerate() {
+                    self.fight.enemies[i] = Some(shape);
+                }
+                self.fight.enemies[2] = Some(generate_spheric_guardian(self.ascension));
+            }
+            Encounter::JawWormHorde => {
+                for i in 0..3 {
+                    self.fight.enemies[i] =
+                        Some(generate_hard_jaw_worm(&mut self.rng, self.ascension));
+                }
+            }
+            Encounter::WrithingMass => {
+                self.fight.enemies[0] = Some(generate_writhing_mass(&mut self.rng, self.ascension));
+            }
+            Encounter::SpireGrowth => {
+                self.fight.enemies[0] = Some(generate_spire_growth(&mut self.rng, self.ascension));
+            }
+            Encounter::Transient => {
+                self.fight.enemies[0] = Some(generate_transient(&mut self.rng, self.ascension));
+            }
+            Encounter::GiantHead => {
+                self.fight.enemies[0] = Some(generate_giant_head(&mut self.rng, self.ascension));
+            }
+            Encounter::Nemesis => {
+                self.fight.enemies[0] = Some(generate_nemesis(&mut self.rng, self.ascension))
+            }
+            Encounter::Reptomancer => {
+                self.fight.enemies[1] = Some(generate_dagger(&mut self.rng, self.ascension));
+                self.fight.enemies[2] = Some(generate_reptomancer(&mut self.rng, self.ascension));
+                self.fight.enemies[3] = Some(generate_dagger(&mut self.rng, self.ascension));
+            }
+            //The cultists flee once the Awakened One is dead for good.
+            Encounter::AwakenedOne => {
+                for i in 0..2 {
+                    let mut cultist = generate_cultist(&mut self.rng, self.ascension);
+                    cultist.buffs.minion = true;
+                    self.fight.enemies[i] = Some(cultist);
+                }
+                self.fight.enemies[2] = Some(generate_awakened_one(&mut self.rng, self.ascension));
+            }
+            Encounter::TimeEater => {
+                self.fight.enemies[0] = Some(generate_time_eater(&mut self.rng, self.ascension));
+            }
+            Encounter::DonuAndDeca => {
+                self.fight.enemies[0] = Some(generate_deca(&mut self.rng, self.ascension));
+                self.fight.enemies[1] = Some(generate_donu(&mut self.rng, self.ascension));
+            }
+            Encounter::ShieldAndSpear => {
+                self.fight.enemies[0] = Some(generate_spire_shield(&mut self.rng, self.ascension));
+                self.fight.enemies[1] = Some(generate_spire_spear(&mut self.rng, self.ascension));
+            }
+            Encounter::CorruptHeart => {
+                self.fight.enemies[0] = Some(generate_corrupt_heart(&mut self.rng, self.ascension));
+            }
+        }
+        self.apply_ascension(encounter);
+        self.start_of_fight_relics(encounter);
+        //Start of fight effects like Mercury Hourglass can queue up actions.
+        if let Some(choice) = self.resolve_actions(None) {
+            return choice;
         }
-        self.start_of_fight_relics();
         self.play_card_choice()
     }
 }
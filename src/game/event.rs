@@ -1,13 +1,45 @@
+mod addict;
+mod back_to_basics;
+mod beggar;
 mod big_fish;
+mod bonfire_spirits;
 mod cleric;
+mod colosseum;
+mod cursed_tome;
 mod dead_adventurer;
+mod designer;
+mod divine_fountain;
+mod drug_dealer;
+mod duplicator;
+mod face_trader;
+mod forgotten_altar;
+mod ghosts;
 mod golden_idol;
+mod golden_shrine;
+mod knowing_skull;
+mod lab;
 mod living_wall;
+mod masked_bandits;
+mod match_and_keep;
+mod neow;
+mod nest;
+mod nloth;
+mod ominous_forge;
+mod purifier;
 mod scrap_ooze;
 mod shining_light;
 mod shrooms;
+mod the_joust;
+mod the_library;
+mod the_mausoleum;
 mod the_ssserpent;
+mod transmogrifier;
+mod upgrade_shrine;
+mod vampires;
+mod we_meet_again;
+mod wheel_of_change;
 mod wing_statue;
+mod woman_in_blue;
 mod world_of_goop;
 
 use crate::{
@@ -15,10 +47,20 @@ use crate::{
         Choice, Game,
         choice::EventAction,
         event::{
-            big_fish::BigFish, cleric::Cleric, dead_adventurer::DeadAdventurer,
-            golden_idol::GoldenIdol, living_wall::LivingWall, scrap_ooze::ScrapOoze,
-            shining_light::ShiningLight, shrooms::HypnotizingShrooms, the_ssserpent::TheSsserpent,
-            wing_statue::WingStatue, world_of_goop::WorldOfGoop,
+            addict::Addict, back_to_basics::BackToBasics, beggar::Beggar, big_fish::BigFish,
+            bonfire_spirits::BonfireSpirits, cleric::Cleric, colosseum::Colosseum,
+            cursed_tome::CursedTome, dead_adventurer::DeadAdventurer, designer::Designer,
+            divine_fountain::DivineFountain, drug_dealer::DrugDealer, duplicator::Duplicator,
+            face_trader::FaceTrader, forgotten_altar::ForgottenAltar, ghosts::Ghosts,
+            golden_idol::GoldenIdol, golden_shrine::GoldenShrine, knowing_skull::KnowingSkull,
+            lab::Lab, living_wall::LivingWall, masked_bandits::MaskedBandits,
+            match_and_keep::MatchAndKeep, neow::Neow, nest::Nest, nloth::Nloth,
+            ominous_forge::OminousForge, purifier::Purifier, scrap_ooze::ScrapOoze,
+            shining_light::ShiningLight, shrooms::HypnotizingShrooms, the_joust::TheJoust,
+            the_library::TheLibrary, the_mausoleum::TheMausoleum, the_ssserpent::TheSsserpent,
+            transmogrifier::Transmogrifier, upgrade_shrine::UpgradeShrine, vampires::Vampires,
+            we_meet_again::WeMeetAgain, wheel_of_change::WheelOfChange, wing_statue::WingStatue,
+            woman_in_blue::WomanInBlue, world_of_goop::WorldOfGoop,
         },
     },
     rng::Rng,
@@ -31,29 +73,131 @@ Event Generation works as follows:
 There is first a weighted coin flip with 75% standard and 25% shrine.
 If shrine if flipped and there are no more shrines left a standard event is generated.
 Otherwise, a standard event is generated with a shrine as backup.
+
+Conditional shrines are only chosen when EventRoom::can_appear allows it.
+They stay in the pool until they can appear.
 */
 
-//Shrines
-//BonfireSpirits,
-//GoldenShrine,
-//Lab,
-//MatchAndKeep,
-//OminousForge,
-//Purifier,
-//Transmogifier,
-//UpgradeShrine,
-//WeMeetAgain,
-//WheelOfChange,
-//Conditional Shrines
-//TheDivineFountain,
-//Duplicator,
-//Designer,
-//FaceTrader,
-//KnowingSkull,
-//Nloth,
-//Joust,
-//WomanInBlue,
-//I'm not including Secret Portal.
+//Designer and Duplicator are in the Act 2 and 3 shrine pools, Knowing Skull, N'loth
+//and The Joust are Act 2 only. I'm not including Secret Portal.
+
+//Shrines in this list are only seen once per run, even if they are in multiple acts' pools.
+pub const ONE_TIME_SHRINES: &[EventName] = &[
+    EventName::BonfireSpirits,
+    EventName::Designer,
+    EventName::DivineFountain,
+    EventName::Duplicator,
+    EventName::FaceTrader,
+    EventName::KnowingSkull,
+    EventName::Lab,
+    EventName::Nloth,
+    EventName::OminousForge,
+    EventName::TheJoust,
+    EventName::WeMeetAgain,
+    EventName::WomanInBlue,
+];
+
+//Each act has its own pool of standard events and shrines.
+//Events are removed from the pool once they are seen.
+pub fn act_1_events() -> Vec<EventName> {
+    vec![
+        EventName::BigFish,
+        EventName::Cleric,
+        EventName::DeadAdventurer,
+        EventName::GoldenIdol,
+        EventName::HypnotizingShrooms,
+        EventName::LivingWall,
+        EventName::ScrapOoze,
+        EventName::ShiningLight,
+        EventName::TheSsserpent,
+        EventName::WorldOfGoop,
+        EventName::WingStatue,
+    ]
+}
+
+pub fn act_1_shrines() -> Vec<EventName> {
+    vec![
+        EventName::BonfireSpirits,
+        EventName::DivineFountain,
+        EventName::FaceTrader,
+        EventName::GoldenShrine,
+        EventName::Lab,
+        EventName::MatchAndKeep,
+        EventName::OminousForge,
+        EventName::Purifier,
+        EventName::Transmogrifier,
+        EventName::UpgradeShrine,
+        EventName::WeMeetAgain,
+        EventName::WheelOfChange,
+        EventName::WomanInBlue,
+    ]
+}
+
+pub fn act_2_events() -> Vec<EventName> {
+    vec![
+        EventName::Addict,
+        EventName::BackToBasics,
+        EventName::Beggar,
+        EventName::Colosseum,
+        EventName::CursedTome,
+        EventName::DrugDealer,
+        EventName::ForgottenAltar,
+        EventName::Ghosts,
+        EventName::MaskedBandits,
+        EventName::Nest,
+        EventName::TheLibrary,
+        EventName::TheMausoleum,
+        EventName::Vampires,
+    ]
+}
+
+pub fn act_2_shrines() -> Vec<EventName> {
+    vec![
+        EventName::BonfireSpirits,
+        EventName::Designer,
+        EventName::DivineFountain,
+        EventName::Duplicator,
+        EventName::FaceTrader,
+        EventName::GoldenShrine,
+        EventName::KnowingSkull,
+        EventName::Lab,
+        EventName::MatchAndKeep,
+        EventName::Nloth,
+        EventName::OminousForge,
+        EventName::Purifier,
+        EventName::TheJoust,
+        EventName::Transmogrifier,
+        EventName::UpgradeShrine,
+        EventName::WeMeetAgain,
+        EventName::WheelOfChange,
+        EventName::WomanInBlue,
+    ]
+}
+
+//TODO - The Beyond's own events aren't implemented yet, so Act 3 only has shrines.
+pub fn act_3_events() -> Vec<EventName> {
+    vec![]
+}
+
+pub fn act_3_shrines() -> Vec<EventName> {
+    vec![
+        EventName::BonfireSpirits,
+        EventName::Designer,
+        EventName::DivineFountain,
+        EventName::Duplicator,
+        EventName::FaceTrader,
+        EventName::GoldenShrine,
+        EventName::Lab,
+        EventName::MatchAndKeep,
+        EventName::OminousForge,
+        EventName::Purifier,
+        EventName::Transmogrifier,
+        EventName::UpgradeShrine,
+        EventName::WeMeetAgain,
+        EventName::WheelOfChange,
+        EventName::WomanInBlue,
+    ]
+}
 
 pub trait EventRoom {
     fn new(rng: &mut Rng) -> Self;
@@ -61,12 +205,18 @@ pub trait EventRoom {
     fn take_action(self, game: &mut Game, action: EventAction) -> Choice;
     fn action_str(&self, game: &Game, action: EventAction) -> String;
     fn name(&self) -> &'static str;
+    //Conditional shrines only appear when the game state allows it.
+    fn can_appear(_game: &Game) -> bool {
+        true
+    }
+    //Events with hidden information rerandomize it here when the state is reseeded.
+    fn reseed(&mut self, _rng: &mut Rng) {}
 }
 
 macro_rules! event_array {
     ($($x:ident),*) => {
         paste!{
-            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
             pub enum EventName {
                 $(
                     $x,
@@ -81,13 +231,21 @@ macro_rules! event_array {
             }
 
             impl EventName {
-                fn new(&self, rng: &mut Rng) -> Event {
+                pub fn new(&self, rng: &mut Rng) -> Event {
                     match &self {
                         $(
                             Self::$x => Event::$x($x::new(rng)),
                         )*
                     }
                 }
+
+                pub fn can_appear(&self, game: &Game) -> bool {
+                    match &self {
+                        $(
+                            Self::$x => $x::can_appear(game),
+                        )*
+                    }
+                }
             }
 
             impl Event {
@@ -122,21 +280,91 @@ macro_rules! event_array {
                             )*
                         }
                 }
+
+                pub fn reseed(&mut self, rng: &mut Rng) {
+                        match self {
+                            $(
+                                Self::$x(event) => event.reseed(rng),
+                            )*
+                        }
+                }
             }
         }
     }
 }
 
+impl Event {
+    //Events with several steps return themselves as the next choice until they are left.
+    pub fn into_choice(self, game: &Game) -> Choice {
+        let actions = self.get_actions(game);
+        Choice::Event(self, actions)
+    }
+}
+
 event_array!(
+    Addict,
+    BackToBasics,
+    Beggar,
     BigFish,
+    BonfireSpirits,
     Cleric,
+    Colosseum,
+    CursedTome,
     DeadAdventurer,
+    Designer,
+    DivineFountain,
+    DrugDealer,
+    Duplicator,
+    FaceTrader,
+    ForgottenAltar,
+    Ghosts,
     GoldenIdol,
+    GoldenShrine,
     HypnotizingShrooms,
+    KnowingSkull,
+    Lab,
     LivingWall,
+    MaskedBandits,
+    MatchAndKeep,
+    Neow,
+    Nest,
+    Nloth,
+    OminousForge,
+    Purifier,
     ScrapOoze,
     ShiningLight,
+    TheJoust,
+    TheLibrary,
+    TheMausoleum,
     TheSsserpent,
-    WorldOfGoop,
-    WingStatue
+    Transmogrifier,
+    UpgradeShrine,
+    Vampires,
+    WeMeetAgain,
+    WheelOfChange,
+    WingStatue,
+    WomanInBlue,
+    WorldOfGoop
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //Shrines like Divine Fountain and Face Trader use the 25% shrine roll,
+    //so they can't also be in a standard event pool.
+    #[test]
+    fn shrines_are_not_standard_events() {
+        let pools = [
+            (act_1_events(), act_1_shrines()),
+            (act_2_events(), act_2_shrines()),
+            (act_3_events(), act_3_shrines()),
+        ];
+        for (events, shrines) in pools {
+            assert!(events.iter().all(|event| !shrines.contains(event)));
+            assert!(events.iter().all(|event| !ONE_TIME_SHRINES.contains(event)));
+        }
+        assert!(act_1_shrines().contains(&EventName::DivineFountain));
+        assert!(act_1_shrines().contains(&EventName::FaceTrader));
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{
+    card::CardBody,
+    game::{Choice, Game, choice::EventAction, event::EventRoom},
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+//The Pleading Vagrant. Robbing him gives the relic with a Shame.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Addict;
+
+const PRICE: i32 = 85;
+
+impl EventRoom for Addict {
+    fn get_actions(&self, game: &Game) -> Vec<EventAction> {
+        let mut actions = Vec::new();
+        if game.gold >= PRICE {
+            actions.push(EventAction(0));
+        }
+        actions.push(EventAction(1));
+        actions.push(EventAction(2));
+        actions
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0 => {
+                game.lose_gold(PRICE);
+                let relic = game.relics.pool.get_random_tier_relic(&mut game.rng);
+                game.gain_relic(relic);
+                game.goto_map()
+            }
+            1 => {
+                let relic = game.relics.pool.get_random_tier_relic(&mut game.rng);
+                game.gain_relic(relic);
+                game.add_card_to_deck(CardBody::Shame);
+                game.goto_map()
+            }
+            2 => game.goto_map(),
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, _game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => format!("Pay {} gold. Obtain a relic.", PRICE),
+            1 => "Rob. Obtain a relic. Become Cursed - Shame.".to_string(),
+            2 => "Leave".to_string(),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Pleading Vagrant"
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        Addict
+    }
+}
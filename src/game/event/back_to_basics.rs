@@ -0,0 +1,49 @@
+use crate::{
+    card::CardBody,
+    game::{Choice, Game, choice::EventAction, event::EventRoom},
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct BackToBasics;
+
+impl EventRoom for BackToBasics {
+    fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
+        vec![EventAction(0), EventAction(1)]
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0 => game.goto_remove_card(),
+            1 => {
+                for card in &mut game.base_deck {
+                    if (card.body == CardBody::Strike || card.body == CardBody::Defend)
+                        && card.can_upgrade()
+                    {
+                        card.upgrade();
+                    }
+                }
+                game.base_deck.sort();
+                game.goto_map()
+            }
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, _game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => "Elegance. Remove a card from your deck.".to_string(),
+            1 => "Simplicity. Upgrade all Strikes and Defends.".to_string(),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Back to Basics"
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        BackToBasics
+    }
+}
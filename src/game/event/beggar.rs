@@ -0,0 +1,52 @@
+use crate::{
+    game::{Choice, Game, choice::EventAction, event::EventRoom},
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Beggar;
+
+const PRICE: i32 = 75;
+
+impl EventRoom for Beggar {
+    fn get_actions(&self, game: &Game) -> Vec<EventAction> {
+        let mut actions = Vec::new();
+        if game.gold >= PRICE {
+            actions.push(EventAction(0));
+        }
+        actions.push(EventAction(1));
+        actions
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0 => {
+                game.lose_gold(PRICE);
+                game.goto_remove_card()
+            }
+            1 => game.goto_map(),
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, _game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => format!("Pay {} gold. Remove a card from your deck.", PRICE),
+            1 => "Leave".to_string(),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Old Beggar"
+    }
+
+    fn can_appear(game: &Game) -> bool {
+        game.gold >= PRICE
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        Beggar
+    }
+}
@@ -30,7 +30,7 @@ impl EventRoom for BigFish {
             2 => {
                 game.add_card_to_deck(CardBody::Regret);
                 let relic = game.relics.pool.get_random_tier_relic(&mut game.rng);
-                game.relics.add(relic); //TODO - handle bottle relics.
+                game.gain_relic(relic);
                 //TODO - handle relic pickup state.
                 game.goto_map()
             }
@@ -0,0 +1,37 @@
+use crate::{
+    game::{Choice, Game, choice::EventAction, event::EventRoom},
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+//The reward for the offered card is handled in handle_offer_card_action.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct BonfireSpirits;
+
+impl EventRoom for BonfireSpirits {
+    fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
+        (0..=0).map(EventAction).collect()
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0 => game.goto_offer_card(),
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, _game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => "Offer a card.".to_string(),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Bonfire Spirits"
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        BonfireSpirits
+    }
+}
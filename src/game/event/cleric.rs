@@ -11,13 +11,17 @@ fn heal_amount(game: &Game) -> i32 {
     return game.player_max_hp / 4;
 }
 
+fn purify_cost(game: &Game) -> i32 {
+    if game.ascension >= 15 { 75 } else { 50 }
+}
+
 impl EventRoom for Cleric {
     fn get_actions(&self, game: &Game) -> Vec<EventAction> {
         let mut res = Vec::new();
         if game.gold > 35 {
             res.push(EventAction(0));
         }
-        if game.gold > 50 {
+        if game.gold > purify_cost(game) {
             res.push(EventAction(1));
         }
         res.push(EventAction(2));
@@ -32,7 +36,7 @@ impl EventRoom for Cleric {
                 game.goto_map()
             }
             1 => {
-                game.lose_gold(50);
+                game.lose_gold(purify_cost(game));
                 game.goto_remove_card()
             }
             2 => game.goto_map(),
@@ -45,7 +49,10 @@ impl EventRoom for Cleric {
             0 => {
                 format!("Pay 35 gold: Heal {}.", heal_amount(game))
             }
-            1 => "Pay 50 gold: Remove a card from your deck.".to_string(),
+            1 => format!(
+                "Pay {} gold: Remove a card from your deck.",
+                purify_cost(game)
+            ),
             2 => "Leave".to_string(),
             _ => panic!("Invalid action: {}.", action.0),
         }
@@ -0,0 +1,90 @@
+use crate::{
+    game::{
+        Choice, Game,
+        choice::EventAction,
+        encounter::Encounter,
+        event::{Event, EventRoom},
+        reward::CardRewardRoom,
+    },
+    map::NUM_FLOORS,
+    relic::RelicRarity,
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+//The first fight gives no rewards and returns to the event. The player can then leave
+//or fight the Nobs for rewards.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Colosseum {
+    fought_slavers: bool,
+}
+
+const GOLD: i32 = 100;
+
+impl EventRoom for Colosseum {
+    fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
+        if self.fought_slavers {
+            vec![EventAction(0), EventAction(1)]
+        } else {
+            vec![EventAction(0)]
+        }
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match (self.fought_slavers, action.0) {
+            (false, 0) => {
+                let choice = game.setup_encounter(Encounter::ColosseumSlavers);
+                game.fight.rewards.resume_event = Some(Event::Colosseum(Colosseum {
+                    fought_slavers: true,
+                }));
+                choice
+            }
+            (true, 0) => game.goto_map(),
+            (true, 1) => {
+                let choice = game.setup_encounter(Encounter::ColosseumNobs);
+                let rare = game.relics.pool.get_relic(&mut game.rng, RelicRarity::Rare);
+                let uncommon = game
+                    .relics
+                    .pool
+                    .get_relic(&mut game.rng, RelicRarity::Uncommon);
+                let rewards = &mut game.fight.rewards;
+                rewards.gold_min = GOLD;
+                rewards.gold_max = GOLD;
+                rewards.relic_count = 0;
+                rewards.fixed_relics = vec![rare, uncommon];
+                rewards.card_room = CardRewardRoom::Elite;
+                choice
+            }
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, _game: &Game, action: EventAction) -> String {
+        match (self.fought_slavers, action.0) {
+            (false, 0) => "Fight the Slavers.".to_string(),
+            (true, 0) => "Cowardice. Escape.".to_string(),
+            (true, 1) => format!(
+                "Victory. Fight the Nobs for {} gold, a rare relic and an uncommon relic.",
+                GOLD
+            ),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "The Colosseum"
+    }
+
+    //The Colosseum only appears in the second half of the act.
+    fn can_appear(game: &Game) -> bool {
+        game.act
+            .position
+            .is_some_and(|position| position.y as usize > NUM_FLOORS / 2)
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        Colosseum {
+            fought_slavers: false,
+        }
+    }
+}
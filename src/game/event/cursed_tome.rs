@@ -0,0 +1,90 @@
+use crate::{
+    game::{
+        Choice, Game,
+        choice::EventAction,
+        event::{Event, EventRoom},
+    },
+    relic::Relic,
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+//Each page read costs more hp. After the last page the book can be taken for a relic.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct CursedTome {
+    pages_read: i32,
+}
+
+const LAST_PAGE: i32 = 3;
+const TAKE_DAMAGE: i32 = 10;
+const STOP_DAMAGE: i32 = 3;
+
+impl EventRoom for CursedTome {
+    fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
+        if self.pages_read == 0 || self.pages_read == LAST_PAGE {
+            vec![EventAction(0), EventAction(1)]
+        } else {
+            vec![EventAction(0)]
+        }
+    }
+
+    fn take_action(mut self, game: &mut Game, action: EventAction) -> Choice {
+        match (self.pages_read, action.0) {
+            (0, 1) => return game.goto_map(),
+            (LAST_PAGE, 0) => {
+                game.player_lose_hp(TAKE_DAMAGE, false);
+                if game.player_hp <= 0 {
+                    return Choice::Loss;
+                }
+                let books: Vec<Relic> =
+                    [Relic::Necronomicon, Relic::Enrichidon, Relic::NilrysCodex]
+                        .into_iter()
+                        .filter(|book| !game.relics.has_relic(book.clone()))
+                        .collect();
+                let relic = game
+                    .rng
+                    .try_sample(books.len())
+                    .map_or(Relic::Circlet, |idx| books[idx].clone());
+                game.gain_relic(relic);
+                return game.goto_map();
+            }
+            (LAST_PAGE, 1) => {
+                game.player_lose_hp(STOP_DAMAGE, false);
+                if game.player_hp <= 0 {
+                    return Choice::Loss;
+                }
+                return game.goto_map();
+            }
+            (_, 0) => {}
+            _ => panic!("Invalid action: {}", action.0),
+        }
+        self.pages_read += 1;
+        game.player_lose_hp(self.pages_read, false);
+        if game.player_hp <= 0 {
+            return Choice::Loss;
+        }
+        Event::CursedTome(self).into_choice(game)
+    }
+
+    fn action_str(&self, _game: &Game, action: EventAction) -> String {
+        match (self.pages_read, action.0) {
+            (0, 0) => "Read. Lose 1 hp.".to_string(),
+            (0, 1) => "Leave".to_string(),
+            (LAST_PAGE, 0) => format!(
+                "Take the book. Obtain a book relic. Lose {} hp.",
+                TAKE_DAMAGE
+            ),
+            (LAST_PAGE, 1) => format!("Stop reading. Lose {} hp.", STOP_DAMAGE),
+            (pages, 0) => format!("Continue. Lose {} hp.", pages + 1),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Cursed Tome"
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        CursedTome { pages_read: 0 }
+    }
+}
@@ -35,9 +35,10 @@ impl DeadAdventurer {
     fn loot_taken(&self) -> usize {
         3 - self.loots.len()
     }
-    fn elite_arrives_chance(&self) -> usize {
+    fn elite_arrives_chance(&self, game: &Game) -> usize {
         let c = self.loot_taken();
-        25 + 25 * c
+        let base = if game.ascension >= 15 { 35 } else { 25 };
+        base + 25 * c
     }
 }
 
@@ -50,7 +51,7 @@ impl EventRoom for DeadAdventurer {
         match action.0 {
             0 => game.goto_map(),
             1 => {
-                let chance = self.elite_arrives_chance();
+                let chance = self.elite_arrives_chance(game);
                 let elite_arrived = game.rng.sample(100) < chance;
                 if elite_arrived {
                     let encounter = game.setup_encounter(match self.elite {
@@ -78,9 +79,10 @@ impl EventRoom for DeadAdventurer {
                             }
                         }
                     }
-                    game.fight.rewards.gold_min = gold;
-                    game.fight.rewards.gold_max = gold;
-                    game.fight.rewards.relic_count = relics;
+                    //The leftover loot is on top of the usual elite rewards.
+                    game.fight.rewards.gold_min += gold;
+                    game.fight.rewards.gold_max += gold;
+                    game.fight.rewards.relic_count += relics;
                     encounter
                 } else {
                     let c = game.rng.sample(self.loots.len());
@@ -92,14 +94,13 @@ impl EventRoom for DeadAdventurer {
                         }
                         DeadAdventurerLoot::Relic => {
                             let relic = game.relics.pool.get_random_tier_relic(&mut game.rng);
-                            game.relics.add(relic);
+                            game.gain_relic(relic);
                         }
                     }
                     if self.loots.len() == 0 {
                         game.goto_map()
                     } else {
-                        let actions = self.get_actions(game);
-                        Choice::Event(Event::DeadAdventurer(self), actions)
+                        Event::DeadAdventurer(self).into_choice(game)
                     }
                 }
             }
@@ -107,12 +108,12 @@ impl EventRoom for DeadAdventurer {
         }
     }
 
-    fn action_str(&self, _game: &Game, action: EventAction) -> String {
+    fn action_str(&self, game: &Game, action: EventAction) -> String {
         match action.0 {
             0 => "Leave".to_string(),
             1 => format!(
                 "Loot. {}% chance elite arrives.",
-                self.elite_arrives_chance()
+                self.elite_arrives_chance(game)
             ),
             _ => panic!("Invalid action: {}.", action.0),
         }
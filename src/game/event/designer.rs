@@ -0,0 +1,138 @@
+use crate::{
+    game::{Choice, Game, choice::EventAction, event::EventRoom},
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+//Costs and punch damage are (normal, Ascension 15).
+const ADJUSTMENT_COST: [i32; 2] = [40, 50];
+const CLEAN_UP_COST: [i32; 2] = [60, 75];
+const FULL_SERVICE_COST: [i32; 2] = [90, 110];
+const PUNCH_DAMAGE: [i32; 2] = [3, 5];
+
+fn amount(game: &Game, amounts: [i32; 2]) -> i32 {
+    amounts[(game.ascension >= 15) as usize]
+}
+
+//Whether Adjustments and Clean Up let the player choose is rolled when the event is generated.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Designer {
+    adjustment_upgrades_one: bool,
+    clean_up_removes_card: bool,
+}
+
+fn has_upgradable(game: &Game) -> bool {
+    game.base_deck.iter().any(|card| card.can_upgrade())
+}
+
+fn has_removable(game: &Game) -> bool {
+    game.base_deck.iter().any(|card| card.body.removable())
+}
+
+impl EventRoom for Designer {
+    fn get_actions(&self, game: &Game) -> Vec<EventAction> {
+        let mut actions = Vec::new();
+        if game.gold >= amount(game, ADJUSTMENT_COST) && has_upgradable(game) {
+            actions.push(EventAction(0));
+        }
+        if game.gold >= amount(game, CLEAN_UP_COST) && has_removable(game) {
+            actions.push(EventAction(1));
+        }
+        if game.gold >= amount(game, FULL_SERVICE_COST) && has_removable(game) {
+            actions.push(EventAction(2));
+        }
+        actions.push(EventAction(3));
+        actions
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0 => {
+                game.lose_gold(amount(game, ADJUSTMENT_COST));
+                if self.adjustment_upgrades_one {
+                    game.goto_upgrade_card()
+                } else {
+                    game.upgrade_random_cards(2);
+                    game.goto_map()
+                }
+            }
+            1 => {
+                game.lose_gold(amount(game, CLEAN_UP_COST));
+                if self.clean_up_removes_card {
+                    game.goto_remove_card()
+                } else {
+                    game.transform_random_cards(2);
+                    game.goto_map()
+                }
+            }
+            2 => {
+                game.lose_gold(amount(game, FULL_SERVICE_COST));
+                game.goto_remove_and_upgrade_card()
+            }
+            3 => {
+                game.player_lose_hp(amount(game, PUNCH_DAMAGE), false);
+                if game.player_hp <= 0 {
+                    return Choice::Loss;
+                }
+                game.goto_map()
+            }
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => {
+                if self.adjustment_upgrades_one {
+                    format!(
+                        "Adjustments. Lose {} gold. Upgrade a card.",
+                        amount(game, ADJUSTMENT_COST)
+                    )
+                } else {
+                    format!(
+                        "Adjustments. Lose {} gold. Upgrade 2 random cards.",
+                        amount(game, ADJUSTMENT_COST)
+                    )
+                }
+            }
+            1 => {
+                if self.clean_up_removes_card {
+                    format!(
+                        "Clean Up. Lose {} gold. Remove a card.",
+                        amount(game, CLEAN_UP_COST)
+                    )
+                } else {
+                    format!(
+                        "Clean Up. Lose {} gold. Transform 2 random cards.",
+                        amount(game, CLEAN_UP_COST)
+                    )
+                }
+            }
+            2 => {
+                format!(
+                    "Full Service. Lose {} gold. Remove a card, then upgrade a random card.",
+                    amount(game, FULL_SERVICE_COST)
+                )
+            }
+            3 => {
+                format!("Punch. Lose {} hp.", amount(game, PUNCH_DAMAGE))
+            }
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Designer In-Spire"
+    }
+
+    fn can_appear(game: &Game) -> bool {
+        game.gold >= 75
+    }
+
+    fn new(rng: &mut Rng) -> Self {
+        Designer {
+            adjustment_upgrades_one: rng.sample(2) == 0,
+            clean_up_removes_card: rng.sample(2) == 0,
+        }
+    }
+}
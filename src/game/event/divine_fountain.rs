@@ -0,0 +1,55 @@
+use crate::{
+    card::CardType,
+    game::{Choice, Game, choice::EventAction, event::EventRoom},
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct DivineFountain;
+
+fn is_removable_curse(game: &Game, idx: usize) -> bool {
+    let body = game.base_deck[idx].body;
+    body.card_type() == CardType::Curse && body.removable()
+}
+
+impl EventRoom for DivineFountain {
+    fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
+        (0..=1).map(EventAction).collect()
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0 => {
+                for i in (0..game.base_deck.len()).rev() {
+                    if is_removable_curse(game, i) {
+                        game.base_deck.remove(i);
+                    }
+                }
+                game.goto_map()
+            }
+            1 => game.goto_map(),
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, _game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => "Drink. Remove all curses.".to_string(),
+            1 => "Leave.".to_string(),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "The Divine Fountain"
+    }
+
+    fn can_appear(game: &Game) -> bool {
+        (0..game.base_deck.len()).any(|i| is_removable_curse(game, i))
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        DivineFountain
+    }
+}
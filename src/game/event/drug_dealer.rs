@@ -0,0 +1,49 @@
+use crate::{
+    card::CardBody,
+    game::{Choice, Game, choice::EventAction, event::EventRoom},
+    relic::Relic,
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+//Augmenter.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct DrugDealer;
+
+impl EventRoom for DrugDealer {
+    fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
+        (0..=2).map(EventAction).collect()
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0 => {
+                game.add_card_to_deck(CardBody::JAX);
+                game.goto_map()
+            }
+            1 => game.goto_transform_two_cards(),
+            2 => {
+                game.gain_relic(Relic::MutagenicStrength);
+                game.goto_map()
+            }
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, _game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => "Test J.A.X. Obtain J.A.X.".to_string(),
+            1 => "Become a Test Subject. Transform 2 cards.".to_string(),
+            2 => "Ingest Mutagens. Obtain Mutagenic Strength.".to_string(),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Augmenter"
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        DrugDealer
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{
+    game::{Choice, Game, choice::EventAction, event::EventRoom},
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Duplicator;
+
+impl EventRoom for Duplicator {
+    fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
+        (0..=1).map(EventAction).collect()
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0 => game.goto_duplicate_card(),
+            1 => game.goto_map(),
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, _game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => "Pray. Duplicate a card in your deck.".to_string(),
+            1 => "Leave.".to_string(),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Duplicator"
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        Duplicator
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{
+    game::{Choice, Game, choice::EventAction, event::EventRoom},
+    relic::Relic,
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+const FACES: &[Relic] = &[
+    Relic::CultistHeadpiece,
+    Relic::FaceofCleric,
+    Relic::GremlinVisage,
+    Relic::NlothsHungryFace,
+    Relic::SsserpentHead,
+];
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct FaceTrader;
+
+fn damage_amount(game: &Game) -> i32 {
+    std::cmp::max(game.player_max_hp / 10, 1)
+}
+
+fn gold_amount(game: &Game) -> i32 {
+    if game.ascension >= 15 { 50 } else { 75 }
+}
+
+impl EventRoom for FaceTrader {
+    fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
+        (0..=2).map(EventAction).collect()
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0 => {
+                game.player_lose_hp(damage_amount(game), false);
+                if game.player_hp <= 0 {
+                    return Choice::Loss;
+                }
+                game.gain_gold(gold_amount(game));
+                game.goto_map()
+            }
+            1 => {
+                let faces: Vec<Relic> = FACES
+                    .iter()
+                    .filter(|face| !game.relics.has_relic((*face).clone()))
+                    .cloned()
+                    .collect();
+                let relic = match game.rng.try_sample(faces.len()) {
+                    Some(idx) => faces[idx].clone(),
+                    None => Relic::Circlet,
+                };
+                game.gain_relic(relic);
+                game.goto_map()
+            }
+            2 => game.goto_map(),
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => {
+                format!(
+                    "Touch. Lose {} hp. Gain {} gold.",
+                    damage_amount(game),
+                    gold_amount(game)
+                )
+            }
+            1 => "Trade. Obtain a random face relic.".to_string(),
+            2 => "Leave.".to_string(),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Face Trader"
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        FaceTrader
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{
+    card::CardBody,
+    game::{Choice, Game, choice::EventAction, event::EventRoom},
+    relic::Relic,
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ForgottenAltar;
+
+const MAX_HP_GAIN: i32 = 5;
+
+fn damage_amount(game: &Game) -> i32 {
+    let percent = if game.ascension >= 15 { 0.35 } else { 0.25 };
+    (game.player_max_hp as f32 * percent).round() as i32
+}
+
+impl EventRoom for ForgottenAltar {
+    fn get_actions(&self, game: &Game) -> Vec<EventAction> {
+        let mut actions = Vec::new();
+        if game.relics.has_relic(Relic::GoldenIdol) {
+            actions.push(EventAction(0));
+        }
+        actions.push(EventAction(1));
+        actions.push(EventAction(2));
+        actions
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0 => {
+                game.relics.remove(Relic::GoldenIdol);
+                game.gain_relic(Relic::BloodyIdol);
+                game.goto_map()
+            }
+            1 => {
+                game.gain_max_hp(MAX_HP_GAIN);
+                game.player_lose_hp(damage_amount(game), false);
+                if game.player_hp <= 0 {
+                    return Choice::Loss;
+                }
+                game.goto_map()
+            }
+            2 => {
+                game.add_card_to_deck(CardBody::Decay);
+                game.goto_map()
+            }
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => "Offer the Golden Idol. Obtain the Bloody Idol.".to_string(),
+            1 => format!(
+                "Sacrifice. Gain {} max hp. Lose {} hp.",
+                MAX_HP_GAIN,
+                damage_amount(game)
+            ),
+            2 => "Desecrate. Become Cursed - Decay.".to_string(),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Forgotten Altar"
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        ForgottenAltar
+    }
+}
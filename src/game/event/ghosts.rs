@@ -0,0 +1,58 @@
+use crate::{
+    card::CardBody,
+    game::{Choice, Game, choice::EventAction, event::EventRoom},
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+//Council of Ghosts.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Ghosts;
+
+fn apparitions(game: &Game) -> usize {
+    if game.ascension >= 15 { 3 } else { 5 }
+}
+
+fn lose_max_hp_amount(game: &Game) -> i32 {
+    (game.player_max_hp + 1) / 2
+}
+
+impl EventRoom for Ghosts {
+    fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
+        vec![EventAction(0), EventAction(1)]
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0 => {
+                game.player_lose_max_hp(lose_max_hp_amount(game));
+                for _ in 0..apparitions(game) {
+                    game.add_card_to_deck(CardBody::Apparition);
+                }
+                game.goto_map()
+            }
+            1 => game.goto_map(),
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => format!(
+                "Accept. Obtain {} Apparitions. Lose {} max hp.",
+                apparitions(game),
+                lose_max_hp_amount(game)
+            ),
+            1 => "Refuse".to_string(),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Council of Ghosts"
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        Ghosts
+    }
+}
@@ -10,11 +10,13 @@ use serde::{Deserialize, Serialize};
 pub struct GoldenIdol;
 
 fn damage_amount(game: &Game) -> i32 {
-    return (game.player_max_hp as f32 * 0.25) as i32;
+    let percent = if game.ascension >= 15 { 0.35 } else { 0.25 };
+    return (game.player_max_hp as f32 * percent) as i32;
 }
 
 fn lose_max_hp_amount(game: &Game) -> i32 {
-    return (game.player_max_hp as f32 * 0.08) as i32;
+    let percent = if game.ascension >= 15 { 0.10 } else { 0.08 };
+    return (game.player_max_hp as f32 * percent) as i32;
 }
 
 impl EventRoom for GoldenIdol {
@@ -26,17 +28,17 @@ impl EventRoom for GoldenIdol {
         match action.0 {
             0 => {
                 game.add_card_to_deck(CardBody::Injury);
-                game.relics.add(Relic::GoldenIdol);
+                game.gain_relic(Relic::GoldenIdol);
                 game.goto_map()
             }
             1 => {
                 game.player_lose_hp(damage_amount(game), false);
-                game.relics.add(Relic::GoldenIdol);
+                game.gain_relic(Relic::GoldenIdol);
                 game.goto_map()
             }
             2 => {
                 game.player_lose_max_hp(lose_max_hp_amount(game));
-                game.relics.add(Relic::GoldenIdol);
+                game.gain_relic(Relic::GoldenIdol);
                 game.goto_map()
             }
             3 => game.goto_map(),
@@ -0,0 +1,52 @@
+use crate::{
+    card::CardBody,
+    game::{Choice, Game, choice::EventAction, event::EventRoom},
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct GoldenShrine;
+
+fn pray_gold(game: &Game) -> i32 {
+    if game.ascension >= 15 { 50 } else { 100 }
+}
+
+impl EventRoom for GoldenShrine {
+    fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
+        (0..=2).map(EventAction).collect()
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0 => {
+                game.gain_gold(pray_gold(game));
+                game.goto_map()
+            }
+            1 => {
+                game.gain_gold(275);
+                game.add_card_to_deck(CardBody::Regret);
+                game.goto_map()
+            }
+            2 => game.goto_map(),
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => format!("Pray. Gain {} gold.", pray_gold(game)),
+            1 => "Desecrate. Gain 275 gold. Become Cursed - Regret.".to_string(),
+            2 => "Leave.".to_string(),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Golden Shrine"
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        GoldenShrine
+    }
+}
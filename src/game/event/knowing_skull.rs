@@ -0,0 +1,100 @@
+use crate::{
+    card::{COLORLESS_UNCOMMON_CARDS, sample_card},
+    game::{
+        Choice, Game,
+        choice::EventAction,
+        event::{Event, EventRoom},
+    },
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+const START_COST: i32 = 6;
+const LEAVE_COST: i32 = 6;
+
+//Each option costs 1 more hp every time it's chosen. The skull keeps
+//asking until the player leaves.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct KnowingSkull {
+    gold_cost: i32,
+    card_cost: i32,
+    potion_cost: i32,
+}
+
+impl EventRoom for KnowingSkull {
+    fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
+        (0..=3).map(EventAction).collect()
+    }
+
+    fn take_action(mut self, game: &mut Game, action: EventAction) -> Choice {
+        let cost = match action.0 {
+            0 => {
+                game.gain_gold(90);
+                self.gold_cost += 1;
+                self.gold_cost - 1
+            }
+            1 => {
+                let card = sample_card(COLORLESS_UNCOMMON_CARDS, &mut game.rng);
+                game.add_card_to_deck(card);
+                self.card_cost += 1;
+                self.card_cost - 1
+            }
+            2 => {
+                let potion = game.random_potion();
+                game.gain_potion(potion);
+                self.potion_cost += 1;
+                self.potion_cost - 1
+            }
+            3 => LEAVE_COST,
+            _ => panic!("Invalid action: {}", action.0),
+        };
+        game.player_lose_hp(cost, false);
+        if game.player_hp <= 0 {
+            return Choice::Loss;
+        }
+        if action.0 == 3 {
+            return game.goto_map();
+        }
+        Event::KnowingSkull(self).into_choice(game)
+    }
+
+    fn action_str(&self, _game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => {
+                format!("Riches. Gain 90 gold. Lose {} hp.", self.gold_cost)
+            }
+            1 => {
+                format!(
+                    "Success. Obtain a colorless card. Lose {} hp.",
+                    self.card_cost
+                )
+            }
+            2 => {
+                format!(
+                    "A Pick Me Up. Obtain a potion. Lose {} hp.",
+                    self.potion_cost
+                )
+            }
+            3 => {
+                format!("Leave. Lose {} hp.", LEAVE_COST)
+            }
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Knowing Skull"
+    }
+
+    fn can_appear(game: &Game) -> bool {
+        game.player_hp > 12
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        KnowingSkull {
+            gold_cost: START_COST,
+            card_cost: START_COST,
+            potion_cost: START_COST,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{
+    game::{Choice, Game, choice::EventAction, event::EventRoom, reward::Rewards},
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Lab;
+
+fn potion_count(game: &Game) -> usize {
+    if game.ascension >= 15 { 2 } else { 3 }
+}
+
+impl EventRoom for Lab {
+    fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
+        (0..=0).map(EventAction).collect()
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0 => {
+                let mut rewards = Rewards::default();
+                for _ in 0..potion_count(game) {
+                    let potion = game.random_potion();
+                    rewards.potions.push(potion);
+                }
+                game.goto_rewards(rewards)
+            }
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => format!("Search. Find {} random potions.", potion_count(game)),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Lab"
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        Lab
+    }
+}
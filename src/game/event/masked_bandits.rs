@@ -0,0 +1,48 @@
+use crate::{
+    game::{Choice, Game, choice::EventAction, encounter::Encounter, event::EventRoom},
+    relic::Relic,
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct MaskedBandits;
+
+impl EventRoom for MaskedBandits {
+    fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
+        vec![EventAction(0), EventAction(1)]
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0 => {
+                if game.gold > 0 {
+                    game.lose_gold(game.gold);
+                }
+                game.goto_map()
+            }
+            1 => {
+                let choice = game.setup_encounter(Encounter::MaskedBandits);
+                game.fight.rewards.fixed_relics.push(Relic::RedMask);
+                choice
+            }
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, _game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => "Pay. Lose all gold.".to_string(),
+            1 => "Fight! Defeat the bandits to obtain the Red Mask.".to_string(),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Masked Bandits"
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        MaskedBandits
+    }
+}
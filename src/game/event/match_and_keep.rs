@@ -0,0 +1,184 @@
+use std::hash::{Hash, Hasher};
+
+use crate::{
+    card::{COLORLESS_UNCOMMON_CARDS, CURSE_CARDS, CardBody, CardRarity},
+    game::{
+        Character, Choice, Game,
+        choice::EventAction,
+        event::{Event, EventRoom},
+    },
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+const NUM_ATTEMPTS: i32 = 5;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+enum MatchCard {
+    Rare,
+    Uncommon,
+    Common,
+    Colorless,
+    Curse,
+    Starter,
+}
+
+const MATCH_CARDS: [MatchCard; 6] = [
+    MatchCard::Rare,
+    MatchCard::Uncommon,
+    MatchCard::Common,
+    MatchCard::Colorless,
+    MatchCard::Curse,
+    MatchCard::Starter,
+];
+
+//12 face down cards, 2 of each kind. The player flips 2 at a time and keeps the card
+//if they match. The positions of cards that haven't been flipped yet are hidden
+//information, so they aren't hashed and are reshuffled when the state is reseeded.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct MatchAndKeep {
+    board: Vec<MatchCard>,
+    //Positions which have been flipped at least once.
+    seen: Vec<bool>,
+    matched: Vec<bool>,
+    flipped: Option<usize>,
+    attempts: i32,
+    //Which card of each kind is used, indexed by the kind.
+    rolls: [usize; 6],
+}
+
+fn starter_card(character: Character) -> CardBody {
+    match character {
+        Character::IRONCLAD => CardBody::Bash,
+        Character::SILENT => CardBody::Neutralize,
+        Character::DEFECT => CardBody::Zap,
+        Character::WATCHER => CardBody::Eruption,
+    }
+}
+
+impl MatchAndKeep {
+    fn card(&self, game: &Game, kind: MatchCard) -> CardBody {
+        let roll = self.rolls[kind as usize];
+        let pool = match kind {
+            MatchCard::Rare => game.character_cards(CardRarity::Rare),
+            MatchCard::Uncommon => game.character_cards(CardRarity::Uncommon),
+            MatchCard::Common => game.character_cards(CardRarity::Common),
+            MatchCard::Colorless => COLORLESS_UNCOMMON_CARDS,
+            MatchCard::Curse => CURSE_CARDS,
+            MatchCard::Starter => return starter_card(game.charachter),
+        };
+        pool[roll % pool.len()]
+    }
+
+    fn kind_seen(&self, kind: MatchCard) -> bool {
+        (0..self.board.len()).any(|i| self.seen[i] && self.board[i] == kind)
+    }
+
+    fn into_choice(self, game: &mut Game) -> Choice {
+        if self.attempts == 0 || self.matched.iter().all(|matched| *matched) {
+            return game.goto_map();
+        }
+        Event::MatchAndKeep(self).into_choice(game)
+    }
+}
+
+//Only the cards the player has seen are hashed, so that agents treat boards which
+//differ only in hidden positions as the same state.
+impl Hash for MatchAndKeep {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for i in 0..self.board.len() {
+            if self.seen[i] {
+                let kind = self.board[i];
+                Some((kind, self.rolls[kind as usize])).hash(state);
+            } else {
+                None::<(MatchCard, usize)>.hash(state);
+            }
+        }
+        self.matched.hash(state);
+        self.flipped.hash(state);
+        self.attempts.hash(state);
+    }
+}
+
+impl EventRoom for MatchAndKeep {
+    fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
+        (0..self.board.len())
+            .filter(|i| !self.matched[*i] && self.flipped != Some(*i))
+            .map(EventAction)
+            .collect()
+    }
+
+    fn take_action(mut self, game: &mut Game, action: EventAction) -> Choice {
+        let idx = action.0;
+        self.seen[idx] = true;
+        let Some(first) = self.flipped.take() else {
+            self.flipped = Some(idx);
+            return self.into_choice(game);
+        };
+        self.attempts -= 1;
+        if self.board[first] == self.board[idx] {
+            self.matched[first] = true;
+            self.matched[idx] = true;
+            let card = self.card(game, self.board[idx]);
+            game.add_card_to_deck(card);
+        }
+        self.into_choice(game)
+    }
+
+    fn action_str(&self, game: &Game, action: EventAction) -> String {
+        let idx = action.0;
+        let card = if self.seen[idx] {
+            format!(" ({:?})", self.card(game, self.board[idx]))
+        } else {
+            String::new()
+        };
+        match self.flipped {
+            None => format!(
+                "Flip card {}{}. {} attempts left.",
+                idx, card, self.attempts
+            ),
+            Some(first) => format!(
+                "Flip card {}{} to match {:?}.",
+                idx,
+                card,
+                self.card(game, self.board[first])
+            ),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Match and Keep"
+    }
+
+    fn reseed(&mut self, rng: &mut Rng) {
+        let hidden: Vec<usize> = (0..self.board.len()).filter(|i| !self.seen[*i]).collect();
+        let mut cards: Vec<MatchCard> = hidden.iter().map(|i| self.board[*i]).collect();
+        rng.shuffle(&mut cards);
+        for (i, card) in hidden.into_iter().zip(cards) {
+            self.board[i] = card;
+        }
+        for kind in MATCH_CARDS {
+            if !self.kind_seen(kind) {
+                self.rolls[kind as usize] = rng.sample(usize::MAX);
+            }
+        }
+    }
+
+    fn new(rng: &mut Rng) -> Self {
+        let mut board: Vec<MatchCard> = MATCH_CARDS
+            .iter()
+            .chain(MATCH_CARDS.iter())
+            .copied()
+            .collect();
+        rng.shuffle(&mut board);
+        let rolls = [0; 6].map(|_: usize| rng.sample(usize::MAX));
+        MatchAndKeep {
+            seen: vec![false; board.len()],
+            matched: vec![false; board.len()],
+            board,
+            flipped: None,
+            attempts: NUM_ATTEMPTS,
+            rolls,
+        }
+    }
+}
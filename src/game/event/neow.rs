@@ -0,0 +1,232 @@
+use crate::{
+    card::{CURSE_CARDS, Card, CardRarity, sample_card},
+    game::{Choice, Game, choice::EventAction, event::EventRoom, reward::Rewards},
+    relic::{RelicRarity, Relics},
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+//Neow offers one blessing from each of four categories. The first two are free,
+//the third comes with a drawback and the fourth swaps the starter relic for a boss relic.
+//TODO - Neow's Lament and the colorless card blessings aren't offered yet.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+enum NeowBonus {
+    ChooseCard,
+    RandomRareCard,
+    RemoveCard,
+    UpgradeCard,
+    TransformCard,
+    ThreePotions,
+    CommonRelic,
+    MaxHp,
+    HundredGold,
+    RemoveTwoCards,
+    RareRelic,
+    ChooseRareCard,
+    TwoFiftyGold,
+    TransformTwoCards,
+    LargeMaxHp,
+    BossRelic,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+enum NeowDrawback {
+    None,
+    LoseMaxHp,
+    NoGold,
+    Curse,
+    TakeDamage,
+    LoseStarterRelic,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Neow {
+    options: Vec<(NeowDrawback, NeowBonus)>,
+}
+
+fn max_hp_amount(game: &Game, bonus: NeowBonus) -> i32 {
+    match bonus {
+        NeowBonus::LargeMaxHp => game.player_max_hp / 5,
+        _ => game.player_max_hp / 10,
+    }
+}
+
+fn damage_amount(game: &Game) -> i32 {
+    game.player_hp / 10 * 3
+}
+
+//Neow's card choices have a 1/3 chance for each card to be uncommon, and never rare.
+fn card_choice(game: &mut Game, rare: bool) -> Vec<Card> {
+    let mut cards: Vec<Card> = Vec::new();
+    while cards.len() < 3 {
+        let rarity = if rare {
+            CardRarity::Rare
+        } else if game.rng.sample(3) == 0 {
+            CardRarity::Uncommon
+        } else {
+            CardRarity::Common
+        };
+        let body = sample_card(game.character_cards(rarity), &mut game.rng);
+        if cards.iter().all(|card| card.body != body) {
+            cards.push(body.to_card());
+        }
+    }
+    cards
+}
+
+impl EventRoom for Neow {
+    fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
+        (0..self.options.len()).map(EventAction).collect()
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        let (drawback, bonus) = self.options[action.0];
+        match drawback {
+            NeowDrawback::None => {}
+            NeowDrawback::LoseMaxHp => game.player_lose_max_hp(game.player_max_hp / 10),
+            NeowDrawback::NoGold => game.lose_gold(game.gold),
+            NeowDrawback::Curse => {
+                let curse = sample_card(CURSE_CARDS, &mut game.rng);
+                game.add_card_to_deck(curse);
+            }
+            NeowDrawback::TakeDamage => game.player_lose_hp(damage_amount(game), false),
+            NeowDrawback::LoseStarterRelic => {
+                game.relics.remove(Relics::starter_relic(game.charachter));
+            }
+        }
+        match bonus {
+            NeowBonus::ChooseCard | NeowBonus::ChooseRareCard => {
+                let cards = card_choice(game, bonus == NeowBonus::ChooseRareCard);
+                let mut rewards = Rewards::default();
+                rewards.cards.push(cards);
+                game.goto_rewards(rewards)
+            }
+            NeowBonus::RandomRareCard => {
+                let card = sample_card(game.character_cards(CardRarity::Rare), &mut game.rng);
+                game.add_card_to_deck(card);
+                game.goto_map()
+            }
+            NeowBonus::RemoveCard => game.goto_remove_card(),
+            NeowBonus::UpgradeCard => game.goto_upgrade_card(),
+            NeowBonus::TransformCard => game.goto_transform_card(),
+            NeowBonus::RemoveTwoCards => game.goto_remove_two_cards(),
+            NeowBonus::TransformTwoCards => game.goto_transform_two_cards(),
+            NeowBonus::ThreePotions => {
+                let mut rewards = Rewards::default();
+                for _ in 0..3 {
+                    let potion = game.random_potion();
+                    rewards.potions.push(potion);
+                }
+                game.goto_rewards(rewards)
+            }
+            NeowBonus::CommonRelic | NeowBonus::RareRelic | NeowBonus::BossRelic => {
+                let rarity = match bonus {
+                    NeowBonus::CommonRelic => RelicRarity::Common,
+                    NeowBonus::RareRelic => RelicRarity::Rare,
+                    _ => RelicRarity::Boss,
+                };
+                let relic = game.relics.pool.get_relic(&mut game.rng, rarity);
+                game.gain_relic(relic);
+                game.goto_map()
+            }
+            NeowBonus::MaxHp | NeowBonus::LargeMaxHp => {
+                game.gain_max_hp(max_hp_amount(game, bonus));
+                game.goto_map()
+            }
+            NeowBonus::HundredGold => {
+                game.gain_gold(100);
+                game.goto_map()
+            }
+            NeowBonus::TwoFiftyGold => {
+                game.gain_gold(250);
+                game.goto_map()
+            }
+        }
+    }
+
+    fn action_str(&self, game: &Game, action: EventAction) -> String {
+        let (drawback, bonus) = self.options[action.0];
+        let bonus_str = match bonus {
+            NeowBonus::ChooseCard => "Choose a card to obtain.".to_string(),
+            NeowBonus::RandomRareCard => "Obtain a random rare card.".to_string(),
+            NeowBonus::RemoveCard => "Remove a card.".to_string(),
+            NeowBonus::UpgradeCard => "Upgrade a card.".to_string(),
+            NeowBonus::TransformCard => "Transform a card.".to_string(),
+            NeowBonus::ThreePotions => "Obtain 3 random potions.".to_string(),
+            NeowBonus::CommonRelic => "Obtain a random common relic.".to_string(),
+            NeowBonus::MaxHp | NeowBonus::LargeMaxHp => {
+                format!("Gain {} max hp.", max_hp_amount(game, bonus))
+            }
+            NeowBonus::HundredGold => "Gain 100 gold.".to_string(),
+            NeowBonus::RemoveTwoCards => "Remove 2 cards.".to_string(),
+            NeowBonus::RareRelic => "Obtain a random rare relic.".to_string(),
+            NeowBonus::ChooseRareCard => "Choose a rare card to obtain.".to_string(),
+            NeowBonus::TwoFiftyGold => "Gain 250 gold.".to_string(),
+            NeowBonus::TransformTwoCards => "Transform 2 cards.".to_string(),
+            NeowBonus::BossRelic => "Obtain a random boss relic.".to_string(),
+        };
+        let drawback_str = match drawback {
+            NeowDrawback::None => return bonus_str,
+            NeowDrawback::LoseMaxHp => format!("Lose {} max hp.", game.player_max_hp / 10),
+            NeowDrawback::NoGold => "Lose all gold.".to_string(),
+            NeowDrawback::Curse => "Obtain a curse.".to_string(),
+            NeowDrawback::TakeDamage => format!("Take {} damage.", damage_amount(game)),
+            NeowDrawback::LoseStarterRelic => "Lose your starting relic.".to_string(),
+        };
+        format!("{} {}", drawback_str, bonus_str)
+    }
+
+    fn name(&self) -> &'static str {
+        "Neow"
+    }
+
+    fn new(rng: &mut Rng) -> Self {
+        const FIRST: &[NeowBonus] = &[
+            NeowBonus::ChooseCard,
+            NeowBonus::RandomRareCard,
+            NeowBonus::RemoveCard,
+            NeowBonus::UpgradeCard,
+            NeowBonus::TransformCard,
+        ];
+        const SECOND: &[NeowBonus] = &[
+            NeowBonus::ThreePotions,
+            NeowBonus::CommonRelic,
+            NeowBonus::MaxHp,
+            NeowBonus::HundredGold,
+        ];
+        const DRAWBACKS: &[NeowDrawback] = &[
+            NeowDrawback::LoseMaxHp,
+            NeowDrawback::NoGold,
+            NeowDrawback::Curse,
+            NeowDrawback::TakeDamage,
+        ];
+        let drawback = DRAWBACKS[rng.sample(DRAWBACKS.len())];
+        //Some bonuses would cancel out the drawback, so they aren't offered together.
+        let third: Vec<NeowBonus> = [
+            NeowBonus::RemoveTwoCards,
+            NeowBonus::RareRelic,
+            NeowBonus::ChooseRareCard,
+            NeowBonus::TwoFiftyGold,
+            NeowBonus::TransformTwoCards,
+            NeowBonus::LargeMaxHp,
+        ]
+        .into_iter()
+        .filter(|bonus| {
+            !matches!(
+                (drawback, bonus),
+                (NeowDrawback::Curse, NeowBonus::RemoveTwoCards)
+                    | (NeowDrawback::NoGold, NeowBonus::TwoFiftyGold)
+                    | (NeowDrawback::LoseMaxHp, NeowBonus::LargeMaxHp)
+            )
+        })
+        .collect();
+        Neow {
+            options: vec![
+                (NeowDrawback::None, FIRST[rng.sample(FIRST.len())]),
+                (NeowDrawback::None, SECOND[rng.sample(SECOND.len())]),
+                (drawback, third[rng.sample(third.len())]),
+                (NeowDrawback::LoseStarterRelic, NeowBonus::BossRelic),
+            ],
+        }
+    }
+}
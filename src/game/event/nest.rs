@@ -0,0 +1,55 @@
+use crate::{
+    card::CardBody,
+    game::{Choice, Game, choice::EventAction, event::EventRoom},
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Nest;
+
+const DAMAGE: i32 = 6;
+
+fn gold_amount(game: &Game) -> i32 {
+    if game.ascension >= 15 { 50 } else { 99 }
+}
+
+impl EventRoom for Nest {
+    fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
+        vec![EventAction(0), EventAction(1)]
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0 => {
+                game.gain_gold(gold_amount(game));
+                game.goto_map()
+            }
+            1 => {
+                game.player_lose_hp(DAMAGE, false);
+                if game.player_hp <= 0 {
+                    return Choice::Loss;
+                }
+                game.add_card_to_deck(CardBody::RitualDagger);
+                game.goto_map()
+            }
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => format!("Smash and Grab. Gain {} gold.", gold_amount(game)),
+            1 => format!("Stay in Line. Obtain Ritual Dagger. Lose {} hp.", DAMAGE),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "The Nest"
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        Nest
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{
+    game::{Choice, Game, choice::EventAction, event::EventRoom},
+    relic::Relic,
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+//N'loth asks for one of two random relics. The relics are picked
+//from rolls made when the event is generated.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Nloth {
+    rolls: [usize; 2],
+}
+
+impl Nloth {
+    fn offers(&self, game: &Game) -> [Relic; 2] {
+        let mut relics = game.relics.list();
+        let first = relics.remove(self.rolls[0] % relics.len());
+        let second = relics.remove(self.rolls[1] % relics.len());
+        [first, second]
+    }
+}
+
+impl EventRoom for Nloth {
+    fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
+        (0..=2).map(EventAction).collect()
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0 | 1 => {
+                let [first, second] = self.offers(game);
+                game.relics
+                    .remove(if action.0 == 0 { first } else { second });
+                game.gain_relic(Relic::NlothsGift);
+                game.goto_map()
+            }
+            2 => game.goto_map(),
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 | 1 => {
+                format!(
+                    "Offer {:?}. Obtain N'loth's Gift.",
+                    self.offers(game)[action.0]
+                )
+            }
+            2 => "Leave.".to_string(),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "N'loth"
+    }
+
+    fn can_appear(game: &Game) -> bool {
+        game.relics.list().len() >= 2
+    }
+
+    fn new(rng: &mut Rng) -> Self {
+        Nloth {
+            rolls: [rng.sample(usize::MAX), rng.sample(usize::MAX)],
+        }
+    }
+}
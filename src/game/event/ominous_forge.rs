@@ -0,0 +1,46 @@
+use crate::{
+    card::CardBody,
+    game::{Choice, Game, choice::EventAction, event::EventRoom},
+    relic::Relic,
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct OminousForge;
+
+impl EventRoom for OminousForge {
+    fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
+        (0..=2).map(EventAction).collect()
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0 => game.goto_upgrade_card(),
+            1 => {
+                game.add_card_to_deck(CardBody::Pain);
+                game.gain_relic(Relic::WarpedTongs);
+                game.goto_map()
+            }
+            2 => game.goto_map(),
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, _game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => "Forge. Upgrade a card.".to_string(),
+            1 => "Rummage. Gain Warped Tongs. Become Cursed - Pain.".to_string(),
+            2 => "Leave.".to_string(),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Ominous Forge"
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        OminousForge
+    }
+}
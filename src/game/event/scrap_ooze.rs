@@ -17,8 +17,9 @@ impl ScrapOoze {
     fn odds(&self) -> i32 {
         25 + 10 * self.click_count
     }
-    fn hp_loss(&self) -> i32 {
-        3 + self.click_count
+    fn hp_loss(&self, game: &Game) -> i32 {
+        let base = if game.ascension >= 15 { 5 } else { 3 };
+        base + self.click_count
     }
 }
 
@@ -30,15 +31,14 @@ impl EventRoom for ScrapOoze {
     fn take_action(mut self, game: &mut Game, action: EventAction) -> Choice {
         match action.0 {
             0 => {
-                game.player_lose_hp(self.hp_loss(), false);
+                game.player_lose_hp(self.hp_loss(game), false);
                 if (game.rng.sample(100) as i32) < self.odds() {
                     let relic = game.relics.pool.get_random_tier_relic(&mut game.rng);
-                    game.relics.add(relic);
+                    game.gain_relic(relic);
                     game.goto_map()
                 } else {
                     self.click_count += 1;
-                    let actions = self.get_actions(game);
-                    Choice::Event(Event::ScrapOoze(self), actions)
+                    Event::ScrapOoze(self).into_choice(game)
                 }
             }
             1 => game.goto_map(),
@@ -46,11 +46,11 @@ impl EventRoom for ScrapOoze {
         }
     }
 
-    fn action_str(&self, _game: &Game, action: EventAction) -> String {
+    fn action_str(&self, game: &Game, action: EventAction) -> String {
         match action.0 {
             0 => format!(
                 "Lose {} hp. {}% chance of reward.",
-                self.hp_loss(),
+                self.hp_loss(game),
                 self.odds()
             ),
             1 => "Leave".to_string(),
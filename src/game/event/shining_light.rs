@@ -8,7 +8,8 @@ use serde::{Deserialize, Serialize};
 pub struct ShiningLight;
 
 fn damage_amount(game: &Game) -> i32 {
-    return (game.player_max_hp as f32 * 0.20) as i32;
+    let percent = if game.ascension >= 15 { 0.30 } else { 0.20 };
+    return (game.player_max_hp as f32 * percent) as i32;
 }
 
 impl EventRoom for ShiningLight {
@@ -20,20 +21,7 @@ impl EventRoom for ShiningLight {
         match action.0 {
             0 => {
                 game.player_lose_hp(damage_amount(game), false);
-                let mut items = Vec::new();
-                for i in 0..game.base_deck.len() {
-                    if game.base_deck[i].can_upgrade() {
-                        items.push(i);
-                    }
-                }
-                for _ in 0..2 {
-                    if items.len() == 0 {
-                        break;
-                    }
-                    let idx = game.rng.sample(items.len());
-                    let card_idx = items.swap_remove(idx);
-                    game.base_deck[card_idx].upgrade();
-                }
+                game.upgrade_random_cards(2);
                 game.goto_map()
             }
             1 => game.goto_map(),
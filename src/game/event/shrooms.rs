@@ -23,7 +23,7 @@ impl EventRoom for HypnotizingShrooms {
             0 => {
                 game.add_card_to_deck(CardBody::Injury);
                 let encounter = game.setup_encounter(Encounter::EventMushrooms);
-                game.fight.rewards.fixed_relic = Some(Relic::OddMushroom);
+                game.fight.rewards.fixed_relics.push(Relic::OddMushroom);
                 encounter
             }
             1 => {
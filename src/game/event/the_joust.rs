@@ -0,0 +1,56 @@
+use crate::{
+    game::{Choice, Game, choice::EventAction, event::EventRoom},
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+const BET: i32 = 50;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct TheJoust;
+
+impl EventRoom for TheJoust {
+    fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
+        (0..=1).map(EventAction).collect()
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        let (chance, winnings) = match action.0 {
+            0 => (70, 100),
+            1 => (30, 250),
+            _ => panic!("Invalid action: {}", action.0),
+        };
+        game.lose_gold(BET);
+        if game.rng.sample(100) < chance {
+            game.gain_gold(winnings);
+        }
+        game.goto_map()
+    }
+
+    fn action_str(&self, _game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => {
+                format!(
+                    "Bet {} gold on the Murderer. 70% chance to win 100 gold.",
+                    BET
+                )
+            }
+            1 => {
+                format!("Bet {} gold on the Owner. 30% chance to win 250 gold.", BET)
+            }
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "The Joust"
+    }
+
+    fn can_appear(game: &Game) -> bool {
+        game.gold >= BET
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        TheJoust
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{
+    game::{
+        Choice, Game, choice::EventAction, event::EventRoom, reward::CardRewardRoom,
+        reward::Rewards,
+    },
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct TheLibrary;
+
+const NUM_CARDS: usize = 20;
+
+fn heal_amount(game: &Game) -> i32 {
+    let percent = if game.ascension >= 15 { 0.20 } else { 0.33 };
+    (game.player_max_hp as f32 * percent).round() as i32
+}
+
+impl EventRoom for TheLibrary {
+    fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
+        vec![EventAction(0), EventAction(1)]
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0 => {
+                let mut rewards = Rewards::default();
+                let cards = game.generate_card_reward(NUM_CARDS, CardRewardRoom::Normal);
+                rewards.cards.push(cards);
+                game.goto_rewards(rewards)
+            }
+            1 => {
+                game.heal(heal_amount(game));
+                game.goto_map()
+            }
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => format!("Read. Choose 1 of {} cards to add to your deck.", NUM_CARDS),
+            1 => format!("Sleep. Heal {} hp.", heal_amount(game)),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "The Library"
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        TheLibrary
+    }
+}
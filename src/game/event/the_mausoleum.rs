@@ -0,0 +1,53 @@
+use crate::{
+    card::CardBody,
+    game::{Choice, Game, choice::EventAction, event::EventRoom},
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct TheMausoleum;
+
+fn curse_chance(game: &Game) -> usize {
+    if game.ascension >= 15 { 100 } else { 50 }
+}
+
+impl EventRoom for TheMausoleum {
+    fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
+        vec![EventAction(0), EventAction(1)]
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0 => {
+                if game.rng.sample(100) < curse_chance(game) {
+                    game.add_card_to_deck(CardBody::Writhe);
+                }
+                let relic = game.relics.pool.get_random_tier_relic(&mut game.rng);
+                game.gain_relic(relic);
+                game.goto_map()
+            }
+            1 => game.goto_map(),
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => format!(
+                "Open the coffin. Obtain a relic. {}% chance to become Cursed - Writhe.",
+                curse_chance(game)
+            ),
+            1 => "Leave".to_string(),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "The Mausoleum"
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        TheMausoleum
+    }
+}
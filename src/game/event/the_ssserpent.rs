@@ -8,6 +8,10 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct TheSsserpent;
 
+fn gold_amount(game: &Game) -> i32 {
+    if game.ascension >= 15 { 150 } else { 175 }
+}
+
 impl EventRoom for TheSsserpent {
     fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
         (0..=1).map(EventAction).collect()
@@ -16,7 +20,7 @@ impl EventRoom for TheSsserpent {
     fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
         match action.0 {
             0 => {
-                game.gain_gold(175);
+                game.gain_gold(gold_amount(game));
                 game.add_card_to_deck(CardBody::Doubt);
                 game.goto_map()
             }
@@ -25,10 +29,10 @@ impl EventRoom for TheSsserpent {
         }
     }
 
-    fn action_str(&self, _game: &Game, action: EventAction) -> String {
+    fn action_str(&self, game: &Game, action: EventAction) -> String {
         match action.0 {
             0 => {
-                format!("Gain 175 gold. Become cursed - Doubt")
+                format!("Gain {} gold. Become cursed - Doubt", gold_amount(game))
             }
             1 => {
                 format!("Refuse.")
@@ -0,0 +1,76 @@
+use crate::{
+    card::CardBody,
+    game::{Choice, Game, choice::EventAction, event::EventRoom},
+    relic::Relic,
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+//Accepting replaces every Strike with 5 Bites.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Vampires;
+
+const BITES: usize = 5;
+
+fn lose_max_hp_amount(game: &Game) -> i32 {
+    (game.player_max_hp as f32 * 0.3).ceil() as i32
+}
+
+fn become_vampire(game: &mut Game) {
+    game.base_deck.retain(|card| card.body != CardBody::Strike);
+    for _ in 0..BITES {
+        game.add_card_to_deck(CardBody::Bite);
+    }
+}
+
+impl EventRoom for Vampires {
+    fn get_actions(&self, game: &Game) -> Vec<EventAction> {
+        let mut actions = vec![EventAction(0)];
+        if game.relics.has_relic(Relic::BloodVial) {
+            actions.push(EventAction(1));
+        }
+        actions.push(EventAction(2));
+        actions
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0 => {
+                game.player_lose_max_hp(lose_max_hp_amount(game));
+                become_vampire(game);
+                game.goto_map()
+            }
+            1 => {
+                game.relics.remove(Relic::BloodVial);
+                become_vampire(game);
+                game.goto_map()
+            }
+            2 => game.goto_map(),
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => format!(
+                "Accept. Replace all Strikes with {} Bites. Lose {} max hp.",
+                BITES,
+                lose_max_hp_amount(game)
+            ),
+            1 => format!(
+                "Offer the Blood Vial. Replace all Strikes with {} Bites.",
+                BITES
+            ),
+            2 => "Refuse".to_string(),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Vampires(?)"
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        Vampires
+    }
+}
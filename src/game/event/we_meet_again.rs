@@ -0,0 +1,111 @@
+use crate::{
+    card::CardRarity,
+    game::{Choice, Game, choice::EventAction, event::EventRoom},
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+//Ranwid asks for a random potion, some gold or a random card in exchange for a relic.
+//The rolls are made when the event is generated so the offers stay the same.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct WeMeetAgain {
+    potion_roll: usize,
+    gold_roll: i32,
+    card_roll: usize,
+}
+
+const MIN_GOLD: i32 = 50;
+
+fn offerable_cards(game: &Game) -> Vec<usize> {
+    (0..game.base_deck.len())
+        .filter(|i| {
+            let body = game.base_deck[*i].body;
+            body.removable()
+                && body.rarity() != CardRarity::Basic
+                && body.rarity() != CardRarity::Curse
+        })
+        .collect()
+}
+
+impl WeMeetAgain {
+    fn potion_slot(&self, game: &Game) -> usize {
+        self.potion_roll % game.potions.len()
+    }
+
+    fn gold_amount(&self, game: &Game) -> i32 {
+        std::cmp::min(self.gold_roll, game.gold)
+    }
+
+    fn card_idx(&self, game: &Game) -> usize {
+        let cards = offerable_cards(game);
+        cards[self.card_roll % cards.len()]
+    }
+}
+
+impl EventRoom for WeMeetAgain {
+    fn get_actions(&self, game: &Game) -> Vec<EventAction> {
+        let mut actions = Vec::new();
+        if !game.potions.is_empty() {
+            actions.push(EventAction(0));
+        }
+        if game.gold >= MIN_GOLD {
+            actions.push(EventAction(1));
+        }
+        if !offerable_cards(game).is_empty() {
+            actions.push(EventAction(2));
+        }
+        actions.push(EventAction(3));
+        actions
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0 => {
+                game.potions.remove(self.potion_slot(game));
+            }
+            1 => game.lose_gold(self.gold_amount(game)),
+            2 => {
+                game.base_deck.remove(self.card_idx(game));
+            }
+            3 => return game.goto_map(),
+            _ => panic!("Invalid action: {}", action.0),
+        }
+        let relic = game.relics.pool.get_random_tier_relic(&mut game.rng);
+        game.gain_relic(relic);
+        game.goto_map()
+    }
+
+    fn action_str(&self, game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => {
+                format!(
+                    "Give {:?}. Gain a random relic.",
+                    game.potions[self.potion_slot(game)]
+                )
+            }
+            1 => {
+                format!("Give {} gold. Gain a random relic.", self.gold_amount(game))
+            }
+            2 => {
+                format!(
+                    "Give {:?}. Gain a random relic.",
+                    game.base_deck[self.card_idx(game)].body
+                )
+            }
+            3 => "Attack. Leave.".to_string(),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "We Meet Again"
+    }
+
+    fn new(rng: &mut Rng) -> Self {
+        WeMeetAgain {
+            potion_roll: rng.sample(usize::MAX),
+            gold_roll: rng.sample_i32_inclusive(MIN_GOLD, 150),
+            card_roll: rng.sample(usize::MAX),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    card::CardBody,
+    game::{Choice, Game, choice::EventAction, event::EventRoom},
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+//The wheel's result is rolled when it is spun, so agents can't see it in advance.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct WheelOfChange;
+
+fn damage_amount(game: &Game) -> i32 {
+    if game.ascension >= 15 {
+        (game.player_max_hp as f32 * 0.15) as i32
+    } else {
+        game.player_max_hp / 10
+    }
+}
+
+impl EventRoom for WheelOfChange {
+    fn get_actions(&self, _game: &Game) -> Vec<EventAction> {
+        (0..=0).map(EventAction).collect()
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0 => match game.rng.sample(6) {
+                0 => {
+                    game.gain_gold(100 * game.act.number);
+                    game.goto_map()
+                }
+                1 => {
+                    let relic = game.relics.pool.get_random_tier_relic(&mut game.rng);
+                    game.gain_relic(relic);
+                    game.goto_map()
+                }
+                2 => {
+                    game.heal(game.player_max_hp);
+                    game.goto_map()
+                }
+                3 => {
+                    game.add_card_to_deck(CardBody::Decay);
+                    game.goto_map()
+                }
+                4 => game.goto_remove_card(),
+                _ => {
+                    game.player_lose_hp(damage_amount(game), false);
+                    if game.player_hp <= 0 {
+                        return Choice::Loss;
+                    }
+                    game.goto_map()
+                }
+            },
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, _game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => "Spin the wheel.".to_string(),
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Wheel of Change"
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        WheelOfChange
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{
+    game::{Choice, Game, choice::EventAction, event::EventRoom, reward::Rewards},
+    rng::Rng,
+};
+use serde::{Deserialize, Serialize};
+
+const PRICES: [i32; 3] = [20, 30, 40];
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct WomanInBlue;
+
+//At Ascension 15 leaving without buying anything costs hp.
+fn leave_damage(game: &Game) -> i32 {
+    if game.ascension >= 15 {
+        (game.player_max_hp as f32 * 0.05).ceil() as i32
+    } else {
+        0
+    }
+}
+
+impl EventRoom for WomanInBlue {
+    fn get_actions(&self, game: &Game) -> Vec<EventAction> {
+        let mut actions: Vec<EventAction> = (0..PRICES.len())
+            .filter(|i| game.gold >= PRICES[*i])
+            .map(EventAction)
+            .collect();
+        actions.push(EventAction(PRICES.len()));
+        actions
+    }
+
+    fn take_action(self, game: &mut Game, action: EventAction) -> Choice {
+        match action.0 {
+            0..3 => {
+                game.lose_gold(PRICES[action.0]);
+                let mut rewards = Rewards::default();
+                for _ in 0..=action.0 {
+                    let potion = game.random_potion();
+                    rewards.potions.push(potion);
+                }
+                game.goto_rewards(rewards)
+            }
+            3 => {
+                game.player_lose_hp(leave_damage(game), false);
+                if game.player_hp <= 0 {
+                    return Choice::Loss;
+                }
+                game.goto_map()
+            }
+            _ => panic!("Invalid action: {}", action.0),
+        }
+    }
+
+    fn action_str(&self, game: &Game, action: EventAction) -> String {
+        match action.0 {
+            0 => {
+                format!("Buy 1 potion for {} gold.", PRICES[0])
+            }
+            1 | 2 => {
+                format!(
+                    "Buy {} potions for {} gold.",
+                    action.0 + 1,
+                    PRICES[action.0]
+                )
+            }
+            3 => match leave_damage(game) {
+                0 => "Leave.".to_string(),
+                damage => format!("Leave. Lose {} hp.", damage),
+            },
+            _ => panic!("Invalid action: {}.", action.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "The Woman in Blue"
+    }
+
+    fn can_appear(game: &Game) -> bool {
+        game.gold >= 50
+    }
+
+    fn new(_rng: &mut Rng) -> Self {
+        WomanInBlue
+    }
+}
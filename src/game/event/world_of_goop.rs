@@ -6,9 +6,22 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 
+//Both gold losses are rolled up front since events are generated without the game.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct WorldOfGoop {
     loss: i32,
+    a15_loss: i32,
+}
+
+impl WorldOfGoop {
+    fn loss(&self, game: &Game) -> i32 {
+        let loss = if game.ascension >= 15 {
+            self.a15_loss
+        } else {
+            self.loss
+        };
+        min(loss, game.gold)
+    }
 }
 
 impl EventRoom for WorldOfGoop {
@@ -24,7 +37,7 @@ impl EventRoom for WorldOfGoop {
                 game.goto_map()
             }
             1 => {
-                game.lose_gold(min(self.loss, game.gold));
+                game.lose_gold(self.loss(game));
                 game.goto_map()
             }
             _ => panic!("Invalid action: {}", action.0),
@@ -37,7 +50,7 @@ impl EventRoom for WorldOfGoop {
                 format!("Gain 75 gold. Lose 11 hp.")
             }
             1 => {
-                format!("Lose {} gold.", min(self.loss, game.gold))
+                format!("Lose {} gold.", self.loss(game))
             }
             _ => panic!("Invalid action: {}.", action.0),
         }
@@ -50,6 +63,7 @@ impl EventRoom for WorldOfGoop {
     fn new(rng: &mut Rng) -> Self {
         WorldOfGoop {
             loss: rng.sample_i32_inclusive(20, 50),
+            a15_loss: rng.sample_i32_inclusive(35, 75),
         }
     }
 }
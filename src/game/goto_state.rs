@@ -1,9 +1,22 @@
 use smallvec::SmallVec;
 
-use crate::game::{
-    Game,
-    choice::{Choice, MapStateAction, RestSiteAction, SelectCardAction, SelectDeckCardReason},
-    encounter::Encounter,
+use crate::{
+    card::CardType,
+    game::{
+        Game,
+        choice::{
+            BossRelicAction, Choice, MapStateAction, RestSiteAction, RewardAction,
+            SelectCardAction, SelectDeckCardReason, ShopAction, TreasureAction,
+        },
+        encounter::{
+            Encounter, elite_encounters, repeats_weak_fight, strong_encounters, weak_encounters,
+        },
+        event::EventName,
+        reward::Rewards,
+        shop::Shop,
+    },
+    map::ActMap,
+    relic::{Relic, RelicRarity},
 };
 
 impl Game {
@@ -34,16 +47,77 @@ impl Game {
     }
 
     pub(super) fn goto_rest_site(&mut self) -> Choice {
-        Choice::RestSite(vec![RestSiteAction::Heal, RestSiteAction::Upgrade])
+        //Eternal Feather heals 3 HP for every 5 cards in the deck.
+        if self.relics.has_relic(Relic::EternalFeather) {
+            self.heal(self.base_deck.len() as i32 / 5 * 3);
+        }
+        let mut actions = Vec::new();
+        if !self.relics.has_relic(Relic::CoffeeDripper) {
+            actions.push(RestSiteAction::Heal);
+        }
+        if !self.relics.has_relic(Relic::FusionHammer) {
+            actions.push(RestSiteAction::Upgrade);
+        }
+        if !self.keys.ruby && self.act.number < 4 {
+            actions.push(RestSiteAction::Recall);
+        }
+        if let Some(lifted) = self.relics.counter(Relic::Girya)
+            && lifted < 3
+        {
+            actions.push(RestSiteAction::Lift);
+        }
+        if self.relics.has_relic(Relic::PeacePipe) {
+            actions.push(RestSiteAction::Toke);
+        }
+        if self.relics.has_relic(Relic::Shovel) {
+            actions.push(RestSiteAction::Dig);
+        }
+        //Coffee Dripper and Fusion Hammer can leave nothing to do.
+        if actions.is_empty() {
+            return self.goto_map();
+        }
+        Choice::RestSite(actions)
+    }
+
+    pub(super) fn goto_rewards(&mut self, rewards: Rewards) -> Choice {
+        let mut actions = Vec::new();
+        if rewards.gold > 0 {
+            actions.push(RewardAction::TakeGold);
+        }
+        for i in 0..rewards.relics.len() {
+            actions.push(RewardAction::TakeRelic(i as u8));
+        }
+        for i in 0..rewards.cards.len() {
+            for j in 0..rewards.cards[i].len() {
+                actions.push(RewardAction::TakeCard(i as u8, j as u8));
+            }
+            if self.relics.has_relic(Relic::SingingBowl) {
+                actions.push(RewardAction::SingingBowl(i as u8));
+            }
+        }
+        if self.can_gain_potion() {
+            for i in 0..rewards.potions.len() {
+                actions.push(RewardAction::TakePotion(i as u8));
+            }
+        } else if !rewards.potions.is_empty() && !self.relics.has_relic(Relic::Sozu) {
+            //The belt is full, a potion can be thrown away to make room.
+            for i in 0..self.potions.len() {
+                actions.push(RewardAction::DiscardPotion(i as u8));
+            }
+        }
+        //Skipping the remaining rewards is always an option.
+        actions.push(RewardAction::Proceed);
+        Choice::Rewards(rewards, actions)
     }
 
     pub(super) fn goto_map(&self) -> Choice {
         let mut actions = Vec::new();
         if let Some(position) = self.act.position {
-            if position.y as usize == self.map.rooms.len() - 1 {
+            if position.y as usize == self.map.num_floors {
+                //The boss has been defeated.
                 return Choice::Win;
-            //TODO - handle going to the boss room.
-            //actions.push(MapStateAction::Forwards);
+            } else if position.y as usize == self.map.num_floors - 1 {
+                actions.push(MapStateAction::Forwards);
             } else {
                 let room = &self.map.rooms[position.y as usize][position.x as usize];
                 if room.has_left_child {
@@ -55,6 +129,21 @@ impl Game {
                 if room.has_right_child {
                     actions.push(MapStateAction::Right);
                 }
+                //Wing Boots can fly to any room on the next floor 3 times.
+                if let Some(flights) = self.relics.counter(Relic::WingBoots)
+                    && flights < 3
+                {
+                    let x = position.x as usize;
+                    let next_row = &self.map.rooms[position.y as usize + 1];
+                    for (i, next_room) in next_row.iter().enumerate() {
+                        let connected = (room.has_left_child && i + 1 == x)
+                            || (room.has_front_child && i == x)
+                            || (room.has_right_child && i == x + 1);
+                        if next_room.reachable && !connected {
+                            actions.push(MapStateAction::Jump(i as i32));
+                        }
+                    }
+                }
             }
         } else {
             let row = &self.map.rooms[0];
@@ -80,63 +169,105 @@ impl Game {
         Choice::SelectDeckCardState(SelectDeckCardReason::Remove, res)
     }
 
+    //Bonfire Spirits take a card, the reward depends on its rarity.
+    pub(super) fn goto_offer_card(&mut self) -> Choice {
+        match self.goto_remove_card() {
+            Choice::SelectDeckCardState(_, res) => {
+                Choice::SelectDeckCardState(SelectDeckCardReason::Offer, res)
+            }
+            choice => choice,
+        }
+    }
+
+    pub(super) fn goto_duplicate_card(&mut self) -> Choice {
+        let res: Vec<SelectCardAction> = (0..self.base_deck.len()).map(SelectCardAction).collect();
+        if res.is_empty() {
+            return self.goto_map();
+        }
+        Choice::SelectDeckCardState(SelectDeckCardReason::Duplicate, res)
+    }
+
+    //The Designer's full service removes a card then upgrades a random card.
+    pub(super) fn goto_remove_and_upgrade_card(&mut self) -> Choice {
+        match self.goto_remove_card() {
+            Choice::SelectDeckCardState(_, res) => {
+                Choice::SelectDeckCardState(SelectDeckCardReason::RemoveAndUpgrade, res)
+            }
+            choice => choice,
+        }
+    }
+
+    //Neow can remove or transform two cards. The first selection uses a *Two reason
+    //which leads into a normal remove or transform.
+    pub(super) fn goto_remove_two_cards(&mut self) -> Choice {
+        match self.goto_remove_card() {
+            Choice::SelectDeckCardState(_, res) if res.len() >= 2 => {
+                Choice::SelectDeckCardState(SelectDeckCardReason::RemoveTwo, res)
+            }
+            choice => choice,
+        }
+    }
+
+    pub(super) fn goto_transform_two_cards(&mut self) -> Choice {
+        match self.goto_transform_card() {
+            Choice::SelectDeckCardState(_, res) if res.len() >= 2 => {
+                Choice::SelectDeckCardState(SelectDeckCardReason::TransformTwo, res)
+            }
+            choice => choice,
+        }
+    }
+
+    //Bottled relics pick a card of their type, then go on to the screen they were
+    //picked up from.
+    pub(super) fn goto_bottle_card(&mut self, card_type: CardType, next: Choice) -> Choice {
+        let res: Vec<SelectCardAction> = (0..self.base_deck.len())
+            .filter(|i| {
+                let card = &self.base_deck[*i];
+                card.body.card_type() == card_type && !card.innate()
+            })
+            .map(SelectCardAction)
+            .collect();
+        if res.is_empty() {
+            return next;
+        }
+        self.bottle_return = Some(Box::new(next));
+        Choice::SelectDeckCardState(SelectDeckCardReason::Bottle, res)
+    }
+
     fn update_act_from_fight(&mut self, encounter: Encounter) {
         self.act.prior_fights[1] = self.act.prior_fights[0];
         self.act.prior_fights[0] = Some(encounter);
         self.act.number_of_fights += 1;
     }
+    //Act 1 has 3 weak fights, later acts have 2.
     pub(super) fn goto_fight(&mut self) -> Choice {
-        if self.act.number_of_fights < 3 {
-            let mut encounters: SmallVec<[Encounter; 4]> = SmallVec::new();
-            for encounter in [
-                Encounter::StarterCultist,
-                Encounter::StarterJawWorm,
-                Encounter::StarterLouse,
-                Encounter::StarterSlimes,
-            ] {
-                if Some(encounter) != self.act.prior_fights[0]
-                    && Some(encounter) != self.act.prior_fights[1]
+        let weak_fights = if self.act.number == 1 { 3 } else { 2 };
+        if self.act.number_of_fights < weak_fights {
+            let mut encounters: SmallVec<[Encounter; 5]> = SmallVec::new();
+            for encounter in weak_encounters(self.act.number) {
+                if Some(*encounter) != self.act.prior_fights[0]
+                    && Some(*encounter) != self.act.prior_fights[1]
                 {
-                    encounters.push(encounter);
+                    encounters.push(*encounter);
                 }
             }
             let encounter = encounters[self.rng.sample(encounters.len())];
             self.update_act_from_fight(encounter);
             self.setup_encounter(encounter)
         } else {
-            let hard_pool = [
-                Encounter::BlueSlaver,
-                Encounter::GremlinGang,
-                Encounter::Looter,
-                Encounter::LargeSlime,
-                Encounter::FiveSmallSlimes,
-                Encounter::ExordiumThugs,
-                Encounter::ExordiumWildlife,
-                Encounter::RedSlaver,
-                Encounter::ThreeLouse,
-                Encounter::TwoMushrooms,
-            ];
-            let weights = [4, 2, 4, 4, 2, 3, 3, 2, 4, 4];
+            let (hard_pool, weights) = strong_encounters(self.act.number);
             let encounter = loop {
-                let idx = self.rng.sample_weighted(&weights);
+                let idx = self.rng.sample_weighted(weights);
                 let encounter = hard_pool[idx];
                 if Some(encounter) == self.act.prior_fights[0]
                     || Some(encounter) == self.act.prior_fights[1]
                 {
                     continue;
                 }
-                if self.act.number_of_fights == 3 {
-                    if encounter == Encounter::ThreeLouse
-                        && self.act.prior_fights[0] == Some(Encounter::StarterLouse)
-                    {
-                        continue;
-                    }
-                    if (encounter == Encounter::LargeSlime
-                        || encounter == Encounter::FiveSmallSlimes)
-                        && self.act.prior_fights[0] == Some(Encounter::StarterSlimes)
-                    {
-                        continue;
-                    }
+                if self.act.number_of_fights == weak_fights
+                    && repeats_weak_fight(encounter, self.act.prior_fights[0])
+                {
+                    continue;
                 }
                 break encounter;
             };
@@ -145,18 +276,162 @@ impl Game {
         }
     }
 
+    //The same elite can't be fought twice in a row.
+    pub(super) fn goto_elite(&mut self) -> Choice {
+        let mut elites: SmallVec<[Encounter; 3]> = SmallVec::new();
+        for encounter in elite_encounters(self.act.number) {
+            if self.act.prior_elite != Some(*encounter) {
+                elites.push(*encounter);
+            }
+        }
+        let encounter = elites[self.rng.sample(elites.len())];
+        self.act.prior_elite = Some(encounter);
+        let choice = self.setup_encounter(encounter);
+        let position = self.act.position.expect("Elites are on the map");
+        if self.map.rooms[position.y as usize][position.x as usize].burning && !self.keys.emerald {
+            self.buff_burning_elite();
+            self.fight.rewards.emerald_key = true;
+        }
+        choice
+    }
+
+    //Burning elites get one random buff which scales with the act.
+    fn buff_burning_elite(&mut self) {
+        let act = self.act.number;
+        let buff = self.rng.sample(4);
+        for idx in self.fight.enemies.indicies() {
+            let enemy = &mut self.fight.enemies[idx];
+            match buff {
+                0 => enemy.buffs.strength += act + 1,
+                1 => {
+                    let bonus = enemy.max_hp / 4;
+                    enemy.max_hp += bonus;
+                    enemy.hp += bonus;
+                }
+                2 => enemy.buffs.metallicize += act * 2 + 2,
+                _ => enemy.buffs.regenerate += act * 2 + 1,
+            }
+        }
+    }
+
     pub(super) fn goto_shop(&mut self) -> Choice {
-        //TODO - go to real shop!
+        if self.relics.has_relic(Relic::MealTicket) {
+            self.heal(15);
+        }
+        let shop = self.generate_shop();
+        self.goto_shop_with(shop)
+    }
+
+    pub(super) fn goto_shop_with(&mut self, shop: Shop) -> Choice {
+        let mut actions = Vec::new();
+        for i in 0..shop.cards.len() {
+            if shop.cards[i].price <= self.gold {
+                actions.push(ShopAction::BuyCard(i as u8));
+            }
+        }
+        for i in 0..shop.relics.len() {
+            if shop.relics[i].price <= self.gold {
+                actions.push(ShopAction::BuyRelic(i as u8));
+            }
+        }
+        if self.can_gain_potion() {
+            for i in 0..shop.potions.len() {
+                if shop.potions[i].price <= self.gold {
+                    actions.push(ShopAction::BuyPotion(i as u8));
+                }
+            }
+        }
+        if let Some(price) = shop.removal_price
+            && price <= self.gold
+        {
+            for i in 0..self.base_deck.len() {
+                if self.base_deck[i].body.removable() {
+                    actions.push(ShopAction::RemoveCard(i as u8));
+                }
+            }
+        }
+        actions.push(ShopAction::Leave);
+        Choice::Shop(shop, actions)
+    }
+
+    pub(super) fn goto_boss_relic(&mut self) -> Choice {
+        let mut relics = Vec::new();
+        let mut actions = Vec::new();
+        for i in 0..3 {
+            relics.push(self.relics.pool.get_relic(&mut self.rng, RelicRarity::Boss));
+            actions.push(BossRelicAction::Take(i));
+        }
+        actions.push(BossRelicAction::Skip);
+        Choice::BossRelic(relics, actions)
+    }
+
+    //Heal to full between acts, Ascension 5 only heals 75% of missing HP.
+    //Each act gets a new map.
+    pub(super) fn goto_next_act(&mut self) -> Choice {
+        if self.ascension >= 5 {
+            self.heal((3 * (self.player_max_hp - self.player_hp) + 2) / 4);
+        } else {
+            self.player_hp = self.player_max_hp;
+        }
+        //Act 4 is only reachable with all three keys.
+        if self.act.number >= 3 && !self.keys.all() {
+            return Choice::Win;
+        }
+        self.act = self.act.next(&mut self.rng, self.ascension);
+        if self.act.number == 4 {
+            self.map = ActMap::act_4();
+        } else {
+            self.map = ActMap::standard(&mut self.rng, self.ascension);
+            if !self.keys.emerald {
+                self.map.mark_burning_elite(&mut self.rng);
+            }
+        }
         self.goto_map()
     }
 
     pub(super) fn goto_treasure(&mut self) -> Choice {
-        //TODO - go to real treasure!
-        self.goto_map()
+        let size = self.roll_chest_size();
+        let mut actions = vec![TreasureAction::Open, TreasureAction::Skip];
+        if !self.keys.sapphire && self.act.number < 4 {
+            actions.push(TreasureAction::OpenForSapphireKey);
+        }
+        Choice::Treasure(size, actions)
+    }
+
+    //Every run starts with Neow's blessing.
+    pub(super) fn goto_neow(&mut self) -> Choice {
+        EventName::Neow.new(&mut self.rng).into_choice(self)
+    }
+
+    fn eligible_events(&self, pool: &[EventName]) -> Vec<usize> {
+        (0..pool.len())
+            .filter(|i| pool[*i].can_appear(self))
+            .collect()
     }
 
+    //Events are 75% standard events and 25% shrines. If the chosen pool has
+    //nothing which can appear the other pool is used instead.
     pub(super) fn goto_event(&mut self) -> Choice {
-        //TODO - go to real event!
-        self.goto_map()
+        let shrine_first = self.rng.sample(4) == 0;
+        let events = self.eligible_events(&self.act.events);
+        let shrines = self.eligible_events(&self.act.shrines);
+        let (first, second) = if shrine_first {
+            (
+                (&mut self.act.shrines, shrines),
+                (&mut self.act.events, events),
+            )
+        } else {
+            (
+                (&mut self.act.events, events),
+                (&mut self.act.shrines, shrines),
+            )
+        };
+        let (pool, eligible) = if !first.1.is_empty() { first } else { second };
+        let Some(idx) = self.rng.try_sample(eligible.len()) else {
+            //Every event in the act which can appear has been seen.
+            return self.goto_map();
+        };
+        let event = pool.remove(eligible[idx]).new(&mut self.rng);
+        event.into_choice(self)
     }
 }
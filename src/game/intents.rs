@@ -0,0 +1,41 @@
+use crate::{
+    enemies::{behavior, opening_state},
+    game::Game,
+    relic::Relic,
+};
+
+impl Game {
+    //Runic Dome hides intents. They can also be hidden to measure how much
+    //the agents rely on them.
+    pub fn intents_hidden(&self) -> bool {
+        self.hidden_intents || self.relics.has_relic(Relic::RunicDome)
+    }
+
+    pub fn set_hidden_intents(&mut self, hidden: bool) {
+        self.hidden_intents = hidden;
+    }
+
+    //Replaces the enemies' intents with ones sampled from their last move. This uses the
+    //current fight for the few AIs that look at it, which is close enough for sampling.
+    //Enemies that haven't acted yet resample their opening intent from their generator.
+    pub(super) fn resample_hidden_intents(&mut self) {
+        if !self.intents_hidden() {
+            return;
+        }
+        for i in self.fight.enemies.indicies() {
+            let enemy = &self.fight.enemies[i];
+            let ai_state = match enemy.last_move {
+                Some(last_move) => behavior(&mut self.rng, &self.fight, enemy, last_move).0,
+                None => opening_state(&mut self.rng, &self.fight, enemy),
+            };
+            self.fight.enemies[i].ai_state = ai_state;
+        }
+    }
+
+    //Clears the intents so that states which only differ in hidden information look the same.
+    pub(super) fn mask_intents(&mut self) {
+        for i in self.fight.enemies.indicies() {
+            self.fight.enemies[i].ai_state = 0;
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use std::cmp::max;
+
+use crate::{game::Game, orb::Orb};
+
+impl Game {
+    //Channeling into full slots evokes the first orb to make room.
+    pub(super) fn channel(&mut self, orb: Orb) {
+        if self.fight.orb_slots <= 0 {
+            return;
+        }
+        match orb {
+            Orb::Lightning => self.fight.lightning_channeled += 1,
+            Orb::Frost => self.fight.frost_channeled += 1,
+            _ => {}
+        }
+        if self.fight.orbs.len() as i32 >= self.fight.orb_slots {
+            self.evoke(1);
+        }
+        self.fight.orbs.push(orb);
+    }
+
+    //Dualcast and Multi-Cast evoke the same orb multiple times before removing it.
+    pub(super) fn evoke(&mut self, times: i32) {
+        if self.fight.orbs.is_empty() {
+            return;
+        }
+        let orb = self.fight.orbs.remove(0);
+        for _ in 0..times {
+            self.evoke_effect(orb);
+        }
+    }
+
+    pub(super) fn gain_orb_slots(&mut self, amount: i32) {
+        self.fight.orb_slots = (self.fight.orb_slots + amount).clamp(0, Orb::MAX_SLOTS);
+        self.fight.orbs.truncate(self.fight.orb_slots as usize);
+    }
+
+    //Plasma is the only orb that triggers at the start of the turn.
+    pub(super) fn trigger_end_of_turn_orbs(&mut self) {
+        for i in 0..self.fight.orbs.len() {
+            if self.fight.orbs[i] != Orb::Plasma {
+                self.trigger_passive(i);
+            }
+        }
+    }
+
+    pub(super) fn trigger_start_of_turn_orbs(&mut self) {
+        for i in 0..self.fight.orbs.len() {
+            if self.fight.orbs[i] == Orb::Plasma {
+                self.trigger_passive(i);
+            }
+        }
+        if !self.fight.orbs.is_empty() {
+            for _ in 0..self.fight.player_buffs.loop_orbs {
+                self.trigger_passive(0);
+            }
+        }
+    }
+
+    pub(super) fn trigger_passive(&mut self, i: usize) {
+        let focus = self.fight.player_buffs.focus;
+        match self.fight.orbs[i] {
+            Orb::Lightning => self.lightning_damage(max(3 + focus, 0)),
+            Orb::Frost => self.player_gain_block(max(2 + focus, 0), false),
+            Orb::Dark(stored) => self.fight.orbs[i] = Orb::Dark(stored + max(6 + focus, 0)),
+            Orb::Plasma => self.fight.energy += 1,
+        }
+    }
+
+    fn evoke_effect(&mut self, orb: Orb) {
+        let focus = self.fight.player_buffs.focus;
+        match orb {
+            Orb::Lightning => self.lightning_damage(max(8 + focus, 0)),
+            Orb::Frost => self.player_gain_block(max(5 + focus, 0), false),
+            Orb::Dark(stored) => {
+                //Dark orbs hit the enemy with the lowest HP.
+                let target = self
+                    .fight
+                    .enemies
+                    .indicies()
+                    .min_by_key(|idx| self.fight.enemies[*idx].hp);
+                if let Some(target) = target {
+                    self.orb_damage(stored, target.0 as usize);
+                }
+            }
+            Orb::Plasma => self.fight.energy += 2,
+        }
+    }
+
+    //Electrodynamics makes Lightning hit all enemies.
+    fn lightning_damage(&mut self, damage: i32) {
+        if self.fight.enemies.len() == 0 {
+            return;
+        }
+        if self.fight.player_buffs.electrodynamics {
+            for idx in self.fight.enemies.indicies() {
+                self.orb_damage(damage, idx.0 as usize);
+            }
+        } else {
+            let target = self.choose_random_enemy();
+            self.orb_damage(damage, target);
+        }
+    }
+
+    //Orb damage ignores strength and vulnerable but is increased by Lock-On.
+    fn orb_damage(&mut self, damage: i32, target: usize) {
+        let Some(enemy) = &self.fight.enemies[target] else {
+            return;
+        };
+        let damage = if enemy.debuffs.lock_on > 0 {
+            (damage as f32 * 1.5) as i32
+        } else {
+            damage
+        };
+        self.damage_enemy(damage, target, false);
+    }
+}
@@ -1,20 +1,26 @@
-use std::mem;
+use std::{cmp::min, mem};
 
 use crate::{
-    card::{Buff, CardAssoc, CardType, IRONCLAD_ATTACK_CARDS, PlayEffect, SelectCardEffect},
+    card::{
+        Buff, CLASS_ATTACK_CARDS, COLORLESS_CARDS, Card, CardAssoc, CardBody, CardType, Cost,
+        DEFECT_POWER_CARDS, Debuff, IRONCLAD_ATTACK_CARDS, LethalEffect, PlayEffect,
+        SILENT_SKILL_CARDS, SelectCardEffect, sample_card,
+    },
     fight::{PlayCardContext, PostCardItem},
     game::{
         ActionControlFlow, Game, apply_debuff_to_enemy,
         choice::{SelectCardAction, SelectionPile},
         choose_card_filter,
     },
+    orb::Orb,
+    stance::Stance,
     util::insert_sorted,
 };
 
 impl Game {
     pub(super) fn perform_selected_action(
         &mut self,
-        _context: &mut PlayCardContext,
+        _context: &mut Option<PlayCardContext>,
         effect: SelectCardEffect,
         action: SelectCardAction,
     ) {
@@ -24,7 +30,7 @@ impl Game {
                 card.upgrade();
             }
             SelectCardEffect::DiscardToTop => {
-                let card = { self.fight.discard_pile.remove(action.0) };
+                let card = self.fight.discard_pile.remove(action.0);
                 self.put_on_top(card);
             }
             SelectCardEffect::ExhaustChosen => {
@@ -45,6 +51,142 @@ impl Game {
                 let card = { self.fight.exhaust.remove(action.0) };
                 self.add_card_to_hand(card);
             }
+            SelectCardEffect::DiscardChosen => {
+                self.discard_from_hand(action.0);
+            }
+            SelectCardEffect::HandToTopFree => {
+                let mut card = { self.fight.hand.remove(action.0) };
+                card.temp_cost = Some(0);
+                self.put_on_top(card);
+            }
+            SelectCardEffect::Nightmare => {
+                let card = { &self.fight.hand[action.0] };
+                self.fight.nightmare.extend(vec![card.clone(); 3]);
+            }
+            SelectCardEffect::Retain => {
+                let card = { self.fight.hand.remove(action.0) };
+                self.fight.retained.push(card);
+            }
+            SelectCardEffect::DiscardToHand => {
+                let card = self.fight.discard_pile.remove(action.0);
+                self.add_card_to_hand(card);
+            }
+            SelectCardEffect::ExhaustForEnergy => {
+                let card = { self.fight.hand.remove(action.0) };
+                self.fight.energy += self.fight.evaluate_cost(&card).unwrap_or(0);
+                self.exhaust(card);
+            }
+            SelectCardEffect::DeckToHand | SelectCardEffect::DeckTypeToHand(_) => {
+                let chosen = self.fight.deck.distinct_cards().swap_remove(action.0);
+                if let Some(card) = self.fight.deck.take(&|card| card == &chosen) {
+                    self.add_card_to_hand(card);
+                }
+            }
+            SelectCardEffect::DiscardToRetained => {
+                let card = self.fight.discard_pile.remove(action.0);
+                self.fight.retained.push(card);
+            }
+            SelectCardEffect::PlayTwiceFromDeck => {
+                let chosen = self.fight.deck.distinct_cards().swap_remove(action.0);
+                if let Some(card) = self.fight.deck.take(&|card| card == &chosen) {
+                    let target = self.select_random_target(&card);
+                    let context = PlayCardContext {
+                        card,
+                        target,
+                        real_card: true,
+                        exhausts: true,
+                        effect_index: 0,
+                        x: self.fight.energy,
+                        rebound: false,
+                        shuffles_back: false,
+                    };
+                    self.fight
+                        .post_card_queue
+                        .push_back(PostCardItem::PlayCard(context.clone()));
+                    self.queue_replay(&context);
+                }
+            }
+            SelectCardEffect::TakeOffered(costs_zero) => {
+                let mut card = mem::take(&mut self.fight.offered).swap_remove(action.0);
+                if costs_zero {
+                    card.temp_cost = Some(0);
+                }
+                self.add_card_to_hand(card);
+            }
+            //Wish's options only have simple effects, so they resolve right away.
+            SelectCardEffect::PlayOffered => {
+                let card = mem::take(&mut self.fight.offered).swap_remove(action.0);
+                let mut context = PlayCardContext {
+                    card,
+                    target: 0,
+                    real_card: false,
+                    exhausts: false,
+                    effect_index: 0,
+                    x: 0,
+                    rebound: false,
+                    shuffles_back: false,
+                };
+                for effect in context.card.actions() {
+                    let _ = self.perform_play_effect(*effect, &mut context);
+                }
+            }
+        }
+    }
+
+    pub(super) fn select_card_targets(
+        &self,
+        effect: SelectCardEffect,
+    ) -> (Vec<SelectCardAction>, SelectionPile) {
+        match effect {
+            SelectCardEffect::UpgradeCardInHand => (
+                choose_card_filter(&self.fight.hand, |card| card.can_upgrade()),
+                SelectionPile::Hand,
+            ),
+            SelectCardEffect::DiscardToTop
+            | SelectCardEffect::DiscardToHand
+            | SelectCardEffect::DiscardToRetained => (
+                choose_card_filter(&self.fight.discard_pile, |_| true),
+                SelectionPile::Discard,
+            ),
+            SelectCardEffect::DuplicatePowerOrAttack(_x) => (
+                choose_card_filter(&self.fight.hand, |card| {
+                    let t = card.body.card_type();
+                    t == CardType::Power || t == CardType::Attack
+                }),
+                SelectionPile::Hand,
+            ),
+            SelectCardEffect::ExhaustToHand => (
+                choose_card_filter(&self.fight.exhaust, |_| true),
+                SelectionPile::Exhaust,
+            ),
+            SelectCardEffect::ExhaustChosen
+            | SelectCardEffect::HandToTop
+            | SelectCardEffect::DiscardChosen
+            | SelectCardEffect::HandToTopFree
+            | SelectCardEffect::Nightmare
+            | SelectCardEffect::Retain
+            | SelectCardEffect::ExhaustForEnergy => (
+                choose_card_filter(&self.fight.hand, |_| true),
+                SelectionPile::Hand,
+            ),
+            SelectCardEffect::DeckToHand | SelectCardEffect::PlayTwiceFromDeck => (
+                (0..self.fight.deck.distinct_cards().len())
+                    .map(SelectCardAction)
+                    .collect(),
+                SelectionPile::Deck,
+            ),
+            SelectCardEffect::DeckTypeToHand(card_type) => (
+                choose_card_filter(&self.fight.deck.distinct_cards(), |card| {
+                    card.body.card_type() == card_type
+                }),
+                SelectionPile::Deck,
+            ),
+            SelectCardEffect::TakeOffered(_) | SelectCardEffect::PlayOffered => (
+                (0..self.fight.offered.len())
+                    .map(SelectCardAction)
+                    .collect(),
+                SelectionPile::Offered,
+            ),
         }
     }
 
@@ -110,75 +252,14 @@ impl Game {
                 let card = self.fight.hand.remove(idx);
                 self.exhaust(card);
             }
-            PlayEffect::SelectCardEffect(select_effect) => match select_effect {
-                SelectCardEffect::UpgradeCardInHand => {
-                    let targets = choose_card_filter(&self.fight.hand, |card| card.can_upgrade());
-                    if targets.len() > 0 {
-                        return ActionControlFlow::SelectCards(
-                            targets,
-                            select_effect,
-                            SelectionPile::Hand,
-                        );
-                    }
-                }
-                SelectCardEffect::DiscardToTop => {
-                    let targets = choose_card_filter(&self.fight.discard_pile, |_| true);
-                    if targets.len() > 0 {
-                        return ActionControlFlow::SelectCards(
-                            targets,
-                            select_effect,
-                            SelectionPile::Discard,
-                        );
-                    }
-                }
-                SelectCardEffect::ExhaustChosen => {
-                    let targets = choose_card_filter(&self.fight.hand, |_| true);
-                    if targets.len() > 0 {
-                        return ActionControlFlow::SelectCards(
-                            targets,
-                            select_effect,
-                            SelectionPile::Hand,
-                        );
-                    }
-                }
-                SelectCardEffect::HandToTop => {
-                    let targets = choose_card_filter(&self.fight.hand, |_| true);
-                    if targets.len() > 0 {
-                        return ActionControlFlow::SelectCards(
-                            targets,
-                            select_effect,
-                            SelectionPile::Hand,
-                        );
-                    }
-                }
-                SelectCardEffect::DuplicatePowerOrAttack(_x) => {
-                    let targets = choose_card_filter(&self.fight.hand, |card| {
-                        let t = card.body.card_type();
-                        t == CardType::Power || t == CardType::Attack
-                    });
-                    if targets.len() > 0 {
-                        return ActionControlFlow::SelectCards(
-                            targets,
-                            select_effect,
-                            SelectionPile::Hand,
-                        );
-                    }
-                }
-                SelectCardEffect::ExhaustToHand => {
-                    let targets = choose_card_filter(&self.fight.exhaust, |_| true);
-                    if targets.len() > 0 {
-                        return ActionControlFlow::SelectCards(
-                            targets,
-                            select_effect,
-                            SelectionPile::Exhaust,
-                        );
-                    }
+            PlayEffect::SelectCardEffect(select_effect) => {
+                let (targets, pile) = self.select_card_targets(select_effect);
+                if !targets.is_empty() {
+                    return ActionControlFlow::SelectCards(targets, select_effect, pile);
                 }
-            },
+            }
             PlayEffect::UpgradeAllCardsInHand => {
-                for card in &mut self.fight.hand {
-                    card.upgrade();
-                }
+                upgrade_all(&mut self.fight.hand);
             }
             PlayEffect::PlayExhaustTop => {
                 if let Some(card) = self.fight.remove_top_of_deck(&mut self.rng) {
@@ -192,6 +273,8 @@ impl Game {
                             exhausts: true,
                             effect_index: 0,
                             x: self.fight.energy,
+                            rebound: false,
+                            shuffles_back: false,
                         }));
                 }
             }
@@ -199,11 +282,15 @@ impl Game {
                 context.exhausts = true;
             }
             PlayEffect::ShuffleInCard(body) => {
-                self.fight.deck.shuffle_in(vec![body.to_card()]);
+                let card = self.create_card(body);
+                self.fight.deck.shuffle_in(vec![card]);
             }
             PlayEffect::LoseHP(x) => {
                 self.player_lose_hp(x, true);
             }
+            PlayEffect::Heal(x) => {
+                self.heal(x);
+            }
             PlayEffect::GainEnergy(x) => {
                 self.fight.energy += x;
             }
@@ -270,15 +357,37 @@ impl Game {
                 }
             }
             PlayEffect::AttackLethalEffect(attack, lethal_effect) => {
-                let res = self.attack_enemy(&context.card, attack, target);
+                let res = self.attack_enemy(
+                    &context.card,
+                    attack + self.bonus_attack(&context.card),
+                    target,
+                );
                 if res.lethal {
                     match lethal_effect {
-                        crate::card::LethalEffect::Gain3MaxHP => {
+                        LethalEffect::Gain3MaxHP => {
                             self.gain_max_hp(3);
                         }
-                        crate::card::LethalEffect::Gain4MaxHP => {
+                        LethalEffect::Gain4MaxHP => {
                             self.gain_max_hp(4);
                         }
+                        LethalEffect::DaggerDamage3 => {
+                            self.grow_card_permanently(&mut context.card, 3);
+                        }
+                        LethalEffect::DaggerDamage5 => {
+                            self.grow_card_permanently(&mut context.card, 5);
+                        }
+                        LethalEffect::Gain3Energy => {
+                            self.fight.energy += 3;
+                        }
+                        LethalEffect::UpgradeRandomCard => {
+                            self.upgrade_random_cards(1);
+                        }
+                        LethalEffect::Gain20Gold => {
+                            self.gain_gold(20);
+                        }
+                        LethalEffect::Gain25Gold => {
+                            self.gain_gold(25);
+                        }
                     }
                 }
             }
@@ -294,7 +403,8 @@ impl Game {
                 }
             }
             PlayEffect::AddCardToDiscard(card_body) => {
-                insert_sorted(card_body.to_card(), &mut self.fight.discard_pile);
+                let card = self.create_card(card_body);
+                insert_sorted(card, &mut self.fight.discard_pile);
             }
             PlayEffect::DoubleStrength => {
                 self.fight.player_buffs.strength *= 2;
@@ -310,7 +420,620 @@ impl Game {
                     self.heal(total);
                 }
             }
+            PlayEffect::AttackIfPoisoned(amount) => {
+                if let Some(enemy) = &self.fight.enemies[target]
+                    && enemy.debuffs.poison > 0
+                {
+                    self.attack_enemy(&context.card, amount, target);
+                }
+            }
+            PlayEffect::AttackX(amount) => {
+                for _ in 0..context.x {
+                    self.attack_enemy(&context.card, amount, target);
+                }
+            }
+            PlayEffect::AttackPerAttackPlayed(amount) => {
+                for _ in 0..self.fight.attacks_played_this_turn {
+                    self.attack_enemy(&context.card, amount, target);
+                }
+            }
+            PlayEffect::AttackPerSkillInHand(amount) => {
+                let skills = self
+                    .fight
+                    .hand
+                    .iter()
+                    .filter(|card| card.body.card_type() == CardType::Skill)
+                    .count();
+                for _ in 0..skills {
+                    self.attack_enemy(&context.card, amount, target);
+                }
+            }
+            PlayEffect::DebuffRandomEnemy(debuff) => {
+                let target = self.choose_random_enemy();
+                if let Some(enemy) = &mut self.fight.enemies[target] {
+                    apply_debuff_to_enemy(enemy, debuff);
+                }
+            }
+            PlayEffect::MultiplyPoison(amount) => {
+                if let Some(enemy) = &mut self.fight.enemies[target] {
+                    enemy.debuffs.poison *= amount;
+                }
+            }
+            PlayEffect::DiscardRandomInHand => {
+                if let Some(idx) = self.rng.try_sample(self.fight.hand.len()) {
+                    self.discard_from_hand(idx);
+                }
+            }
+            PlayEffect::DiscardHandAndDraw => {
+                let count = self.fight.hand.len();
+                while !self.fight.hand.is_empty() {
+                    self.discard_from_hand(self.fight.hand.len() - 1);
+                }
+                for _ in 0..count {
+                    self.fight.draw(&mut self.rng);
+                }
+            }
+            PlayEffect::DiscardNonAttacks => {
+                for idx in (0..self.fight.hand.len()).rev() {
+                    if self.fight.hand[idx].body.card_type() != CardType::Attack {
+                        self.discard_from_hand(idx);
+                    }
+                }
+            }
+            PlayEffect::DiscardHandForShivs(upgraded) => {
+                let count = self.fight.hand.len();
+                while !self.fight.hand.is_empty() {
+                    self.discard_from_hand(self.fight.hand.len() - 1);
+                }
+                self.add_shivs(count as i32, upgraded);
+            }
+            PlayEffect::GenerateSkillDistraction => {
+                let idx = self.rng.sample(SILENT_SKILL_CARDS.len());
+                self.gen_temp_card(SILENT_SKILL_CARDS[idx], true);
+            }
+            PlayEffect::DrawBlockIfSkill(amount) => {
+                if let Some(card) = self.fight.draw(&mut self.rng)
+                    && card.body.card_type() == CardType::Skill
+                {
+                    self.player_gain_block(amount, true);
+                }
+            }
+            PlayEffect::DrawToHandSize(amount) => {
+                while self.fight.hand.len() < amount as usize {
+                    if self.fight.draw(&mut self.rng).is_none() {
+                        break;
+                    }
+                }
+            }
+            PlayEffect::HeelHookDraw => {
+                if let Some(enemy) = &self.fight.enemies[target]
+                    && enemy.debuffs.weak > 0
+                {
+                    self.fight.energy += 1;
+                    self.fight.draw(&mut self.rng);
+                }
+            }
+            PlayEffect::BlockNextTurn(amount) => {
+                self.fight.player_buffs.block_next_turn += self.block_from_card(amount);
+            }
+            PlayEffect::GainEnergyIfDiscarded(amount) => {
+                if self.fight.cards_discarded_this_turn > 0 {
+                    self.fight.energy += amount;
+                }
+            }
+            PlayEffect::HandCostsZero => {
+                for card in &mut self.fight.hand {
+                    if !matches!(card.cost, Cost::Unplayable | Cost::X) {
+                        card.temp_cost = Some(0);
+                    }
+                }
+            }
+            PlayEffect::Doppelganger(bonus) => {
+                let amount = context.x + bonus;
+                self.apply_buff_to_player(Buff::EnergyNextTurn(amount));
+                self.apply_buff_to_player(Buff::DrawNextTurn(amount));
+            }
+            PlayEffect::GainRandomPotion => {
+                let potion = self.random_potion();
+                self.gain_potion(potion);
+            }
+            PlayEffect::MalaiseDebuff(bonus) => {
+                let amount = context.x + bonus;
+                if amount > 0
+                    && let Some(enemy) = &mut self.fight.enemies[target]
+                {
+                    apply_debuff_to_enemy(enemy, Debuff::Weak(amount));
+                    apply_debuff_to_enemy(enemy, Debuff::MinusStrength(amount));
+                }
+            }
+            PlayEffect::Channel(orb) => {
+                self.channel(orb);
+            }
+            PlayEffect::ChannelRandomOrb => {
+                let orb = Orb::random(&mut self.rng);
+                self.channel(orb);
+            }
+            PlayEffect::ChannelFrostPerEnemy => {
+                for _ in 0..self.fight.enemies.len() {
+                    self.channel(Orb::Frost);
+                }
+            }
+            PlayEffect::ChannelLightningX(bonus) => {
+                for _ in 0..context.x + bonus {
+                    self.channel(Orb::Lightning);
+                }
+            }
+            PlayEffect::Evoke(times) => {
+                self.evoke(times);
+            }
+            PlayEffect::EvokeX(bonus) => {
+                if context.x + bonus > 0 {
+                    self.evoke(context.x + bonus);
+                }
+            }
+            PlayEffect::EvokeAndRechannel => {
+                if let Some(orb) = self.fight.orbs.first().copied() {
+                    self.evoke(1);
+                    //The rechanneled Dark orb starts over with its base damage.
+                    self.channel(if matches!(orb, Orb::Dark(_)) {
+                        Orb::DARK
+                    } else {
+                        orb
+                    });
+                }
+            }
+            PlayEffect::TriggerDarkPassives => {
+                for i in 0..self.fight.orbs.len() {
+                    if matches!(self.fight.orbs[i], Orb::Dark(_)) {
+                        self.trigger_passive(i);
+                    }
+                }
+            }
+            PlayEffect::Fission(evoke) => {
+                let count = self.fight.orbs.len();
+                if evoke {
+                    for _ in 0..count {
+                        self.evoke(1);
+                    }
+                } else {
+                    self.fight.orbs.clear();
+                }
+                self.fight.energy += count as i32;
+                for _ in 0..count {
+                    self.fight.draw(&mut self.rng);
+                }
+            }
+            PlayEffect::GainOrbSlots(amount) => {
+                self.gain_orb_slots(amount);
+            }
+            PlayEffect::AttackPerOrb(amount) => {
+                for _ in 0..self.fight.orbs.len() {
+                    self.attack_enemy(&context.card, amount, target);
+                }
+            }
+            PlayEffect::DrawPerUniqueOrb => {
+                let mut kinds: Vec<Orb> = Vec::new();
+                for orb in &self.fight.orbs {
+                    if !kinds.iter().any(|kind| kind.same_kind(orb)) {
+                        kinds.push(*orb);
+                    }
+                }
+                for _ in 0..kinds.len() {
+                    self.fight.draw(&mut self.rng);
+                }
+            }
+            PlayEffect::AttackAllPerFrost(amount) => {
+                let damage = amount * self.fight.frost_channeled;
+                for enemy in self.fight.enemies.indicies() {
+                    self.attack_enemy(&context.card, damage, enemy.0 as usize);
+                }
+            }
+            PlayEffect::AttackRandomPerLightning(amount) => {
+                for _ in 0..self.fight.lightning_channeled {
+                    if self.fight.enemies.len() == 0 {
+                        break;
+                    }
+                    let target = self.choose_random_enemy();
+                    self.attack_enemy(&context.card, amount, target);
+                }
+            }
+            PlayEffect::IncreaseClawDamage(amount) => {
+                self.fight.claw_bonus += amount;
+            }
+            PlayEffect::WeakIfAttacking(amount) => {
+                if self.intends_to_attack(target)
+                    && let Some(enemy) = &mut self.fight.enemies[target]
+                {
+                    apply_debuff_to_enemy(enemy, Debuff::Weak(amount));
+                }
+            }
+            PlayEffect::BlockPerDiscard(bonus) => {
+                let block = self.fight.discard_pile.len() as i32 + bonus;
+                self.player_gain_block(block, true);
+            }
+            PlayEffect::BlockPlusBonus(block) => {
+                let bonus = card.assoc_data.get_bonus_block();
+                self.player_gain_block(block + bonus, true);
+            }
+            PlayEffect::IncreaseBlock(amount) => {
+                card.assoc_data = card.assoc_data.grow(amount);
+            }
+            PlayEffect::IncreaseBlockPermanently(amount) => {
+                self.grow_card_permanently(&mut context.card, amount);
+            }
+            PlayEffect::ReduceOwnCost => {
+                card.reduce_cost(1);
+            }
+            PlayEffect::Rebound => {
+                self.fight.rebound = true;
+            }
+            PlayEffect::EnergyPerDrawPile(cards_per_energy) => {
+                self.fight.energy += self.fight.deck.len() as i32 / cards_per_energy;
+            }
+            PlayEffect::BlockIfNoBlock(block) => {
+                if self.fight.player_block == 0 {
+                    self.player_gain_block(block, true);
+                }
+            }
+            PlayEffect::DoubleEnergy => {
+                self.fight.energy *= 2;
+            }
+            PlayEffect::DrawIfFewCardsPlayed(amount) => {
+                if self.fight.cards_played_this_turn < amount {
+                    self.fight.draw(&mut self.rng);
+                }
+            }
+            PlayEffect::RemoveEnemyBlock => {
+                if let Some(enemy) = &mut self.fight.enemies[target] {
+                    enemy.block = 0;
+                }
+            }
+            PlayEffect::ScrapeDraw(amount) => {
+                let mut drawn = Vec::new();
+                for _ in 0..amount {
+                    if let Some(card) = self.fight.draw(&mut self.rng) {
+                        drawn.push(card);
+                    }
+                }
+                for card in drawn {
+                    if self.fight.evaluate_cost(&card) != Some(0)
+                        && let Some(idx) = self.fight.hand.iter().position(|c| c == &card)
+                    {
+                        self.discard_from_hand(idx);
+                    }
+                }
+            }
+            PlayEffect::BlockX(block) => {
+                for _ in 0..context.x {
+                    self.player_gain_block(block, true);
+                }
+            }
+            PlayEffect::GenerateRandomPower => {
+                let body = sample_card(DEFECT_POWER_CARDS, &mut self.rng);
+                self.gen_temp_card(body, true);
+            }
+            PlayEffect::ZeroCostDiscardToHand => {
+                for card in mem::take(&mut self.fight.discard_pile) {
+                    if self.fight.hand.len() < 10 && self.fight.evaluate_cost(&card) == Some(0) {
+                        insert_sorted(card, &mut self.fight.hand);
+                    } else {
+                        self.fight.discard_pile.push(card);
+                    }
+                }
+            }
+            PlayEffect::Reboot(amount) => {
+                let mut cards = mem::take(&mut self.fight.hand);
+                cards.append(&mut self.fight.discard_pile);
+                self.fight.deck.shuffle_in(cards);
+                for _ in 0..amount {
+                    self.fight.draw(&mut self.rng);
+                }
+            }
+            PlayEffect::EnterStance(stance) => {
+                self.change_stance(stance);
+            }
+            PlayEffect::Scry(amount) => {
+                if self.scry(amount) {
+                    return ActionControlFlow::Scry;
+                }
+            }
+            PlayEffect::GainMantra(amount) => {
+                self.gain_mantra(amount);
+            }
+            PlayEffect::AttackPerEnemy(amount) => {
+                for _ in 0..self.fight.enemies.len() {
+                    self.attack_enemy(&context.card, amount, target);
+                }
+            }
+            PlayEffect::VulnerableIfLastSkill(amount) => {
+                if self.fight.last_card_type == Some(CardType::Skill)
+                    && let Some(enemy) = &mut self.fight.enemies[target]
+                {
+                    apply_debuff_to_enemy(enemy, Debuff::Vulnerable(amount));
+                }
+            }
+            PlayEffect::EnergyIfLastAttack(amount) => {
+                if self.fight.last_card_type == Some(CardType::Attack) {
+                    self.fight.energy += amount;
+                }
+            }
+            PlayEffect::WeakIfLastAttack(amount) => {
+                if self.fight.last_card_type == Some(CardType::Attack)
+                    && let Some(enemy) = &mut self.fight.enemies[target]
+                {
+                    apply_debuff_to_enemy(enemy, Debuff::Weak(amount));
+                }
+            }
+            PlayEffect::DrawIfLastSkill(amount) => {
+                if self.fight.last_card_type == Some(CardType::Skill) {
+                    for _ in 0..amount {
+                        self.fight.draw(&mut self.rng);
+                    }
+                }
+            }
+            PlayEffect::BlockIfWrath(block) => {
+                if self.fight.stance == Stance::Wrath {
+                    self.player_gain_block(block, true);
+                }
+            }
+            PlayEffect::TriggerMarks => {
+                //An enemy dying can remove others, like minions fleeing or Corpse Explosion.
+                for idx in self.fight.enemies.indicies() {
+                    let Some(enemy) = &self.fight.enemies[idx.0 as usize] else {
+                        continue;
+                    };
+                    let mark = enemy.debuffs.mark;
+                    if mark > 0 {
+                        self.enemy_lose_hp(mark, idx.0 as usize);
+                    }
+                    if self.fight.enemies.len() == 0 {
+                        break;
+                    }
+                }
+            }
+            PlayEffect::CalmIfAttacking => {
+                if self.intends_to_attack(target) {
+                    self.change_stance(Stance::Calm);
+                }
+            }
+            PlayEffect::Indignation(amount) => {
+                if self.fight.stance == Stance::Wrath {
+                    for idx in self.fight.enemies.indicies() {
+                        apply_debuff_to_enemy(
+                            &mut self.fight.enemies[idx],
+                            Debuff::Vulnerable(amount),
+                        );
+                    }
+                } else {
+                    self.change_stance(Stance::Wrath);
+                }
+            }
+            PlayEffect::InnerPeace(amount) => {
+                if self.fight.stance == Stance::Calm {
+                    for _ in 0..amount {
+                        self.fight.draw(&mut self.rng);
+                    }
+                } else {
+                    self.change_stance(Stance::Calm);
+                }
+            }
+            PlayEffect::EndTurn => {
+                self.fight.end_turn_early = true;
+            }
+            PlayEffect::ExtraTurn => {
+                self.fight.extra_turn = true;
+            }
+            PlayEffect::AttackGainBlock(amount) => {
+                let result = self.attack_enemy(&context.card, amount, target);
+                self.player_gain_block(result.damage_dealt, false);
+            }
+            PlayEffect::ShuffleBack => {
+                context.shuffles_back = true;
+            }
+            PlayEffect::BlockPerCardInHand(amount) => {
+                let block = amount * self.fight.hand.len() as i32;
+                self.player_gain_block(block, true);
+            }
+            PlayEffect::Judgment(threshold) => {
+                if let Some(enemy) = &self.fight.enemies[target]
+                    && enemy.hp <= threshold
+                {
+                    self.enemy_lose_hp(enemy.hp, target);
+                }
+            }
+            PlayEffect::ConjureBlade(bonus) => {
+                let mut blade = self.create_card(CardBody::Expunger);
+                blade.assoc_data = CardAssoc::Hits(context.x + bonus);
+                self.fight.deck.shuffle_in(vec![blade]);
+            }
+            PlayEffect::AttackPerHit(amount) => {
+                for _ in 0..context.card.assoc_data.get_hits() {
+                    self.attack_enemy(&context.card, amount, target);
+                }
+            }
+            PlayEffect::CollectX(bonus) => {
+                self.apply_buff_to_player(Buff::Collect(context.x + bonus));
+            }
+            PlayEffect::GainGold(amount) => {
+                self.gain_gold(amount);
+            }
+            PlayEffect::ChooseClassAttack(costs_zero) => {
+                self.fight.offered = self.offer_cards(CLASS_ATTACK_CARDS, 3);
+                let effect = SelectCardEffect::TakeOffered(costs_zero);
+                let (targets, pile) = self.select_card_targets(effect);
+                return ActionControlFlow::SelectCards(targets, effect, pile);
+            }
+            PlayEffect::Wish => {
+                let upgraded = card.is_upgraded();
+                self.fight.offered = [
+                    CardBody::BecomeAlmighty,
+                    CardBody::FameAndFortune,
+                    CardBody::LiveForever,
+                ]
+                .map(|body| {
+                    let mut option = body.to_card();
+                    if upgraded {
+                        option.upgrade();
+                    }
+                    option
+                })
+                .to_vec();
+                let effect = SelectCardEffect::PlayOffered;
+                let (targets, pile) = self.select_card_targets(effect);
+                return ActionControlFlow::SelectCards(targets, effect, pile);
+            }
+            PlayEffect::UpgradeAllCards => {
+                upgrade_all(&mut self.fight.hand);
+                upgrade_all(&mut self.fight.discard_pile);
+                upgrade_all(&mut self.fight.exhaust);
+                self.fight.deck.for_each_mut(&mut |card| {
+                    if card.can_upgrade() {
+                        card.upgrade();
+                    }
+                });
+            }
+            PlayEffect::ShuffleInFreeClassCards(card_type, amount) => {
+                let pool: Vec<CardBody> = self
+                    .class_cards()
+                    .iter()
+                    .copied()
+                    .filter(|body| body.card_type() == card_type)
+                    .collect();
+                let mut cards = Vec::new();
+                for _ in 0..amount {
+                    let body = pool[self.rng.sample(pool.len())];
+                    let mut card = self.create_card(body);
+                    if let Cost::Fixed(_) = card.cost {
+                        card.cost = Cost::Fixed(0);
+                    }
+                    cards.push(card);
+                }
+                self.fight.deck.shuffle_in(cards);
+            }
+            PlayEffect::ShuffleDiscardIntoDeck => {
+                let discard = mem::take(&mut self.fight.discard_pile);
+                self.fight.deck.shuffle_in(discard);
+            }
+            PlayEffect::Discovery => {
+                self.fight.offered = self.offer_cards(self.class_cards(), 3);
+                let effect = SelectCardEffect::TakeOffered(true);
+                let (targets, pile) = self.select_card_targets(effect);
+                return ActionControlFlow::SelectCards(targets, effect, pile);
+            }
+            PlayEffect::Enlightenment(permanent) => {
+                for card in &mut self.fight.hand {
+                    if permanent && let Cost::Fixed(cost) = card.cost {
+                        card.cost = Cost::Fixed(min(cost, 1));
+                    }
+                    card.temp_cost = Some(card.temp_cost.map_or(1, |temp| min(temp, 1)));
+                }
+            }
+            PlayEffect::DrawIfNoAttacks(amount) => {
+                if self
+                    .fight
+                    .hand
+                    .iter()
+                    .all(|card| card.body.card_type() != CardType::Attack)
+                {
+                    for _ in 0..amount {
+                        self.fight.draw(&mut self.rng);
+                    }
+                }
+            }
+            PlayEffect::AddRandomColorless(amount) => {
+                for _ in 0..amount {
+                    let body = sample_card(COLORLESS_CARDS, &mut self.rng);
+                    self.gen_temp_card(body, false);
+                }
+            }
+            PlayEffect::Madness => {
+                let costly: Vec<usize> = (0..self.fight.hand.len())
+                    .filter(|i| matches!(self.fight.hand[*i].cost, Cost::Fixed(cost) if cost > 0))
+                    .collect();
+                if let Some(idx) = self.rng.try_sample(costly.len()) {
+                    self.fight.hand[costly[idx]].cost = Cost::Fixed(0);
+                    self.fight.hand.sort();
+                }
+            }
+            PlayEffect::AttackPerCardInDeck => {
+                let damage = self.fight.deck.len() as i32;
+                self.attack_enemy(&context.card, damage, target);
+            }
+            //Duplicate cards in the draw pile aren't more likely to be taken.
+            PlayEffect::DeckAttacksToHand(amount) => {
+                for _ in 0..amount {
+                    let attacks: Vec<Card> = self
+                        .fight
+                        .deck
+                        .distinct_cards()
+                        .into_iter()
+                        .filter(|card| card.body.card_type() == CardType::Attack)
+                        .collect();
+                    let Some(idx) = self.rng.try_sample(attacks.len()) else {
+                        break;
+                    };
+                    if let Some(card) = self.fight.deck.take(&|card| card == &attacks[idx]) {
+                        self.add_card_to_hand(card);
+                    }
+                }
+            }
+            PlayEffect::TransmutationX(upgraded) => {
+                for _ in 0..context.x {
+                    let body = sample_card(COLORLESS_CARDS, &mut self.rng);
+                    let mut card = self.create_card(body);
+                    if upgraded && card.can_upgrade() {
+                        card.upgrade();
+                    }
+                    card.temp_cost = Some(0);
+                    self.add_card_to_hand(card);
+                }
+            }
         }
         ActionControlFlow::Continue
     }
 }
+
+//Cards which can't be upgraded, like curses, are skipped.
+fn upgrade_all(cards: &mut [Card]) {
+    for card in cards.iter_mut() {
+        if card.can_upgrade() {
+            card.upgrade();
+        }
+    }
+    cards.sort();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        card::{CardBody, PlayEffect},
+        enemies::jaw_worm::generate_jaw_worm,
+        game::{Character, Game},
+        rng::Rng,
+    };
+
+    //Corpse Explosion on the first enemy kills the second before its Mark is checked.
+    #[test]
+    fn trigger_marks_skips_enemies_killed_earlier() {
+        let mut rng = Rng::new();
+        let mut first = generate_jaw_worm(&mut rng, 0);
+        first.hp = 1;
+        first.debuffs.mark = 5;
+        first.debuffs.corpse_explosion = 1;
+        let explosion = first.max_hp;
+        let mut second = generate_jaw_worm(&mut rng, 0);
+        second.hp = 1;
+        second.debuffs.mark = 5;
+        let mut third = generate_jaw_worm(&mut rng, 0);
+        third.hp = 100;
+        third.debuffs.mark = 5;
+        let mut game = Game::test_fight(Character::WATCHER, vec![first, second, third]);
+        let mut context = Game::test_context(CardBody::PressurePoints);
+        let _ = game.perform_play_effect(PlayEffect::TriggerMarks, &mut context);
+        assert_eq!(game.fight.enemies.len(), 1);
+        assert_eq!(
+            game.fight.enemies[2].as_ref().unwrap().hp,
+            100 - explosion - 5
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{
+    card::{Buff, CardBody, Debuff},
+    fight::Fight,
+    game::{Choice, Game, apply_debuff_to_enemy},
+    map::RoomType,
+    orb::Orb,
+    potion::Potion,
+    relic::Relic,
+    stance::Stance,
+    util::insert_sorted,
+};
+
+//The chance of a potion dropping after a fight starts at 40% and moves by 10% each time
+//a potion does or doesn't drop.
+pub const POTION_CHANCE_START: i32 = 40;
+const POTION_CHANCE_STEP: i32 = 10;
+
+impl Game {
+    pub(super) fn can_gain_potion(&self) -> bool {
+        !self.relics.has_relic(Relic::Sozu) && (self.potions.len() as i32) < self.max_potion_slots
+    }
+
+    //Potions are kept sorted for greater MCTS efficiency, the slot they are in doesn't matter.
+    pub(super) fn gain_potion(&mut self, potion: Potion) {
+        if self.can_gain_potion() {
+            insert_sorted(potion, &mut self.potions);
+        }
+    }
+
+    pub(super) fn random_potion(&mut self) -> Potion {
+        Potion::random(&mut self.rng, self.charachter)
+    }
+
+    pub(super) fn roll_potion_drop(&mut self, num_rewards: usize) -> Option<Potion> {
+        let mut chance = self.potion_chance;
+        if self.relics.has_relic(Relic::WhiteBeastStatue) {
+            chance = 100;
+        }
+        //The reward screen is full.
+        if num_rewards >= 4 {
+            chance = 0;
+        }
+        if self.rng.sample_i32(100) < chance {
+            self.potion_chance -= POTION_CHANCE_STEP;
+            Some(self.random_potion())
+        } else {
+            self.potion_chance += POTION_CHANCE_STEP;
+            None
+        }
+    }
+
+    //Smoke Bomb can't be used to escape a boss.
+    pub(super) fn can_use_potion(&self, potion: Potion) -> bool {
+        potion.can_use()
+            && !(potion == Potion::SmokeBomb
+                && self.act.room_history.last() == Some(&RoomType::Boss))
+    }
+
+    pub(super) fn choose_potion_target_choice(&mut self, slot: usize) -> Choice {
+        Choice::ChoosePotionTargetState(self.target_actions(), slot)
+    }
+
+    //Called when the player would die, a Fairy in a Bottle revives them.
+    pub(super) fn try_use_fairy(&mut self) {
+        if let Some(idx) = self
+            .potions
+            .iter()
+            .position(|potion| *potion == Potion::FairyInABottle)
+        {
+            self.potions.remove(idx);
+            self.player_hp = 0;
+            self.heal(self.player_max_hp * 30 * self.potency() / 100);
+        }
+    }
+
+    //Sacred Bark doubles the effect of potions.
+    fn potency(&self) -> i32 {
+        if self.relics.has_relic(Relic::SacredBark) {
+            2
+        } else {
+            1
+        }
+    }
+
+    pub(super) fn use_potion(&mut self, slot: usize, target: usize) -> Choice {
+        let potion = self.potions.remove(slot);
+        let potency = self.potency();
+        match potion {
+            Potion::BlockPotion => self.player_gain_block(12 * potency, false),
+            Potion::BloodPotion => self.heal(self.player_max_hp * 20 * potency / 100),
+            Potion::BottledMiracle => {
+                for _ in 0..2 * potency {
+                    self.gen_temp_card(CardBody::Miracle, false);
+                }
+            }
+            Potion::DexterityPotion => self.fight.player_buffs.dexterity += 2 * potency,
+            Potion::EnergyPotion => self.fight.energy += 2 * potency,
+            Potion::ExplosivePotion => {
+                for i in self.fight.enemies.indicies() {
+                    self.damage_enemy(10 * potency, i.0 as usize, false);
+                }
+            }
+            Potion::FearPotion => {
+                if let Some(enemy) = &mut self.fight.enemies[target] {
+                    apply_debuff_to_enemy(enemy, Debuff::Vulnerable(3 * potency));
+                }
+            }
+            Potion::FirePotion => {
+                self.damage_enemy(20 * potency, target, false);
+            }
+            Potion::FlexPotion => {
+                self.apply_buff_to_player(Buff::Strength(5 * potency));
+                self.apply_debuff_to_player(Debuff::StrengthDown(5 * potency));
+            }
+            Potion::FocusPotion => self.apply_buff_to_player(Buff::Focus(2 * potency)),
+            Potion::PoisonPotion => {
+                if let Some(enemy) = &mut self.fight.enemies[target] {
+                    apply_debuff_to_enemy(enemy, Debuff::Poison(6 * potency));
+                }
+            }
+            Potion::SpeedPotion => {
+                self.fight.player_buffs.dexterity += 5 * potency;
+                self.apply_debuff_to_player(Debuff::DexterityDown(5 * potency));
+            }
+            Potion::StrengthPotion => self.apply_buff_to_player(Buff::Strength(2 * potency)),
+            Potion::SwiftPotion => {
+                for _ in 0..3 * potency {
+                    self.fight.draw(&mut self.rng);
+                }
+            }
+            Potion::WeakPotion => {
+                if let Some(enemy) = &mut self.fight.enemies[target] {
+                    apply_debuff_to_enemy(enemy, Debuff::Weak(3 * potency));
+                }
+            }
+            Potion::BlessingOfTheForge => {
+                for card in &mut self.fight.hand {
+                    if card.can_upgrade() {
+                        card.upgrade();
+                    }
+                }
+                self.fight.hand.sort();
+            }
+            Potion::CunningPotion => self.add_shivs(3 * potency, true),
+            Potion::HeartOfIron => self.apply_buff_to_player(Buff::Metallicize(6 * potency)),
+            Potion::LiquidBronze => self.apply_buff_to_player(Buff::Thorns(3 * potency)),
+            Potion::PotionOfCapacity => self.gain_orb_slots(2 * potency),
+            Potion::Ambrosia => self.change_stance(Stance::Divinity),
+            Potion::CultistPotion => self.apply_buff_to_player(Buff::Ritual(potency)),
+            Potion::EntropicBrew => {
+                while self.can_gain_potion() {
+                    let potion = self.random_potion();
+                    self.gain_potion(potion);
+                }
+            }
+            Potion::EssenceOfDarkness => {
+                for _ in 0..self.fight.orb_slots * potency {
+                    self.channel(Orb::DARK);
+                }
+            }
+            Potion::FairyInABottle => panic!("Fairy in a Bottle can't be used"),
+            Potion::FruitJuice => self.gain_max_hp(5 * potency),
+            Potion::GhostInAJar => self.apply_buff_to_player(Buff::Intangible(potency)),
+            Potion::SmokeBomb => {
+                //Escaping gives no rewards.
+                self.fight = Fight::default();
+                return self.goto_map();
+            }
+        }
+        if self.relics.has_relic(Relic::ToyOrnithopter) {
+            self.heal(5);
+        }
+        if let Some(choice) = self.resolve_actions(None) {
+            return choice;
+        }
+        self.play_card_choice()
+    }
+}
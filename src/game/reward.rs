@@ -0,0 +1,211 @@
+use std::cmp::max;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    card::{
+        Card, CardBody, CardRarity, DEFECT_COMMON_CARDS, DEFECT_RARE_CARDS, DEFECT_UNCOMMON_CARDS,
+        IRONCLAD_COMMON_CARDS, IRONCLAD_RARE_CARDS, IRONCLAD_UNCOMMON_CARDS, SILENT_COMMON_CARDS,
+        SILENT_RARE_CARDS, SILENT_UNCOMMON_CARDS, WATCHER_COMMON_CARDS, WATCHER_RARE_CARDS,
+        WATCHER_UNCOMMON_CARDS, sample_card,
+    },
+    game::{Character, Game, choice::RewardAction},
+    potion::Potion,
+    relic::Relic,
+};
+
+//The rare pity offset is added to card rarity rolls. It starts positive and goes down by
+//one for every common card rolled until a rare is rolled and it resets.
+pub const CARD_RARITY_OFFSET_START: i32 = 5;
+const CARD_RARITY_OFFSET_MIN: i32 = -40;
+
+//The room a card reward comes from determines the rarity odds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CardRewardRoom {
+    #[default]
+    Normal,
+    Elite,
+    Shop,
+    //Boss card rewards are always rare.
+    Boss,
+}
+
+impl CardRewardRoom {
+    //The chances out of 100 of rolling a rare or an uncommon.
+    fn rare_uncommon_chances(&self) -> (i32, i32) {
+        match self {
+            CardRewardRoom::Normal => (3, 37),
+            CardRewardRoom::Elite => (10, 40),
+            CardRewardRoom::Shop => (9, 37),
+            CardRewardRoom::Boss => panic!("Boss card rewards are always rare"),
+        }
+    }
+}
+
+//The rewards on the screen after a fight. Rewards are removed from here
+//as they are taken, anything left over when proceeding is lost.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Rewards {
+    pub gold: i32,
+    pub relics: Vec<Relic>,
+    //Each entry is a seperate card reward the player can pick one card from.
+    pub cards: Vec<Vec<Card>>,
+    pub potions: Vec<Potion>,
+}
+
+impl Rewards {
+    pub fn action_str(&self, action: RewardAction) -> String {
+        match action {
+            RewardAction::TakeGold => format!("Take {} gold", self.gold),
+            RewardAction::TakeRelic(idx) => format!("Take {:?}", self.relics[idx as usize]),
+            RewardAction::TakeCard(reward, card) => {
+                let card = &self.cards[reward as usize][card as usize];
+                let upgraded = if card.is_upgraded() { "+" } else { "" };
+                format!("Add {:?}{} to deck", card.body, upgraded)
+            }
+            RewardAction::TakePotion(idx) => format!("Take {:?}", self.potions[idx as usize]),
+            RewardAction::DiscardPotion(slot) => format!("Discard potion in slot {}", slot),
+            RewardAction::SingingBowl(idx) => format!("Skip card reward {} for 2 max HP", idx),
+            RewardAction::Proceed => "Proceed".to_owned(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        (self.gold > 0) as usize + self.relics.len() + self.cards.len() + self.potions.len()
+    }
+}
+
+impl Game {
+    pub(super) fn character_cards(&self, rarity: CardRarity) -> &'static [CardBody] {
+        match self.charachter {
+            Character::IRONCLAD => match rarity {
+                CardRarity::Common => IRONCLAD_COMMON_CARDS,
+                CardRarity::Uncommon => IRONCLAD_UNCOMMON_CARDS,
+                CardRarity::Rare => IRONCLAD_RARE_CARDS,
+                CardRarity::Basic | CardRarity::Curse | CardRarity::Special => {
+                    panic!("{:?} cards aren't offered as rewards", rarity)
+                }
+            },
+            Character::SILENT => match rarity {
+                CardRarity::Common => SILENT_COMMON_CARDS,
+                CardRarity::Uncommon => SILENT_UNCOMMON_CARDS,
+                CardRarity::Rare => SILENT_RARE_CARDS,
+                CardRarity::Basic | CardRarity::Curse | CardRarity::Special => {
+                    panic!("{:?} cards aren't offered as rewards", rarity)
+                }
+            },
+            Character::DEFECT => match rarity {
+                CardRarity::Common => DEFECT_COMMON_CARDS,
+                CardRarity::Uncommon => DEFECT_UNCOMMON_CARDS,
+                CardRarity::Rare => DEFECT_RARE_CARDS,
+                CardRarity::Basic | CardRarity::Curse | CardRarity::Special => {
+                    panic!("{:?} cards aren't offered as rewards", rarity)
+                }
+            },
+            Character::WATCHER => match rarity {
+                CardRarity::Common => WATCHER_COMMON_CARDS,
+                CardRarity::Uncommon => WATCHER_UNCOMMON_CARDS,
+                CardRarity::Rare => WATCHER_RARE_CARDS,
+                CardRarity::Basic | CardRarity::Curse | CardRarity::Special => {
+                    panic!("{:?} cards aren't offered as rewards", rarity)
+                }
+            },
+        }
+    }
+
+    //Only card rewards move the rare pity offset, but shops are affected by it.
+    pub(super) fn roll_card_rarity(&mut self, room: CardRewardRoom) -> CardRarity {
+        if room == CardRewardRoom::Boss {
+            return CardRarity::Rare;
+        }
+        let (rare_chance, uncommon_chance) = room.rare_uncommon_chances();
+        let roll = self.rng.sample_i32(100) + self.card_rarity_offset;
+        if roll < rare_chance {
+            CardRarity::Rare
+        } else if roll < rare_chance + uncommon_chance {
+            CardRarity::Uncommon
+        } else {
+            CardRarity::Common
+        }
+    }
+
+    //Card rewards never offer the same card twice. If a duplicate is rolled the card
+    //is rerolled with the same rarity.
+    pub(super) fn generate_card_reward(
+        &mut self,
+        num_cards: usize,
+        room: CardRewardRoom,
+    ) -> Vec<Card> {
+        let mut cards: Vec<Card> = Vec::new();
+        for _ in 0..num_cards {
+            let rarity = self.roll_card_rarity(room);
+            match rarity {
+                CardRarity::Rare => self.card_rarity_offset = CARD_RARITY_OFFSET_START,
+                CardRarity::Common => {
+                    self.card_rarity_offset =
+                        max(self.card_rarity_offset - 1, CARD_RARITY_OFFSET_MIN)
+                }
+                _ => {}
+            }
+            let pool = self.character_cards(rarity);
+            let body = loop {
+                let body = sample_card(pool, &mut self.rng);
+                if cards.iter().all(|card| card.body != body) {
+                    break body;
+                }
+            };
+            let mut card = body.to_card();
+            if rarity != CardRarity::Rare
+                && (self.rng.sample_u32(100) as i32) < self.act.card_upgrade_chance
+                && card.can_upgrade()
+            {
+                card.upgrade();
+            }
+            cards.push(card);
+        }
+        cards
+    }
+
+    pub(super) fn card_reward_size(&self) -> usize {
+        let mut size = 3;
+        if self.relics.has_relic(Relic::QuestionCard) {
+            size += 1;
+        }
+        if self.relics.has_relic(Relic::BustedCrown) {
+            size -= 2;
+        }
+        size
+    }
+
+    pub(super) fn generate_fight_rewards(&mut self) -> Rewards {
+        let fight_rewards = self.fight.rewards.clone();
+        let mut rewards = Rewards::default();
+        if fight_rewards.gold_max > 0 {
+            rewards.gold += self
+                .rng
+                .sample_i32_inclusive(fight_rewards.gold_min, fight_rewards.gold_max);
+        }
+        //Gold stolen by Looters and Muggers is returned if they are killed.
+        rewards.gold += self.fight.stolen_back_gold;
+        for _ in 0..fight_rewards.relic_count {
+            let relic = self.relics.pool.get_random_tier_relic(&mut self.rng);
+            rewards.relics.push(relic);
+        }
+        rewards.relics.extend(fight_rewards.fixed_relics);
+        let card_reward =
+            self.generate_card_reward(self.card_reward_size(), fight_rewards.card_room);
+        rewards.cards.push(card_reward);
+        //Prayer Wheel adds a second card reward to normal fights.
+        if fight_rewards.card_room == CardRewardRoom::Normal
+            && self.relics.has_relic(Relic::PrayerWheel)
+        {
+            let card_reward =
+                self.generate_card_reward(self.card_reward_size(), CardRewardRoom::Normal);
+            rewards.cards.push(card_reward);
+        }
+        if let Some(potion) = self.roll_potion_drop(rewards.len()) {
+            rewards.potions.push(potion);
+        }
+        rewards
+    }
+}
@@ -0,0 +1,63 @@
+use std::{cmp::max, mem};
+
+use crate::{
+    card::CardBody,
+    fight::PlayCardContext,
+    game::{
+        Game,
+        choice::{Choice, ScryAction},
+    },
+    util::insert_sorted,
+};
+
+impl Game {
+    //Returns if there are cards to decide on. Unlike drawing, scrying doesn't
+    //reshuffle the discard pile.
+    pub(super) fn scry(&mut self, amount: i32) -> bool {
+        self.fight.scrying = self
+            .fight
+            .deck
+            .take_top(max(amount, 0) as usize, &mut self.rng);
+        if self.fight.scrying.is_empty() {
+            self.after_scry();
+            return false;
+        }
+        true
+    }
+
+    pub(super) fn scry_choice(&self, context: Option<PlayCardContext>) -> Choice {
+        Choice::ScryState(context, vec![ScryAction::Keep, ScryAction::Discard])
+    }
+
+    pub(super) fn handle_scry_action(
+        &mut self,
+        context: Option<PlayCardContext>,
+        action: ScryAction,
+    ) -> Choice {
+        let card = self.fight.scrying.remove(0);
+        match action {
+            ScryAction::Keep => self.fight.scry_kept.push(card),
+            ScryAction::Discard => insert_sorted(card, &mut self.fight.discard_pile),
+        }
+        if !self.fight.scrying.is_empty() {
+            return self.scry_choice(context);
+        }
+        //The first kept card was on top, so it goes last to be drawn first.
+        let mut kept = mem::take(&mut self.fight.scry_kept);
+        if !kept.is_empty() {
+            kept.reverse();
+            self.fight.deck.put_on_top(kept);
+        }
+        self.after_scry();
+        if let Some(choice) = self.resolve_actions(context) {
+            return choice;
+        }
+        self.play_card_choice()
+    }
+
+    //Weave comes back even if it was just scried into the discard pile.
+    fn after_scry(&mut self) {
+        self.player_gain_block(self.fight.player_buffs.nirvana, false);
+        self.return_from_discard(CardBody::Weave);
+    }
+}
@@ -0,0 +1,246 @@
+use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
+
+use crate::{
+    card::{
+        COLORLESS_RARE_CARDS, COLORLESS_UNCOMMON_CARDS, Card, CardCharachter, CardRarity, CardType,
+    },
+    game::{Game, choice::ShopAction, reward::CardRewardRoom},
+    potion::{Potion, PotionRarity},
+    relic::{Relic, RelicRarity},
+};
+
+const REMOVAL_BASE_PRICE: i32 = 75;
+const REMOVAL_PRICE_INCREASE: i32 = 25;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ShopItem<T> {
+    pub item: T,
+    pub price: i32,
+}
+
+//Items are removed from the shop as they are bought.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Shop {
+    pub cards: Vec<ShopItem<Card>>,
+    pub relics: Vec<ShopItem<Relic>>,
+    pub potions: Vec<ShopItem<Potion>>,
+    //This is None once the removal service is used.
+    pub removal_price: Option<i32>,
+}
+
+impl Shop {
+    pub fn action_str(&self, game: &Game, action: ShopAction) -> String {
+        match action {
+            ShopAction::BuyCard(idx) => {
+                let card = &self.cards[idx as usize];
+                format!("Buy {:?} for {} gold", card.item.body, card.price)
+            }
+            ShopAction::BuyRelic(idx) => {
+                let relic = &self.relics[idx as usize];
+                format!("Buy {:?} for {} gold", relic.item, relic.price)
+            }
+            ShopAction::BuyPotion(idx) => {
+                let potion = &self.potions[idx as usize];
+                format!("Buy {:?} for {} gold", potion.item, potion.price)
+            }
+            ShopAction::RemoveCard(idx) => {
+                format!(
+                    "Remove {:?} for {} gold",
+                    game.base_deck[idx as usize].body,
+                    self.removal_price.expect("Removal is available")
+                )
+            }
+            ShopAction::Leave => "Leave".to_owned(),
+        }
+    }
+}
+
+fn card_price(rarity: CardRarity) -> i32 {
+    match rarity {
+        CardRarity::Common => 50,
+        CardRarity::Uncommon => 75,
+        CardRarity::Rare => 150,
+        CardRarity::Basic | CardRarity::Curse | CardRarity::Special => {
+            panic!("{:?} cards aren't sold", rarity)
+        }
+    }
+}
+
+fn potion_price(rarity: PotionRarity) -> i32 {
+    match rarity {
+        PotionRarity::Common => 50,
+        PotionRarity::Uncommon => 75,
+        PotionRarity::Rare => 100,
+    }
+}
+
+fn relic_price(rarity: RelicRarity) -> i32 {
+    match rarity {
+        RelicRarity::Common => 150,
+        RelicRarity::Uncommon => 250,
+        RelicRarity::Rare => 300,
+        RelicRarity::Shop => 150,
+        RelicRarity::Boss => panic!("Boss relics aren't sold"),
+    }
+}
+
+impl Game {
+    //Prices vary randomly by up to the given percentage.
+    fn jitter_price(&mut self, price: i32, percent: i32) -> i32 {
+        let price = price * self.rng.sample_i32_inclusive(100 - percent, 100 + percent) / 100;
+        //Ascension 16 makes shops 10% more expensive.
+        let price = if self.ascension >= 16 {
+            price * 11 / 10
+        } else {
+            price
+        };
+        self.courier_discount(price)
+    }
+
+    fn courier_discount(&self, price: i32) -> i32 {
+        if self.relics.has_relic(Relic::TheCourier) {
+            price * 4 / 5
+        } else {
+            price
+        }
+    }
+
+    //Powers and some character's attacks or skills may not exist for the rolled rarity,
+    //in that case the shop moves up to the next rarity.
+    fn shop_colored_card(
+        &mut self,
+        card_type: CardType,
+        sold: &[ShopItem<Card>],
+    ) -> ShopItem<Card> {
+        let mut rarity = self.roll_card_rarity(CardRewardRoom::Shop);
+        loop {
+            let options: SmallVec<[_; 64]> = self
+                .character_cards(rarity)
+                .iter()
+                .filter(|body| body.card_type() == card_type)
+                .filter(|body| sold.iter().all(|sold| sold.item.body != **body))
+                .copied()
+                .collect();
+            if !options.is_empty() {
+                let body = options[self.rng.sample(options.len())];
+                let price = self.jitter_price(card_price(rarity), 10);
+                return ShopItem {
+                    item: body.to_card(),
+                    price,
+                };
+            }
+            rarity = match rarity {
+                CardRarity::Common => CardRarity::Uncommon,
+                CardRarity::Uncommon => CardRarity::Rare,
+                _ => panic!("No {:?} cards can be sold", card_type),
+            };
+        }
+    }
+
+    fn shop_relic(&mut self, rarity: RelicRarity) -> ShopItem<Relic> {
+        let price = self.jitter_price(relic_price(rarity), 5);
+        ShopItem {
+            item: self.relics.pool.get_relic(&mut self.rng, rarity),
+            price,
+        }
+    }
+
+    pub(super) fn card_removal_price(&self) -> i32 {
+        if self.relics.has_relic(Relic::SmilingMask) {
+            return 50;
+        }
+        self.courier_discount(
+            REMOVAL_BASE_PRICE + REMOVAL_PRICE_INCREASE * self.cards_removed_at_shop,
+        )
+    }
+
+    //Colorless cards cost 20% more.
+    fn shop_colorless_card(&mut self, rarity: CardRarity) -> Option<ShopItem<Card>> {
+        let pool = if rarity == CardRarity::Rare {
+            COLORLESS_RARE_CARDS
+        } else {
+            COLORLESS_UNCOMMON_CARDS
+        };
+        let idx = self.rng.try_sample(pool.len())?;
+        let price = self.jitter_price(card_price(rarity), 10) * 6 / 5;
+        Some(ShopItem {
+            item: pool[idx].to_card(),
+            price,
+        })
+    }
+
+    fn shop_relic_rarity(&mut self) -> RelicRarity {
+        match self.rng.sample_weighted(&[50, 33, 17]) {
+            0 => RelicRarity::Common,
+            1 => RelicRarity::Uncommon,
+            _ => RelicRarity::Rare,
+        }
+    }
+
+    pub(super) fn shop_potion(&mut self) -> ShopItem<Potion> {
+        let potion = self.random_potion();
+        let price = self.jitter_price(potion_price(potion.rarity()), 5);
+        ShopItem {
+            item: potion,
+            price,
+        }
+    }
+
+    //The Courier replaces anything bought with a new item of the same kind.
+    pub(super) fn restock_card(
+        &mut self,
+        bought: &Card,
+        sold: &[ShopItem<Card>],
+    ) -> Option<ShopItem<Card>> {
+        if bought.charachter() == CardCharachter::COLORLESS {
+            self.shop_colorless_card(bought.body.rarity())
+        } else {
+            Some(self.shop_colored_card(bought.body.card_type(), sold))
+        }
+    }
+
+    pub(super) fn restock_relic(&mut self) -> ShopItem<Relic> {
+        let rarity = self.shop_relic_rarity();
+        self.shop_relic(rarity)
+    }
+
+    pub(super) fn generate_shop(&mut self) -> Shop {
+        let mut cards = Vec::new();
+        for card_type in [
+            CardType::Attack,
+            CardType::Attack,
+            CardType::Skill,
+            CardType::Skill,
+            CardType::Power,
+        ] {
+            let card = self.shop_colored_card(card_type, &cards);
+            cards.push(card);
+        }
+        //One of the colored cards is on sale for half price.
+        let sale = self.rng.sample(cards.len());
+        cards[sale].price /= 2;
+        for rarity in [CardRarity::Uncommon, CardRarity::Rare] {
+            if let Some(card) = self.shop_colorless_card(rarity) {
+                cards.push(card);
+            }
+        }
+        let mut relics = Vec::new();
+        for _ in 0..2 {
+            let rarity = self.shop_relic_rarity();
+            relics.push(self.shop_relic(rarity));
+        }
+        relics.push(self.shop_relic(RelicRarity::Shop));
+        let mut potions = Vec::new();
+        for _ in 0..3 {
+            let potion = self.shop_potion();
+            potions.push(potion);
+        }
+        Shop {
+            cards,
+            relics,
+            potions,
+            removal_price: Some(self.card_removal_price()),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use std::mem;
+
+use crate::{card::CardBody, fight::PostCardItem, game::Game, stance::Stance, util::insert_sorted};
+
+impl Game {
+    //Entering the stance the player is already in doesn't count as a change.
+    pub(super) fn change_stance(&mut self, stance: Stance) {
+        let old = self.fight.stance;
+        if old == stance {
+            return;
+        }
+        if old == Stance::Calm {
+            self.fight.energy += 2;
+        }
+        if stance == Stance::Divinity {
+            self.fight.energy += 3;
+        }
+        let rushdown = self.fight.player_buffs.rushdown;
+        if stance == Stance::Wrath && rushdown > 0 {
+            self.fight
+                .post_card_queue
+                .push_back(PostCardItem::Draw(rushdown));
+        }
+        self.fight.stance = stance;
+        self.player_gain_block(self.fight.player_buffs.mental_fortress, false);
+        self.return_from_discard(CardBody::FlurryOfBlows);
+    }
+
+    //Reaching 10 Mantra spends it to enter Divinity.
+    pub(super) fn gain_mantra(&mut self, amount: i32) {
+        self.fight.mantra += amount;
+        self.fight.mantra_gained += amount;
+        if self.fight.mantra >= 10 {
+            self.fight.mantra -= 10;
+            self.change_stance(Stance::Divinity);
+        }
+    }
+
+    //Used for Flurry of Blows and Weave which come back from the discard pile.
+    pub(super) fn return_from_discard(&mut self, body: CardBody) {
+        for card in mem::take(&mut self.fight.discard_pile) {
+            if card.body == body && self.fight.hand.len() < Game::MAX_CARDS_IN_HAND {
+                insert_sorted(card, &mut self.fight.hand);
+            } else {
+                self.fight.discard_pile.push(card);
+            }
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game::{Game, reward::Rewards},
+    relic::{Relic, RelicRarity},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChestSize {
+    Small,
+    Medium,
+    Large,
+}
+
+impl ChestSize {
+    //Chances out of 100 for a common, uncommon and rare relic.
+    fn relic_weights(&self) -> [u32; 3] {
+        match self {
+            ChestSize::Small => [75, 25, 0],
+            ChestSize::Medium => [35, 50, 15],
+            ChestSize::Large => [0, 75, 25],
+        }
+    }
+    //The chance out of 100 of gold and the base gold amount.
+    fn gold(&self) -> (i32, i32) {
+        match self {
+            ChestSize::Small => (50, 25),
+            ChestSize::Medium => (35, 50),
+            ChestSize::Large => (50, 75),
+        }
+    }
+}
+
+impl Game {
+    fn chest_relic(&mut self, size: ChestSize) -> Relic {
+        let rarity = match self.rng.sample_weighted(&size.relic_weights()) {
+            0 => RelicRarity::Common,
+            1 => RelicRarity::Uncommon,
+            _ => RelicRarity::Rare,
+        };
+        self.relics.pool.get_relic(&mut self.rng, rarity)
+    }
+
+    pub(super) fn roll_chest_size(&mut self) -> ChestSize {
+        match self.rng.sample_weighted(&[50, 33, 17]) {
+            0 => ChestSize::Small,
+            1 => ChestSize::Medium,
+            _ => ChestSize::Large,
+        }
+    }
+
+    //The contents of a chest are only rolled when it is opened.
+    pub(super) fn open_chest(&mut self, size: ChestSize) -> Rewards {
+        let mut rewards = Rewards::default();
+        let relic = self.chest_relic(size);
+        rewards.relics.push(relic);
+        //Matryoshka's counter is the number of chests it has added a relic to.
+        if let Some(opened) = self.relics.counter(Relic::Matryoshka)
+            && opened < 2
+        {
+            self.relics.set_counter(Relic::Matryoshka, opened + 1);
+            let relic = self.chest_relic(size);
+            rewards.relics.push(relic);
+        }
+        let (gold_chance, gold) = size.gold();
+        if self.rng.sample_i32(100) < gold_chance {
+            rewards.gold = gold * self.rng.sample_i32_inclusive(90, 110) / 100;
+        }
+        rewards
+    }
+}
@@ -33,9 +33,11 @@ mod enemies;
 mod fight;
 mod game;
 mod map;
+mod orb;
 mod potion;
 mod relic;
 mod rng;
+mod stance;
 mod ui;
 mod util;
 fn main() -> Result<(), Box<dyn Error>> {
@@ -84,7 +86,19 @@ async fn run_game<T: From<Arc<ChoiceState>>>(
     mut reciever: Receiver<GameAction>,
     sender: Sender<T>,
 ) {
-    let game = Game::new(Character::IRONCLAD);
+    //The character can be picked by passing its name as the first argument.
+    let charachter = std::env::args()
+        .nth(1)
+        .and_then(|arg| Character::from_name(&arg))
+        .unwrap_or(Character::IRONCLAD);
+    let ascension = std::env::args()
+        .find_map(|arg| arg.strip_prefix("--ascension=")?.parse().ok())
+        .unwrap_or(0);
+    let mut game = Game::new(charachter, ascension);
+    //Hiding intents shows how much the agent relies on them.
+    if std::env::args().any(|arg| arg == "--hidden-intents") {
+        game.set_hidden_intents(true);
+    }
     let game_seed = game.get_seed();
     let mut choice = Arc::new(game.start());
     let mut log = GameLog::new(game_seed);
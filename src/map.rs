@@ -8,11 +8,15 @@ pub const NUM_FLOORS: usize = 15;
 const SHOP_CHANCE: f32 = 0.05;
 const QUESTION_CHANCE: f32 = 0.22;
 const ELITE_CHANCE: f32 = 0.08;
+//Ascension 1 makes elites 60% more common.
+const ASCENSION_ELITE_CHANCE: f32 = ELITE_CHANCE * 1.6;
 const REST_CHANCE: f32 = 0.12;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ActMap {
     pub rooms: [[Room; ROW_WIDTH]; NUM_FLOORS],
+    //Act 4 is shorter than the other acts.
+    pub num_floors: usize,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -29,22 +33,64 @@ pub enum RoomType {
     Rest,
     Monster,
     Elite,
+    //The boss room isn't part of the map grid, it comes after the last floor.
+    Boss,
     #[default]
     Unassigned,
 }
 
 impl ActMap {
-    pub fn standard(rng: &mut Rng) -> Self {
+    pub fn standard(rng: &mut Rng, ascension: i32) -> Self {
         let mut res = Self {
             rooms: [[Room::default(); ROW_WIDTH]; NUM_FLOORS],
+            num_floors: NUM_FLOORS,
         };
-        res.create(rng);
+        let elite_chance = if ascension >= 1 {
+            ASCENSION_ELITE_CHANCE
+        } else {
+            ELITE_CHANCE
+        };
+        res.create(rng, elite_chance);
+        res
+    }
+
+    //Act 4 is a straight line of a rest site, a shop and the elite before the Heart.
+    pub fn act_4() -> Self {
+        let mut res = Self {
+            rooms: [[Room::default(); ROW_WIDTH]; NUM_FLOORS],
+            num_floors: 3,
+        };
+        let x = ROW_WIDTH / 2;
+        for (i, room_type) in [RoomType::Rest, RoomType::Shop, RoomType::Elite]
+            .into_iter()
+            .enumerate()
+        {
+            res.rooms[i][x].room_type = room_type;
+            res.rooms[i][x].reachable = true;
+            res.rooms[i][x].has_front_child = i < 2;
+        }
         res
     }
-    fn create(&mut self, rng: &mut Rng) {
+
+    //The Emerald Key is held by one of the act's elites.
+    pub fn mark_burning_elite(&mut self, rng: &mut Rng) {
+        let mut elites = Vec::new();
+        for i in 0..self.num_floors {
+            for j in 0..ROW_WIDTH {
+                if self.rooms[i][j].reachable && self.rooms[i][j].room_type == RoomType::Elite {
+                    elites.push((i, j));
+                }
+            }
+        }
+        if let Some(idx) = rng.try_sample(elites.len()) {
+            let (i, j) = elites[idx];
+            self.rooms[i][j].burning = true;
+        }
+    }
+    fn create(&mut self, rng: &mut Rng, elite_chance: f32) {
         self.create_paths(6, rng);
         self.assign_fixed_rows();
-        self.assign_remaining_random(rng);
+        self.assign_remaining_random(rng, elite_chance);
     }
 
     fn assign_fixed_rows(&mut self) {
@@ -59,7 +105,7 @@ impl ActMap {
         }
     }
 
-    fn assign_remaining_random(&mut self, rng: &mut Rng) {
+    fn assign_remaining_random(&mut self, rng: &mut Rng, elite_chance: f32) {
         let count = self.room_count_for_buckets() as f32;
         let mut bucket = Vec::new();
         for _ in 0..((count * SHOP_CHANCE).round_ties_even() as usize) {
@@ -68,7 +114,7 @@ impl ActMap {
         for _ in 0..((count * QUESTION_CHANCE).round_ties_even() as usize) {
             bucket.push(RoomType::QuestionMark);
         }
-        for _ in 0..((count * ELITE_CHANCE).round_ties_even() as usize) {
+        for _ in 0..((count * elite_chance).round_ties_even() as usize) {
             bucket.push(RoomType::Elite);
         }
         for _ in 0..((count * REST_CHANCE).round_ties_even() as usize) {
@@ -246,4 +292,5 @@ pub struct Room {
     pub has_right_child: bool,
     pub room_type: RoomType,
     pub reachable: bool,
+    pub burning: bool,
 }
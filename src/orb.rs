@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use crate::rng::Rng;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Orb {
+    Lightning,
+    Frost,
+    //Dark orbs store up damage each turn which is dealt when they are evoked.
+    Dark(i32),
+    Plasma,
+}
+
+impl Orb {
+    pub const MAX_SLOTS: i32 = 10;
+    //Dark orbs start with 6 damage stored.
+    pub const DARK: Orb = Orb::Dark(6);
+
+    pub fn random(rng: &mut Rng) -> Orb {
+        [Orb::Lightning, Orb::Frost, Orb::DARK, Orb::Plasma][rng.sample(4)]
+    }
+
+    //Used for Compile Driver, Dark orbs with different stored damage are the same kind.
+    pub fn same_kind(&self, other: &Orb) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
@@ -1,7 +1,146 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{game::Character, rng::Rng};
+
+//Potions which need card selection or discovery (Attack Potion, Elixir, Liquid Memories, etc.)
+//and the Watcher's Stance Potion aren't implemented yet.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Potion {
-    Placeholder1,
-    Placeholder2,
-    Placeholder3,
-    Placeholder4,
-    Placeholder5,
+    //Common
+    BlockPotion,
+    BloodPotion,
+    BottledMiracle,
+    DexterityPotion,
+    EnergyPotion,
+    ExplosivePotion,
+    FearPotion,
+    FirePotion,
+    FlexPotion,
+    FocusPotion,
+    PoisonPotion,
+    SpeedPotion,
+    StrengthPotion,
+    SwiftPotion,
+    WeakPotion,
+    //Uncommon
+    BlessingOfTheForge,
+    CunningPotion,
+    HeartOfIron,
+    LiquidBronze,
+    PotionOfCapacity,
+    //Rare
+    Ambrosia,
+    CultistPotion,
+    EntropicBrew,
+    EssenceOfDarkness,
+    FairyInABottle,
+    FruitJuice,
+    GhostInAJar,
+    SmokeBomb,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PotionRarity {
+    Common,
+    Uncommon,
+    Rare,
+}
+
+const COMMON_POTIONS: &[Potion] = &[
+    Potion::BlockPotion,
+    Potion::DexterityPotion,
+    Potion::EnergyPotion,
+    Potion::ExplosivePotion,
+    Potion::FearPotion,
+    Potion::FirePotion,
+    Potion::FlexPotion,
+    Potion::SpeedPotion,
+    Potion::StrengthPotion,
+    Potion::SwiftPotion,
+    Potion::WeakPotion,
+];
+const UNCOMMON_POTIONS: &[Potion] = &[Potion::BlessingOfTheForge, Potion::LiquidBronze];
+const RARE_POTIONS: &[Potion] = &[
+    Potion::CultistPotion,
+    Potion::EntropicBrew,
+    Potion::FairyInABottle,
+    Potion::FruitJuice,
+    Potion::SmokeBomb,
+];
+
+impl Potion {
+    pub fn rarity(&self) -> PotionRarity {
+        match self {
+            Potion::BlockPotion
+            | Potion::BloodPotion
+            | Potion::BottledMiracle
+            | Potion::DexterityPotion
+            | Potion::EnergyPotion
+            | Potion::ExplosivePotion
+            | Potion::FearPotion
+            | Potion::FirePotion
+            | Potion::FlexPotion
+            | Potion::FocusPotion
+            | Potion::PoisonPotion
+            | Potion::SpeedPotion
+            | Potion::StrengthPotion
+            | Potion::SwiftPotion
+            | Potion::WeakPotion => PotionRarity::Common,
+            Potion::BlessingOfTheForge
+            | Potion::CunningPotion
+            | Potion::HeartOfIron
+            | Potion::LiquidBronze
+            | Potion::PotionOfCapacity => PotionRarity::Uncommon,
+            Potion::Ambrosia
+            | Potion::CultistPotion
+            | Potion::EntropicBrew
+            | Potion::EssenceOfDarkness
+            | Potion::FairyInABottle
+            | Potion::FruitJuice
+            | Potion::GhostInAJar
+            | Potion::SmokeBomb => PotionRarity::Rare,
+        }
+    }
+
+    pub fn requires_target(&self) -> bool {
+        matches!(
+            self,
+            Potion::FearPotion | Potion::FirePotion | Potion::PoisonPotion | Potion::WeakPotion
+        )
+    }
+
+    //Fairy in a Bottle can't be drunk, it triggers when the player would die.
+    pub fn can_use(&self) -> bool {
+        *self != Potion::FairyInABottle
+    }
+
+    //A random potion for the character, using the in-game rarity odds of 65/25/10.
+    pub fn random(rng: &mut Rng, character: Character) -> Potion {
+        let (common_class, uncommon_class, rare_class): (&[Potion], &[Potion], &[Potion]) =
+            match character {
+                Character::IRONCLAD => (&[Potion::BloodPotion], &[Potion::HeartOfIron], &[]),
+                Character::SILENT => (
+                    &[Potion::PoisonPotion],
+                    &[Potion::CunningPotion],
+                    &[Potion::GhostInAJar],
+                ),
+                Character::DEFECT => (
+                    &[Potion::FocusPotion],
+                    &[Potion::PotionOfCapacity],
+                    &[Potion::EssenceOfDarkness],
+                ),
+                Character::WATCHER => (&[Potion::BottledMiracle], &[], &[Potion::Ambrosia]),
+            };
+        let (pool, class_pool) = match rng.sample_weighted(&[65, 25, 10]) {
+            0 => (COMMON_POTIONS, common_class),
+            1 => (UNCOMMON_POTIONS, uncommon_class),
+            _ => (RARE_POTIONS, rare_class),
+        };
+        let idx = rng.sample(pool.len() + class_pool.len());
+        if idx < pool.len() {
+            pool[idx]
+        } else {
+            class_pool[idx - pool.len()]
+        }
+    }
 }
@@ -13,10 +13,12 @@ macro_rules! make_relics {
                 )*
             }
 
+            //Each relic the player has stores a counter. It's part of the game state
+            //so relics like Pen Nib which count cards or turns hash correctly.
             #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
             pub struct RelicBar {
                 $(
-                    pub [<$x:lower>]: bool,
+                    pub [<$x:lower>]: Option<i32>,
                 )*
             }
 
@@ -24,24 +26,50 @@ macro_rules! make_relics {
                 pub fn new() -> Self {
                     RelicBar {
                         $(
-                            [<$x:lower>] : false,
+                            [<$x:lower>] : None,
                         )*
                     }
                 }
                 pub fn add(&mut self, relic: Relic) {
                     match relic {
                         $(
-                            Relic::$x => {self.[<$x:lower>] = true;}
+                            Relic::$x => {self.[<$x:lower>] = Some(0);}
                         )*
                     }
                 }
                 pub fn has_relic(&self, relic: Relic) -> bool {
+                    self.counter(relic).is_some()
+                }
+                pub fn counter(&self, relic: Relic) -> Option<i32> {
                     match relic {
                         $(
                             Relic::$x => self.[<$x:lower>],
                         )*
                     }
                 }
+                pub fn counter_mut(&mut self, relic: Relic) -> Option<&mut i32> {
+                    match relic {
+                        $(
+                            Relic::$x => self.[<$x:lower>].as_mut(),
+                        )*
+                    }
+                }
+                pub fn remove(&mut self, relic: Relic) {
+                    match relic {
+                        $(
+                            Relic::$x => {self.[<$x:lower>] = None;}
+                        )*
+                    }
+                }
+                pub fn list(&self) -> Vec<Relic> {
+                    let mut relics = Vec::new();
+                    $(
+                        if self.[<$x:lower>].is_some() {
+                            relics.push(Relic::$x);
+                        }
+                    )*
+                    relics
+                }
             }
         }
     }
@@ -54,17 +82,56 @@ pub struct Relics {
 
 impl Relics {
     pub fn new(character: Character) -> Self {
+        let mut bar = RelicBar::new();
+        bar.add(Self::starter_relic(character));
         Self {
-            bar: RelicBar::new(),
+            bar,
             pool: RelicPool::new(character),
         }
     }
+    pub fn starter_relic(character: Character) -> Relic {
+        match character {
+            Character::IRONCLAD => Relic::BurningBlood,
+            Character::SILENT => Relic::RingoftheSnake,
+            Character::DEFECT => Relic::CrackedCore,
+            Character::WATCHER => Relic::PureWater,
+        }
+    }
     pub fn add(&mut self, relic: Relic) {
         self.bar.add(relic);
     }
+    pub fn remove(&mut self, relic: Relic) {
+        self.bar.remove(relic);
+    }
     pub fn has_relic(&self, relic: Relic) -> bool {
         self.bar.has_relic(relic)
     }
+    //The relic's counter, or None if the player doesn't have it.
+    pub fn counter(&self, relic: Relic) -> Option<i32> {
+        self.bar.counter(relic)
+    }
+    //Does nothing if the player doesn't have the relic.
+    pub fn set_counter(&mut self, relic: Relic, value: i32) {
+        if let Some(counter) = self.bar.counter_mut(relic) {
+            *counter = value;
+        }
+    }
+    //Counts up for relics like Pen Nib which trigger every few cards or turns.
+    //Returns true and resets the counter when it reaches the period.
+    pub fn tick(&mut self, relic: Relic, period: i32) -> bool {
+        let Some(counter) = self.bar.counter_mut(relic) else {
+            return false;
+        };
+        *counter += 1;
+        if *counter >= period {
+            *counter = 0;
+            return true;
+        }
+        false
+    }
+    pub fn list(&self) -> Vec<Relic> {
+        self.bar.list()
+    }
 }
 
 macro_rules! relic_segments {
@@ -380,6 +447,7 @@ relic_segments!(
     HolyWater,
     VioletLotus);
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum RelicRarity {
     Common,
     Uncommon,
@@ -41,8 +41,11 @@ impl Rng {
         if bound == 1 {
             return Some(0);
         }
-        let next_pow_2 = bound.next_power_of_two();
-        let mask = next_pow_2 - 1;
+        //Events roll with a bound of usize::MAX, which has no next power of two.
+        let mask = match bound.checked_next_power_of_two() {
+            Some(next_pow_2) => next_pow_2 - 1,
+            None => usize::MAX,
+        };
         loop {
             let rand = { self.rng.next_u64() };
             let rand = mask & (rand as usize);
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum Stance {
+    #[default]
+    Neutral,
+    //Leaving Calm gives 2 energy.
+    Calm,
+    Wrath,
+    //Entering Divinity gives 3 energy, it is left at the start of the next turn.
+    Divinity,
+}
+
+impl Stance {
+    pub fn damage_dealt_multiplier(&self) -> f32 {
+        match self {
+            Stance::Wrath => 2.0,
+            Stance::Divinity => 3.0,
+            _ => 1.0,
+        }
+    }
+
+    //Only Wrath increases the damage the player takes.
+    pub fn damage_taken_multiplier(&self) -> f32 {
+        match self {
+            Stance::Wrath => 2.0,
+            _ => 1.0,
+        }
+    }
+}
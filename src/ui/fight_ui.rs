@@ -7,12 +7,12 @@ use fliptui::{Element, Node, taffy};
 
 use crate::card::{Card, SelectCardEffect};
 use crate::fight::PlayCardContext;
-use crate::game::Game;
 use crate::game::choice::{
     ChooseEnemyAction, EventAction, MapStateAction, PlayCardAction, RestSiteAction,
     SelectCardAction, SelectDeckCardReason, SelectionPile,
 };
 use crate::game::event::Event;
+use crate::game::{Character, Game};
 use crate::map::{self, NUM_FLOORS, ROW_WIDTH};
 use crate::ui::ui_actor::UICtx;
 
@@ -41,7 +41,10 @@ fn matches_rotated_key(event: KeyEvent, idx: usize) -> bool {
     event.code == KeyCode::Char(char::from_digit(rotate_key(idx), 10).expect("Number is in-bounds"))
 }
 
-fn render_player(widget: &mut impl Element, state: &UICtx) {
+//Potions are used with the keys in this string and discarded with the same keys while holding shift.
+const POTION_KEYS: &str = "qwert";
+
+fn render_player(widget: &mut impl Element, state: &UICtx, play_card_actions: &[PlayCardAction]) {
     let game = state.game();
     BorderWidget::builder(widget, |center| {
         writeln!(center.cursor(), "{}", game.charachter().name());
@@ -53,13 +56,75 @@ fn render_player(widget: &mut impl Element, state: &UICtx) {
         );
         writeln!(center.cursor(), "{} energy", game.fight().energy());
         writeln!(center.cursor(), "{} block", game.fight().player_block());
+        let focus = game.fight().player_buffs().focus;
+        if focus != 0 {
+            writeln!(center.cursor(), "{} focus", focus);
+        }
+        if game.charachter() == &Character::WATCHER {
+            writeln!(center.cursor(), "{:?} stance", game.fight().stance());
+            writeln!(center.cursor(), "{} mantra", game.fight().mantra());
+        }
+        if *game.fight().orb_slots() > 0 {
+            let orbs = game.fight().orbs();
+            writeln!(
+                center.cursor(),
+                "orbs {}/{}",
+                orbs.len(),
+                game.fight().orb_slots()
+            );
+            for orb in orbs {
+                writeln!(center.cursor(), "  {:?}", orb);
+            }
+        }
         if let Some(position) = game.act().position {
             writeln!(center.cursor(), "{} block", game.fight().player_block());
-            writeln!(center.cursor(), "floor {}", position.y);
+            writeln!(
+                center.cursor(),
+                "act {} floor {}",
+                game.act().number,
+                position.y
+            );
+        }
+        let keys = game.keys();
+        if keys.ruby || keys.emerald || keys.sapphire {
+            writeln!(
+                center.cursor(),
+                "keys:{}{}{}",
+                if keys.ruby { " ruby" } else { "" },
+                if keys.emerald { " emerald" } else { "" },
+                if keys.sapphire { " sapphire" } else { "" }
+            );
+        }
+        for (slot, potion) in game.potions().iter().enumerate() {
+            let key = POTION_KEYS.chars().nth(slot).unwrap_or(' ');
+            writeln!(center.cursor(), "{key}: {:?}", potion);
+        }
+        for relic in game.relics().list() {
+            if let Some(counter) = game.relics().counter(relic.clone())
+                && counter > 0
+            {
+                writeln!(center.cursor(), "{:?} {}", relic, counter);
+            }
         }
     })
     .title("Player")
     .build();
+    for (action_idx, action) in play_card_actions.iter().enumerate() {
+        let (slot, shift) = match *action {
+            PlayCardAction::UsePotion(slot) => (slot, false),
+            PlayCardAction::DiscardPotion(slot) => (slot, true),
+            _ => continue,
+        };
+        let Some(key) = POTION_KEYS.chars().nth(slot as usize) else {
+            continue;
+        };
+        let key = if shift { key.to_ascii_uppercase() } else { key };
+        widget.key_press(|event| {
+            if event.code == KeyCode::Char(key) {
+                state.set_action(action_idx);
+            }
+        });
+    }
 }
 
 fn render_card(
@@ -150,12 +215,91 @@ fn render_enemy(
         if enemy.buffs.curl_up > 0 {
             writeln!(center.cursor(), "{} curl up", enemy.buffs.curl_up);
         }
+        if enemy.buffs.mode_shift > 0 {
+            writeln!(center.cursor(), "{} mode shift", enemy.buffs.mode_shift);
+        }
+        if enemy.buffs.sharp_hide > 0 {
+            writeln!(center.cursor(), "{} sharp hide", enemy.buffs.sharp_hide);
+        }
+        if enemy.buffs.metallicize > 0 {
+            writeln!(center.cursor(), "{} metallicize", enemy.buffs.metallicize);
+        }
+        if enemy.buffs.plated_armor > 0 {
+            writeln!(center.cursor(), "{} plated armor", enemy.buffs.plated_armor);
+        }
+        if enemy.buffs.flying > 0 {
+            writeln!(center.cursor(), "{} flying", enemy.buffs.flying);
+        }
+        if enemy.buffs.malleable > 0 {
+            writeln!(
+                center.cursor(),
+                "{} malleable",
+                enemy.buffs.malleable + enemy.buffs.malleable_hits
+            );
+        }
+        if let Some(card) = &enemy.buffs.stasis {
+            writeln!(center.cursor(), "stasis {:?}", card.body);
+        }
+        if enemy.buffs.minion {
+            writeln!(center.cursor(), "minion");
+        }
+        if enemy.buffs.half_dead {
+            writeln!(center.cursor(), "half dead");
+        }
+        if enemy.buffs.intangible > 0 {
+            writeln!(center.cursor(), "intangible");
+        }
+        if enemy.buffs.slow {
+            writeln!(center.cursor(), "{} slow", enemy.buffs.slow_stacks);
+        }
+        if enemy.buffs.time_warp {
+            writeln!(
+                center.cursor(),
+                "{}/12 time warp",
+                enemy.buffs.time_warp_cards
+            );
+        }
+        if enemy.buffs.invincible > 0 {
+            writeln!(
+                center.cursor(),
+                "{} invincible",
+                enemy.buffs.invincible_left
+            );
+        }
+        if enemy.buffs.beat_of_death > 0 {
+            writeln!(
+                center.cursor(),
+                "{} beat of death",
+                enemy.buffs.beat_of_death
+            );
+        }
+        if enemy.buffs.regenerate > 0 {
+            writeln!(center.cursor(), "{} regenerate", enemy.buffs.regenerate);
+        }
+        if enemy.buffs.artifact > 0 {
+            writeln!(center.cursor(), "{} artifact", enemy.buffs.artifact);
+        }
+        if enemy.buffs.fading > 0 {
+            writeln!(center.cursor(), "{} fading", enemy.buffs.fading);
+        }
         if enemy.debuffs.vulnerable > 0 {
             writeln!(center.cursor(), "{} vulnerable", enemy.debuffs.vulnerable);
         }
         if enemy.debuffs.weak > 0 {
             writeln!(center.cursor(), "{} weak", enemy.debuffs.weak);
         }
+        if enemy.debuffs.poison > 0 {
+            writeln!(center.cursor(), "{} poison", enemy.debuffs.poison);
+        }
+        if enemy.debuffs.choked > 0 {
+            writeln!(center.cursor(), "{} choked", enemy.debuffs.choked);
+        }
+        if enemy.debuffs.lock_on > 0 {
+            writeln!(center.cursor(), "{} lock-on", enemy.debuffs.lock_on);
+        }
+        if enemy.debuffs.mark > 0 {
+            writeln!(center.cursor(), "{} mark", enemy.debuffs.mark);
+        }
         if action_idx.is_some() {
             writeln!(center.cursor(), "Key {:?}", rotate_key(enemy_idx));
         }
@@ -248,6 +392,34 @@ fn render_rest_site_action(
         }
     });
 }
+//Renders a column of actions described by their labels.
+fn render_action_list(widget: &mut impl Element, ui_ctx: &UICtx, title: &str, labels: Vec<String>) {
+    let top = widget.child(|child| {
+        writeln!(child.cursor(), "{title}");
+    });
+    let middle = widget.child(|child| {
+        child
+            .layout()
+            .flex_direction(FlexDirection::Column)
+            .align_items(taffy::AlignItems::Center);
+        for (action_idx, label) in labels.iter().enumerate() {
+            child.child(|child| {
+                BorderWidget::builder(child, |center| {
+                    writeln!(center.cursor(), "{:?}: {}", rotate_key(action_idx), label);
+                    center.key_press(|event| {
+                        if matches_rotated_key(event, action_idx) {
+                            ui_ctx.set_action(action_idx);
+                        }
+                    });
+                })
+                .build();
+            });
+        }
+    });
+    let bottom = widget.child(|_child| {});
+    style_vertical_breakdown(widget, &mut [top, middle, bottom]);
+}
+
 fn render_game_over(widget: &mut impl Element, ui_ctx: &UICtx) {
     let top = widget.child(|_child| {});
     let middle = widget.child(|child| {
@@ -283,7 +455,7 @@ fn render_battlefield(
     let middle = widget.child(|child| {
         child.layout().push_grid_template_row_fr(1.0);
         let player_box = child.child(|child| {
-            render_player(child, ui_ctx);
+            render_player(child, ui_ctx, &play_card_actions);
         });
         let fight_box = child.child(|child| {
             render_enemies(child, ui_ctx, choose_enemy_actions);
@@ -341,7 +513,8 @@ pub fn render_map_state(
     for _ in 0..ROW_WIDTH {
         widget.layout().push_grid_template_row_fr(1.0);
     }
-    for _ in 0..NUM_FLOORS {
+    //The extra floor is for the boss.
+    for _ in 0..NUM_FLOORS + 1 {
         //First column is for actions, second is for rooms.
         widget.layout().push_grid_template_column_px(1);
         widget.layout().push_grid_template_column_fr(1.0);
@@ -361,6 +534,31 @@ pub fn render_map_state(
             });
         }
     }
+    let boss_row = ROW_WIDTH / 2;
+    widget.child(|child| {
+        child.layout().grid_row(boss_row).grid_col(NUM_FLOORS * 2);
+        let at_last_floor = ui_ctx
+            .game()
+            .act()
+            .position
+            .is_some_and(|pos| pos.y as usize == ui_ctx.game().map().num_floors - 1);
+        if at_last_floor {
+            let rotated = rotate_key(0);
+            child.child(|child| writeln!(child.cursor(), "{rotated}"));
+            child.key_press(|event| {
+                if matches_rotated_key(event, 0) {
+                    ui_ctx.set_action(0);
+                }
+            });
+        }
+    });
+    widget.child(|child| {
+        child
+            .layout()
+            .grid_row(boss_row)
+            .grid_col(NUM_FLOORS * 2 + 1);
+        writeln!(child.cursor(), "{:?}", ui_ctx.game().act().boss);
+    });
 }
 
 fn draw_room_actions(
@@ -431,7 +629,9 @@ fn draw_room(widget: &mut impl Element, ui_ctx: &UICtx, i: usize, j: usize) {
         map::RoomType::Treasure => "Chest",
         map::RoomType::Rest => "Rest",
         map::RoomType::Monster => "Fight",
+        map::RoomType::Elite if room.burning && !game.keys().emerald => "Elite*",
         map::RoomType::Elite => "Elite",
+        map::RoomType::Boss => "Boss",
         map::RoomType::Unassigned => "",
     };
     widget
@@ -450,7 +650,7 @@ fn draw_room(widget: &mut impl Element, ui_ctx: &UICtx, i: usize, j: usize) {
 fn render_select_card_state(
     widget: &mut impl Element,
     ui_ctx: &UICtx,
-    _play_card_context: PlayCardContext,
+    _play_card_context: Option<PlayCardContext>,
     select_card_effect: SelectCardEffect,
     select_card_actions: Vec<SelectCardAction>,
     selection_pile: SelectionPile,
@@ -464,10 +664,16 @@ fn render_select_card_state(
         );
     });
     let middle = widget.child(|child| {
+        let deck_cards;
         let cards = match selection_pile {
             SelectionPile::Hand => ui_ctx.game().fight().hand(),
             SelectionPile::Discard => ui_ctx.game().fight().discard_pile(),
             SelectionPile::Exhaust => ui_ctx.game().fight().exhaust(),
+            SelectionPile::Offered => ui_ctx.game().fight().offered(),
+            SelectionPile::Deck => {
+                deck_cards = ui_ctx.game().fight().deck().distinct_cards();
+                &deck_cards
+            }
         };
         render_card_view_inner(child, ui_ctx, cards, select_card_actions);
     });
@@ -511,6 +717,11 @@ fn render_event_inner(
                     rotate_key(action_idx),
                     event.action_str(ui_ctx.game(), *action)
                 );
+                child.key_press(|key| {
+                    if matches_rotated_key(key, action_idx) {
+                        ui_ctx.set_action(action_idx);
+                    }
+                });
             })
             .build();
         });
@@ -523,7 +734,8 @@ pub fn draw_game(widget: &mut impl Element, ui_ctx: &UICtx) {
                 render_battlefield(elem, ui_ctx, play_card_actions, vec![]);
             });
         }
-        crate::game::choice::Choice::ChooseEnemyState(choose_enemy_actions, _) => {
+        crate::game::choice::Choice::ChooseEnemyState(choose_enemy_actions, _)
+        | crate::game::choice::Choice::ChoosePotionTargetState(choose_enemy_actions, _) => {
             widget.child(|elem| {
                 render_battlefield(elem, ui_ctx, vec![], choose_enemy_actions);
             });
@@ -560,6 +772,15 @@ pub fn draw_game(widget: &mut impl Element, ui_ctx: &UICtx) {
                 );
             });
         }
+        crate::game::choice::Choice::ScryState(_, actions) => {
+            let game = ui_ctx.game();
+            let card = &game.fight().scrying()[0];
+            let labels = actions.iter().map(|a| format!("{:?}", a)).collect();
+            let title = format!("Scry {:?}", card.body);
+            widget.child(|elem| {
+                render_action_list(elem, ui_ctx, &title, labels);
+            });
+        }
         crate::game::choice::Choice::Event(event, event_actions) => {
             render_event(widget, ui_ctx, event, event_actions);
         }
@@ -573,5 +794,32 @@ pub fn draw_game(widget: &mut impl Element, ui_ctx: &UICtx) {
                 render_rest_site(elem, ui_ctx, actions);
             });
         }
+        crate::game::choice::Choice::Rewards(rewards, actions) => {
+            let labels = actions.iter().map(|a| rewards.action_str(*a)).collect();
+            widget.child(|elem| {
+                render_action_list(elem, ui_ctx, "Rewards", labels);
+            });
+        }
+        crate::game::choice::Choice::Shop(shop, actions) => {
+            let game = ui_ctx.game();
+            let labels = actions.iter().map(|a| shop.action_str(game, *a)).collect();
+            let title = format!("Shop - {} gold", game.gold());
+            widget.child(|elem| {
+                render_action_list(elem, ui_ctx, &title, labels);
+            });
+        }
+        crate::game::choice::Choice::Treasure(size, actions) => {
+            let labels = actions.iter().map(|a| format!("{:?}", a)).collect();
+            let title = format!("{:?} Chest", size);
+            widget.child(|elem| {
+                render_action_list(elem, ui_ctx, &title, labels);
+            });
+        }
+        crate::game::choice::Choice::BossRelic(relics, actions) => {
+            let labels = actions.iter().map(|a| a.action_str(&relics)).collect();
+            widget.child(|elem| {
+                render_action_list(elem, ui_ctx, "Boss Relic", labels);
+            });
+        }
     }
 }
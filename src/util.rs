@@ -1,10 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-use crate::card::Card;
-
-pub fn insert_sorted(card: Card, vec: &mut Vec<Card>) {
-    let pos = vec.binary_search(&card).unwrap_or_else(|e| e);
-    vec.insert(pos, card);
+pub fn insert_sorted<T: Ord>(item: T, vec: &mut Vec<T>) {
+    let pos = vec.binary_search(&item).unwrap_or_else(|e| e);
+    vec.insert(pos, item);
 }
 
 //This represents a game state and the action taken in that state.